@@ -4,19 +4,49 @@ select_image_to_preview = Ein Terminal-Bildbetrachter. Tastenkombinationen:
 keys_navigation = ↑/k/←: Nach oben navigieren, ↓/j/→: Nach unten navigieren
 keys_page_navigation = Bild auf/Bild ab: Seitenweise springen
 keys_jump_navigation = f/b: Um 10 Dateien vor/zurück springen
+keys_fuzzy_search = Strg+F: Datei mit fzf suchen (falls installiert)
+keys_command_prompt = :q / :e config: Beenden / Konfiguration in $EDITOR bearbeiten
 keys_home_end_navigation = Pos1: Zum Anfang, Ende: Zum Ende
+keys_chords = gg: Zum Anfang, gd: Übergeordnetes Verzeichnis, dd: Datei löschen
 keys_sort = d: Nach Datum sortieren (neueste/älteste umschalten), n: Nach Name sortieren
+keys_content_filter = Strg+T: Nur Bilder, nur Text, nur Dateien ohne Vorschau oder alle anzeigen
 keys_enter_directory = Enter: Verzeichnis öffnen
 keys_backspace_parent_dir = Backspace: Ein Verzeichnis höher gehen
 keys_resize_window = [ und ]: Fensterrahmen vergrößern/verkleinern
 keys_refresh_image = r: Bild aktualisieren
 keys_save_ascii = s: ASCII-Datei speichern
+keys_batch_export = B: ASCII-Art für alle Bilder im Verzeichnis exportieren
 keys_delete_file = x: Aktuelle Datei löschen
+keys_copy_path = c / Strg+C: Dateipfad in die Zwischenablage kopieren
+keys_copy_preview = Strg+Y / Alt+Y: ASCII-Art kopieren (Alt: ohne Farben)
+keys_checksum = C: SHA-256-Prüfsumme der Datei anzeigen
+keys_directory_size = Strg+D: Gesamtgröße eines Verzeichnisses anzeigen
 keys_open_in_browser = o: In System-Dateibrowser öffnen
+keys_open_with = O: Mit ausgewählter Anwendung öffnen
+keys_charset_picker = Alt+C: Zeichensatz für jp2a wählen
+keys_permissions = P: Berechtigungen der ausgewählten Datei ändern
+keys_average_color = A: Durchschnittsfarbe des Bildes im Debug-Bereich anzeigen
+keys_metadata = i: Bildmetadaten anzeigen (EXIF, wenn exiftool installiert ist)
+keys_recent_files = Alt+R: Zuletzt angesehene Dateien
+keys_dual_pane = Strg+\: Zweiten Bereich öffnen oder schließen (Tab wechselt, Enter kopiert/verschiebt)
 keys_slideshow = Leertaste: Diashow starten/stoppen (nur Bilder)
+keys_slideshow_shuffle = z: Diashow-Reihenfolge mischen (in der Diashow)
+keys_slideshow_loop = l: Diashow-Wiederholung umschalten (in der Diashow)
+keys_slideshow_filename = t: Dateinamen-Einblendung umschalten (in Diashow)
+keys_slideshow_speed = +/-: Längere/kürzere Diashow-Verzögerung (in der Diashow)
+keys_slideshow_save_frame = Strg+S: Angezeigtes Bild als .ascii speichern (in der Diashow), sonst die Diashow-Verzögerung speichern
+keys_slideshow_export_html = Strg+E: Diashow als animierte HTML-Seite exportieren (in der Diashow)
+keys_fullscreen = F: Vollbildvorschau umschalten
+keys_grid_view = G: Miniaturansicht als Raster umschalten
+keys_debug_pane = D: Debug-Bereich zwischen Datei, Konverter, Cache und Meldungen wechseln
+keys_animation_pause = p: GIF-Animation anhalten/fortsetzen
+keys_tiff_pages = < / >: Vorherige/nächste TIFF-Seite oder ICO-Größe
 keys_text_scroll = u: Text nach oben scrollen, Leertaste: Text nach unten scrollen
+keys_text_scroll_line = J / K: Text eine Zeile nach unten / oben scrollen
 keys_converter_cycle = Tab: Konverter wechseln
 keys_help_toggle = ?: Hilfe anzeigen
+help_scroll_up = u: Hilfe nach oben scrollen
+help_scroll_down = Leertaste / d: Hilfe nach unten scrollen
 keys_quit = q/Esc: Beenden
 not_supported_file_type = Die ausgewählte Datei ist keine Bilddatei, Textdatei oder ASCII-Datei
 selected_file_not_image = Die ausgewählte Datei ist keine Bilddatei
@@ -26,18 +56,136 @@ file_type_not_supported = Dateityp wird für Vorschau nicht unterstützt
 unknown_file_type = Unbekannter Dateityp
 ascii_file_prefix = ASCII-Datei: 
 text_file_prefix = Textdatei: 
+text_file_unreadable = Fehler: Datei konnte nicht geöffnet werden
 image_file_prefix = Bild: 
+video_file_prefix = Video: 
+video_preview_failed = Videobild konnte nicht extrahiert werden (ist ffmpeg installiert?)
+audio_file_prefix = Audio: 
+audio_preview_failed = Audiodetails konnten nicht gelesen werden (ist ffprobe installiert?)
+audio_title = Titel: {$value}
+audio_artist = Interpret: {$value}
+audio_album = Album: {$value}
+audio_duration = Dauer: {$value}
+audio_bitrate = Bitrate: {$kbps} kb/s
+audio_sample_rate = Abtastrate: {$hz} Hz
+preview_loading_timed_out = Vorschau nach {$ms}ms abgebrochen
+clipboard_copied_path = Pfad in die Zwischenablage kopiert: {$path}
+clipboard_unavailable = Zwischenablage nicht verfügbar: {$error}
+clipboard_not_compiled = Zwischenablage-Unterstützung nicht einkompiliert
+clipboard_copied_ascii = ASCII-Art kopiert ({$count} Zeichen)
+clipboard_no_preview = Keine Vorschau zum Kopieren
+heic_requires_converter = HEIC: erfordert {$binary}
+raw_requires_extractor = RAW-Vorschau: dcraw oder darktable installieren
+tiff_page = Seite {$page} von {$total}
+tiff_single_page = Einseitiges TIFF
+ico_sizes = ICO: {$sizes} (Größe {$index} wird angezeigt)
+ico_single_size = ICO mit nur einer Größe
+ico_sizes_unavailable = ImageMagick installieren, um zwischen ICO-Größen zu wechseln
 slideshow_mode = Diashow-Modus
-slideshow_image = Bild
-slideshow_press_any_key = Beliebige Taste zum Beenden drücken
+slideshow_image_of = Bild {$current}/{$total}
+slideshow_hint_prev = zurück
+slideshow_hint_next = weiter
+slideshow_hint_exit = beenden
+slideshow_hint_shuffle = mischen
+slideshow_hint_loop = Schleife
+slideshow_hint_name = Name
+slideshow_hint_speed = Tempo
+slideshow_no_images = Fehler: keine Bilder für die Diashow gefunden
+slideshow_shuffle_on = Zufallswiedergabe: AN
+slideshow_shuffle_off = Zufallswiedergabe: AUS
+slideshow_loop_on = Wiederholen: AN
+slideshow_loop_off = Wiederholen: AUS
+slideshow_filename_overlay = Datei: {$file}
+slideshow_delay = Verzögerung: {$seconds}s
+slideshow_delay_saved = Diashow-Verzögerung in der Konfiguration gespeichert
+slideshow_frame_saved = Bild gespeichert: {$file}
+slideshow_html_exported = Diashow gespeichert: {$file}
+slideshow_html_exported_partly = Diashow gespeichert: {$file} ({$failed} fehlgeschlagen, siehe {$log})
+slideshow_html_failed = Diashow konnte nicht exportiert werden: {$error}
+fullscreen_mode = Vollbildvorschau
+animation_paused = Animation angehalten
+animation_resumed = Animation fortgesetzt
 messages = Nachrichten
+debug_pane_file_info = Dateiinfo
+debug_pane_converter_info = Konverter
+debug_pane_cache_stats = Cache
+debug_pane_custom = Notizen
+debug_file_info = {$name} | {$size} | {$dimensions} | Geändert vor {$age}
+debug_converter_info = Konverter: {$converter} | Ausgabe: {$output} | Letzte Konvertierung: {$ms} ms
+debug_cache_stats = Treffer: {$hits} | Fehlschläge: {$misses} | Einträge: {$entries}/{$capacity}
+cache_hit_rate = Trefferquote: {$percent}%
+files_selected = {$count} Dateien ausgewählt
 ptui_ready = PTUI bereit...
 saved_to = Gespeichert unter:
-delete_file_prompt = Datei {$file} löschen?
+delete_file_prompt = Datei {$file} endgültig löschen?
+trash_file_prompt = {$file} in den Papierkorb verschieben?
 delete_confirmation_instructions = Drücken Sie 'y' zum Bestätigen, 'n' oder Esc zum Abbrechen
 delete_confirmation_title = Löschen bestätigen
 date_sort_newest_first = Datumssortierung geändert: neueste zu älteste
 date_sort_oldest_first = Datumssortierung geändert: älteste zu neueste
+content_filter_all = Alle Dateien werden angezeigt
+content_filter_images_only = Nur Bilder werden angezeigt
+content_filter_text_only = Nur Textdateien werden angezeigt
+content_filter_non_previewable = Nur Dateien ohne Vorschau werden angezeigt
 opened_file_in_browser = Datei im System-Browser geöffnet
 opened_directory_in_browser = Verzeichnis im System-Browser geöffnet
 failed_to_open_in_browser = Fehler beim Öffnen im System-Browser
+opened_with = {$file} mit {$app} geöffnet
+open_with_failed = Öffnen mit {$app} fehlgeschlagen
+fuzzy_search_failed = Unscharfe Suche fehlgeschlagen: { $error }
+open_with_title = {$file} öffnen mit
+open_with_instructions = j/k zum Auswählen, Enter zum Öffnen, Esc zum Abbrechen
+charset_picker_title = jp2a-Zeichensatz
+charset_picker_instructions = j/k zum Auswählen, Enter zum Übernehmen, Esc zum Abbrechen
+charset_custom_instructions = Zeichen eingeben, dunkelstes zuerst, Enter zum Übernehmen, Esc zurück
+permissions_title = Berechtigungen von {$file}
+permissions_current = Aktueller Modus: {$mode}
+permissions_instructions = 3-stelligen Oktalmodus eingeben, Enter zum Übernehmen, Esc zum Abbrechen
+permissions_changed = Berechtigungen von {$file} auf {$mode} gesetzt
+permissions_failed = Berechtigungen von {$file} konnten nicht geändert werden: {$error}
+permissions_unsupported = Berechtigungen können auf diesem System nicht geändert werden
+jp2a_charset_changed = jp2a-Zeichensatz: {$charset}
+debug_jp2a_charset = Zeichensatz: {$charset}
+debug_average_color = Mittel: {$hex}
+open_with_none = Keine Anwendungen zum Öffnen dieser Datei gefunden
+checksum_computing = Prüfsumme wird berechnet…
+checksum_result = SHA-256: {$hash}
+checksum_failed = Prüfsumme fehlgeschlagen: {$error}
+directory_size_calculating = Wird berechnet…
+directory_size_result = Größe: {$size} ({$count} Dateien)
+directory_size_timed_out = Größenberechnung nach {$seconds}s abgebrochen
+directory_image_count = {$count ->
+    [one] {$count} Bild
+   *[other] {$count} Bilder
+}
+recent_files_title = Zuletzt angesehene Dateien
+recent_files_instructions = j/k zum Auswählen, Enter zum Öffnen, c zum Leeren, Esc zum Schließen
+recent_files_empty = Keine zuletzt angesehenen Dateien
+recent_file_missing = Datei existiert nicht mehr: {$file}
+dual_pane_mode = Zwei Bereiche: Tab wechselt den Bereich, Enter kopiert oder verschiebt eine Datei hinüber
+dual_pane_closed = Zweiter Bereich geschlossen
+transfer_title = {$file} kopieren oder verschieben
+copy_to_secondary = c: Nach {$dir} kopieren
+move_to_secondary = m: Nach {$dir} verschieben
+transfer_cancel = Esc: Abbrechen
+file_copied = {$file} nach {$dir} kopiert
+file_moved = {$file} nach {$dir} verschoben
+transfer_failed = {$file} konnte nicht kopiert oder verschoben werden: {$error}
+batch_export_title = ASCII-Art für {$count} Bilder exportieren
+batch_export_skip_existing = Enter: Exportieren, Bilder mit vorhandener .ascii-Datei überspringen
+batch_export_overwrite = o: Exportieren und vorhandene .ascii-Dateien überschreiben
+batch_export_progress = Exportiere: {$current}/{$total} ({$file})
+batch_export_done = Stapelexport fertig: {$succeeded} erfolgreich, {$failed} fehlgeschlagen, {$skipped} übersprungen
+batch_export_no_images = Keine Bilder zum Exportieren in diesem Verzeichnis
+batch_export_running = Ein Stapelexport läuft bereits
+command_prompt = q: beenden | e config: Konfiguration bearbeiten | Esc: abbrechen
+command_unknown = Unbekannter Befehl: {$command}
+command_editor_failed = Konfiguration konnte nicht bearbeitet werden: {$error}
+metadata_title = Bildinformationen
+metadata_exif_title = EXIF (exiftool)
+metadata_format = Format
+metadata_dimensions = Abmessungen
+metadata_size = Dateigröße
+metadata_modified = Geändert
+metadata_modified_ago = vor {$age}
+metadata_unknown = unbekannt
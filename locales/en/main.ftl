@@ -4,19 +4,49 @@ select_image_to_preview = A terminal image viewer. Keyboard shortcuts:
 keys_navigation = ↑/k/←: Navigate up, ↓/j/→: Navigate down
 keys_page_navigation = Page Up/Page Down: Jump by page
 keys_jump_navigation = f/b: Jump forward/back by 10 files
+keys_fuzzy_search = Ctrl+F: Find a file with fzf (when installed)
+keys_command_prompt = :q / :e config: Quit / edit the config in $EDITOR
 keys_home_end_navigation = Home: Go to start, End: Go to end
+keys_chords = gg: Go to start, gd: Parent directory, dd: Delete file
 keys_sort = d: Sort by date (toggle newest/oldest), n: Sort by name
+keys_content_filter = Ctrl+T: Show only images, only text, only files without a preview, or all
 keys_enter_directory = Enter: Open directory
 keys_backspace_parent_dir = Backspace: Go up directory
 keys_resize_window = [ and ]: Resize window frame
 keys_refresh_image = r: Refresh image
 keys_save_ascii = s: Save ASCII file
+keys_batch_export = B: Export ASCII art for every image in the directory
 keys_delete_file = x: Delete current file
+keys_copy_path = c / Ctrl+C: Copy file path to clipboard
+keys_copy_preview = Ctrl+Y / Alt+Y: Copy ASCII art to clipboard (Alt: without colours)
+keys_checksum = C: Show the file's SHA-256 checksum
+keys_directory_size = Ctrl+D: Show the total size of a directory
 keys_open_in_browser = o: Open in system file browser
+keys_open_with = O: Open with a chosen application
+keys_charset_picker = Alt+C: Choose the character set jp2a draws with
+keys_permissions = P: Change the selected file's permissions
+keys_average_color = A: Show the selected image's average colour in the debug pane
+keys_metadata = i: Show image metadata (EXIF when exiftool is installed)
+keys_recent_files = Alt+R: Recent files
+keys_dual_pane = Ctrl+\: Open or close a second pane (Tab switches, Enter copies/moves)
 keys_slideshow = Space: Start/stop slideshow (images only)
+keys_slideshow_shuffle = z: Shuffle slideshow order (in slideshow)
+keys_slideshow_loop = l: Toggle slideshow looping (in slideshow)
+keys_slideshow_filename = t: Toggle filename overlay (in slideshow)
+keys_slideshow_speed = +/-: Longer/shorter slideshow delay (in slideshow)
+keys_slideshow_save_frame = Ctrl+S: Save the frame on screen as .ascii (in slideshow), otherwise save the slideshow delay
+keys_slideshow_export_html = Ctrl+E: Export the slideshow as an animated HTML page (in slideshow)
+keys_fullscreen = F: Toggle full-screen preview
+keys_grid_view = G: Toggle thumbnail grid view
+keys_debug_pane = D: Cycle the debug pane between file, converter, cache and messages
+keys_animation_pause = p: Pause/resume GIF animation
+keys_tiff_pages = < / >: Previous/next TIFF page or ICO size
 keys_text_scroll = u: Scroll text up, Space: Scroll text down
+keys_text_scroll_line = J / K: Scroll text down / up one line
 keys_converter_cycle = Tab: Cycle converters
 keys_help_toggle = ?: Show help
+help_scroll_up = u: Scroll help up
+help_scroll_down = Space / d: Scroll help down
 keys_quit = q/Esc: Quit
 not_supported_file_type = Selected file is not an image, text file, or ASCII file
 selected_file_not_image = Selected file is not an image
@@ -26,19 +56,137 @@ file_type_not_supported = File type not supported for preview
 unknown_file_type = Unknown file type
 ascii_file_prefix = ASCII file: 
 text_file_prefix = Text file: 
+text_file_unreadable = Error: Could not open file
 image_file_prefix = Image: 
+video_file_prefix = Video: 
+video_preview_failed = Could not extract a video frame (is ffmpeg installed?)
+audio_file_prefix = Audio: 
+audio_preview_failed = Could not read the audio details (is ffprobe installed?)
+audio_title = Title: {$value}
+audio_artist = Artist: {$value}
+audio_album = Album: {$value}
+audio_duration = Duration: {$value}
+audio_bitrate = Bitrate: {$kbps} kb/s
+audio_sample_rate = Sample rate: {$hz} Hz
+preview_loading_timed_out = Preview timed out after {$ms}ms
+clipboard_copied_path = Copied path to clipboard: {$path}
+clipboard_unavailable = Clipboard unavailable: {$error}
+clipboard_not_compiled = Clipboard support not compiled in
+clipboard_copied_ascii = Copied ASCII art ({$count} chars)
+clipboard_no_preview = No preview to copy
+heic_requires_converter = HEIC: requires {$binary}
+raw_requires_extractor = RAW preview: install dcraw or darktable
+tiff_page = Page {$page} of {$total}
+tiff_single_page = Single-page TIFF
+ico_sizes = ICO: {$sizes} (showing size {$index})
+ico_single_size = Single-size ICO
+ico_sizes_unavailable = Install ImageMagick to switch between ICO sizes
 slideshow_mode = Slideshow Mode
-slideshow_image = Image
-slideshow_press_any_key = Press any key to exit
+slideshow_image_of = Image {$current}/{$total}
+slideshow_hint_prev = prev
+slideshow_hint_next = next
+slideshow_hint_exit = exit
+slideshow_hint_shuffle = shuffle
+slideshow_hint_loop = loop
+slideshow_hint_name = name
+slideshow_hint_speed = speed
+slideshow_no_images = Error: no images found for the slideshow
+slideshow_shuffle_on = Shuffle: ON
+slideshow_shuffle_off = Shuffle: OFF
+slideshow_loop_on = Loop: ON
+slideshow_loop_off = Loop: OFF
+slideshow_filename_overlay = File: {$file}
+slideshow_delay = Delay: {$seconds}s
+slideshow_delay_saved = Slideshow delay saved to config
+slideshow_frame_saved = Saved frame: {$file}
+slideshow_html_exported = Saved slideshow: {$file}
+slideshow_html_exported_partly = Saved slideshow: {$file} ({$failed} failed, see {$log})
+slideshow_html_failed = Couldn't export slideshow: {$error}
+fullscreen_mode = Full-Screen Preview
+animation_paused = Animation paused
+animation_resumed = Animation resumed
 messages = Messages
+debug_pane_file_info = File info
+debug_pane_converter_info = Converter
+debug_pane_cache_stats = Cache
+debug_pane_custom = Notes
+debug_file_info = {$name} | {$size} | {$dimensions} | Modified {$age} ago
+debug_converter_info = Converter: {$converter} | Output: {$output} | Last conversion: {$ms} ms
+debug_cache_stats = Hits: {$hits} | Misses: {$misses} | Entries: {$entries}/{$capacity}
+cache_hit_rate = Hit rate: {$percent}%
+files_selected = {$count} files selected
 ptui_ready = PTUI ready...
 saved_to = Saved to:
-delete_file_prompt = Delete file {$file}?
+delete_file_prompt = Permanently delete {$file}?
+trash_file_prompt = Move {$file} to Trash?
 delete_confirmation_instructions = Press 'y' to confirm, 'n' or Esc to cancel
 delete_confirmation_title = Confirm Delete
 date_sort_newest_first = Date sort changed: newest to oldest
 date_sort_oldest_first = Date sort changed: oldest to newest
+content_filter_all = Showing all files
+content_filter_images_only = Showing only images
+content_filter_text_only = Showing only text files
+content_filter_non_previewable = Showing only files without a preview
 opened_file_in_browser = Opened file in system browser
 opened_directory_in_browser = Opened directory in system browser  
 failed_to_open_in_browser = Failed to open in system browser
+opened_with = Opened {$file} with {$app}
+open_with_failed = Failed to open with {$app}
+fuzzy_search_failed = Fuzzy search failed: { $error }
+open_with_title = Open {$file} with
+open_with_instructions = j/k to choose, Enter to open, Esc to cancel
+charset_picker_title = jp2a charset
+charset_picker_instructions = j/k to choose, Enter to apply, Esc to cancel
+charset_custom_instructions = Type characters, darkest first, Enter to apply, Esc to go back
+permissions_title = Permissions of {$file}
+permissions_current = Current mode: {$mode}
+permissions_instructions = Type a 3-digit octal mode, Enter to apply, Esc to cancel
+permissions_changed = Permissions of {$file} set to {$mode}
+permissions_failed = Couldn't change permissions of {$file}: {$error}
+permissions_unsupported = Changing permissions isn't supported on this system
+jp2a_charset_changed = jp2a charset: {$charset}
+debug_jp2a_charset = Charset: {$charset}
+debug_average_color = Avg: {$hex}
+open_with_none = No applications found to open this file
 
+checksum_computing = Computing checksum…
+checksum_result = SHA-256: {$hash}
+checksum_failed = Checksum failed: {$error}
+directory_size_calculating = Calculating…
+directory_size_result = Size: {$size} ({$count} files)
+directory_size_timed_out = Gave up calculating the size after {$seconds}s
+directory_image_count = {$count ->
+    [one] {$count} image
+   *[other] {$count} images
+}
+recent_files_title = Recent files
+recent_files_instructions = j/k to choose, Enter to open, c to clear history, Esc to close
+recent_files_empty = No recent files
+recent_file_missing = File no longer exists: {$file}
+dual_pane_mode = Dual pane: Tab switches panes, Enter copies or moves a file across
+dual_pane_closed = Second pane closed
+transfer_title = Copy or move {$file}
+copy_to_secondary = c: Copy to {$dir}
+move_to_secondary = m: Move to {$dir}
+transfer_cancel = Esc: Cancel
+file_copied = Copied {$file} to {$dir}
+file_moved = Moved {$file} to {$dir}
+transfer_failed = Could not copy or move {$file}: {$error}
+batch_export_title = Export ASCII art for {$count} images
+batch_export_skip_existing = Enter: Export, skipping images that already have a .ascii file
+batch_export_overwrite = o: Export and overwrite existing .ascii files
+batch_export_progress = Exporting: {$current}/{$total} ({$file})
+batch_export_done = Batch export done: {$succeeded} success, {$failed} failed, {$skipped} skipped
+batch_export_no_images = No images to export in this directory
+batch_export_running = A batch export is already running
+command_prompt = q: quit | e config: edit the config | Esc: cancel
+command_unknown = Unknown command: {$command}
+command_editor_failed = Couldn't edit the config: {$error}
+metadata_title = Image information
+metadata_exif_title = EXIF (exiftool)
+metadata_format = Format
+metadata_dimensions = Dimensions
+metadata_size = File size
+metadata_modified = Modified
+metadata_modified_ago = {$age} ago
+metadata_unknown = unknown
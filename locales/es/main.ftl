@@ -4,19 +4,49 @@ select_image_to_preview = Un visor de imágenes de terminal. Atajos de teclado:
 keys_navigation = ↑/k/←: Navegar hacia arriba, ↓/j/→: Navegar hacia abajo
 keys_page_navigation = Av Pág/Re Pág: Saltar por página
 keys_jump_navigation = f/b: Saltar adelante/atrás por 10 archivos
+keys_fuzzy_search = Ctrl+F: Buscar un archivo con fzf (si está instalado)
+keys_command_prompt = :q / :e config: Salir / editar la configuración en $EDITOR
 keys_home_end_navigation = Inicio: Ir al inicio, Fin: Ir al final
+keys_chords = gg: Ir al inicio, gd: Directorio superior, dd: Eliminar archivo
 keys_sort = d: Ordenar por fecha (alternar más reciente/más antiguo), n: Ordenar por nombre
+keys_content_filter = Ctrl+T: Mostrar solo imágenes, solo texto, solo archivos sin vista previa o todos
 keys_enter_directory = Enter: Abrir directorio
 keys_backspace_parent_dir = Retroceso: Subir un nivel en el directorio
 keys_resize_window = [ y ]: Redimensionar marco de ventana
 keys_refresh_image = r: Actualizar imagen
 keys_save_ascii = s: Guardar archivo ASCII
+keys_batch_export = B: Exportar arte ASCII de todas las imágenes del directorio
 keys_delete_file = x: Eliminar archivo actual
+keys_copy_path = c / Ctrl+C: Copiar la ruta del archivo al portapapeles
+keys_copy_preview = Ctrl+Y / Alt+Y: Copiar arte ASCII (Alt: sin colores)
+keys_checksum = C: Mostrar la suma SHA-256 del archivo
+keys_directory_size = Ctrl+D: Mostrar el tamaño total de un directorio
 keys_open_in_browser = o: Abrir en explorador del sistema
+keys_open_with = O: Abrir con una aplicación elegida
+keys_charset_picker = Alt+C: Elegir el juego de caracteres de jp2a
+keys_permissions = P: Cambiar los permisos del archivo seleccionado
+keys_average_color = A: Mostrar el color medio de la imagen en el panel de depuración
+keys_metadata = i: Mostrar metadatos de la imagen (EXIF si exiftool está instalado)
+keys_recent_files = Alt+R: Archivos recientes
+keys_dual_pane = Ctrl+\: Abrir o cerrar un segundo panel (Tab cambia, Enter copia/mueve)
 keys_slideshow = Espacio: Iniciar/detener presentación (solo imágenes)
+keys_slideshow_shuffle = z: Orden aleatorio de la presentación (en la presentación)
+keys_slideshow_loop = l: Alternar repetición de la presentación (en la presentación)
+keys_slideshow_filename = t: Mostrar/ocultar nombre de archivo (en presentación)
+keys_slideshow_speed = +/-: Intervalo más largo/corto (en la presentación)
+keys_slideshow_save_frame = Ctrl+S: Guardar el fotograma en pantalla como .ascii (en la presentación), si no, guardar el intervalo
+keys_slideshow_export_html = Ctrl+E: Exportar la presentación como página HTML animada (en la presentación)
+keys_fullscreen = F: Alternar vista previa a pantalla completa
+keys_grid_view = G: Alternar vista de cuadrícula de miniaturas
+keys_debug_pane = D: Alternar el panel de depuración entre archivo, conversor, caché y mensajes
+keys_animation_pause = p: Pausar/reanudar animación GIF
+keys_tiff_pages = < / >: Página TIFF o tamaño ICO anterior/siguiente
 keys_text_scroll = u: Desplazar texto hacia arriba, Espacio: Desplazar texto hacia abajo
+keys_text_scroll_line = J / K: Desplazar texto una línea hacia abajo / arriba
 keys_converter_cycle = Tab: Cambiar convertidores
 keys_help_toggle = ?: Mostrar ayuda
+help_scroll_up = u: Desplazar la ayuda hacia arriba
+help_scroll_down = Espacio / d: Desplazar la ayuda hacia abajo
 keys_quit = q/Esc: Salir
 not_supported_file_type = El archivo seleccionado no es una imagen, archivo de texto o archivo ASCII
 selected_file_not_image = El archivo seleccionado no es una imagen
@@ -26,18 +56,136 @@ file_type_not_supported = Tipo de archivo no compatible para vista previa
 unknown_file_type = Tipo de archivo desconocido
 ascii_file_prefix = Archivo ASCII: 
 text_file_prefix = Archivo de texto: 
+text_file_unreadable = Error: No se pudo abrir el archivo
 image_file_prefix = Imagen: 
+video_file_prefix = Vídeo: 
+video_preview_failed = No se pudo extraer un fotograma del vídeo (¿está instalado ffmpeg?)
+audio_file_prefix = Audio: 
+audio_preview_failed = No se pudieron leer los detalles del audio (¿está instalado ffprobe?)
+audio_title = Título: {$value}
+audio_artist = Artista: {$value}
+audio_album = Álbum: {$value}
+audio_duration = Duración: {$value}
+audio_bitrate = Tasa de bits: {$kbps} kb/s
+audio_sample_rate = Frecuencia de muestreo: {$hz} Hz
+preview_loading_timed_out = La vista previa superó el tiempo límite de {$ms}ms
+clipboard_copied_path = Ruta copiada al portapapeles: {$path}
+clipboard_unavailable = Portapapeles no disponible: {$error}
+clipboard_not_compiled = Soporte de portapapeles no compilado
+clipboard_copied_ascii = Arte ASCII copiado ({$count} caracteres)
+clipboard_no_preview = No hay vista previa para copiar
+heic_requires_converter = HEIC: requiere {$binary}
+raw_requires_extractor = Vista previa RAW: instala dcraw o darktable
+tiff_page = Página {$page} de {$total}
+tiff_single_page = TIFF de una sola página
+ico_sizes = ICO: {$sizes} (mostrando el tamaño {$index})
+ico_single_size = ICO de un solo tamaño
+ico_sizes_unavailable = Instala ImageMagick para cambiar entre tamaños ICO
 slideshow_mode = Modo Presentación
-slideshow_image = Imagen
-slideshow_press_any_key = Presiona cualquier tecla para salir
+slideshow_image_of = Imagen {$current}/{$total}
+slideshow_hint_prev = anterior
+slideshow_hint_next = siguiente
+slideshow_hint_exit = salir
+slideshow_hint_shuffle = aleatorio
+slideshow_hint_loop = bucle
+slideshow_hint_name = nombre
+slideshow_hint_speed = velocidad
+slideshow_no_images = Error: no se encontraron imágenes para la presentación
+slideshow_shuffle_on = Aleatorio: ACTIVADO
+slideshow_shuffle_off = Aleatorio: DESACTIVADO
+slideshow_loop_on = Repetir: ACTIVADO
+slideshow_loop_off = Repetir: DESACTIVADO
+slideshow_filename_overlay = Archivo: {$file}
+slideshow_delay = Intervalo: {$seconds}s
+slideshow_delay_saved = Intervalo de la presentación guardado en la configuración
+slideshow_frame_saved = Fotograma guardado: {$file}
+slideshow_html_exported = Presentación guardada: {$file}
+slideshow_html_exported_partly = Presentación guardada: {$file} ({$failed} fallidas, ver {$log})
+slideshow_html_failed = No se pudo exportar la presentación: {$error}
+fullscreen_mode = Vista previa a pantalla completa
+animation_paused = Animación en pausa
+animation_resumed = Animación reanudada
 messages = Mensajes
+debug_pane_file_info = Información del archivo
+debug_pane_converter_info = Conversor
+debug_pane_cache_stats = Caché
+debug_pane_custom = Notas
+debug_file_info = {$name} | {$size} | {$dimensions} | Modificado hace {$age}
+debug_converter_info = Conversor: {$converter} | Salida: {$output} | Última conversión: {$ms} ms
+debug_cache_stats = Aciertos: {$hits} | Fallos: {$misses} | Entradas: {$entries}/{$capacity}
+cache_hit_rate = Tasa de aciertos: {$percent}%
+files_selected = {$count} archivos seleccionados
 ptui_ready = PTUI listo...
 saved_to = Guardado en:
-delete_file_prompt = ¿Eliminar archivo {$file}?
+delete_file_prompt = ¿Eliminar permanentemente {$file}?
+trash_file_prompt = ¿Mover {$file} a la papelera?
 delete_confirmation_instructions = Presiona 'y' para confirmar, 'n' o Esc para cancelar
 delete_confirmation_title = Confirmar Eliminación
 date_sort_newest_first = Orden por fecha cambiado: más reciente a más antiguo
 date_sort_oldest_first = Orden por fecha cambiado: más antiguo a más reciente
+content_filter_all = Mostrando todos los archivos
+content_filter_images_only = Mostrando solo imágenes
+content_filter_text_only = Mostrando solo archivos de texto
+content_filter_non_previewable = Mostrando solo archivos sin vista previa
 opened_file_in_browser = Archivo abierto en explorador del sistema
 opened_directory_in_browser = Directorio abierto en explorador del sistema
 failed_to_open_in_browser = Error al abrir en explorador del sistema
+opened_with = {$file} abierto con {$app}
+open_with_failed = No se pudo abrir con {$app}
+fuzzy_search_failed = La búsqueda difusa falló: { $error }
+open_with_title = Abrir {$file} con
+open_with_instructions = j/k para elegir, Enter para abrir, Esc para cancelar
+charset_picker_title = Juego de caracteres de jp2a
+charset_picker_instructions = j/k para elegir, Enter para aplicar, Esc para cancelar
+charset_custom_instructions = Escribe caracteres, el más oscuro primero, Enter para aplicar, Esc para volver
+permissions_title = Permisos de {$file}
+permissions_current = Modo actual: {$mode}
+permissions_instructions = Escribe un modo octal de 3 dígitos, Enter para aplicar, Esc para cancelar
+permissions_changed = Permisos de {$file} cambiados a {$mode}
+permissions_failed = No se pudieron cambiar los permisos de {$file}: {$error}
+permissions_unsupported = Cambiar permisos no es compatible con este sistema
+jp2a_charset_changed = Juego de caracteres de jp2a: {$charset}
+debug_jp2a_charset = Caracteres: {$charset}
+debug_average_color = Media: {$hex}
+open_with_none = No se encontraron aplicaciones para abrir este archivo
+checksum_computing = Calculando suma de verificación…
+checksum_result = SHA-256: {$hash}
+checksum_failed = Error al calcular la suma: {$error}
+directory_size_calculating = Calculando…
+directory_size_result = Tamaño: {$size} ({$count} archivos)
+directory_size_timed_out = Se abandonó el cálculo del tamaño tras {$seconds}s
+directory_image_count = {$count ->
+    [one] {$count} imagen
+   *[other] {$count} imágenes
+}
+recent_files_title = Archivos recientes
+recent_files_instructions = j/k para elegir, Enter para abrir, c para borrar el historial, Esc para cerrar
+recent_files_empty = No hay archivos recientes
+recent_file_missing = El archivo ya no existe: {$file}
+dual_pane_mode = Dos paneles: Tab cambia de panel, Enter copia o mueve un archivo al otro
+dual_pane_closed = Segundo panel cerrado
+transfer_title = Copiar o mover {$file}
+copy_to_secondary = c: Copiar a {$dir}
+move_to_secondary = m: Mover a {$dir}
+transfer_cancel = Esc: Cancelar
+file_copied = {$file} copiado a {$dir}
+file_moved = {$file} movido a {$dir}
+transfer_failed = No se pudo copiar ni mover {$file}: {$error}
+batch_export_title = Exportar arte ASCII de {$count} imágenes
+batch_export_skip_existing = Enter: Exportar, omitiendo las imágenes que ya tienen un archivo .ascii
+batch_export_overwrite = o: Exportar y sobrescribir los archivos .ascii existentes
+batch_export_progress = Exportando: {$current}/{$total} ({$file})
+batch_export_done = Exportación por lotes terminada: {$succeeded} correctas, {$failed} fallidas, {$skipped} omitidas
+batch_export_no_images = No hay imágenes para exportar en este directorio
+batch_export_running = Ya hay una exportación por lotes en curso
+command_prompt = q: salir | e config: editar la configuración | Esc: cancelar
+command_unknown = Comando desconocido: {$command}
+command_editor_failed = No se pudo editar la configuración: {$error}
+metadata_title = Información de la imagen
+metadata_exif_title = EXIF (exiftool)
+metadata_format = Formato
+metadata_dimensions = Dimensiones
+metadata_size = Tamaño
+metadata_modified = Modificado
+metadata_modified_ago = hace {$age}
+metadata_unknown = desconocido
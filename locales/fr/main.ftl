@@ -4,19 +4,49 @@ select_image_to_preview = Un visualiseur d'images en terminal. Raccourcis clavie
 keys_navigation = ↑/k/← : Naviguer vers le haut, ↓/j/→ : Naviguer vers le bas
 keys_page_navigation = Page Préc/Page Suiv : Saut par page
 keys_jump_navigation = f/b : Saut de 10 fichiers avant/arrière
+keys_fuzzy_search = Ctrl+F : Chercher un fichier avec fzf (s'il est installé)
+keys_command_prompt = :q / :e config : Quitter / modifier la configuration dans $EDITOR
 keys_home_end_navigation = Début : Aller au début, Fin : Aller à la fin
+keys_chords = gg : Aller au début, gd : Dossier parent, dd : Supprimer le fichier
 keys_sort = d : Trier par date (basculer récent/ancien), n : Trier par nom
+keys_content_filter = Ctrl+T : Afficher seulement les images, le texte, les fichiers sans aperçu, ou tout
 keys_enter_directory = Entrée : Ouvrir le répertoire
 keys_backspace_parent_dir = Retour arrière : Remonter dans le répertoire
 keys_resize_window = [ et ] : Redimensionner le cadre de la fenêtre
 keys_refresh_image = r : Actualiser l'image
 keys_save_ascii = s : Sauvegarder le fichier ASCII
+keys_batch_export = B : Exporter l'art ASCII de toutes les images du dossier
 keys_delete_file = x : Supprimer le fichier actuel
+keys_copy_path = c / Ctrl+C : Copier le chemin du fichier
+keys_copy_preview = Ctrl+Y / Alt+Y : Copier l'art ASCII (Alt : sans couleurs)
+keys_checksum = C: Afficher la somme SHA-256 du fichier
+keys_directory_size = Ctrl+D: Afficher la taille totale d'un dossier
 keys_open_in_browser = o : Ouvrir dans l'explorateur système
+keys_open_with = O: Ouvrir avec une application choisie
+keys_charset_picker = Alt+C: Choisir le jeu de caractères de jp2a
+keys_permissions = P: Modifier les permissions du fichier sélectionné
+keys_average_color = A: Afficher la couleur moyenne de l'image dans le panneau de débogage
+keys_metadata = i : Afficher les métadonnées de l'image (EXIF si exiftool est installé)
+keys_recent_files = Alt+R: Fichiers récents
+keys_dual_pane = Ctrl+\ : Ouvrir ou fermer un second panneau (Tab change, Entrée copie/déplace)
 keys_slideshow = Espace : Démarrer/arrêter le diaporama (images uniquement)
+keys_slideshow_shuffle = z : Ordre aléatoire du diaporama (pendant le diaporama)
+keys_slideshow_loop = l : Activer/désactiver la boucle du diaporama (pendant le diaporama)
+keys_slideshow_filename = t : Afficher/masquer le nom du fichier (en diaporama)
+keys_slideshow_speed = +/- : Délai du diaporama plus long/court (pendant le diaporama)
+keys_slideshow_save_frame = Ctrl+S : Enregistrer l'image affichée en .ascii (pendant le diaporama), sinon enregistrer le délai
+keys_slideshow_export_html = Ctrl+E : Exporter le diaporama en page HTML animée (dans le diaporama)
+keys_fullscreen = F : Basculer l'aperçu plein écran
+keys_grid_view = G : Basculer la grille de vignettes
+keys_debug_pane = D : Faire défiler le panneau de débogage entre fichier, convertisseur, cache et messages
+keys_animation_pause = p : Mettre en pause/reprendre l'animation GIF
+keys_tiff_pages = < / > : Page TIFF ou taille ICO précédente/suivante
 keys_text_scroll = u : Faire défiler le texte vers le haut, Espace : Faire défiler le texte vers le bas
+keys_text_scroll_line = J / K : Faire défiler le texte d'une ligne vers le bas / le haut
 keys_converter_cycle = Tab : Changer les convertisseurs
 keys_help_toggle = ? : Afficher l'aide
+help_scroll_up = u : Faire défiler l'aide vers le haut
+help_scroll_down = Espace / d : Faire défiler l'aide vers le bas
 keys_quit = q/Echap : Quitter
 not_supported_file_type = Le fichier sélectionné n'est pas une image, un fichier texte ou un fichier ASCII
 selected_file_not_image = Le fichier sélectionné n'est pas une image
@@ -26,18 +56,136 @@ file_type_not_supported = Type de fichier non pris en charge pour l'aperçu
 unknown_file_type = Type de fichier inconnu
 ascii_file_prefix = Fichier ASCII : 
 text_file_prefix = Fichier texte : 
+text_file_unreadable = Erreur : impossible d'ouvrir le fichier
 image_file_prefix = Image : 
+video_file_prefix = Vidéo : 
+video_preview_failed = Impossible d'extraire une image de la vidéo (ffmpeg est-il installé ?)
+audio_file_prefix = Audio : 
+audio_preview_failed = Impossible de lire les détails audio (ffprobe est-il installé ?)
+audio_title = Titre : {$value}
+audio_artist = Artiste : {$value}
+audio_album = Album : {$value}
+audio_duration = Durée : {$value}
+audio_bitrate = Débit : {$kbps} kb/s
+audio_sample_rate = Fréquence d'échantillonnage : {$hz} Hz
+preview_loading_timed_out = Aperçu abandonné après {$ms}ms
+clipboard_copied_path = Chemin copié dans le presse-papiers : {$path}
+clipboard_unavailable = Presse-papiers indisponible : {$error}
+clipboard_not_compiled = Prise en charge du presse-papiers non compilée
+clipboard_copied_ascii = Art ASCII copié ({$count} caractères)
+clipboard_no_preview = Aucun aperçu à copier
+heic_requires_converter = HEIC : nécessite {$binary}
+raw_requires_extractor = Aperçu RAW : installez dcraw ou darktable
+tiff_page = Page {$page} sur {$total}
+tiff_single_page = TIFF d'une seule page
+ico_sizes = ICO : {$sizes} (taille {$index} affichée)
+ico_single_size = ICO d'une seule taille
+ico_sizes_unavailable = Installez ImageMagick pour passer d'une taille ICO à l'autre
 slideshow_mode = Mode Diaporama
-slideshow_image = Image
-slideshow_press_any_key = Appuyez sur n'importe quelle touche pour quitter
+slideshow_image_of = Image {$current}/{$total}
+slideshow_hint_prev = précédente
+slideshow_hint_next = suivante
+slideshow_hint_exit = quitter
+slideshow_hint_shuffle = aléatoire
+slideshow_hint_loop = boucle
+slideshow_hint_name = nom
+slideshow_hint_speed = vitesse
+slideshow_no_images = Erreur : aucune image trouvée pour le diaporama
+slideshow_shuffle_on = Lecture aléatoire : ACTIVÉE
+slideshow_shuffle_off = Lecture aléatoire : DÉSACTIVÉE
+slideshow_loop_on = Boucle : ACTIVÉE
+slideshow_loop_off = Boucle : DÉSACTIVÉE
+slideshow_filename_overlay = Fichier : {$file}
+slideshow_delay = Délai : {$seconds}s
+slideshow_delay_saved = Délai du diaporama enregistré dans la configuration
+slideshow_frame_saved = Image enregistrée : {$file}
+slideshow_html_exported = Diaporama enregistré : {$file}
+slideshow_html_exported_partly = Diaporama enregistré : {$file} ({$failed} échoués, voir {$log})
+slideshow_html_failed = Impossible d'exporter le diaporama : {$error}
+fullscreen_mode = Aperçu plein écran
+animation_paused = Animation en pause
+animation_resumed = Animation reprise
 messages = Messages
+debug_pane_file_info = Infos du fichier
+debug_pane_converter_info = Convertisseur
+debug_pane_cache_stats = Cache
+debug_pane_custom = Notes
+debug_file_info = {$name} | {$size} | {$dimensions} | Modifié il y a {$age}
+debug_converter_info = Convertisseur : {$converter} | Sortie : {$output} | Dernière conversion : {$ms} ms
+debug_cache_stats = Succès : {$hits} | Échecs : {$misses} | Entrées : {$entries}/{$capacity}
+cache_hit_rate = Taux de succès : {$percent} %
+files_selected = {$count} fichiers sélectionnés
 ptui_ready = PTUI prêt...
 saved_to = Sauvegardé dans :
-delete_file_prompt = Supprimer le fichier {$file} ?
+delete_file_prompt = Supprimer définitivement {$file} ?
+trash_file_prompt = Mettre {$file} à la corbeille ?
 delete_confirmation_instructions = Appuyez sur 'y' pour confirmer, 'n' ou Échap pour annuler
 delete_confirmation_title = Confirmer la Suppression
 date_sort_newest_first = Tri par date modifié : plus récent au plus ancien
 date_sort_oldest_first = Tri par date modifié : plus ancien au plus récent
+content_filter_all = Affichage de tous les fichiers
+content_filter_images_only = Affichage des images uniquement
+content_filter_text_only = Affichage des fichiers texte uniquement
+content_filter_non_previewable = Affichage des fichiers sans aperçu uniquement
 opened_file_in_browser = Fichier ouvert dans l'explorateur système
 opened_directory_in_browser = Répertoire ouvert dans l'explorateur système
 failed_to_open_in_browser = Échec de l'ouverture dans l'explorateur système
+opened_with = {$file} ouvert avec {$app}
+open_with_failed = Impossible d'ouvrir avec {$app}
+fuzzy_search_failed = La recherche floue a échoué : { $error }
+open_with_title = Ouvrir {$file} avec
+open_with_instructions = j/k pour choisir, Entrée pour ouvrir, Échap pour annuler
+charset_picker_title = Jeu de caractères de jp2a
+charset_picker_instructions = j/k pour choisir, Entrée pour appliquer, Échap pour annuler
+charset_custom_instructions = Tapez les caractères, le plus sombre d'abord, Entrée pour appliquer, Échap pour revenir
+permissions_title = Permissions de {$file}
+permissions_current = Mode actuel : {$mode}
+permissions_instructions = Tapez un mode octal à 3 chiffres, Entrée pour appliquer, Échap pour annuler
+permissions_changed = Permissions de {$file} réglées sur {$mode}
+permissions_failed = Impossible de modifier les permissions de {$file} : {$error}
+permissions_unsupported = La modification des permissions n'est pas prise en charge sur ce système
+jp2a_charset_changed = Jeu de caractères de jp2a : {$charset}
+debug_jp2a_charset = Caractères : {$charset}
+debug_average_color = Moy. : {$hex}
+open_with_none = Aucune application trouvée pour ouvrir ce fichier
+checksum_computing = Calcul de la somme de contrôle…
+checksum_result = SHA-256: {$hash}
+checksum_failed = Échec de la somme de contrôle : {$error}
+directory_size_calculating = Calcul en cours…
+directory_size_result = Taille : {$size} ({$count} fichiers)
+directory_size_timed_out = Calcul de la taille abandonné après {$seconds}s
+directory_image_count = {$count ->
+    [one] {$count} image
+   *[other] {$count} images
+}
+recent_files_title = Fichiers récents
+recent_files_instructions = j/k pour choisir, Entrée pour ouvrir, c pour effacer l'historique, Échap pour fermer
+recent_files_empty = Aucun fichier récent
+recent_file_missing = Le fichier n'existe plus : {$file}
+dual_pane_mode = Double panneau : Tab change de panneau, Entrée copie ou déplace un fichier vers l'autre
+dual_pane_closed = Second panneau fermé
+transfer_title = Copier ou déplacer {$file}
+copy_to_secondary = c : Copier vers {$dir}
+move_to_secondary = m : Déplacer vers {$dir}
+transfer_cancel = Échap : Annuler
+file_copied = {$file} copié vers {$dir}
+file_moved = {$file} déplacé vers {$dir}
+transfer_failed = Impossible de copier ou déplacer {$file} : {$error}
+batch_export_title = Exporter l'art ASCII de {$count} images
+batch_export_skip_existing = Entrée : Exporter, en ignorant les images qui ont déjà un fichier .ascii
+batch_export_overwrite = o : Exporter et écraser les fichiers .ascii existants
+batch_export_progress = Exportation : {$current}/{$total} ({$file})
+batch_export_done = Export par lot terminé : {$succeeded} réussis, {$failed} échoués, {$skipped} ignorés
+batch_export_no_images = Aucune image à exporter dans ce dossier
+batch_export_running = Un export par lot est déjà en cours
+command_prompt = q : quitter | e config : modifier la configuration | Échap : annuler
+command_unknown = Commande inconnue : {$command}
+command_editor_failed = Impossible de modifier la configuration : {$error}
+metadata_title = Informations sur l'image
+metadata_exif_title = EXIF (exiftool)
+metadata_format = Format
+metadata_dimensions = Dimensions
+metadata_size = Taille
+metadata_modified = Modifié
+metadata_modified_ago = il y a {$age}
+metadata_unknown = inconnu
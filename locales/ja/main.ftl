@@ -4,19 +4,49 @@ select_image_to_preview = ターミナル画像ビューア。キーボードシ
 keys_navigation = ↑/k/←: 上に移動, ↓/j/→: 下に移動
 keys_page_navigation = Page Up/Page Down: ページ単位でジャンプ
 keys_jump_navigation = f/b: 10ファイル単位で前後移動
+keys_fuzzy_search = Ctrl+F: fzf でファイルを検索（インストール時）
+keys_command_prompt = :q / :e config: 終了 / $EDITOR で設定を編集
 keys_home_end_navigation = Home: 最初へ, End: 最後へ
+keys_chords = gg: 先頭へ, gd: 親ディレクトリ, dd: ファイルを削除
 keys_sort = d: 日付順ソート（新しい順/古い順切り替え）, n: 名前順ソート
+keys_content_filter = Ctrl+T: 画像のみ・テキストのみ・プレビュー不可のみ・すべてを切り替え
 keys_enter_directory = Enter: ディレクトリを開く
 keys_backspace_parent_dir = Backspace: 親ディレクトリへ移動
 keys_resize_window = [ and ]: ウィンドウフレームのサイズ変更
 keys_refresh_image = r: 画像を再読み込み
 keys_save_ascii = s: ASCIIファイルを保存
+keys_batch_export = B: ディレクトリ内の全画像の ASCII アートを書き出す
 keys_delete_file = x: 現在のファイルを削除
+keys_copy_path = c / Ctrl+C: ファイルパスをクリップボードにコピー
+keys_copy_preview = Ctrl+Y / Alt+Y: アスキーアートをコピー（Alt: 色なし）
+keys_checksum = C: ファイルのSHA-256チェックサムを表示
+keys_directory_size = Ctrl+D: ディレクトリの合計サイズを表示
 keys_open_in_browser = o: システムファイルブラウザで開く
+keys_open_with = O: アプリケーションを選んで開く
+keys_charset_picker = Alt+C: jp2a の文字セットを選ぶ
+keys_permissions = P: 選択したファイルのパーミッションを変更
+keys_average_color = A: 選択した画像の平均色をデバッグ欄に表示
+keys_metadata = i: 画像のメタデータを表示（exiftool があれば EXIF）
+keys_recent_files = Alt+R: 最近のファイル
+keys_dual_pane = Ctrl+\: 2つ目のペインを開閉 (Tab で切替、Enter でコピー/移動)
 keys_slideshow = スペース: スライドショー開始/停止（画像のみ）
+keys_slideshow_shuffle = z: スライドショーの順序をシャッフル（スライドショー中）
+keys_slideshow_loop = l: スライドショーのループ切り替え（スライドショー中）
+keys_slideshow_filename = t: ファイル名表示の切り替え（スライドショー中）
+keys_slideshow_speed = +/-: スライドショー間隔を長く/短く（スライドショー中）
+keys_slideshow_save_frame = Ctrl+S: 表示中のフレームを .ascii で保存（スライドショー中）、それ以外は間隔を保存
+keys_slideshow_export_html = Ctrl+E: スライドショーをアニメーション HTML として書き出す（スライドショー中）
+keys_fullscreen = F: 全画面プレビューの切り替え
+keys_grid_view = G: サムネイルグリッド表示の切り替え
+keys_debug_pane = D: デバッグ欄をファイル・コンバーター・キャッシュ・メッセージで切り替え
+keys_animation_pause = p: GIFアニメーションの一時停止/再開
+keys_tiff_pages = < / >: 前/次の TIFF ページまたは ICO サイズ
 keys_text_scroll = u: テキスト上スクロール, スペース: テキスト下スクロール
+keys_text_scroll_line = J / K: テキストを1行下 / 上にスクロール
 keys_converter_cycle = Tab: 変換器を切り替える
 keys_help_toggle = ?: ヘルプを表示
+help_scroll_up = u: ヘルプを上にスクロール
+help_scroll_down = スペース / d: ヘルプを下にスクロール
 keys_quit = q/Esc: 終了
 not_supported_file_type = 選択されたファイルは画像、テキスト、またはASCIIファイルではありません
 selected_file_not_image = 選択されたファイルは画像ではありません
@@ -26,18 +56,133 @@ file_type_not_supported = プレビュー対象のファイル形式がサポー
 unknown_file_type = 不明なファイル形式
 ascii_file_prefix = ASCIIファイル: 
 text_file_prefix = テキストファイル: 
+text_file_unreadable = エラー: ファイルを開けませんでした
 image_file_prefix = 画像: 
+video_file_prefix = 動画: 
+video_preview_failed = 動画のフレームを抽出できませんでした（ffmpeg はインストールされていますか？）
+audio_file_prefix = 音声: 
+audio_preview_failed = 音声の情報を読み取れませんでした（ffprobe はインストールされていますか？）
+audio_title = タイトル: {$value}
+audio_artist = アーティスト: {$value}
+audio_album = アルバム: {$value}
+audio_duration = 再生時間: {$value}
+audio_bitrate = ビットレート: {$kbps} kb/s
+audio_sample_rate = サンプリングレート: {$hz} Hz
+preview_loading_timed_out = プレビューが{$ms}msでタイムアウトしました
+clipboard_copied_path = パスをクリップボードにコピーしました: {$path}
+clipboard_unavailable = クリップボードを使用できません: {$error}
+clipboard_not_compiled = クリップボード機能は組み込まれていません
+clipboard_copied_ascii = アスキーアートをコピーしました（{$count}文字）
+clipboard_no_preview = コピーするプレビューがありません
+heic_requires_converter = HEIC: {$binary} が必要です
+raw_requires_extractor = RAW プレビュー: dcraw または darktable をインストールしてください
+tiff_page = {$total} ページ中 {$page} ページ目
+tiff_single_page = 単一ページの TIFF
+ico_sizes = ICO: {$sizes}（サイズ {$index} を表示中）
+ico_single_size = 単一サイズの ICO
+ico_sizes_unavailable = ICO のサイズを切り替えるには ImageMagick をインストールしてください
 slideshow_mode = スライドショーモード
-slideshow_image = 画像
-slideshow_press_any_key = 任意のキーを押して終了
+slideshow_image_of = 画像 {$current}/{$total}
+slideshow_hint_prev = 前へ
+slideshow_hint_next = 次へ
+slideshow_hint_exit = 終了
+slideshow_hint_shuffle = シャッフル
+slideshow_hint_loop = ループ
+slideshow_hint_name = ファイル名
+slideshow_hint_speed = 速度
+slideshow_no_images = エラー: スライドショーの画像が見つかりません
+slideshow_shuffle_on = シャッフル: オン
+slideshow_shuffle_off = シャッフル: オフ
+slideshow_loop_on = ループ: オン
+slideshow_loop_off = ループ: オフ
+slideshow_filename_overlay = ファイル: {$file}
+slideshow_delay = 間隔: {$seconds}秒
+slideshow_delay_saved = スライドショーの間隔を設定に保存しました
+slideshow_frame_saved = フレームを保存しました: {$file}
+slideshow_html_exported = スライドショーを保存しました: {$file}
+slideshow_html_exported_partly = スライドショーを保存しました: {$file}（失敗 {$failed}、{$log} を参照）
+slideshow_html_failed = スライドショーを書き出せませんでした: {$error}
+fullscreen_mode = 全画面プレビュー
+animation_paused = アニメーションを一時停止しました
+animation_resumed = アニメーションを再開しました
 messages = メッセージ
+debug_pane_file_info = ファイル情報
+debug_pane_converter_info = コンバーター
+debug_pane_cache_stats = キャッシュ
+debug_pane_custom = メモ
+debug_file_info = {$name} | {$size} | {$dimensions} | {$age} 前に更新
+debug_converter_info = コンバーター: {$converter} | 出力: {$output} | 前回の変換: {$ms} ms
+debug_cache_stats = ヒット: {$hits} | ミス: {$misses} | エントリ: {$entries}/{$capacity}
+cache_hit_rate = ヒット率: {$percent}%
+files_selected = {$count} 個のファイルを選択
 ptui_ready = PTUI 準備完了...
 saved_to = 保存先:
-delete_file_prompt = ファイル {$file} を削除しますか？
+delete_file_prompt = {$file} を完全に削除しますか？
+trash_file_prompt = {$file} をゴミ箱に移動しますか？
 delete_confirmation_instructions = 'y'で確認、'n'またはEscでキャンセル
 delete_confirmation_title = 削除の確認
 date_sort_newest_first = 日付順ソートを変更しました：新しい順
 date_sort_oldest_first = 日付順ソートを変更しました：古い順
+content_filter_all = すべてのファイルを表示中
+content_filter_images_only = 画像のみ表示中
+content_filter_text_only = テキストファイルのみ表示中
+content_filter_non_previewable = プレビューできないファイルのみ表示中
 opened_file_in_browser = システムブラウザでファイルを開きました
 opened_directory_in_browser = システムブラウザでディレクトリを開きました
 failed_to_open_in_browser = システムブラウザで開けませんでした
+opened_with = {$file} を {$app} で開きました
+open_with_failed = {$app} で開けませんでした
+fuzzy_search_failed = あいまい検索に失敗しました: { $error }
+open_with_title = {$file} を開くアプリ
+open_with_instructions = j/kで選択、Enterで開く、Escでキャンセル
+charset_picker_title = jp2a の文字セット
+charset_picker_instructions = j/kで選択、Enterで適用、Escでキャンセル
+charset_custom_instructions = 文字を暗い順に入力、Enterで適用、Escで戻る
+permissions_title = {$file} のパーミッション
+permissions_current = 現在のモード: {$mode}
+permissions_instructions = 3桁の8進モードを入力、Enterで適用、Escでキャンセル
+permissions_changed = {$file} のパーミッションを {$mode} に設定しました
+permissions_failed = {$file} のパーミッションを変更できませんでした: {$error}
+permissions_unsupported = このシステムではパーミッションを変更できません
+jp2a_charset_changed = jp2a の文字セット: {$charset}
+debug_jp2a_charset = 文字セット: {$charset}
+debug_average_color = 平均: {$hex}
+open_with_none = このファイルを開けるアプリケーションが見つかりません
+checksum_computing = チェックサムを計算中…
+checksum_result = SHA-256: {$hash}
+checksum_failed = チェックサムの計算に失敗しました: {$error}
+directory_size_calculating = 計算中…
+directory_size_result = サイズ: {$size} ({$count} ファイル)
+directory_size_timed_out = {$seconds}秒経過したためサイズの計算を中止しました
+directory_image_count = {$count}枚の画像
+recent_files_title = 最近のファイル
+recent_files_instructions = j/kで選択、Enterで開く、cで履歴を消去、Escで閉じる
+recent_files_empty = 最近のファイルはありません
+recent_file_missing = ファイルはもう存在しません: {$file}
+dual_pane_mode = 2ペイン: Tab でペイン切替、Enter でファイルを反対側へコピー/移動
+dual_pane_closed = 2つ目のペインを閉じました
+transfer_title = {$file} をコピーまたは移動
+copy_to_secondary = c: {$dir} へコピー
+move_to_secondary = m: {$dir} へ移動
+transfer_cancel = Esc: キャンセル
+file_copied = {$file} を {$dir} へコピーしました
+file_moved = {$file} を {$dir} へ移動しました
+transfer_failed = {$file} をコピー/移動できませんでした: {$error}
+batch_export_title = {$count} 枚の画像の ASCII アートを書き出す
+batch_export_skip_existing = Enter: 書き出す (.ascii ファイルがある画像は飛ばす)
+batch_export_overwrite = o: 書き出して既存の .ascii ファイルを上書き
+batch_export_progress = 書き出し中: {$current}/{$total} ({$file})
+batch_export_done = 一括書き出し完了: 成功 {$succeeded}、失敗 {$failed}、スキップ {$skipped}
+batch_export_no_images = このディレクトリに書き出す画像がありません
+batch_export_running = 一括書き出しはすでに実行中です
+command_prompt = q: 終了 | e config: 設定を編集 | Esc: キャンセル
+command_unknown = 不明なコマンド: {$command}
+command_editor_failed = 設定を編集できませんでした: {$error}
+metadata_title = 画像情報
+metadata_exif_title = EXIF (exiftool)
+metadata_format = 形式
+metadata_dimensions = サイズ
+metadata_size = ファイルサイズ
+metadata_modified = 更新日時
+metadata_modified_ago = {$age}前
+metadata_unknown = 不明
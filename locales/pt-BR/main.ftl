@@ -0,0 +1,191 @@
+image_preview = Pré-visualização da imagem
+app_subtitle = Interface de Terminal para Imagens
+select_image_to_preview = Um visualizador de imagens para o terminal. Atalhos de teclado:
+keys_navigation = ↑/k/←: Subir, ↓/j/→: Descer
+keys_page_navigation = Page Up/Page Down: Pular uma página
+keys_jump_navigation = f/b: Avançar/voltar 10 arquivos
+keys_fuzzy_search = Ctrl+F: Encontrar um arquivo com fzf (se instalado)
+keys_command_prompt = :q / :e config: Sair / editar a configuração no $EDITOR
+keys_home_end_navigation = Home: Ir para o início, End: Ir para o fim
+keys_chords = gg: Ir para o início, gd: Diretório pai, dd: Excluir arquivo
+keys_sort = d: Ordenar por data (alterna mais novos/mais antigos), n: Ordenar por nome
+keys_content_filter = Ctrl+T: Mostrar só imagens, só texto, só arquivos sem pré-visualização ou todos
+keys_enter_directory = Enter: Abrir diretório
+keys_backspace_parent_dir = Backspace: Subir um diretório
+keys_resize_window = [ e ]: Redimensionar o painel
+keys_refresh_image = r: Atualizar imagem
+keys_save_ascii = s: Salvar arquivo ASCII
+keys_batch_export = B: Exportar arte ASCII de todas as imagens do diretório
+keys_delete_file = x: Excluir o arquivo atual
+keys_copy_path = c / Ctrl+C: Copiar o caminho do arquivo
+keys_copy_preview = Ctrl+Y / Alt+Y: Copiar a arte ASCII (Alt: sem cores)
+keys_checksum = C: Mostrar o checksum SHA-256 do arquivo
+keys_directory_size = Ctrl+D: Mostrar o tamanho total de um diretório
+keys_open_in_browser = o: Abrir no gerenciador de arquivos do sistema
+keys_open_with = O: Abrir com um aplicativo escolhido
+keys_charset_picker = Alt+C: Escolher o conjunto de caracteres do jp2a
+keys_permissions = P: Alterar as permissões do arquivo selecionado
+keys_average_color = A: Mostrar a cor média da imagem no painel de depuração
+keys_metadata = i: Mostrar metadados da imagem (EXIF se o exiftool estiver instalado)
+keys_recent_files = Alt+R: Arquivos recentes
+keys_dual_pane = Ctrl+\: Abrir ou fechar um segundo painel (Tab alterna, Enter copia/move)
+keys_slideshow = Espaço: Iniciar/parar apresentação de slides (somente imagens)
+keys_slideshow_shuffle = z: Embaralhar a ordem dos slides (na apresentação)
+keys_slideshow_loop = l: Ativar/desativar repetição (na apresentação)
+keys_slideshow_filename = t: Mostrar/ocultar o nome do arquivo (na apresentação)
+keys_slideshow_speed = +/-: Intervalo maior/menor (na apresentação)
+keys_slideshow_save_frame = Ctrl+S: Salvar o quadro na tela como .ascii (na apresentação), senão salvar o intervalo
+keys_slideshow_export_html = Ctrl+E: Exportar a apresentação como página HTML animada (na apresentação)
+keys_fullscreen = F: Alternar pré-visualização em tela cheia
+keys_grid_view = G: Alternar grade de miniaturas
+keys_debug_pane = D: Alternar o painel de depuração entre arquivo, conversor, cache e mensagens
+keys_animation_pause = p: Pausar/retomar animação GIF
+keys_tiff_pages = < / >: Página TIFF ou tamanho ICO anterior/seguinte
+keys_text_scroll = u: Rolar o texto para cima, Espaço: Rolar o texto para baixo
+keys_text_scroll_line = J / K: Rolar o texto uma linha para baixo / cima
+keys_converter_cycle = Tab: Alternar conversores
+keys_help_toggle = ?: Mostrar ajuda
+help_scroll_up = u: Rolar a ajuda para cima
+help_scroll_down = Espaço / d: Rolar a ajuda para baixo
+keys_quit = q/Esc: Sair
+not_supported_file_type = O arquivo selecionado não é uma imagem, arquivo de texto ou arquivo ASCII
+selected_file_not_image = O arquivo selecionado não é uma imagem
+directory_selected = Diretório selecionado
+no_file_selected = Nenhum arquivo selecionado
+file_type_not_supported = Tipo de arquivo não suportado para pré-visualização
+unknown_file_type = Tipo de arquivo desconhecido
+ascii_file_prefix = Arquivo ASCII: 
+text_file_prefix = Arquivo de texto: 
+text_file_unreadable = Erro: Não foi possível abrir o arquivo
+image_file_prefix = Imagem: 
+video_file_prefix = Vídeo: 
+video_preview_failed = Não foi possível extrair um quadro do vídeo (o ffmpeg está instalado?)
+audio_file_prefix = Áudio: 
+audio_preview_failed = Não foi possível ler os detalhes do áudio (o ffprobe está instalado?)
+audio_title = Título: {$value}
+audio_artist = Artista: {$value}
+audio_album = Álbum: {$value}
+audio_duration = Duração: {$value}
+audio_bitrate = Taxa de bits: {$kbps} kb/s
+audio_sample_rate = Taxa de amostragem: {$hz} Hz
+preview_loading_timed_out = A pré-visualização expirou após {$ms}ms
+clipboard_copied_path = Caminho copiado para a área de transferência: {$path}
+clipboard_unavailable = Área de transferência indisponível: {$error}
+clipboard_not_compiled = Suporte à área de transferência não compilado
+clipboard_copied_ascii = Arte ASCII copiada ({$count} caracteres)
+clipboard_no_preview = Nenhuma pré-visualização para copiar
+heic_requires_converter = HEIC: requer {$binary}
+raw_requires_extractor = Pré-visualização RAW: instale o dcraw ou o darktable
+tiff_page = Página {$page} de {$total}
+tiff_single_page = TIFF de página única
+ico_sizes = ICO: {$sizes} (mostrando o tamanho {$index})
+ico_single_size = ICO de tamanho único
+ico_sizes_unavailable = Instale o ImageMagick para alternar entre tamanhos ICO
+slideshow_mode = Modo de apresentação
+slideshow_image_of = Imagem {$current}/{$total}
+slideshow_hint_prev = anterior
+slideshow_hint_next = próxima
+slideshow_hint_exit = sair
+slideshow_hint_shuffle = embaralhar
+slideshow_hint_loop = repetir
+slideshow_hint_name = nome
+slideshow_hint_speed = velocidade
+slideshow_no_images = Erro: nenhuma imagem encontrada para a apresentação
+slideshow_shuffle_on = Embaralhar: SIM
+slideshow_shuffle_off = Embaralhar: NÃO
+slideshow_loop_on = Repetir: SIM
+slideshow_loop_off = Repetir: NÃO
+slideshow_filename_overlay = Arquivo: {$file}
+slideshow_delay = Intervalo: {$seconds}s
+slideshow_delay_saved = Intervalo da apresentação salvo na configuração
+slideshow_frame_saved = Quadro salvo: {$file}
+slideshow_html_exported = Apresentação salva: {$file}
+slideshow_html_exported_partly = Apresentação salva: {$file} ({$failed} com falha, veja {$log})
+slideshow_html_failed = Não foi possível exportar a apresentação: {$error}
+fullscreen_mode = Pré-visualização em tela cheia
+animation_paused = Animação pausada
+animation_resumed = Animação retomada
+messages = Mensagens
+debug_pane_file_info = Informações do arquivo
+debug_pane_converter_info = Conversor
+debug_pane_cache_stats = Cache
+debug_pane_custom = Notas
+debug_file_info = {$name} | {$size} | {$dimensions} | Modificado há {$age}
+debug_converter_info = Conversor: {$converter} | Saída: {$output} | Última conversão: {$ms} ms
+debug_cache_stats = Acertos: {$hits} | Falhas: {$misses} | Entradas: {$entries}/{$capacity}
+cache_hit_rate = Taxa de acertos: {$percent}%
+files_selected = {$count} arquivos selecionados
+ptui_ready = PTUI pronto...
+saved_to = Salvo em:
+delete_file_prompt = Excluir {$file} permanentemente?
+trash_file_prompt = Mover {$file} para a Lixeira?
+delete_confirmation_instructions = Pressione 'y' para confirmar, 'n' ou Esc para cancelar
+delete_confirmation_title = Confirmar exclusão
+date_sort_newest_first = Ordenação por data: do mais novo ao mais antigo
+date_sort_oldest_first = Ordenação por data: do mais antigo ao mais novo
+content_filter_all = Mostrando todos os arquivos
+content_filter_images_only = Mostrando só imagens
+content_filter_text_only = Mostrando só arquivos de texto
+content_filter_non_previewable = Mostrando só arquivos sem pré-visualização
+opened_file_in_browser = Arquivo aberto no gerenciador do sistema
+opened_directory_in_browser = Diretório aberto no gerenciador do sistema
+failed_to_open_in_browser = Falha ao abrir no gerenciador do sistema
+opened_with = {$file} aberto com {$app}
+open_with_failed = Falha ao abrir com {$app}
+fuzzy_search_failed = A busca aproximada falhou: { $error }
+open_with_title = Abrir {$file} com
+open_with_instructions = j/k para escolher, Enter para abrir, Esc para cancelar
+charset_picker_title = Conjunto de caracteres do jp2a
+charset_picker_instructions = j/k para escolher, Enter para aplicar, Esc para cancelar
+charset_custom_instructions = Digite os caracteres, o mais escuro primeiro, Enter para aplicar, Esc para voltar
+permissions_title = Permissões de {$file}
+permissions_current = Modo atual: {$mode}
+permissions_instructions = Digite um modo octal de 3 dígitos, Enter para aplicar, Esc para cancelar
+permissions_changed = Permissões de {$file} definidas como {$mode}
+permissions_failed = Não foi possível alterar as permissões de {$file}: {$error}
+permissions_unsupported = Alterar permissões não é compatível com este sistema
+jp2a_charset_changed = Conjunto de caracteres do jp2a: {$charset}
+debug_jp2a_charset = Caracteres: {$charset}
+debug_average_color = Média: {$hex}
+open_with_none = Nenhum aplicativo encontrado para abrir este arquivo
+checksum_computing = Calculando checksum…
+checksum_result = SHA-256: {$hash}
+checksum_failed = Falha ao calcular o checksum: {$error}
+directory_size_calculating = Calculando…
+directory_size_result = Tamanho: {$size} ({$count} arquivos)
+directory_size_timed_out = Cálculo do tamanho abandonado após {$seconds}s
+directory_image_count = {$count ->
+    [one] {$count} imagem
+   *[other] {$count} imagens
+}
+recent_files_title = Arquivos recentes
+recent_files_instructions = j/k para escolher, Enter para abrir, c para limpar o histórico, Esc para fechar
+recent_files_empty = Nenhum arquivo recente
+recent_file_missing = O arquivo não existe mais: {$file}
+dual_pane_mode = Dois painéis: Tab alterna o painel, Enter copia ou move um arquivo para o outro
+dual_pane_closed = Segundo painel fechado
+transfer_title = Copiar ou mover {$file}
+copy_to_secondary = c: Copiar para {$dir}
+move_to_secondary = m: Mover para {$dir}
+transfer_cancel = Esc: Cancelar
+file_copied = {$file} copiado para {$dir}
+file_moved = {$file} movido para {$dir}
+transfer_failed = Não foi possível copiar ou mover {$file}: {$error}
+batch_export_title = Exportar arte ASCII de {$count} imagens
+batch_export_skip_existing = Enter: Exportar, pulando imagens que já têm um arquivo .ascii
+batch_export_overwrite = o: Exportar e sobrescrever arquivos .ascii existentes
+batch_export_progress = Exportando: {$current}/{$total} ({$file})
+batch_export_done = Exportação em lote concluída: {$succeeded} com sucesso, {$failed} com falha, {$skipped} pulados
+batch_export_no_images = Nenhuma imagem para exportar neste diretório
+batch_export_running = Uma exportação em lote já está em andamento
+command_prompt = q: sair | e config: editar a configuração | Esc: cancelar
+command_unknown = Comando desconhecido: {$command}
+command_editor_failed = Não foi possível editar a configuração: {$error}
+metadata_title = Informações da imagem
+metadata_exif_title = EXIF (exiftool)
+metadata_format = Formato
+metadata_dimensions = Dimensões
+metadata_size = Tamanho
+metadata_modified = Modificado
+metadata_modified_ago = há {$age}
+metadata_unknown = desconhecido
@@ -0,0 +1,192 @@
+image_preview = Просмотр изображения
+app_subtitle = Терминальный интерфейс для изображений
+select_image_to_preview = Просмотрщик изображений для терминала. Сочетания клавиш:
+keys_navigation = ↑/k/←: Вверх, ↓/j/→: Вниз
+keys_page_navigation = Page Up/Page Down: Перейти на страницу
+keys_jump_navigation = f/b: Вперёд/назад на 10 файлов
+keys_fuzzy_search = Ctrl+F: Найти файл с помощью fzf (если установлен)
+keys_command_prompt = :q / :e config: Выход / изменить настройки в $EDITOR
+keys_home_end_navigation = Home: В начало, End: В конец
+keys_chords = gg: В начало, gd: Родительский каталог, dd: Удалить файл
+keys_sort = d: Сортировка по дате (новые/старые), n: Сортировка по имени
+keys_content_filter = Ctrl+T: Показывать только изображения, только текст, только файлы без просмотра или все
+keys_enter_directory = Enter: Открыть каталог
+keys_backspace_parent_dir = Backspace: На уровень выше
+keys_resize_window = [ и ]: Изменить размер панели
+keys_refresh_image = r: Обновить изображение
+keys_save_ascii = s: Сохранить ASCII-файл
+keys_batch_export = B: Экспорт ASCII-арта для всех изображений в каталоге
+keys_delete_file = x: Удалить текущий файл
+keys_copy_path = c / Ctrl+C: Скопировать путь к файлу
+keys_copy_preview = Ctrl+Y / Alt+Y: Скопировать ASCII-арт (Alt: без цветов)
+keys_checksum = C: Показать контрольную сумму SHA-256 файла
+keys_directory_size = Ctrl+D: Показать общий размер каталога
+keys_open_in_browser = o: Открыть в системном файловом менеджере
+keys_open_with = O: Открыть в выбранном приложении
+keys_charset_picker = Alt+C: Выбрать набор символов jp2a
+keys_permissions = P: Изменить права доступа выбранного файла
+keys_average_color = A: Показать средний цвет изображения в панели отладки
+keys_metadata = i: Метаданные изображения (EXIF, если установлен exiftool)
+keys_recent_files = Alt+R: Недавние файлы
+keys_dual_pane = Ctrl+\: Открыть или закрыть вторую панель (Tab переключает, Enter копирует/перемещает)
+keys_slideshow = Пробел: Запустить/остановить слайд-шоу (только изображения)
+keys_slideshow_shuffle = z: Перемешать порядок слайдов (в слайд-шоу)
+keys_slideshow_loop = l: Включить/выключить повтор (в слайд-шоу)
+keys_slideshow_filename = t: Показать/скрыть имя файла (в слайд-шоу)
+keys_slideshow_speed = +/-: Дольше/короче показ слайда (в слайд-шоу)
+keys_slideshow_save_frame = Ctrl+S: Сохранить кадр на экране в .ascii (в слайд-шоу), иначе сохранить задержку
+keys_slideshow_export_html = Ctrl+E: Экспортировать слайд-шоу в анимированную HTML-страницу (в слайд-шоу)
+keys_fullscreen = F: Полноэкранный просмотр
+keys_grid_view = G: Сетка миниатюр
+keys_debug_pane = D: Переключить панель отладки: файл, конвертер, кэш, сообщения
+keys_animation_pause = p: Пауза/продолжение GIF-анимации
+keys_tiff_pages = < / >: Предыдущая/следующая страница TIFF или размер ICO
+keys_text_scroll = u: Прокрутить текст вверх, Пробел: Прокрутить текст вниз
+keys_text_scroll_line = J / K: Прокрутить текст на одну строку вниз / вверх
+keys_converter_cycle = Tab: Переключить конвертер
+keys_help_toggle = ?: Показать справку
+help_scroll_up = u: Прокрутить справку вверх
+help_scroll_down = Пробел / d: Прокрутить справку вниз
+keys_quit = q/Esc: Выход
+not_supported_file_type = Выбранный файл не является изображением, текстовым или ASCII-файлом
+selected_file_not_image = Выбранный файл не является изображением
+directory_selected = Выбран каталог
+no_file_selected = Файл не выбран
+file_type_not_supported = Тип файла не поддерживается для просмотра
+unknown_file_type = Неизвестный тип файла
+ascii_file_prefix = ASCII-файл: 
+text_file_prefix = Текстовый файл: 
+text_file_unreadable = Ошибка: не удалось открыть файл
+image_file_prefix = Изображение: 
+video_file_prefix = Видео: 
+video_preview_failed = Не удалось извлечь кадр из видео (установлен ли ffmpeg?)
+audio_file_prefix = Аудио: 
+audio_preview_failed = Не удалось прочитать сведения об аудио (установлен ли ffprobe?)
+audio_title = Название: {$value}
+audio_artist = Исполнитель: {$value}
+audio_album = Альбом: {$value}
+audio_duration = Длительность: {$value}
+audio_bitrate = Битрейт: {$kbps} кбит/с
+audio_sample_rate = Частота дискретизации: {$hz} Гц
+preview_loading_timed_out = Время ожидания просмотра истекло через {$ms} мс
+clipboard_copied_path = Путь скопирован в буфер обмена: {$path}
+clipboard_unavailable = Буфер обмена недоступен: {$error}
+clipboard_not_compiled = Поддержка буфера обмена не включена при сборке
+clipboard_copied_ascii = ASCII-арт скопирован ({$count} символов)
+clipboard_no_preview = Нечего копировать
+heic_requires_converter = HEIC: требуется {$binary}
+raw_requires_extractor = Просмотр RAW: установите dcraw или darktable
+tiff_page = Страница {$page} из {$total}
+tiff_single_page = Одностраничный TIFF
+ico_sizes = ICO: {$sizes} (показан размер {$index})
+ico_single_size = ICO с одним размером
+ico_sizes_unavailable = Установите ImageMagick, чтобы переключать размеры ICO
+slideshow_mode = Режим слайд-шоу
+slideshow_image_of = Изображение {$current}/{$total}
+slideshow_hint_prev = назад
+slideshow_hint_next = вперёд
+slideshow_hint_exit = выход
+slideshow_hint_shuffle = перемешать
+slideshow_hint_loop = повтор
+slideshow_hint_name = имя
+slideshow_hint_speed = скорость
+slideshow_no_images = Ошибка: нет изображений для слайд-шоу
+slideshow_shuffle_on = Перемешивание: ВКЛ
+slideshow_shuffle_off = Перемешивание: ВЫКЛ
+slideshow_loop_on = Повтор: ВКЛ
+slideshow_loop_off = Повтор: ВЫКЛ
+slideshow_filename_overlay = Файл: {$file}
+slideshow_delay = Задержка: {$seconds} с
+slideshow_delay_saved = Задержка слайд-шоу сохранена в конфигурации
+slideshow_frame_saved = Кадр сохранён: {$file}
+slideshow_html_exported = Слайд-шоу сохранено: {$file}
+slideshow_html_exported_partly = Слайд-шоу сохранено: {$file} (с ошибкой {$failed}, см. {$log})
+slideshow_html_failed = Не удалось экспортировать слайд-шоу: {$error}
+fullscreen_mode = Полноэкранный просмотр
+animation_paused = Анимация приостановлена
+animation_resumed = Анимация продолжена
+messages = Сообщения
+debug_pane_file_info = Сведения о файле
+debug_pane_converter_info = Конвертер
+debug_pane_cache_stats = Кэш
+debug_pane_custom = Заметки
+debug_file_info = {$name} | {$size} | {$dimensions} | Изменён {$age} назад
+debug_converter_info = Конвертер: {$converter} | Вывод: {$output} | Последнее преобразование: {$ms} мс
+debug_cache_stats = Попадания: {$hits} | Промахи: {$misses} | Записи: {$entries}/{$capacity}
+cache_hit_rate = Доля попаданий: {$percent}%
+files_selected = Выбрано файлов: {$count}
+ptui_ready = PTUI готов...
+saved_to = Сохранено в:
+delete_file_prompt = Удалить {$file} безвозвратно?
+trash_file_prompt = Переместить {$file} в корзину?
+delete_confirmation_instructions = Нажмите 'y' для подтверждения, 'n' или Esc для отмены
+delete_confirmation_title = Подтверждение удаления
+date_sort_newest_first = Сортировка по дате: от новых к старым
+date_sort_oldest_first = Сортировка по дате: от старых к новым
+content_filter_all = Показаны все файлы
+content_filter_images_only = Показаны только изображения
+content_filter_text_only = Показаны только текстовые файлы
+content_filter_non_previewable = Показаны только файлы без просмотра
+opened_file_in_browser = Файл открыт в системном файловом менеджере
+opened_directory_in_browser = Каталог открыт в системном файловом менеджере
+failed_to_open_in_browser = Не удалось открыть в системном файловом менеджере
+opened_with = {$file} открыт в {$app}
+open_with_failed = Не удалось открыть в {$app}
+fuzzy_search_failed = Нечёткий поиск не удался: { $error }
+open_with_title = Открыть {$file} в
+open_with_instructions = j/k для выбора, Enter для открытия, Esc для отмены
+charset_picker_title = Набор символов jp2a
+charset_picker_instructions = j/k для выбора, Enter для применения, Esc для отмены
+charset_custom_instructions = Введите символы, начиная с самого тёмного, Enter для применения, Esc для возврата
+permissions_title = Права доступа {$file}
+permissions_current = Текущий режим: {$mode}
+permissions_instructions = Введите трёхзначный восьмеричный режим, Enter для применения, Esc для отмены
+permissions_changed = Права доступа {$file} изменены на {$mode}
+permissions_failed = Не удалось изменить права доступа {$file}: {$error}
+permissions_unsupported = Изменение прав доступа не поддерживается в этой системе
+jp2a_charset_changed = Набор символов jp2a: {$charset}
+debug_jp2a_charset = Символы: {$charset}
+debug_average_color = Средний: {$hex}
+open_with_none = Не найдено приложений для открытия этого файла
+checksum_computing = Вычисление контрольной суммы…
+checksum_result = SHA-256: {$hash}
+checksum_failed = Не удалось вычислить контрольную сумму: {$error}
+directory_size_calculating = Вычисление…
+directory_size_result = Размер: {$size} (файлов: {$count})
+directory_size_timed_out = Вычисление размера прервано через {$seconds} с
+directory_image_count = {$count ->
+    [one] {$count} изображение
+    [few] {$count} изображения
+   *[many] {$count} изображений
+}
+recent_files_title = Недавние файлы
+recent_files_instructions = j/k для выбора, Enter для открытия, c для очистки истории, Esc для закрытия
+recent_files_empty = Нет недавних файлов
+recent_file_missing = Файл больше не существует: {$file}
+dual_pane_mode = Две панели: Tab переключает панель, Enter копирует или перемещает файл
+dual_pane_closed = Вторая панель закрыта
+transfer_title = Копировать или переместить {$file}
+copy_to_secondary = c: Копировать в {$dir}
+move_to_secondary = m: Переместить в {$dir}
+transfer_cancel = Esc: Отмена
+file_copied = {$file} скопирован в {$dir}
+file_moved = {$file} перемещён в {$dir}
+transfer_failed = Не удалось скопировать или переместить {$file}: {$error}
+batch_export_title = Экспорт ASCII-арта для {$count} изображений
+batch_export_skip_existing = Enter: Экспорт, пропуская изображения с готовым файлом .ascii
+batch_export_overwrite = o: Экспорт с перезаписью существующих файлов .ascii
+batch_export_progress = Экспорт: {$current}/{$total} ({$file})
+batch_export_done = Пакетный экспорт завершён: успешно {$succeeded}, с ошибкой {$failed}, пропущено {$skipped}
+batch_export_no_images = В этом каталоге нет изображений для экспорта
+batch_export_running = Пакетный экспорт уже выполняется
+command_prompt = q: выход | e config: изменить настройки | Esc: отмена
+command_unknown = Неизвестная команда: {$command}
+command_editor_failed = Не удалось изменить настройки: {$error}
+metadata_title = Сведения об изображении
+metadata_exif_title = EXIF (exiftool)
+metadata_format = Формат
+metadata_dimensions = Размеры
+metadata_size = Размер файла
+metadata_modified = Изменён
+metadata_modified_ago = {$age} назад
+metadata_unknown = неизвестно
@@ -4,19 +4,49 @@ select_image_to_preview = 终端图像查看器。键盘快捷键：
 keys_navigation = ↑/k/←: 向上导航, ↓/j/→: 向下导航
 keys_page_navigation = Page Up/Page Down: 按页跳转
 keys_jump_navigation = f/b: 按10个文件前进/后退
+keys_fuzzy_search = Ctrl+F: 用 fzf 查找文件（如已安装）
+keys_command_prompt = :q / :e config：退出 / 在 $EDITOR 中编辑配置
 keys_home_end_navigation = Home: 转到开头, End: 转到结尾
+keys_chords = gg: 跳到开头, gd: 上级目录, dd: 删除文件
 keys_sort = d: 按日期排序（切换最新/最旧）, n: 按名称排序
+keys_content_filter = Ctrl+T: 仅显示图片、仅文本、仅无法预览的文件或全部
 keys_enter_directory = Enter: 打开目录
 keys_backspace_parent_dir = Backspace: 返回上级目录
 keys_resize_window = [ 和 ]: 调整窗口大小
 keys_refresh_image = r: 刷新图像
 keys_save_ascii = s: 保存ASCII文件
+keys_batch_export = B: 导出目录中所有图片的 ASCII 字符画
 keys_delete_file = x: 删除当前文件
+keys_copy_path = c / Ctrl+C: 复制文件路径到剪贴板
+keys_copy_preview = Ctrl+Y / Alt+Y: 复制 ASCII 艺术（Alt: 无颜色）
+keys_checksum = C: 显示文件的 SHA-256 校验和
+keys_directory_size = Ctrl+D: 显示目录的总大小
 keys_open_in_browser = o: 在系统文件浏览器中打开
+keys_open_with = O: 选择应用程序打开
+keys_charset_picker = Alt+C: 选择 jp2a 使用的字符集
+keys_permissions = P: 更改所选文件的权限
+keys_average_color = A: 在调试面板中显示所选图片的平均颜色
+keys_metadata = i：显示图像元数据（安装 exiftool 时显示 EXIF）
+keys_recent_files = Alt+R: 最近的文件
+keys_dual_pane = Ctrl+\: 打开或关闭第二个窗格 (Tab 切换，Enter 复制/移动)
 keys_slideshow = 空格: 开始/停止幻灯片（仅图像）
+keys_slideshow_shuffle = z: 随机播放幻灯片（幻灯片中）
+keys_slideshow_loop = l: 切换幻灯片循环（幻灯片中）
+keys_slideshow_filename = t: 切换文件名显示（幻灯片中）
+keys_slideshow_speed = +/-: 延长/缩短幻灯片间隔（幻灯片中）
+keys_slideshow_save_frame = Ctrl+S: 将屏幕上的帧保存为 .ascii（幻灯片中），否则保存幻灯片间隔
+keys_slideshow_export_html = Ctrl+E: 将幻灯片导出为动画 HTML 页面（幻灯片模式）
+keys_fullscreen = F: 切换全屏预览
+keys_grid_view = G: 切换缩略图网格视图
+keys_debug_pane = D: 在文件、转换器、缓存和消息之间切换调试面板
+keys_animation_pause = p: 暂停/继续 GIF 动画
+keys_tiff_pages = < / >: 上一个/下一个 TIFF 页面或 ICO 尺寸
 keys_text_scroll = u: 向上滚动文本, 空格: 向下滚动文本
+keys_text_scroll_line = J / K: 向下 / 向上滚动一行文本
 keys_converter_cycle = Tab: 切换转换器
 keys_help_toggle = ?: 显示帮助
+help_scroll_up = u: 向上滚动帮助
+help_scroll_down = 空格 / d: 向下滚动帮助
 keys_quit = q/Esc: 退出
 not_supported_file_type = 所选文件不是图像、文本或ASCII文件
 selected_file_not_image = 所选文件不是图像
@@ -26,18 +56,133 @@ file_type_not_supported = 不支持该文件类型预览
 unknown_file_type = 未知文件类型
 ascii_file_prefix = ASCII文件: 
 text_file_prefix = 文本文件: 
+text_file_unreadable = 错误：无法打开文件
 image_file_prefix = 图像: 
+video_file_prefix = 视频: 
+video_preview_failed = 无法提取视频帧（是否已安装 ffmpeg？）
+audio_file_prefix = 音频: 
+audio_preview_failed = 无法读取音频信息（是否已安装 ffprobe？）
+audio_title = 标题: {$value}
+audio_artist = 艺术家: {$value}
+audio_album = 专辑: {$value}
+audio_duration = 时长: {$value}
+audio_bitrate = 比特率: {$kbps} kb/s
+audio_sample_rate = 采样率: {$hz} Hz
+preview_loading_timed_out = 预览在{$ms}毫秒后超时
+clipboard_copied_path = 已将路径复制到剪贴板: {$path}
+clipboard_unavailable = 剪贴板不可用: {$error}
+clipboard_not_compiled = 未编译剪贴板支持
+clipboard_copied_ascii = 已复制 ASCII 艺术（{$count} 个字符）
+clipboard_no_preview = 没有可复制的预览
+heic_requires_converter = HEIC：需要 {$binary}
+raw_requires_extractor = RAW 预览：请安装 dcraw 或 darktable
+tiff_page = 第 {$page} 页，共 {$total} 页
+tiff_single_page = 单页 TIFF
+ico_sizes = ICO: {$sizes}（显示尺寸 {$index}）
+ico_single_size = 单一尺寸 ICO
+ico_sizes_unavailable = 安装 ImageMagick 以切换 ICO 尺寸
 slideshow_mode = 幻灯片模式
-slideshow_image = 图像
-slideshow_press_any_key = 按任意键退出
+slideshow_image_of = 图像 {$current}/{$total}
+slideshow_hint_prev = 上一张
+slideshow_hint_next = 下一张
+slideshow_hint_exit = 退出
+slideshow_hint_shuffle = 随机
+slideshow_hint_loop = 循环
+slideshow_hint_name = 文件名
+slideshow_hint_speed = 速度
+slideshow_no_images = 错误: 未找到可用于幻灯片的图片
+slideshow_shuffle_on = 随机播放：开
+slideshow_shuffle_off = 随机播放：关
+slideshow_loop_on = 循环：开
+slideshow_loop_off = 循环：关
+slideshow_filename_overlay = 文件: {$file}
+slideshow_delay = 间隔：{$seconds}秒
+slideshow_delay_saved = 幻灯片间隔已保存到配置
+slideshow_frame_saved = 已保存帧: {$file}
+slideshow_html_exported = 已保存幻灯片：{$file}
+slideshow_html_exported_partly = 已保存幻灯片：{$file}（失败 {$failed}，见 {$log}）
+slideshow_html_failed = 无法导出幻灯片：{$error}
+fullscreen_mode = 全屏预览
+animation_paused = 动画已暂停
+animation_resumed = 动画已继续
 messages = 消息
+debug_pane_file_info = 文件信息
+debug_pane_converter_info = 转换器
+debug_pane_cache_stats = 缓存
+debug_pane_custom = 备注
+debug_file_info = {$name} | {$size} | {$dimensions} | {$age} 前修改
+debug_converter_info = 转换器: {$converter} | 输出: {$output} | 上次转换: {$ms} 毫秒
+debug_cache_stats = 命中: {$hits} | 未命中: {$misses} | 条目: {$entries}/{$capacity}
+cache_hit_rate = 命中率: {$percent}%
+files_selected = 已选择 {$count} 个文件
 ptui_ready = PTUI 准备就绪...
 saved_to = 已保存至:
-delete_file_prompt = 删除文件 {$file}？
+delete_file_prompt = 永久删除 {$file}？
+trash_file_prompt = 将 {$file} 移到回收站？
 delete_confirmation_instructions = 按 'y' 确认，按 'n' 或 Esc 取消
 delete_confirmation_title = 确认删除
 date_sort_newest_first = 日期排序已更改：从新到旧
 date_sort_oldest_first = 日期排序已更改：从旧到新
+content_filter_all = 显示所有文件
+content_filter_images_only = 仅显示图片
+content_filter_text_only = 仅显示文本文件
+content_filter_non_previewable = 仅显示无法预览的文件
 opened_file_in_browser = 在系统浏览器中打开了文件
 opened_directory_in_browser = 在系统浏览器中打开了目录
 failed_to_open_in_browser = 无法在系统浏览器中打开
+opened_with = 已用 {$app} 打开 {$file}
+open_with_failed = 无法用 {$app} 打开
+fuzzy_search_failed = 模糊搜索失败: { $error }
+open_with_title = 打开 {$file} 的方式
+open_with_instructions = j/k 选择，Enter 打开，Esc 取消
+charset_picker_title = jp2a 字符集
+charset_picker_instructions = j/k 选择，Enter 应用，Esc 取消
+charset_custom_instructions = 输入字符（从最暗开始），Enter 应用，Esc 返回
+permissions_title = {$file} 的权限
+permissions_current = 当前模式：{$mode}
+permissions_instructions = 输入 3 位八进制模式，Enter 应用，Esc 取消
+permissions_changed = 已将 {$file} 的权限设为 {$mode}
+permissions_failed = 无法更改 {$file} 的权限：{$error}
+permissions_unsupported = 此系统不支持更改权限
+jp2a_charset_changed = jp2a 字符集：{$charset}
+debug_jp2a_charset = 字符集：{$charset}
+debug_average_color = 平均：{$hex}
+open_with_none = 未找到可打开此文件的应用程序
+checksum_computing = 正在计算校验和…
+checksum_result = SHA-256: {$hash}
+checksum_failed = 计算校验和失败: {$error}
+directory_size_calculating = 正在计算…
+directory_size_result = 大小: {$size} ({$count} 个文件)
+directory_size_timed_out = {$seconds} 秒后放弃计算大小
+directory_image_count = {$count} 张图片
+recent_files_title = 最近的文件
+recent_files_instructions = j/k 选择，Enter 打开，c 清除历史，Esc 关闭
+recent_files_empty = 没有最近的文件
+recent_file_missing = 文件已不存在: {$file}
+dual_pane_mode = 双窗格: Tab 切换窗格，Enter 复制或移动文件到另一侧
+dual_pane_closed = 已关闭第二个窗格
+transfer_title = 复制或移动 {$file}
+copy_to_secondary = c: 复制到 {$dir}
+move_to_secondary = m: 移动到 {$dir}
+transfer_cancel = Esc: 取消
+file_copied = 已将 {$file} 复制到 {$dir}
+file_moved = 已将 {$file} 移动到 {$dir}
+transfer_failed = 无法复制或移动 {$file}: {$error}
+batch_export_title = 导出 {$count} 张图片的 ASCII 字符画
+batch_export_skip_existing = Enter: 导出，跳过已有 .ascii 文件的图片
+batch_export_overwrite = o: 导出并覆盖已有的 .ascii 文件
+batch_export_progress = 正在导出: {$current}/{$total} ({$file})
+batch_export_done = 批量导出完成: 成功 {$succeeded}，失败 {$failed}，跳过 {$skipped}
+batch_export_no_images = 此目录中没有可导出的图片
+batch_export_running = 批量导出已在进行中
+command_prompt = q：退出 | e config：编辑配置 | Esc：取消
+command_unknown = 未知命令：{$command}
+command_editor_failed = 无法编辑配置：{$error}
+metadata_title = 图像信息
+metadata_exif_title = EXIF (exiftool)
+metadata_format = 格式
+metadata_dimensions = 尺寸
+metadata_size = 文件大小
+metadata_modified = 修改时间
+metadata_modified_ago = {$age}前
+metadata_unknown = 未知
@@ -1,18 +1,82 @@
-use crate::config::PTuiConfig;
-use crate::converter;
-use crate::file_browser::FileBrowser;
+use crate::args::Args;
+use crate::clipboard::SystemClipboard;
+use crate::command::{self, PromptCommand};
+use crate::config::{PTuiConfig, SlideshowOrder};
+use crate::converter::{self, CharsetPicker};
+use crate::error::PTuiError;
+use crate::file_browser::{
+    FileBrowser, FileItem, SortMode, directory_size, format_age, format_count, format_size,
+};
+use crate::fzf;
+use crate::history::RecentFiles;
 use crate::localization::Localization;
-use crate::preview::{PreviewContent, PreviewManager};
-use crate::transitions::TransitionManager;
-use crate::ui::{UILayout, UIRenderer};
+use crate::opener::{OpenWithPicker, Opener, detect_openers, open_with_command};
+use crate::preview::{
+    ContentAlignment, ConvertedPreview, EXPORT_ERRORS_FILE, ExportProgress, IcoSize, InlinePreview,
+    PreviewContent, PreviewHandle, PreviewManager,
+};
+use crate::session::SessionState;
+use crate::transitions::{Direction, TransitionManager};
+use crate::ui::{
+    DebugPaneContent, GRID_THUMBNAIL_HEIGHT, SlideshowStatus, UILayout, UIRenderer, ViewMode,
+    slideshow_progress_fill, slideshow_status_height,
+};
+use crate::watcher::{FileSystemEvent, FileWatcher};
 use ansi_to_tui::IntoText;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use fluent::FluentArgs;
 use ratatui::text::Text;
+use std::collections::HashMap;
 use std::error::Error;
 use std::process::Command;
-use std::time::{Duration, Instant};
+use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 const DIVIDER_PERCENT_INCREMENT: u16 = 2;
+// Only show the spinner for conversions that are noticeably slow
+const SPINNER_DELAY: Duration = Duration::from_millis(100);
+// Give up on directory sizes for trees too big to walk quickly
+const DIRECTORY_SIZE_TIMEOUT: Duration = Duration::from_secs(5);
+// Wait for file changes to settle so a burst of writes only refreshes once
+const DIRECTORY_CHANGE_DEBOUNCE: Duration = Duration::from_millis(200);
+// How long the first key of a chord waits for the second
+const CHORD_TIMEOUT: Duration = Duration::from_millis(500);
+// How long a notice like "Saved frame" replaces the slideshow status line
+const SLIDESHOW_NOTICE_DURATION: Duration = Duration::from_secs(3);
+
+// Ctrl+E in the slideshow writes this page into the current directory
+const SLIDESHOW_HTML_FILE: &str = "slideshow.html";
+
+/// What a multi-key sequence does
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ChordAction {
+    GoToTop,
+    GoToParent,
+    Delete,
+}
+
+/// Vim style key sequences. Their first key still does its usual job when no second
+/// key follows within CHORD_TIMEOUT.
+const KEY_CHORDS: &[(&str, ChordAction)] = &[
+    ("gg", ChordAction::GoToTop),
+    ("gd", ChordAction::GoToParent),
+    ("dd", ChordAction::Delete),
+];
+
+fn chord_action(chord: &str) -> Option<ChordAction> {
+    KEY_CHORDS
+        .iter()
+        .find(|(keys, _)| *keys == chord)
+        .map(|(_, action)| *action)
+}
+
+// Changed settings listed in the debug pane after a config reload
+const MAX_RELOAD_CHANGES_SHOWN: usize = 3;
+
+/// Directory path to (total bytes, file count)
+type DirectorySizeCache = HashMap<String, (u64, u64)>;
 
 const EMBEDDED_LOGO: &str = r#"
 
@@ -29,22 +93,119 @@ const EMBEDDED_LOGO: &str = r#"
 {app_subtitle}
 v{version}"#;
 
+/// Progress of the image conversion for the selected file
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConversionState {
+    Idle,
+    Loading {
+        file_name: String,
+        started_at: Instant,
+    },
+    Done,
+}
+
+/// A conversion running on a background thread
+struct PendingConversion {
+    path: String,
+    modified: std::time::SystemTime,
+    width: u16,
+    height: u16,
+    receiver: Receiver<ConvertedPreview>,
+}
+
+/// A second file list shown next to the first, opened with Ctrl+\
+struct SecondaryBrowser {
+    // The pane without focus; Tab swaps it with file_browser
+    browser: FileBrowser,
+    // Whether the focused pane is the secondary (right hand) one
+    active: bool,
+}
+
+/// The file Enter offers to copy or move to the other pane's directory
+struct TransferDialog {
+    file_path: String,
+    file_name: String,
+    target_dir: String,
+}
+
+/// The permission editor opened with P, holding the octal mode typed so far
+struct PermissionDialog {
+    file_path: String,
+    file_name: String,
+    mode: u32,
+    input: String,
+}
+
+/// A checksum being computed on a background thread
+struct PendingChecksum {
+    path: String,
+    modified: SystemTime,
+    receiver: Receiver<Result<String, String>>,
+}
+
+/// A batch ASCII export running on background threads, and the latest progress line
+struct PendingBatchExport {
+    receiver: Receiver<ExportProgress>,
+    progress: Option<String>,
+    // Where a slideshow HTML export is writing its page; None for a batch export
+    html_output: Option<String>,
+}
+
+/// A directory size being calculated on a background thread
+struct PendingDirectorySize {
+    path: String,
+    // The file browser refresh it was started in, so results from before a refresh aren't cached
+    refresh_count: u64,
+    receiver: Receiver<Option<(u64, u64)>>,
+}
+
+/// Image counts of the visible directories being worked out on a background thread
+struct PendingImageCounts {
+    dir: String,
+    // The file browser refresh it was started in, so counts from before a refresh aren't kept
+    refresh_count: u64,
+    receiver: Receiver<Vec<(String, usize)>>,
+}
+
 pub struct ChafaTui {
     file_browser: FileBrowser,
     preview_manager: PreviewManager,
     transition_manager: TransitionManager,
     ui_layout: UILayout,
-    localization: Localization,
+    localization: Arc<Localization>,
     preview_content: Option<PreviewContent>,
-    is_preview_image: bool,
-    is_text_file: bool,
     terminal_width: u16,
     terminal_height: u16,
     show_help_on_startup: bool,
     show_help_toggle: bool,
+    help_scroll_offset: usize,
     ascii_logo: Option<Text<'static>>,
+    fullscreen_preview: bool,
     // Text file scrolling state
     text_scroll_offset: usize,
+    text_total_lines: Option<usize>, // Line count of the text file being previewed
+    // The file and modification time the line count was asked for
+    text_lines_counted_for: Option<(String, SystemTime)>,
+    // Background conversion state
+    conversion_state: ConversionState,
+    pending_conversion: Option<PendingConversion>,
+    // A text preview being read on another thread
+    pending_preview_handle: Option<PreviewHandle>,
+    // When the last navigation asked for a preview that hasn't been generated yet
+    pending_preview_at: Option<Instant>,
+    // When the terminal size was last applied, and a newer size waiting for the debounce
+    last_resize_at: Option<Instant>,
+    pending_resize: Option<(u16, u16)>,
+    // Animated GIF playback state
+    animation_frame_index: usize,
+    animation_last_frame: Instant,
+    animation_paused: bool,
+    // Multi-page TIFF and multi-size ICO state (paged_file tracks which file it belongs to)
+    tiff_page: usize,
+    tiff_page_count: usize,
+    ico_page: usize, // Index into ico_sizes, which are largest first
+    ico_sizes: Vec<IcoSize>,
+    paged_file: Option<String>,
     // Slideshow state
     is_slideshow_mode: bool,
     slideshow_start_index: usize,
@@ -53,48 +214,201 @@ pub struct ChafaTui {
     slideshow_delay: Duration,
     slideshow_image_files: Vec<usize>, // Indices of image files only
     slideshow_previous_content: Option<PreviewContent>,
+    slideshow_shuffle: bool,
+    slideshow_shuffled_indices: Vec<usize>, // Positions in slideshow_image_files, in play order
+    slideshow_shuffle_position: usize,
+    slideshow_loop: bool,
+    slideshow_show_filename: bool,
+    slideshow_progress_drawn: u16, // Progress bar cells filled at the last redraw
+    slideshow_notice: Option<(String, Instant)>, // Message and when it was shown
     // Delete confirmation dialog state
     show_delete_confirmation: bool,
     delete_target_file: Option<String>,
+    move_to_trash: fn(&str) -> Result<(), Box<dyn Error>>, // Tests swap in their own
+    open_with_picker: Option<OpenWithPicker>,
+    charset_picker: Option<CharsetPicker>,
+    // Whether Ctrl+F runs fzf, and a search waiting for the main loop to hand over the terminal
+    fzf_integration: bool,
+    fuzzy_search_requested: bool,
+    command_prompt: Option<String>, // Typed after ':', None when the prompt is closed
+    config_edit_requested: bool,
+    secondary_browser: Option<SecondaryBrowser>,
+    transfer_dialog: Option<TransferDialog>,
+    permission_dialog: Option<PermissionDialog>,
+    // Images the B dialog offers to export, and the export once it's running
+    batch_export_dialog: Option<Vec<FileItem>>,
+    pending_batch_export: Option<PendingBatchExport>,
+    // Recently previewed files, and the selected row while the Alt+R dialog is open
+    recent_files: RecentFiles,
+    recent_files_dialog: Option<usize>,
+    // What the debug pane shows, cycled with D
+    debug_pane_mode: DebugPaneContent,
+    // Whether the debug pane ends with the selected image's average colour, toggled with A
+    show_avg_color: bool,
+    show_metadata: bool, // The preview pane shows the i metadata panel instead
+    // Keys typed so far of a chord like gg, and when the last one was pressed
+    key_chord_buffer: String,
+    chord_timeout: Instant,
+    clipboard: SystemClipboard,
+    // SHA-256 checksums by file path, with the modification time they were computed for
+    checksums: HashMap<String, (SystemTime, String)>,
+    pending_checksum: Option<PendingChecksum>,
+    // Directory sizes, dropped whenever the file browser refreshes
+    directory_sizes: DirectorySizeCache,
+    directory_sizes_refresh: u64,
+    pending_directory_size: Option<PendingDirectorySize>,
+    pending_image_counts: Option<PendingImageCounts>,
+    // Watches the current directory when watch_current_directory is on
+    directory_watcher: Option<FileWatcher>,
+    file_system_events: Vec<FileSystemEvent>,
+    last_directory_change: Option<Instant>,
+    // The Sixel or iTerm2 image written after the last frame, which only a full clear removes
+    inline_image_shown: Option<Rc<InlinePreview>>,
     // Dirty flag for render optimization
     needs_redraw: bool,
 }
 
 impl ChafaTui {
-    pub fn new() -> Result<Self, Box<dyn Error>> {
-        let config = PTuiConfig::load()?;
-        Self::check_required_applications(&config)?;
+    /// Load the config, session and recent files, check the converter is installed and
+    /// start the app as the command line asks
+    pub fn new(args: &Args) -> Result<Self, PTuiError> {
+        let mut config =
+            PTuiConfig::load().map_err(|e| PTuiError::from_boxed(e, PTuiError::ConfigError))?;
 
-        let locale = config.get_locale();
-        let slideshow_delay = Duration::from_millis(config.get_slideshow_delay_ms());
+        // A path on the command line wins over wherever the last session ended
+        let session = if args.start_path().is_none() && config.get_restore_session() {
+            SessionState::default_path().and_then(|path| SessionState::load_from(&path))
+        } else {
+            None
+        };
+        // Restored before the check, so a saved converter has to be installed too
+        if let Some(session) = &session {
+            config.converter.selected = session.converter.clone();
+        }
+        Self::check_required_applications(&config)?;
 
+        let locale = config.resolve_locale();
         println!("Using locale: {}", locale);
 
-        let localization = Localization::new(&locale)?;
-        let file_browser = FileBrowser::new()?;
+        let localization = Localization::new(&locale)
+            .map_err(|e| PTuiError::from_boxed(e, PTuiError::LocalizationError))?;
+        let recent_files = RecentFiles::default_path()
+            .map(|path| RecentFiles::load_from(&path))
+            .unwrap_or_default();
+        Self::start(config, localization, args, session, recent_files)
+    }
+
+    /// Start in the directory given on the command line, or in the directory of the
+    /// given file with it selected. Without a path the session's directory, selection
+    /// and scroll position are picked up, or the current directory used. --slideshow
+    /// then starts the slideshow.
+    pub fn start(
+        config: PTuiConfig,
+        localization: Localization,
+        args: &Args,
+        session: Option<SessionState>,
+        recent_files: RecentFiles,
+    ) -> Result<Self, PTuiError> {
+        let browser_error = |e| PTuiError::from_boxed(e, PTuiError::NotFound);
+        let file_browser = match (args.start_path(), &session) {
+            (Some(path), _) => FileBrowser::new_for_path(path).map_err(browser_error)?,
+            (None, Some(session)) => Self::restore_session_browser(session)?,
+            (None, None) => FileBrowser::new()?,
+        };
+
+        let mut app = Self::with_parts(config, file_browser, localization);
+        app.recent_files = recent_files;
+        if let Some(session) = session {
+            app.text_scroll_offset = session.text_scroll_offset;
+        }
+        app.update_preview();
+        app.report_converter_fallback();
+        if args.slideshow {
+            // Falls back to the file browser with an error if there are no images
+            app.enter_slideshow_mode();
+        }
+        Ok(app)
+    }
+
+    /// The browser where a session left off. The directory may have been deleted since,
+    /// in which case the current directory is listed instead.
+    fn restore_session_browser(session: &SessionState) -> Result<FileBrowser, PTuiError> {
+        let restored = FileBrowser::new_with_dir(&session.current_dir).and_then(|mut browser| {
+            browser.set_sort_mode(session.sort_mode.clone());
+            browser.set_content_filter(session.filter_mode)?;
+            browser.set_selected_index(session.selected_index);
+            Ok(browser)
+        });
+        match restored {
+            Ok(file_browser) => Ok(file_browser),
+            Err(_) => FileBrowser::new(),
+        }
+    }
+
+    /// Assemble the app from an already loaded config, browser and localization.
+    /// Unlike new() this doesn't touch the user's config file or check for converters.
+    pub fn with_parts(
+        config: PTuiConfig,
+        mut file_browser: FileBrowser,
+        localization: Localization,
+    ) -> Self {
+        file_browser.set_git_integration(config.get_git_integration());
+        file_browser.set_show_image_counts(config.get_show_image_counts());
+        file_browser.set_show_permissions(config.get_show_permissions());
+        file_browser.set_locale(localization.current_locale());
+        let slideshow_delay = Duration::from_millis(config.get_slideshow_delay_ms());
+        let slideshow_loop = config.get_slideshow_loop();
+        let fzf_integration = config.get_fzf_integration();
         let mut preview_manager = PreviewManager::new(config.clone());
         let transition_manager = TransitionManager::new(config.get_slideshow_transitions());
 
         // Set initial ready message
         preview_manager.debug_info = localization.get("ptui_ready");
-        let ascii_logo = Self::load_ascii_logo();
+        let (ascii_logo, logo_warning) = Self::help_logo(&config);
+        if let Some(warning) = logo_warning {
+            preview_manager.debug_info =
+                format!("{} | Warning: {}", preview_manager.debug_info, warning);
+        }
+        let watch_current_directory = config.get_watch_current_directory();
+        let mut ui_layout = UILayout::new();
+        let (wide_width, narrow_width) = config.get_file_browser_width_percents();
+        ui_layout.set_file_browser_width_percents(wide_width, narrow_width);
 
         let mut app = Self {
             file_browser,
             preview_manager,
             transition_manager,
-            ui_layout: UILayout::new(),
-            localization,
+            ui_layout,
+            localization: Arc::new(localization),
             preview_content: None,
-            is_preview_image: false,
-            is_text_file: false,
             terminal_width: 80,
             terminal_height: 24,
             show_help_on_startup: true,
             show_help_toggle: false,
+            help_scroll_offset: 0,
             ascii_logo,
+            fullscreen_preview: false,
             // Text file scrolling state
             text_scroll_offset: 0,
+            text_total_lines: None,
+            text_lines_counted_for: None,
+            // Background conversion state
+            conversion_state: ConversionState::Idle,
+            pending_conversion: None,
+            pending_preview_handle: None,
+            pending_preview_at: None,
+            last_resize_at: None,
+            pending_resize: None,
+            // Animated GIF playback state
+            animation_frame_index: 0,
+            animation_last_frame: Instant::now(),
+            animation_paused: false,
+            // Multi-page TIFF state
+            tiff_page: 0,
+            tiff_page_count: 1,
+            ico_page: 0,
+            ico_sizes: Vec::new(),
+            paged_file: None,
             // Slideshow state
             is_slideshow_mode: false,
             slideshow_start_index: 0,
@@ -103,18 +417,63 @@ impl ChafaTui {
             slideshow_delay,
             slideshow_image_files: Vec::new(),
             slideshow_previous_content: None,
+            slideshow_shuffle: false,
+            slideshow_shuffled_indices: Vec::new(),
+            slideshow_shuffle_position: 0,
+            slideshow_loop,
+            slideshow_show_filename: false,
+            slideshow_progress_drawn: 0,
+            slideshow_notice: None,
             // Delete confirmation dialog state
             show_delete_confirmation: false,
             delete_target_file: None,
+            move_to_trash: Self::move_to_os_trash,
+            open_with_picker: None,
+            charset_picker: None,
+            fzf_integration,
+            fuzzy_search_requested: false,
+            command_prompt: None,
+            config_edit_requested: false,
+            secondary_browser: None,
+            transfer_dialog: None,
+            permission_dialog: None,
+            batch_export_dialog: None,
+            pending_batch_export: None,
+            recent_files: RecentFiles::default(),
+            recent_files_dialog: None,
+            debug_pane_mode: DebugPaneContent::Messages,
+            show_avg_color: false,
+            show_metadata: false,
+            key_chord_buffer: String::new(),
+            chord_timeout: Instant::now(),
+            clipboard: SystemClipboard::new(),
+            checksums: HashMap::new(),
+            pending_checksum: None,
+            directory_sizes: HashMap::new(),
+            directory_sizes_refresh: 0,
+            pending_directory_size: None,
+            pending_image_counts: None,
+            directory_watcher: None,
+            file_system_events: Vec::new(),
+            last_directory_change: None,
+            inline_image_shown: None,
             // Dirty flag for render optimization
             needs_redraw: true,
         };
+        app.set_directory_watching(watch_current_directory);
+        app
+    }
 
-        app.update_preview();
-        Ok(app)
+    /// Say so in the messages pane when the selected converter can't run in this terminal
+    /// and chafa is used instead
+    fn report_converter_fallback(&mut self) {
+        let selected = &self.preview_manager.get_config().converter.selected;
+        if let Some(notice) = converter::fallback_notice(selected, converter::is_iterm2()) {
+            self.preview_manager.set_message(notice);
+        }
     }
 
-    fn check_required_applications(config: &PTuiConfig) -> Result<(), Box<dyn Error>> {
+    fn check_required_applications(config: &PTuiConfig) -> Result<(), PTuiError> {
         // Check selected converter availability
         let selected_converter = &config.converter.selected;
         if let Err(e) = converter::check_converter_availability(selected_converter) {
@@ -123,32 +482,62 @@ impl ChafaTui {
                 "Please install {} before running this application.",
                 selected_converter
             );
-            return Err(format!("{} not available", selected_converter).into());
+            return Err(PTuiError::ConverterError {
+                converter: selected_converter.clone(),
+                message: e.to_string(),
+            });
         }
 
-        // Check if identify is available (from ImageMagick) - always required for dimension detection
+        // Check if identify is available (from ImageMagick) for dimension detection.
+        // Common formats can be measured natively, so it's only required when that's turned off.
         let identify_result = Command::new("identify").arg("-version").output();
         if identify_result.is_err() || !identify_result.unwrap().status.success() {
-            eprintln!(
-                "Error: identify application (from ImageMagick) is required but not found in PATH."
-            );
-            eprintln!("Please install ImageMagick before running this application.");
-            return Err("identify not found".into());
+            if config.get_prefer_native_dimension_detection() {
+                eprintln!("Warning: identify (from ImageMagick) not found in PATH.");
+                eprintln!("Image sizes will be read from the file headers instead.");
+            } else {
+                eprintln!(
+                    "Error: identify application (from ImageMagick) is required but not found in PATH."
+                );
+                eprintln!("Please install ImageMagick before running this application.");
+                return Err(PTuiError::NotFound("identify not found".to_string()));
+            }
         }
 
         println!("Using converter: {}", selected_converter);
         Ok(())
     }
 
-    fn load_ascii_logo() -> Option<Text<'static>> {
-        // Use embedded logo instead of reading from file
-        match EMBEDDED_LOGO.into_text() {
-            Ok(text) => Some(text),
-            Err(_) => {
-                eprintln!("Warning: Failed to parse embedded ASCII logo");
-                None
+    /// The logo from logo_file, falling back to the embedded one if it's unset or can't
+    /// be read, with a warning saying why. Empty when logo_hidden is set.
+    fn load_ascii_logo(config: &PTuiConfig) -> (Text<'static>, Option<String>) {
+        if config.get_logo_hidden() {
+            return (Text::default(), None);
+        }
+
+        let mut warning = None;
+        if let Some(path) = config.get_logo_file() {
+            match std::fs::read(path).map(|bytes| bytes.into_text()) {
+                Ok(Ok(text)) => return (text, None),
+                Ok(Err(e)) => warning = Some(format!("Failed to parse logo {}: {}", path, e)),
+                Err(e) => warning = Some(format!("Failed to read logo {}: {}", path, e)),
             }
         }
+
+        match EMBEDDED_LOGO.into_text() {
+            Ok(text) => (text, warning),
+            Err(_) => (
+                Text::default(),
+                Some("Failed to parse embedded ASCII logo".to_string()),
+            ),
+        }
+    }
+
+    /// The logo above the help text, if there is one to show, and any warning from
+    /// loading it
+    fn help_logo(config: &PTuiConfig) -> (Option<Text<'static>>, Option<String>) {
+        let (logo, warning) = Self::load_ascii_logo(config);
+        (Some(logo).filter(|logo| !logo.lines.is_empty()), warning)
     }
 
     pub fn handle_key_event(&mut self, key: KeyEvent) -> Result<(), Box<dyn Error>> {
@@ -157,36 +546,125 @@ impl ChafaTui {
             self.handle_delete_confirmation(key)?;
             return Ok(());
         }
+        if self.open_with_picker.is_some() {
+            self.handle_open_with_picker(key);
+            return Ok(());
+        }
+        if self.charset_picker.is_some() {
+            self.handle_charset_picker(key);
+            return Ok(());
+        }
+        if self.recent_files_dialog.is_some() {
+            self.handle_recent_files_dialog(key);
+            return Ok(());
+        }
+        if self.transfer_dialog.is_some() {
+            self.handle_transfer_dialog(key);
+            return Ok(());
+        }
+        if self.permission_dialog.is_some() {
+            self.handle_permission_dialog(key);
+            return Ok(());
+        }
+        if self.batch_export_dialog.is_some() {
+            self.handle_batch_export_dialog(key);
+            return Ok(());
+        }
+        if self.command_prompt.is_some() {
+            return self.handle_command_prompt(key);
+        }
+
+        // While the help is up, u, Space and d scroll it instead, before d can start a
+        // chord like dd
+        if (self.show_help_on_startup || self.show_help_toggle)
+            && key.modifiers.is_empty()
+            && matches!(key.code, KeyCode::Char('u' | ' ' | 'd'))
+        {
+            self.flush_key_chord()?;
+            if key.code == KeyCode::Char('u') {
+                self.scroll_help_up();
+            } else {
+                self.scroll_help_down();
+            }
+            return Ok(());
+        }
+
+        if !self.is_slideshow_mode
+            && !key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+            && let KeyCode::Char(c) = key.code
+        {
+            // A key that starts a chord waits for the next one
+            if !self.key_chord_buffer.is_empty() {
+                let chord = format!("{}{}", self.key_chord_buffer, c);
+                if let Some(action) = chord_action(&chord) {
+                    self.key_chord_buffer.clear();
+                    return self.run_chord_action(action);
+                }
+                self.flush_key_chord()?;
+            }
+            if KEY_CHORDS.iter().any(|(chord, _)| chord.starts_with(c)) {
+                self.key_chord_buffer.push(c);
+                self.chord_timeout = Instant::now();
+                return Ok(());
+            }
+        } else {
+            self.flush_key_chord()?;
+        }
+
+        self.handle_single_key(key)
+    }
 
+    /// Handle a key that isn't part of a chord
+    fn handle_single_key(&mut self, key: KeyEvent) -> Result<(), Box<dyn Error>> {
         match key.code {
             KeyCode::Char('q') | KeyCode::Esc => return Err("Quit".into()),
             KeyCode::Down | KeyCode::Char('j') => {
                 self.show_help_on_startup = false;
                 self.show_help_toggle = false;
-                self.file_browser.move_down();
+                if self.ui_layout.view_mode == ViewMode::Grid {
+                    self.file_browser.move_row_down();
+                } else {
+                    self.file_browser.move_down();
+                }
                 self.reset_text_scroll();
-                self.update_preview();
+                self.schedule_preview();
             }
             KeyCode::Up | KeyCode::Char('k') => {
                 self.show_help_on_startup = false;
                 self.show_help_toggle = false;
-                self.file_browser.move_up();
+                if self.ui_layout.view_mode == ViewMode::Grid {
+                    self.file_browser.move_row_up();
+                } else {
+                    self.file_browser.move_up();
+                }
                 self.reset_text_scroll();
-                self.update_preview();
+                self.schedule_preview();
             }
             KeyCode::PageDown => {
                 self.show_help_on_startup = false;
                 self.show_help_toggle = false;
                 self.file_browser.page_down();
                 self.reset_text_scroll();
-                self.update_preview();
+                self.schedule_preview();
             }
             KeyCode::PageUp => {
                 self.show_help_on_startup = false;
                 self.show_help_toggle = false;
                 self.file_browser.page_up();
                 self.reset_text_scroll();
-                self.update_preview();
+                self.schedule_preview();
+            }
+            KeyCode::Char('f')
+                if key.modifiers.contains(KeyModifiers::CONTROL) && self.fzf_integration =>
+            {
+                self.show_help_on_startup = false;
+                self.show_help_toggle = false;
+                self.fuzzy_search_requested = true;
+            }
+            KeyCode::Char(':') if !self.is_slideshow_mode => {
+                self.command_prompt = Some(String::new());
             }
             KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                 self.show_help_on_startup = false;
@@ -207,6 +685,17 @@ impl ChafaTui {
                     self.scroll_text_up();
                 }
             }
+            // One line at a time, whatever text_scroll_lines is
+            KeyCode::Char('J') if self.is_text_file_selected() => {
+                self.show_help_on_startup = false;
+                self.show_help_toggle = false;
+                self.scroll_text_down_by(1);
+            }
+            KeyCode::Char('K') if self.is_text_file_selected() => {
+                self.show_help_on_startup = false;
+                self.show_help_toggle = false;
+                self.scroll_text_up_by(1);
+            }
             KeyCode::Char('f') => {
                 self.show_help_on_startup = false;
                 self.show_help_toggle = false;
@@ -221,6 +710,31 @@ impl ChafaTui {
                 self.reset_text_scroll();
                 self.update_preview();
             }
+            // Ctrl+I only arrives as itself in terminals that tell it apart from Tab
+            KeyCode::Char('D') => self.cycle_debug_pane(),
+            KeyCode::Char('A') => {
+                self.show_avg_color = !self.show_avg_color;
+                self.needs_redraw = true;
+            }
+            KeyCode::Char('i') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.cycle_debug_pane()
+            }
+            KeyCode::Char('i') => {
+                self.show_help_on_startup = false;
+                self.show_help_toggle = false;
+                self.show_metadata = !self.show_metadata;
+                self.update_preview();
+            }
+            KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.show_help_on_startup = false;
+                self.show_help_toggle = false;
+                self.cycle_content_filter();
+            }
+            KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.show_help_on_startup = false;
+                self.show_help_toggle = false;
+                self.show_directory_size();
+            }
             KeyCode::Char('d') => {
                 self.show_help_on_startup = false;
                 self.show_help_toggle = false;
@@ -238,7 +752,13 @@ impl ChafaTui {
             KeyCode::Enter => {
                 self.show_help_on_startup = false;
                 self.show_help_toggle = false;
-                if self.file_browser.enter_directory()? {
+                let file_selected = self
+                    .file_browser
+                    .get_selected_file()
+                    .is_some_and(|file| !file.is_directory);
+                if self.secondary_browser.is_some() && file_selected {
+                    self.show_transfer_dialog();
+                } else if self.file_browser.enter_directory()? {
                     self.preview_manager.clear_cache();
                     self.update_preview();
                 }
@@ -251,6 +771,12 @@ impl ChafaTui {
                     self.update_preview();
                 }
             }
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::ALT) => {
+                self.show_help_on_startup = false;
+                self.show_help_toggle = false;
+                self.recent_files_dialog = Some(0);
+                self.needs_redraw = true;
+            }
             KeyCode::Char('r') => {
                 self.show_help_on_startup = false;
                 self.show_help_toggle = false;
@@ -268,11 +794,59 @@ impl ChafaTui {
                 self.ui_layout.increase_size(DIVIDER_PERCENT_INCREMENT);
                 self.update_preview();
             }
+            KeyCode::Char('s')
+                if key.modifiers.contains(KeyModifiers::CONTROL) && self.is_slideshow_mode =>
+            {
+                self.save_slideshow_frame();
+            }
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.save_slideshow_delay();
+            }
+            KeyCode::Char('e')
+                if key.modifiers.contains(KeyModifiers::CONTROL) && self.is_slideshow_mode =>
+            {
+                self.export_slideshow_html();
+            }
             KeyCode::Char('s') => {
                 self.show_help_on_startup = false;
                 self.show_help_toggle = false;
                 self.save_ascii_file();
             }
+            KeyCode::Char('B') if !self.is_slideshow_mode => {
+                self.show_help_on_startup = false;
+                self.show_help_toggle = false;
+                self.show_batch_export_dialog();
+            }
+            KeyCode::Char('y')
+                if key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                // Ctrl+Y keeps the colours, Alt+Y copies plain text
+                let plain = key.modifiers.contains(KeyModifiers::ALT);
+                self.copy_preview_to_clipboard(plain);
+            }
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::ALT) => {
+                self.show_help_on_startup = false;
+                self.show_help_toggle = false;
+                self.show_charset_picker();
+            }
+            // c and Ctrl+C both copy, quitting is q or Esc
+            KeyCode::Char('c') if !self.is_slideshow_mode => {
+                self.show_help_on_startup = false;
+                self.show_help_toggle = false;
+                self.copy_path_to_clipboard();
+            }
+            KeyCode::Char('C') => {
+                self.show_help_on_startup = false;
+                self.show_help_toggle = false;
+                self.show_checksum();
+            }
+            KeyCode::Char('P') => {
+                self.show_help_on_startup = false;
+                self.show_help_toggle = false;
+                self.show_permission_dialog();
+            }
             KeyCode::Char('x') => {
                 self.show_help_on_startup = false;
                 self.show_help_toggle = false;
@@ -284,6 +858,11 @@ impl ChafaTui {
                 self.show_help_toggle = false;
                 self.open_in_system_browser();
             }
+            KeyCode::Char('O') => {
+                self.show_help_on_startup = false;
+                self.show_help_toggle = false;
+                self.show_open_with_picker();
+            }
             KeyCode::Char(' ') => {
                 self.show_help_on_startup = false;
                 self.show_help_toggle = false;
@@ -299,6 +878,7 @@ impl ChafaTui {
             KeyCode::Char('?') => {
                 self.show_help_on_startup = false;
                 self.show_help_toggle = !self.show_help_toggle;
+                self.help_scroll_offset = 0;
                 self.update_preview();
             }
             KeyCode::Right => {
@@ -337,6 +917,61 @@ impl ChafaTui {
                 self.reset_text_scroll();
                 self.update_preview();
             }
+            KeyCode::Char('F') => {
+                self.show_help_on_startup = false;
+                self.show_help_toggle = false;
+                if self.is_slideshow_mode {
+                    self.exit_slideshow_mode();
+                }
+                self.toggle_fullscreen_preview();
+            }
+            KeyCode::Char('>') => {
+                self.show_help_on_startup = false;
+                self.show_help_toggle = false;
+                self.change_page(true);
+            }
+            KeyCode::Char('<') => {
+                self.show_help_on_startup = false;
+                self.show_help_toggle = false;
+                self.change_page(false);
+            }
+            KeyCode::Char('z') if self.is_slideshow_mode => {
+                self.toggle_slideshow_shuffle();
+            }
+            KeyCode::Char('l') if self.is_slideshow_mode => {
+                self.toggle_slideshow_loop();
+            }
+            KeyCode::Char('t') if self.is_slideshow_mode => {
+                self.toggle_slideshow_filename();
+            }
+            KeyCode::Char('+') if self.is_slideshow_mode => {
+                self.adjust_slideshow_delay(true);
+            }
+            KeyCode::Char('-') if self.is_slideshow_mode => {
+                self.adjust_slideshow_delay(false);
+            }
+            KeyCode::Char('p') => {
+                // Works in the slideshow too, so it doesn't fall through to the exit handler
+                self.toggle_animation_pause();
+            }
+            KeyCode::Char('G') => {
+                self.show_help_on_startup = false;
+                self.show_help_toggle = false;
+                self.toggle_grid_view();
+            }
+            KeyCode::Char('\\') | KeyCode::Char('4')
+                if key.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                // Some terminals send Ctrl+\ as Ctrl+4
+                self.show_help_on_startup = false;
+                self.show_help_toggle = false;
+                self.toggle_dual_pane();
+            }
+            KeyCode::Tab if self.secondary_browser.is_some() => {
+                self.show_help_on_startup = false;
+                self.show_help_toggle = false;
+                self.switch_pane_focus();
+            }
             KeyCode::Tab => {
                 self.show_help_on_startup = false;
                 self.show_help_toggle = false;
@@ -352,6 +987,43 @@ impl ChafaTui {
         Ok(())
     }
 
+    /// Run the chord's first key on its own once no second key has come within CHORD_TIMEOUT
+    pub fn poll_key_chord(&mut self) -> Result<(), Box<dyn Error>> {
+        if !self.key_chord_buffer.is_empty() && self.chord_timeout.elapsed() >= CHORD_TIMEOUT {
+            self.flush_key_chord()?;
+        }
+        Ok(())
+    }
+
+    /// Give up on the chord in progress, handling its keys as single keys
+    fn flush_key_chord(&mut self) -> Result<(), Box<dyn Error>> {
+        let pending = std::mem::take(&mut self.key_chord_buffer);
+        for c in pending.chars() {
+            self.handle_single_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))?;
+        }
+        Ok(())
+    }
+
+    fn run_chord_action(&mut self, action: ChordAction) -> Result<(), Box<dyn Error>> {
+        self.show_help_on_startup = false;
+        self.show_help_toggle = false;
+        match action {
+            ChordAction::GoToTop => {
+                self.file_browser.move_to_start();
+                self.reset_text_scroll();
+                self.update_preview();
+            }
+            ChordAction::GoToParent => {
+                if self.file_browser.go_to_parent()? {
+                    self.preview_manager.clear_cache();
+                    self.update_preview();
+                }
+            }
+            ChordAction::Delete => self.show_delete_dialog(),
+        }
+        Ok(())
+    }
+
     pub fn handle_resize(&mut self, width: u16, height: u16) {
         self.terminal_width = width;
         self.terminal_height = height;
@@ -359,27 +1031,106 @@ impl ChafaTui {
         self.needs_redraw = true;
     }
 
+    /// How long the main loop should wait for input before checking timers again
+    pub fn poll_interval(&self) -> Duration {
+        self.preview_manager.get_config().get_poll_interval()
+    }
+
+    /// Apply a resize straight away unless one was applied within the debounce;
+    /// then the newest size waits for poll_pending_resize
+    pub fn handle_resize_event(&mut self, width: u16, height: u16) {
+        let debounce = self.preview_manager.get_config().get_resize_debounce();
+        if self
+            .last_resize_at
+            .is_none_or(|applied| applied.elapsed() >= debounce)
+        {
+            self.apply_resize(width, height);
+        } else {
+            self.pending_resize = Some((width, height));
+        }
+    }
+
+    /// Apply the newest resize held back by the debounce once it has passed
+    pub fn poll_pending_resize(&mut self) {
+        let debounce = self.preview_manager.get_config().get_resize_debounce();
+        if let Some((width, height)) = self.pending_resize
+            && self
+                .last_resize_at
+                .is_none_or(|applied| applied.elapsed() >= debounce)
+        {
+            self.apply_resize(width, height);
+        }
+    }
+
+    fn apply_resize(&mut self, width: u16, height: u16) {
+        self.last_resize_at = Some(Instant::now());
+        self.pending_resize = None;
+        self.handle_resize(width, height);
+    }
+
     pub fn handle_config_reload(&mut self, new_config: PTuiConfig) -> Result<(), Box<dyn Error>> {
         // Check if locale has changed and needs reloading
         let current_locale = self.localization.current_locale();
-        let new_locale = new_config.get_locale();
+        let new_locale = new_config.resolve_locale();
 
         if current_locale != new_locale {
             // Reload localization
-            self.localization = Localization::new(&new_locale)?;
+            self.localization = Arc::new(Localization::new(&new_locale)?);
+        }
+
+        // Say what changed; the debug pane has room for a few settings
+        let changes = PTuiConfig::diff(self.preview_manager.get_config(), &new_config);
+        self.preview_manager.debug_info = match changes.len() {
+            0 => "Config reloaded (no changes)".to_string(),
+            1..=MAX_RELOAD_CHANGES_SHOWN => format!("Config reloaded: {}", changes.join(" | ")),
+            count => format!(
+                "Config reloaded: {} | {} more",
+                changes[..MAX_RELOAD_CHANGES_SHOWN].join(" | "),
+                count - MAX_RELOAD_CHANGES_SHOWN
+            ),
+        };
+        #[cfg(all(not(test), feature = "debug-output"))]
+        if !changes.is_empty() {
+            eprintln!("[CONFIG] Reloaded:\n{}", new_config);
+        }
+        for warning in new_config.validate() {
             self.preview_manager.debug_info =
-                format!("Config reloaded | Locale changed to: {}", new_locale);
-        } else {
-            self.preview_manager.debug_info = "Config reloaded".to_string();
+                format!("{} | Warning: {}", self.preview_manager.debug_info, warning);
+        }
+        if let Some(notice) =
+            converter::fallback_notice(&new_config.converter.selected, converter::is_iterm2())
+        {
+            self.preview_manager.debug_info =
+                format!("{} | {}", self.preview_manager.debug_info, notice);
         }
 
         // Update slideshow delay
         self.slideshow_delay = Duration::from_millis(new_config.get_slideshow_delay_ms());
+        self.slideshow_loop = new_config.get_slideshow_loop();
 
         // Update transition manager config
         self.transition_manager
             .update_config(new_config.get_slideshow_transitions());
 
+        self.file_browser
+            .set_git_integration(new_config.get_git_integration());
+        self.file_browser
+            .set_show_image_counts(new_config.get_show_image_counts());
+        self.file_browser
+            .set_show_permissions(new_config.get_show_permissions());
+        let (wide_width, narrow_width) = new_config.get_file_browser_width_percents();
+        self.ui_layout
+            .set_file_browser_width_percents(wide_width, narrow_width);
+        self.fzf_integration = new_config.get_fzf_integration();
+        let (ascii_logo, logo_warning) = Self::help_logo(&new_config);
+        self.ascii_logo = ascii_logo;
+        if let Some(warning) = logo_warning {
+            self.preview_manager.debug_info =
+                format!("{} | Warning: {}", self.preview_manager.debug_info, warning);
+        }
+        self.file_browser.set_locale(&new_locale);
+        self.set_directory_watching(new_config.get_watch_current_directory());
+
         // Update preview manager config (for converter settings)
         self.preview_manager.update_config(new_config);
 
@@ -402,741 +1153,4695 @@ impl ChafaTui {
         }
     }
 
-    fn update_preview(&mut self) {
-        if self.show_help_on_startup || self.show_help_toggle {
-            self.preview_content = None;
-            self.is_preview_image = false;
-            self.is_text_file = false;
-        } else if let Some(file) = self.file_browser.get_selected_file() {
-            self.is_text_file = file.is_text_file();
-            self.preview_content = Some(self.preview_manager.generate_preview(
+    /// Preview the selection once the user stops moving through the list, so holding
+    /// j or k doesn't start a conversion for every file passed. Cached previews show
+    /// straight away.
+    fn schedule_preview(&mut self) {
+        let debounce = self.preview_manager.get_config().get_preview_debounce();
+        let cached = self.file_browser.get_selected_file().is_some_and(|file| {
+            self.preview_manager.is_cached(
                 file,
                 self.ui_layout.preview_width,
                 self.ui_layout.preview_height,
-                self.text_scroll_offset,
-                &self.localization,
-            ));
-            // Only treat actual image files as images for UI rendering (centered alignment)
-            // ASCII files should be left-aligned like text files
-            self.is_preview_image = file.is_image();
+            )
+        });
+        if debounce.is_zero() || cached || self.ui_layout.view_mode == ViewMode::Grid {
+            self.update_preview();
         } else {
-            self.is_text_file = false;
-            self.preview_content = None;
-            self.is_preview_image = false;
+            self.pending_preview_at = Some(Instant::now());
+            self.needs_redraw = true;
         }
-        self.needs_redraw = true;
     }
 
-    fn refresh_current_preview(&mut self) {
-        if let Some(file) = self.file_browser.get_selected_file()
-            && file.can_preview()
+    /// Preview the selection once the debounce after the last navigation has passed
+    pub fn poll_pending_preview(&mut self) {
+        if let Some(scheduled) = self.pending_preview_at
+            && scheduled.elapsed() >= self.preview_manager.get_config().get_preview_debounce()
         {
-            self.preview_manager.remove_from_cache(
-                file,
-                self.ui_layout.preview_width,
-                self.ui_layout.preview_height,
-            );
             self.update_preview();
         }
     }
 
-    fn save_ascii_file(&mut self) {
-        if let Some(file) = self.file_browser.get_selected_file() {
-            match self.preview_manager.save_ascii_to_file(
-                file,
-                self.ui_layout.preview_width,
-                self.ui_layout.preview_height,
-                &self.localization,
-            ) {
-                Ok(success_msg) => {
-                    // Update debug info with success message
-                    let current_debug = self.preview_manager.get_debug_info();
-                    self.preview_manager.debug_info =
-                        format!("{} | {}", current_debug, success_msg);
+    /// Regenerate the preview for the selected file
+    pub fn update_preview(&mut self) {
+        self.pending_preview_at = None;
+        // Any conversion still running is for a file that is no longer wanted
+        self.pending_conversion = None;
+        self.pending_preview_handle = None;
+        // Scrolling keeps the line count, only another file or a change to this one needs
+        // it counted again
+        let selected = self
+            .file_browser
+            .get_selected_file()
+            .map(|file| (file.path.clone(), file.modified));
+        if selected != self.text_lines_counted_for {
+            self.text_total_lines = None;
+            self.text_lines_counted_for = None;
+        }
+        self.conversion_state = ConversionState::Idle;
+        self.restart_animation();
 
-                    // Refresh file list to show the new ASCII file
-                    if let Err(e) = self.file_browser.refresh_files() {
-                        let current_debug = self.preview_manager.get_debug_info();
-                        self.preview_manager.debug_info = format!(
-                            "{} | WARNING: Failed to refresh file list: {}",
-                            current_debug, e
-                        );
-                    }
+        if self.show_help_on_startup
+            || self.show_help_toggle
+            || self.ui_layout.view_mode == ViewMode::Grid
+        {
+            // The grid view shows thumbnails instead of a preview pane
+            self.preview_content = None;
+        } else if self.show_metadata
+            && let Some(file) = self.file_browser.get_selected_file()
+        {
+            // Stays on while moving through the list, until i is pressed again
+            self.preview_content = Some(PreviewContent::Text(
+                self.preview_manager
+                    .generate_metadata_panel(file, &self.localization),
+                ContentAlignment::Text,
+            ));
+        } else if let Some(file) = self.file_browser.get_selected_file() {
+            let width = self.ui_layout.preview_width;
+            let height = self.ui_layout.preview_height;
+
+            // Start each newly selected TIFF at its first page and ICO at its largest size
+            // Most RAW formats are TIFF inside, but only their extracted JPEG is shown
+            let is_tiff = file.is_tiff() && !file.is_raw_image();
+            let is_ico = file.is_ico();
+            if self.paged_file.as_deref() != Some(file.path.as_str()) {
+                self.tiff_page = 0;
+                self.tiff_page_count = if is_tiff {
+                    self.preview_manager.tiff_page_count(&file.path)
+                } else {
+                    1
+                };
+                self.ico_page = 0;
+                self.ico_sizes = if is_ico {
+                    self.preview_manager.ico_sizes(&file.path)
+                } else {
+                    Vec::new()
+                };
+                self.paged_file = Some(file.path.clone());
+            }
+
+            // Convert images in the background so the spinner can animate while we wait
+            // HEIC needs decoding with heif-convert first, which generate_preview handles
+            // RAW files have their JPEG extracted with dcraw or darktable-cli in the background too,
+            // and audio files are probed and have their waveform drawn there
+            // Without ImageMagick there are no ICO sizes and the image crate shows the largest
+            if is_tiff && self.tiff_page > 0 {
+                self.preview_content = Some(self.preview_manager.generate_page_preview(
+                    file,
+                    self.tiff_page,
+                    width,
+                    height,
+                    &self.localization,
+                ));
+            } else if let Some(size) = self.ico_sizes.get(self.ico_page) {
+                self.preview_content = Some(self.preview_manager.generate_page_preview(
+                    file,
+                    size.index,
+                    width,
+                    height,
+                    &self.localization,
+                ));
+            } else if ((file.is_image() && !file.is_heic()) || file.is_audio())
+                && let Some(receiver) = self.preview_manager.start_background_conversion(
+                    file,
+                    width,
+                    height,
+                    &self.localization,
+                )
+            {
+                self.conversion_state = ConversionState::Loading {
+                    file_name: file.name.clone(),
+                    started_at: Instant::now(),
+                };
+                self.pending_conversion = Some(PendingConversion {
+                    path: file.path.clone(),
+                    modified: file.modified,
+                    width,
+                    height,
+                    receiver,
+                });
+                self.preview_content = Some(PreviewContent::Text(
+                    Text::default(),
+                    ContentAlignment::Image,
+                ));
+            } else if file.is_text_file() {
+                // Large files can take a while to skip through, so read them on another thread
+                let count_lines = self.text_total_lines.is_none();
+                self.pending_preview_handle = Some(self.preview_manager.request_preview_async(
+                    file,
+                    width,
+                    height,
+                    self.text_scroll_offset,
+                    count_lines,
+                    Arc::clone(&self.localization),
+                ));
+                if count_lines {
+                    self.text_lines_counted_for = Some((file.path.clone(), file.modified));
                 }
-                Err(error_msg) => {
-                    // Update debug info with error message
-                    let current_debug = self.preview_manager.get_debug_info();
-                    self.preview_manager.debug_info =
-                        format!("{} | ERROR: {}", current_debug, error_msg);
+                // Text already on screen, like this file before a scroll, stays until the
+                // new text arrives
+                if !matches!(
+                    self.preview_content,
+                    Some(PreviewContent::Text(_, ContentAlignment::Text))
+                ) {
+                    self.preview_content = Some(PreviewContent::Text(
+                        Text::default(),
+                        ContentAlignment::Text,
+                    ));
                 }
+            } else {
+                self.preview_content = Some(self.preview_manager.generate_preview(
+                    file,
+                    width,
+                    height,
+                    self.text_scroll_offset,
+                    &self.localization,
+                ));
             }
-        } else {
-            // Update debug info when no file is selected
-            let current_debug = self.preview_manager.get_debug_info();
-            self.preview_manager.debug_info =
-                format!("{} | ERROR: No file selected", current_debug);
-        }
-    }
-
-    fn show_delete_dialog(&mut self) {
-        if let Some(file) = self.file_browser.get_selected_file() {
-            if file.is_directory {
-                // Don't allow deleting directories
-                let current_debug = self.preview_manager.get_debug_info();
+            if is_tiff {
+                let page_info = self.tiff_page_info();
                 self.preview_manager.debug_info =
-                    format!("{} | ERROR: Cannot delete directories", current_debug);
-                return;
+                    format!("{} | {}", self.preview_manager.debug_info, page_info);
+            } else if !self.ico_sizes.is_empty() {
+                let size_info = self.ico_size_info();
+                self.preview_manager.debug_info =
+                    format!("{} | {}", self.preview_manager.debug_info, size_info);
             }
 
-            self.show_delete_confirmation = true;
-            self.delete_target_file = Some(file.name.clone());
-            self.needs_redraw = true;
+            if file.is_image()
+                || self.preview_content.as_ref().map(PreviewContent::alignment)
+                    == Some(ContentAlignment::Text)
+            {
+                self.recent_files
+                    .push(&file.path, &self.file_browser.current_dir);
+            }
         } else {
-            let current_debug = self.preview_manager.get_debug_info();
-            self.preview_manager.debug_info =
-                format!("{} | ERROR: No file selected", current_debug);
+            self.preview_content = None;
         }
+        self.needs_redraw = true;
     }
 
-    fn handle_delete_confirmation(&mut self, key: KeyEvent) -> Result<(), Box<dyn Error>> {
-        match key.code {
-            KeyCode::Char('y') | KeyCode::Char('Y') => {
-                // User confirmed deletion
-                if let Some(file_name) = &self.delete_target_file {
-                    self.delete_current_file(file_name.clone())?;
+    /// Check whether a background conversion has finished and show its result
+    pub fn poll_conversion(&mut self) {
+        let Some(pending) = self.pending_conversion.take() else {
+            return;
+        };
+
+        match pending.receiver.try_recv() {
+            Ok(converted) => {
+                let content = self.preview_manager.finish_background_conversion(
+                    &pending.path,
+                    pending.modified,
+                    pending.width,
+                    pending.height,
+                    converted,
+                    &self.localization,
+                );
+                // Slideshow renders its own previews at full screen size
+                if !self.is_slideshow_mode {
+                    self.preview_content = Some(content);
+                    self.restart_animation();
                 }
-                self.hide_delete_dialog();
+                self.conversion_state = ConversionState::Done;
+                self.needs_redraw = true;
             }
-            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
-                // User canceled deletion
-                self.hide_delete_dialog();
+            Err(TryRecvError::Empty) => {
+                let elapsed = match &self.conversion_state {
+                    ConversionState::Loading { started_at, .. } => started_at.elapsed(),
+                    _ => Duration::ZERO,
+                };
+                let timeout_ms = self
+                    .preview_manager
+                    .get_config()
+                    .get_preview_loading_timeout_ms();
+                if elapsed >= Duration::from_millis(timeout_ms) {
+                    // Give up on a stale conversion; dropping the receiver discards its result
+                    use fluent::fluent_args;
+                    let args = fluent_args!["ms" => timeout_ms];
+                    let message = self
+                        .localization
+                        .get_with_args("preview_loading_timed_out", Some(&args));
+                    if !self.is_slideshow_mode {
+                        self.preview_content = Some(PreviewContent::Text(
+                            Text::from(message.clone()),
+                            ContentAlignment::Image,
+                        ));
+                    }
+                    self.preview_manager.debug_info = message;
+                    self.conversion_state = ConversionState::Idle;
+                    self.needs_redraw = true;
+                    return;
+                }
+
+                // Keep redrawing so the spinner animates
+                if elapsed >= SPINNER_DELAY {
+                    self.needs_redraw = true;
+                }
+                self.pending_conversion = Some(pending);
             }
-            _ => {
-                // Ignore other keys
+            Err(TryRecvError::Disconnected) => {
+                self.conversion_state = ConversionState::Idle;
             }
         }
-        Ok(())
     }
 
-    fn hide_delete_dialog(&mut self) {
-        self.show_delete_confirmation = false;
-        self.delete_target_file = None;
-        self.needs_redraw = true;
+    /// Show a text preview once it has been read on its own thread
+    pub fn poll_preview_handle(&mut self) {
+        let Some(handle) = self.pending_preview_handle.take() else {
+            return;
+        };
+
+        match handle.try_recv() {
+            Ok((text, total_lines)) => {
+                if !self.is_slideshow_mode {
+                    self.preview_content = Some(PreviewContent::Text(text, ContentAlignment::Text));
+                    if total_lines.is_some() {
+                        self.text_total_lines = total_lines;
+                    }
+                }
+                self.needs_redraw = true;
+            }
+            Err(TryRecvError::Empty) => self.pending_preview_handle = Some(handle),
+            Err(TryRecvError::Disconnected) => {}
+        }
     }
 
-    fn delete_current_file(&mut self, file_name: String) -> Result<(), Box<dyn Error>> {
-        if let Some(file) = self.file_browser.get_selected_file() {
-            let file_path = &file.path;
+    /// Whether Ctrl+F asked for a fuzzy search. The main loop then leaves the
+    /// alternate screen and calls run_fuzzy_search.
+    pub fn take_fuzzy_search_request(&mut self) -> bool {
+        std::mem::take(&mut self.fuzzy_search_requested)
+    }
 
-            match std::fs::remove_file(file_path) {
-                Ok(()) => {
-                    let current_debug = self.preview_manager.get_debug_info();
-                    self.preview_manager.debug_info =
-                        format!("{} | Deleted: {}", current_debug, file_name);
+    /// Whether `:e config` asked to edit the config. The main loop then leaves the
+    /// alternate screen and calls run_config_editor.
+    pub fn take_config_edit_request(&mut self) -> bool {
+        std::mem::take(&mut self.config_edit_requested)
+    }
 
-                    // Refresh file list to remove deleted file
-                    if let Err(e) = self.file_browser.refresh_files() {
-                        let current_debug = self.preview_manager.get_debug_info();
-                        self.preview_manager.debug_info = format!(
-                            "{} | WARNING: Failed to refresh file list: {}",
-                            current_debug, e
-                        );
-                    }
+    /// Open the config file in $VISUAL or $EDITOR and reload it once the editor exits
+    pub fn run_config_editor(&mut self) {
+        let editor =
+            command::editor_command(std::env::var("VISUAL").ok(), std::env::var("EDITOR").ok());
+        self.run_config_editor_with(&editor);
+    }
 
-                    // Update preview after refresh
-                    self.update_preview();
-                }
-                Err(e) => {
-                    let current_debug = self.preview_manager.get_debug_info();
-                    self.preview_manager.debug_info = format!(
-                        "{} | ERROR: Failed to delete {}: {}",
-                        current_debug, file_name, e
-                    );
+    fn run_config_editor_with(&mut self, editor: &str) {
+        let result = PTuiConfig::get_config_path()
+            .map_err(|e| e.to_string())
+            .and_then(|path| {
+                command::run_editor(editor, &path)?;
+                PTuiConfig::try_reload_from_file(&path).map_err(|e| e.to_string())
+            })
+            .and_then(|config| self.handle_config_reload(config).map_err(|e| e.to_string()));
+        if let Err(e) = result {
+            use fluent::fluent_args;
+            let args = fluent_args!["error" => e];
+            let message = self
+                .localization
+                .get_with_args("command_editor_failed", Some(&args));
+            self.preview_manager.set_timed_message(message);
+        }
+        self.needs_redraw = true;
+    }
+
+    /// Typing at the `:` prompt: Enter runs the command, Esc or backspacing past the
+    /// colon closes the prompt
+    fn handle_command_prompt(&mut self, key: KeyEvent) -> Result<(), Box<dyn Error>> {
+        let Some(input) = self.command_prompt.as_mut() else {
+            return Ok(());
+        };
+        self.needs_redraw = true;
+        match key.code {
+            KeyCode::Char(c) => input.push(c),
+            KeyCode::Backspace if input.is_empty() => self.command_prompt = None,
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Enter => {
+                if let Some(input) = self.command_prompt.take() {
+                    return self.run_prompt_command(&input);
                 }
             }
+            KeyCode::Esc => self.command_prompt = None,
+            _ => {}
         }
         Ok(())
     }
 
-    fn open_in_system_browser(&mut self) {
-        if let Some(file) = self.file_browser.get_selected_file() {
-            let file_path = std::path::Path::new(&file.path);
-            let target_path = if file.is_directory {
-                // If it's a directory, open the directory itself
-                file_path
-            } else {
-                // If it's a file, open the parent directory and select the file
-                file_path.parent().unwrap_or(file_path)
-            };
+    fn run_prompt_command(&mut self, input: &str) -> Result<(), Box<dyn Error>> {
+        match PromptCommand::parse(input) {
+            Some(PromptCommand::Quit) => return Err("Quit".into()),
+            Some(PromptCommand::EditConfig) => self.config_edit_requested = true,
+            None if input.trim().is_empty() => {}
+            None => {
+                use fluent::fluent_args;
+                let args = fluent_args!["command" => input.trim()];
+                let message = self
+                    .localization
+                    .get_with_args("command_unknown", Some(&args));
+                self.preview_manager.set_timed_message(message);
+            }
+        }
+        Ok(())
+    }
 
-            let result = self.open_path_in_system_browser(
-                target_path,
-                if file.is_directory {
-                    None
-                } else {
-                    Some(file_path)
-                },
-            );
+    /// Pick a file in the current directory with fzf and select it
+    pub fn run_fuzzy_search(&mut self) {
+        self.run_fuzzy_search_with(fzf::FZF_COMMAND);
+    }
 
-            match result {
-                Ok(()) => {
-                    let message = if file.is_directory {
-                        self.localization.get("opened_directory_in_browser")
-                    } else {
-                        self.localization.get("opened_file_in_browser")
-                    };
-                    let current_debug = self.preview_manager.get_debug_info();
-                    self.preview_manager.debug_info =
-                        format!("{} | {}: {}", current_debug, message, file.name);
-                }
-                Err(e) => {
-                    let error_msg = self.localization.get("failed_to_open_in_browser");
-                    let current_debug = self.preview_manager.get_debug_info();
-                    self.preview_manager.debug_info =
-                        format!("{} | {}: {}", current_debug, error_msg, e);
+    fn run_fuzzy_search_with(&mut self, command: &[&str]) {
+        let names: Vec<String> = self
+            .file_browser
+            .files
+            .iter()
+            .map(|file| file.name.clone())
+            .collect();
+        match fzf::pick(command, &names) {
+            // There's no multi-selection, so only the first pick counts
+            Ok(picked) => {
+                if let Some(index) = picked
+                    .first()
+                    .and_then(|name| names.iter().position(|n| n == name))
+                {
+                    self.file_browser.set_selected_index(index);
+                    self.reset_text_scroll();
+                    self.update_preview();
                 }
             }
-        } else {
-            let error_msg = self.localization.get("no_file_selected");
-            let current_debug = self.preview_manager.get_debug_info();
-            self.preview_manager.debug_info = format!("{} | {}", current_debug, error_msg);
+            Err(e) => {
+                use fluent::fluent_args;
+                let args = fluent_args!["error" => e];
+                self.preview_manager.debug_info = self
+                    .localization
+                    .get_with_args("fuzzy_search_failed", Some(&args));
+            }
         }
+        self.needs_redraw = true;
     }
 
-    #[cfg(target_os = "macos")]
-    fn open_path_in_system_browser(
-        &self,
-        dir_path: &std::path::Path,
-        file_path: Option<&std::path::Path>,
-    ) -> Result<(), Box<dyn Error>> {
-        if let Some(file) = file_path {
-            // On macOS, we can use 'open -R' to reveal the file in Finder
-            Command::new("open")
-                .args(["-R", &file.to_string_lossy()])
-                .spawn()?;
-        } else {
-            // Open directory normally
-            Command::new("open").arg(dir_path).spawn()?;
-        }
-        Ok(())
+    fn toggle_fullscreen_preview(&mut self) {
+        self.fullscreen_preview = !self.fullscreen_preview;
+        self.ui_layout.fullscreen_preview = self.fullscreen_preview;
+
+        // Recalculate the preview dimensions so the new preview fills the resized pane
+        self.ui_layout.calculate_layout(ratatui::layout::Rect::new(
+            0,
+            0,
+            self.terminal_width,
+            self.terminal_height,
+        ));
+        self.update_preview();
     }
 
-    #[cfg(target_os = "windows")]
-    fn open_path_in_system_browser(
-        &self,
-        dir_path: &std::path::Path,
-        file_path: Option<&std::path::Path>,
-    ) -> Result<(), Box<dyn Error>> {
-        if let Some(file) = file_path {
-            // On Windows, we can use explorer.exe /select to open and highlight the file
-            Command::new("explorer")
-                .args(&["/select,", &file.to_string_lossy()])
-                .spawn()?;
-        } else {
-            Command::new("explorer").arg(dir_path).spawn()?;
+    fn tiff_page_info(&self) -> String {
+        if self.tiff_page_count <= 1 {
+            return self.localization.get("tiff_single_page");
         }
-        Ok(())
+
+        let mut args = FluentArgs::new();
+        args.set("page", self.tiff_page + 1);
+        args.set("total", self.tiff_page_count);
+        self.localization.get_with_args("tiff_page", Some(&args))
     }
 
-    #[cfg(target_os = "linux")]
-    fn open_path_in_system_browser(
-        &self,
-        dir_path: &std::path::Path,
-        file_path: Option<&std::path::Path>,
-    ) -> Result<(), Box<dyn Error>> {
-        // Try different file managers with file selection support where available
-        let file_managers_with_selection = [
-            ("nautilus", vec!["--select"]),
-            ("dolphin", vec!["--select"]),
-            ("thunar", vec![]), // Thunar doesn't have file selection, but we'll try to open the file directly
-        ];
+    fn ico_size_info(&self) -> String {
+        let sizes: Vec<String> = self
+            .ico_sizes
+            .iter()
+            .map(|size| format!("{}×{}", size.width, size.height))
+            .collect();
 
-        let file_managers_basic = ["xdg-open", "pcmanfm"];
+        use fluent::fluent_args;
+        let args = fluent_args!["sizes" => sizes.join(", "), "index" => self.ico_page];
+        self.localization.get_with_args("ico_sizes", Some(&args))
+    }
 
-        // First try file managers that support file selection
-        if let Some(file) = file_path {
-            for (manager, args) in &file_managers_with_selection {
-                if Command::new("which")
-                    .arg(manager)
-                    .output()?
-                    .status
-                    .success()
-                {
-                    let mut cmd = Command::new(manager);
+    /// Move through the pages of a TIFF or the sizes of an ICO
+    fn change_page(&mut self, forward: bool) {
+        let is_ico = self
+            .file_browser
+            .get_selected_file()
+            .is_some_and(FileItem::is_ico);
+        if is_ico {
+            self.change_ico_size(forward);
+        } else {
+            self.change_tiff_page(forward);
+        }
+    }
 
-                    if !args.is_empty() {
-                        // Use selection argument with the file path
-                        cmd.args(args).arg(file);
-                    } else if *manager == "thunar" {
-                        // For thunar, try to open the file directly, then fall back to directory
-                        if Command::new("thunar").arg(file).spawn().is_err() {
-                            Command::new("thunar").arg(dir_path).spawn()?;
-                        }
-                        return Ok(());
-                    }
+    fn change_ico_size(&mut self, forward: bool) {
+        if self.ico_sizes.len() <= 1 {
+            let key = if self.ico_sizes.is_empty() {
+                "ico_sizes_unavailable"
+            } else {
+                "ico_single_size"
+            };
+            self.preview_manager.debug_info = self.localization.get(key);
+            self.needs_redraw = true;
+            return;
+        }
 
-                    if cmd.spawn().is_ok() {
-                        return Ok(());
-                    }
-                }
-            }
+        let page = if forward {
+            (self.ico_page + 1).min(self.ico_sizes.len() - 1)
+        } else {
+            self.ico_page.saturating_sub(1)
+        };
+        if page != self.ico_page {
+            self.ico_page = page;
+            self.update_preview();
         }
+    }
 
-        // Fall back to basic file managers (just open directory)
-        for manager in &file_managers_basic {
-            if Command::new("which")
-                .arg(manager)
-                .output()?
-                .status
-                .success()
-            {
-                Command::new(manager).arg(dir_path).spawn()?;
-                return Ok(());
-            }
+    fn change_tiff_page(&mut self, forward: bool) {
+        let Some(file) = self.file_browser.get_selected_file() else {
+            return;
+        };
+        if !file.is_tiff() || file.is_raw_image() {
+            return;
         }
 
-        // Last resort: try all the managers we know about for directory opening
-        let all_managers = ["nautilus", "dolphin", "thunar", "pcmanfm"];
-        for manager in &all_managers {
-            if Command::new("which")
-                .arg(manager)
-                .output()?
-                .status
-                .success()
-            {
-                Command::new(manager).arg(dir_path).spawn()?;
-                return Ok(());
-            }
+        if self.tiff_page_count <= 1 {
+            self.preview_manager.debug_info = self.localization.get("tiff_single_page");
+            self.needs_redraw = true;
+            return;
         }
 
-        Err("No suitable file manager found".into())
+        let page = if forward {
+            (self.tiff_page + 1).min(self.tiff_page_count - 1)
+        } else {
+            self.tiff_page.saturating_sub(1)
+        };
+        if page == self.tiff_page {
+            return;
+        }
+
+        self.tiff_page = page;
+        self.preview_manager.remove_from_cache(
+            file,
+            self.ui_layout.preview_width,
+            self.ui_layout.preview_height,
+        );
+        self.update_preview();
     }
 
-    #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
-    fn open_path_in_system_browser(
-        &self,
-        _dir_path: &std::path::Path,
-        _file_path: Option<&std::path::Path>,
-    ) -> Result<(), Box<dyn Error>> {
-        Err("Opening system file browser not supported on this platform".into())
+    /// Start a newly shown animation from its first frame
+    fn restart_animation(&mut self) {
+        self.animation_frame_index = 0;
+        self.animation_last_frame = Instant::now();
+        self.animation_paused = false;
     }
 
-    fn enter_slideshow_mode(&mut self) {
-        // Build list of image files starting from current selection
-        self.slideshow_image_files.clear();
-        self.slideshow_start_index = self.file_browser.selected_index;
+    fn toggle_animation_pause(&mut self) {
+        if !matches!(self.preview_content, Some(PreviewContent::Animated { .. })) {
+            return;
+        }
 
-        // Find all image files in the current directory
-        for (i, file) in self.file_browser.files.iter().enumerate() {
-            if file.is_image() {
-                self.slideshow_image_files.push(i);
+        self.animation_paused = !self.animation_paused;
+        self.animation_last_frame = Instant::now();
+        self.preview_manager.debug_info = if self.animation_paused {
+            self.localization.get("animation_paused")
+        } else {
+            self.localization.get("animation_resumed")
+        };
+        self.needs_redraw = true;
+    }
+
+    fn toggle_grid_view(&mut self) {
+        self.ui_layout.toggle_view_mode();
+        if self.ui_layout.view_mode == ViewMode::List {
+            // Back to one file per row
+            self.file_browser.set_grid_columns(1);
+        }
+
+        // Recalculate the pane sizes before regenerating the preview
+        self.ui_layout.calculate_layout(ratatui::layout::Rect::new(
+            0,
+            0,
+            self.terminal_width,
+            self.terminal_height,
+        ));
+        self.update_preview();
+    }
+
+    fn refresh_current_preview(&mut self) {
+        if let Some(file) = self.file_browser.get_selected_file()
+            && file.can_preview()
+        {
+            self.preview_manager.remove_from_cache(
+                file,
+                self.ui_layout.preview_width,
+                self.ui_layout.preview_height,
+            );
+            self.update_preview();
+        }
+    }
+
+    fn save_ascii_file(&mut self) {
+        if let Some(file) = self.file_browser.get_selected_file() {
+            match self.preview_manager.save_ascii_to_file(
+                file,
+                self.ui_layout.preview_width,
+                self.ui_layout.preview_height,
+                &self.localization,
+            ) {
+                Ok(success_msg) => {
+                    // Update debug info with success message
+                    let current_debug = self.preview_manager.get_debug_info();
+                    self.preview_manager
+                        .set_timed_message(format!("{} | {}", current_debug, success_msg));
+
+                    // Refresh file list to show the new ASCII file
+                    if let Err(e) = self.file_browser.refresh_files() {
+                        let current_debug = self.preview_manager.get_debug_info();
+                        self.preview_manager.set_timed_message(format!(
+                            "{} | WARNING: Failed to refresh file list: {}",
+                            current_debug, e
+                        ));
+                    }
+                }
+                Err(error_msg) => {
+                    // Update debug info with error message
+                    let current_debug = self.preview_manager.get_debug_info();
+                    self.preview_manager
+                        .set_timed_message(format!("{} | ERROR: {}", current_debug, error_msg));
+                }
             }
+        } else {
+            // Update debug info when no file is selected
+            let current_debug = self.preview_manager.get_debug_info();
+            self.preview_manager
+                .set_timed_message(format!("{} | ERROR: No file selected", current_debug));
         }
+    }
 
-        if self.slideshow_image_files.is_empty() {
-            // No images to show slideshow
+    /// Show the selected file's SHA-256, computing it in the background unless it's cached
+    fn show_checksum(&mut self) {
+        let Some(file) = self
+            .file_browser
+            .get_selected_file()
+            .filter(|f| !f.is_directory)
+        else {
             return;
-        }
+        };
+        let Ok(modified) = std::fs::metadata(&file.path).and_then(|m| m.modified()) else {
+            return;
+        };
 
-        // Find the position of current selection in image files list
-        if let Some(pos) = self
-            .slideshow_image_files
-            .iter()
-            .position(|&i| i == self.slideshow_start_index)
+        // A cached checksum only counts if the file hasn't changed since
+        if let Some((cached_modified, hash)) = self.checksums.get(&file.path)
+            && *cached_modified == modified
         {
-            self.slideshow_current_index = pos;
-        } else {
-            // Current selection is not an image, start with first image
-            self.slideshow_current_index = 0;
-            // Update slideshow_start_index to the first image for consistency
-            if !self.slideshow_image_files.is_empty() {
-                self.slideshow_start_index = self.slideshow_image_files[0];
-            }
+            use fluent::fluent_args;
+            let args = fluent_args!["hash" => hash.as_str()];
+            self.preview_manager.debug_info = self
+                .localization
+                .get_with_args("checksum_result", Some(&args));
+            self.needs_redraw = true;
+            return;
         }
 
-        self.is_slideshow_mode = true;
-        self.update_slideshow_preview();
-        self.slideshow_last_change = Instant::now();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let item = file.clone();
+        std::thread::spawn(move || {
+            let _ = sender.send(item.sha256().map_err(|e| e.to_string()));
+        });
+        self.pending_checksum = Some(PendingChecksum {
+            path: file.path.clone(),
+            modified,
+            receiver,
+        });
+        self.preview_manager.debug_info = self.localization.get("checksum_computing");
         self.needs_redraw = true;
     }
 
-    fn exit_slideshow_mode(&mut self) {
-        self.is_slideshow_mode = false;
-
-        // Select the current slideshow file in the file browser
-        if !self.slideshow_image_files.is_empty()
-            && self.slideshow_current_index < self.slideshow_image_files.len()
-        {
-            let current_file_index = self.slideshow_image_files[self.slideshow_current_index];
-            self.file_browser.set_selected_index(current_file_index);
-        } else {
-            // Fallback to original selection if something went wrong
-            self.file_browser
-                .set_selected_index(self.slideshow_start_index);
+    fn set_directory_watching(&mut self, enabled: bool) {
+        if !enabled {
+            self.directory_watcher = None;
+        } else if self.directory_watcher.is_none() {
+            match FileWatcher::new() {
+                Ok(watcher) => self.directory_watcher = Some(watcher),
+                Err(e) => {
+                    self.preview_manager.debug_info = format!(
+                        "{} | Failed to watch directory: {}",
+                        self.preview_manager.get_debug_info(),
+                        e
+                    );
+                }
+            }
         }
+    }
 
-        self.update_preview();
+    /// Put the ready message back once feedback has been up for notification_duration_ms
+    pub fn poll_notification(&mut self) {
+        if self.preview_manager.timed_message_expired() {
+            self.preview_manager
+                .set_message(self.localization.get("ptui_ready"));
+            self.needs_redraw = true;
+        }
     }
 
-    fn advance_slideshow(&mut self) {
-        if !self.is_slideshow_mode || self.slideshow_image_files.is_empty() {
+    /// Refresh the file list once files in the current directory stop changing
+    pub fn poll_directory_changes(&mut self) {
+        let Some(watcher) = self.directory_watcher.as_mut() else {
             return;
+        };
+        // Follow the browser into whichever directory it's showing
+        let _ = watcher.watch(std::path::Path::new(&self.file_browser.current_dir));
+
+        let events = watcher.events();
+        if !events.is_empty() {
+            // Bursts, like cp -r into the directory, are applied together once they stop
+            self.file_system_events.extend(events);
+            self.last_directory_change = Some(Instant::now());
         }
 
-        // Store current content for potential transition
-        self.slideshow_previous_content = self.preview_content.clone();
+        // The slideshow keeps indices into the file list, so hold changes until it ends
+        let settled = self
+            .last_directory_change
+            .is_some_and(|last| last.elapsed() >= DIRECTORY_CHANGE_DEBOUNCE);
+        if !settled || self.is_slideshow_mode {
+            return;
+        }
+        self.last_directory_change = None;
+        let events = std::mem::take(&mut self.file_system_events);
+        self.handle_file_system_events(&events);
+    }
 
-        self.slideshow_current_index =
-            (self.slideshow_current_index + 1) % self.slideshow_image_files.len();
-        self.slideshow_last_change = Instant::now();
-        self.update_slideshow_preview();
+    /// Refresh the file list after files were created, changed or removed by other
+    /// programs. The selection stays on the same file, or moves to the one before
+    /// it when it was removed.
+    fn handle_file_system_events(&mut self, events: &[FileSystemEvent]) {
+        let selected = self.file_browser.get_selected_file().cloned();
+        let selected_index = self.file_browser.selected_index;
+        if self.file_browser.refresh_files().is_err() {
+            return;
+        }
 
-        // Check if we should start a transition effect
-        // Transitions only work with Text content (ASCII art), not graphical content
-        if self.transition_manager.is_enabled()
-            && self.preview_manager.converter_supports_transitions()
-            && let (Some(prev_content), Some(new_content)) =
-                (&self.slideshow_previous_content, &self.preview_content)
-            && let (PreviewContent::Text(prev_text), PreviewContent::Text(new_text)) =
-                (prev_content, new_content)
-            && self
-                .transition_manager
-                .start_transition(prev_text, new_text)
-        {
-            // Successfully started transition
-            let current_debug = self.preview_manager.get_debug_info();
-            self.preview_manager.debug_info = format!(
-                "{} | Starting {} transition",
-                current_debug,
-                self.transition_manager.get_effect_name()
+        let is_selected =
+            |file: &FileItem, path: &std::path::Path| path == std::path::Path::new(&file.path);
+        let selected_removed = selected.as_ref().is_some_and(|file| {
+            events.iter().any(
+                |event| matches!(event, FileSystemEvent::Removed(path) if is_selected(file, path)),
+            )
+        });
+        let file_count = self.file_browser.files.len();
+        let index = selected
+            .as_ref()
+            .and_then(|old| {
+                self.file_browser
+                    .files
+                    .iter()
+                    .position(|f| f.name == old.name)
+            })
+            .unwrap_or_else(|| {
+                let index = if selected_removed {
+                    selected_index.saturating_sub(1)
+                } else {
+                    selected_index
+                };
+                index.min(file_count.saturating_sub(1))
+            });
+        self.file_browser.set_selected_index(index);
+
+        let current = self.file_browser.get_selected_file().cloned();
+        let selected_changed = current
+            .as_ref()
+            .is_some_and(|file| events.iter().any(|event| is_selected(file, event.path())));
+        if let Some(file) = current.as_ref().filter(|_| selected_changed) {
+            self.preview_manager.remove_from_cache(
+                file,
+                self.ui_layout.preview_width,
+                self.ui_layout.preview_height,
             );
         }
-
+        if selected_changed || current.map(|f| f.path) != selected.map(|f| f.path) {
+            self.update_preview();
+        }
         self.needs_redraw = true;
     }
 
-    fn slideshow_go_backward(&mut self) {
-        if !self.is_slideshow_mode || self.slideshow_image_files.is_empty() {
+    /// Check whether a background checksum has finished and show it
+    pub fn poll_checksum(&mut self) {
+        use fluent::fluent_args;
+        let Some(pending) = self.pending_checksum.take() else {
             return;
+        };
+
+        let message = match pending.receiver.try_recv() {
+            Ok(Ok(hash)) => {
+                let args = fluent_args!["hash" => hash.as_str()];
+                let message = self
+                    .localization
+                    .get_with_args("checksum_result", Some(&args));
+                self.checksums
+                    .insert(pending.path.clone(), (pending.modified, hash));
+                message
+            }
+            Ok(Err(e)) => {
+                let args = fluent_args!["error" => e];
+                self.localization
+                    .get_with_args("checksum_failed", Some(&args))
+            }
+            Err(TryRecvError::Empty) => {
+                self.pending_checksum = Some(pending);
+                return;
+            }
+            Err(TryRecvError::Disconnected) => return,
+        };
+
+        // Only report it while the file is still selected, it stays cached either way
+        let still_selected = self
+            .file_browser
+            .get_selected_file()
+            .is_some_and(|file| file.path == pending.path);
+        if still_selected {
+            self.preview_manager.debug_info = message;
+            self.needs_redraw = true;
+        }
+    }
+
+    fn cycle_debug_pane(&mut self) {
+        let custom_text = self.preview_manager.get_config().get_debug_pane_text();
+        self.debug_pane_mode = self.debug_pane_mode.next(custom_text);
+        self.needs_redraw = true;
+    }
+
+    /// Average colour of the selected image, while the debug pane is showing it. None
+    /// until it has been computed in the background.
+    fn selected_average_color(&mut self) -> Option<(u8, u8, u8)> {
+        if !self.show_avg_color {
+            return None;
         }
+        let file = self
+            .file_browser
+            .get_selected_file()
+            .filter(|file| file.is_image())?;
+        self.preview_manager
+            .average_color(&file.path, file.modified)
+    }
 
-        // Store current content for potential transition
-        self.slideshow_previous_content = self.preview_content.clone();
+    /// Draw the debug pane again once the selected image's average colour is ready
+    pub fn poll_average_color(&mut self) {
+        if self.preview_manager.poll_average_color() {
+            self.needs_redraw = true;
+        }
+    }
 
-        // Go backward with wrap-around (if at 0, go to last image)
-        if self.slideshow_current_index == 0 {
-            self.slideshow_current_index = self.slideshow_image_files.len() - 1;
-        } else {
-            self.slideshow_current_index -= 1;
+    /// Draw the grid again once the background worker has made more thumbnails
+    pub fn poll_thumbnails(&mut self) {
+        if self.preview_manager.poll_thumbnails() {
+            self.needs_redraw = true;
         }
-        self.slideshow_last_change = Instant::now();
-        self.update_slideshow_preview();
+    }
 
-        // Check if we should start a transition effect (same as advance_slideshow)
-        // Transitions only work with Text content (ASCII art), not graphical content
-        if self.transition_manager.is_enabled()
-            && self.preview_manager.converter_supports_transitions()
-            && let (Some(prev_content), Some(new_content)) =
-                (&self.slideshow_previous_content, &self.preview_content)
-            && let (PreviewContent::Text(prev_text), PreviewContent::Text(new_text)) =
-                (prev_content, new_content)
-            && self
-                .transition_manager
-                .start_transition(prev_text, new_text)
-        {
-            // Successfully started transition
-            let current_debug = self.preview_manager.get_debug_info();
-            self.preview_manager.debug_info = format!(
-                "{} | Starting {} transition",
-                current_debug,
-                self.transition_manager.get_effect_name()
-            );
+    /// What the debug pane shows in its current mode, from what the last preview collected
+    fn debug_pane_text(&self) -> String {
+        use fluent::fluent_args;
+        let preview_manager = &self.preview_manager;
+        match &self.debug_pane_mode {
+            DebugPaneContent::Messages => preview_manager.get_debug_info().to_string(),
+            DebugPaneContent::Custom(text) => text.clone(),
+            DebugPaneContent::FileInfo => {
+                let Some(file) = self.file_browser.get_selected_file() else {
+                    return String::new();
+                };
+                let size = std::fs::metadata(&file.path)
+                    .ok()
+                    .filter(|_| !file.is_directory)
+                    .map_or_else(|| "-".to_string(), |metadata| format_size(metadata.len()));
+                let dimensions = preview_manager
+                    .image_dimensions(&file.path)
+                    .map_or_else(|| "-".to_string(), |(w, h)| format!("{}x{}", w, h));
+                let age = SystemTime::now()
+                    .duration_since(file.modified)
+                    .unwrap_or_default();
+                let args = fluent_args![
+                    "name" => file.name.as_str(),
+                    "size" => size,
+                    "dimensions" => dimensions,
+                    "age" => format_age(age)
+                ];
+                self.localization
+                    .get_with_args("debug_file_info", Some(&args))
+            }
+            DebugPaneContent::ConverterInfo => {
+                let output = preview_manager
+                    .last_output_size
+                    .map_or_else(|| "-".to_string(), |(w, h)| format!("{}x{}", w, h));
+                let args = fluent_args![
+                    "converter" => preview_manager.current_converter_name(),
+                    "output" => output,
+                    "ms" => preview_manager.timing_ms
+                ];
+                let info = self
+                    .localization
+                    .get_with_args("debug_converter_info", Some(&args));
+                if preview_manager.current_converter_name() == "jp2a" {
+                    format!(
+                        "{} | {}",
+                        info,
+                        self.jp2a_charset_message("debug_jp2a_charset")
+                    )
+                } else {
+                    info
+                }
+            }
+            DebugPaneContent::CacheStats => {
+                let args = fluent_args![
+                    "hits" => preview_manager.cache_hits,
+                    "misses" => preview_manager.cache_misses,
+                    "entries" => preview_manager.cache_len(),
+                    "capacity" => preview_manager.max_cache_size()
+                ];
+                let stats = self
+                    .localization
+                    .get_with_args("debug_cache_stats", Some(&args));
+                let lookups = preview_manager.cache_hits + preview_manager.cache_misses;
+                if lookups == 0 {
+                    return stats;
+                }
+                let percent = preview_manager.cache_hits * 100 / lookups;
+                let args = fluent_args!["percent" => percent];
+                format!(
+                    "{} | {}",
+                    stats,
+                    self.localization
+                        .get_with_args("cache_hit_rate", Some(&args))
+                )
+            }
+        }
+    }
+
+    /// Show the selected directory's total size, calculating it in the background if needed
+    fn show_directory_size(&mut self) {
+        let Some(dir) = self
+            .file_browser
+            .get_selected_file()
+            .filter(|f| f.is_directory)
+        else {
+            return;
+        };
+        let path = dir.path.clone();
+        self.drop_stale_directory_sizes();
+
+        if let Some(&(bytes, file_count)) = self.directory_sizes.get(&path) {
+            self.preview_manager.debug_info = self.directory_size_message(bytes, file_count);
+            self.needs_redraw = true;
+            return;
         }
 
-        self.needs_redraw = true;
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let walk_path = path.clone();
+        std::thread::spawn(move || {
+            let _ = sender.send(directory_size(
+                std::path::Path::new(&walk_path),
+                DIRECTORY_SIZE_TIMEOUT,
+            ));
+        });
+        self.pending_directory_size = Some(PendingDirectorySize {
+            path,
+            refresh_count: self.file_browser.refresh_count(),
+            receiver,
+        });
+        self.preview_manager.debug_info = self.localization.get("directory_size_calculating");
+        self.needs_redraw = true;
+    }
+
+    /// Check whether a background directory size has finished and show it
+    pub fn poll_directory_size(&mut self) {
+        let Some(pending) = self.pending_directory_size.take() else {
+            return;
+        };
+
+        let message = match pending.receiver.try_recv() {
+            Ok(Some((bytes, file_count))) => {
+                self.drop_stale_directory_sizes();
+                if pending.refresh_count == self.directory_sizes_refresh {
+                    self.directory_sizes
+                        .insert(pending.path.clone(), (bytes, file_count));
+                }
+                self.directory_size_message(bytes, file_count)
+            }
+            Ok(None) => {
+                use fluent::fluent_args;
+                let args = fluent_args!["seconds" => DIRECTORY_SIZE_TIMEOUT.as_secs()];
+                self.localization
+                    .get_with_args("directory_size_timed_out", Some(&args))
+            }
+            Err(TryRecvError::Empty) => {
+                self.pending_directory_size = Some(pending);
+                return;
+            }
+            Err(TryRecvError::Disconnected) => return,
+        };
+
+        let still_selected = self
+            .file_browser
+            .get_selected_file()
+            .is_some_and(|file| file.path == pending.path);
+        if still_selected {
+            self.preview_manager.debug_info = message;
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Show directory image counts once they've been worked out, and start on any
+    /// directories that have scrolled into view since
+    pub fn poll_image_counts(&mut self) {
+        if let Some(pending) = self.pending_image_counts.take() {
+            match pending.receiver.try_recv() {
+                Ok(counts) => {
+                    let browsers = std::iter::once(&mut self.file_browser).chain(
+                        self.secondary_browser
+                            .as_mut()
+                            .map(|secondary| &mut secondary.browser),
+                    );
+                    for browser in browsers {
+                        if browser.current_dir == pending.dir
+                            && browser.refresh_count() == pending.refresh_count
+                        {
+                            browser.set_image_counts(&counts);
+                        }
+                    }
+                    self.needs_redraw = true;
+                }
+                Err(TryRecvError::Empty) => {
+                    self.pending_image_counts = Some(pending);
+                    return;
+                }
+                Err(TryRecvError::Disconnected) => {}
+            }
+        }
+
+        let browsers = std::iter::once(&self.file_browser).chain(
+            self.secondary_browser
+                .as_ref()
+                .map(|secondary| &secondary.browser),
+        );
+        let Some((browser, directories)) = browsers
+            .map(|browser| (browser, browser.uncounted_visible_directories()))
+            .find(|(_, directories)| !directories.is_empty())
+        else {
+            return;
+        };
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let counts = directories
+                .iter()
+                .map(|dir| (dir.name.clone(), dir.count_images_shallow()))
+                .collect();
+            let _ = sender.send(counts);
+        });
+        self.pending_image_counts = Some(PendingImageCounts {
+            dir: browser.current_dir.clone(),
+            refresh_count: browser.refresh_count(),
+            receiver,
+        });
+    }
+
+    /// Forget cached directory sizes once the file browser has refreshed its listing
+    fn drop_stale_directory_sizes(&mut self) {
+        let refresh_count = self.file_browser.refresh_count();
+        if self.directory_sizes_refresh != refresh_count {
+            self.directory_sizes.clear();
+            self.directory_sizes_refresh = refresh_count;
+        }
+    }
+
+    fn directory_size_message(&self, bytes: u64, file_count: u64) -> String {
+        use fluent::fluent_args;
+        let args = fluent_args![
+            "size" => format_size(bytes),
+            "count" => format_count(file_count)
+        ];
+        self.localization
+            .get_with_args("directory_size_result", Some(&args))
+    }
+
+    fn copy_path_to_clipboard(&mut self) {
+        use fluent::fluent_args;
+        let Some(path) = self
+            .file_browser
+            .get_selected_file()
+            .map(|file| file.path.clone())
+        else {
+            return;
+        };
+
+        let message = if !SystemClipboard::is_supported() {
+            self.localization.get("clipboard_not_compiled")
+        } else {
+            match self.clipboard.set_text(&path) {
+                Ok(()) => {
+                    let args = fluent_args!["path" => path];
+                    self.localization
+                        .get_with_args("clipboard_copied_path", Some(&args))
+                }
+                Err(e) => {
+                    let args = fluent_args!["error" => e];
+                    self.localization
+                        .get_with_args("clipboard_unavailable", Some(&args))
+                }
+            }
+        };
+        self.preview_manager.set_timed_message(message);
+        self.needs_redraw = true;
+    }
+
+    fn copy_preview_to_clipboard(&mut self, plain: bool) {
+        use fluent::fluent_args;
+        let raw = self.file_browser.get_selected_file().and_then(|file| {
+            self.preview_manager.get_raw_preview_string(
+                file,
+                self.ui_layout.preview_width,
+                self.ui_layout.preview_height,
+            )
+        });
+        let Some(raw) = raw else {
+            self.preview_manager
+                .set_timed_message(self.localization.get("clipboard_no_preview"));
+            self.needs_redraw = true;
+            return;
+        };
+        let content = if plain {
+            crate::preview::strip_ansi_codes(&raw)
+        } else {
+            raw
+        };
+
+        let message = if !SystemClipboard::is_supported() {
+            self.localization.get("clipboard_not_compiled")
+        } else {
+            match self.clipboard.set_text(&content) {
+                Ok(()) => {
+                    let args = fluent_args!["count" => content.chars().count()];
+                    self.localization
+                        .get_with_args("clipboard_copied_ascii", Some(&args))
+                }
+                Err(e) => {
+                    let args = fluent_args!["error" => e];
+                    self.localization
+                        .get_with_args("clipboard_unavailable", Some(&args))
+                }
+            }
+        };
+        self.preview_manager.set_timed_message(message);
+        self.needs_redraw = true;
+    }
+
+    fn show_delete_dialog(&mut self) {
+        if let Some(file) = self.file_browser.get_selected_file() {
+            if file.is_directory {
+                // Don't allow deleting directories
+                let current_debug = self.preview_manager.get_debug_info();
+                self.preview_manager.set_timed_message(format!(
+                    "{} | ERROR: Cannot delete directories",
+                    current_debug
+                ));
+                return;
+            }
+
+            self.show_delete_confirmation = true;
+            self.delete_target_file = Some(file.name.clone());
+            self.needs_redraw = true;
+        } else {
+            let current_debug = self.preview_manager.get_debug_info();
+            self.preview_manager
+                .set_timed_message(format!("{} | ERROR: No file selected", current_debug));
+        }
+    }
+
+    fn handle_delete_confirmation(&mut self, key: KeyEvent) -> Result<(), Box<dyn Error>> {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                // User confirmed deletion
+                if let Some(file_name) = &self.delete_target_file {
+                    self.delete_current_file(file_name.clone())?;
+                }
+                self.hide_delete_dialog();
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                // User canceled deletion
+                self.hide_delete_dialog();
+            }
+            _ => {
+                // Ignore other keys
+            }
+        }
+        Ok(())
+    }
+
+    fn hide_delete_dialog(&mut self) {
+        self.show_delete_confirmation = false;
+        self.delete_target_file = None;
+        self.needs_redraw = true;
+    }
+
+    /// Whether x moves files to the OS trash rather than deleting them for good
+    fn uses_trash(&self) -> bool {
+        cfg!(feature = "trash-support") && self.preview_manager.get_config().get_use_trash()
+    }
+
+    fn move_to_os_trash(path: &str) -> Result<(), Box<dyn Error>> {
+        #[cfg(feature = "trash-support")]
+        {
+            trash::delete(path)?;
+            Ok(())
+        }
+        #[cfg(not(feature = "trash-support"))]
+        {
+            Err(format!("{}: built without trash support", path).into())
+        }
+    }
+
+    fn remove_file(&self, path: &str, use_trash: bool) -> Result<(), Box<dyn Error>> {
+        if use_trash {
+            return (self.move_to_trash)(path);
+        }
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+
+    fn delete_current_file(&mut self, file_name: String) -> Result<(), Box<dyn Error>> {
+        let use_trash = self.uses_trash();
+        if let Some(file) = self.file_browser.get_selected_file() {
+            let file_path = &file.path;
+
+            match self.remove_file(file_path, use_trash) {
+                Ok(()) => {
+                    let current_debug = self.preview_manager.get_debug_info();
+                    let action = if use_trash {
+                        "Moved to Trash"
+                    } else {
+                        "Deleted"
+                    };
+                    self.preview_manager.set_timed_message(format!(
+                        "{} | {}: {}",
+                        current_debug, action, file_name
+                    ));
+
+                    // Refresh file list to remove deleted file
+                    if let Err(e) = self.file_browser.refresh_files() {
+                        let current_debug = self.preview_manager.get_debug_info();
+                        self.preview_manager.set_timed_message(format!(
+                            "{} | WARNING: Failed to refresh file list: {}",
+                            current_debug, e
+                        ));
+                    }
+
+                    // Update preview after refresh
+                    self.update_preview();
+                }
+                Err(e) => {
+                    let current_debug = self.preview_manager.get_debug_info();
+                    self.preview_manager.set_timed_message(format!(
+                        "{} | ERROR: Failed to delete {}: {}",
+                        current_debug, file_name, e
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn open_in_system_browser(&mut self) {
+        if let Some(file) = self.file_browser.get_selected_file() {
+            // A command configured for the file's extension takes precedence
+            if !file.is_directory
+                && let Some(command) = open_with_command(
+                    &self.preview_manager.get_config().get_open_with(),
+                    &file.path,
+                )
+                && let Some(opener) = Opener::from_command(&command)
+            {
+                let (path, name) = (file.path.clone(), file.name.clone());
+                self.launch_opener(&opener, &path, &name);
+                return;
+            }
+
+            let file_path = std::path::Path::new(&file.path);
+            let target_path = if file.is_directory {
+                // If it's a directory, open the directory itself
+                file_path
+            } else {
+                // If it's a file, open the parent directory and select the file
+                file_path.parent().unwrap_or(file_path)
+            };
+
+            let result = self.open_path_in_system_browser(
+                target_path,
+                if file.is_directory {
+                    None
+                } else {
+                    Some(file_path)
+                },
+            );
+
+            match result {
+                Ok(()) => {
+                    let message = if file.is_directory {
+                        self.localization.get("opened_directory_in_browser")
+                    } else {
+                        self.localization.get("opened_file_in_browser")
+                    };
+                    let current_debug = self.preview_manager.get_debug_info();
+                    self.preview_manager.set_timed_message(format!(
+                        "{} | {}: {}",
+                        current_debug, message, file.name
+                    ));
+                }
+                Err(e) => {
+                    let error_msg = self.localization.get("failed_to_open_in_browser");
+                    let current_debug = self.preview_manager.get_debug_info();
+                    self.preview_manager
+                        .set_timed_message(format!("{} | {}: {}", current_debug, error_msg, e));
+                }
+            }
+        } else {
+            let error_msg = self.localization.get("no_file_selected");
+            let current_debug = self.preview_manager.get_debug_info();
+            self.preview_manager
+                .set_timed_message(format!("{} | {}", current_debug, error_msg));
+        }
+    }
+
+    fn handle_recent_files_dialog(&mut self, key: KeyEvent) {
+        let Some(selected) = self.recent_files_dialog else {
+            return;
+        };
+        match key.code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                let last = self.recent_files.len().saturating_sub(1);
+                self.recent_files_dialog = Some((selected + 1).min(last));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.recent_files_dialog = Some(selected.saturating_sub(1));
+            }
+            KeyCode::Char('c') => {
+                self.recent_files.clear();
+                self.recent_files_dialog = Some(0);
+            }
+            KeyCode::Enter => {
+                self.recent_files_dialog = None;
+                if let Some((path, directory)) = self.recent_files.get(selected).cloned() {
+                    self.open_recent_file(&path, &directory);
+                }
+            }
+            KeyCode::Esc | KeyCode::Char('q') => self.recent_files_dialog = None,
+            _ => {}
+        }
+        self.needs_redraw = true;
+    }
+
+    fn open_recent_file(&mut self, path: &str, directory: &str) {
+        use fluent::fluent_args;
+        let file_name = std::path::Path::new(path)
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let found = std::path::Path::new(path).exists()
+            && self
+                .file_browser
+                .open_file_in_directory(directory, &file_name)
+                .unwrap_or(false);
+        if found {
+            self.reset_text_scroll();
+            self.update_preview();
+        } else {
+            let args = fluent_args!["file" => path];
+            self.preview_manager.debug_info = self
+                .localization
+                .get_with_args("recent_file_missing", Some(&args));
+        }
+    }
+
+    /// Keep the recent files for next time, in $XDG_CACHE_HOME/ptui/recent.json
+    pub fn save_recent_files(&self) -> Result<(), Box<dyn Error>> {
+        let path = RecentFiles::default_path().ok_or("No cache directory")?;
+        self.recent_files.save_to(&path)
+    }
+
+    /// Switch to the next content filter and say which files are now listed
+    fn cycle_content_filter(&mut self) {
+        let message = match self.file_browser.cycle_content_filter() {
+            Ok(filter) => self.localization.get(filter.message_key()),
+            Err(e) => format!("Error: {}", e),
+        };
+        self.preview_manager.set_message(message);
+        self.reset_text_scroll();
+        self.update_preview();
+    }
+
+    /// The directory, selection, sort, filter, converter and scroll position to pick up next time
+    pub fn session_state(&self) -> SessionState {
+        SessionState {
+            current_dir: self.file_browser.current_dir.clone(),
+            selected_index: self.file_browser.selected_index,
+            sort_mode: self.file_browser.sort_mode.clone(),
+            converter: self.preview_manager.get_config().converter.selected.clone(),
+            text_scroll_offset: self.text_scroll_offset,
+            filter_mode: self.file_browser.content_filter,
+        }
+    }
+
+    /// Save where we are to $XDG_CACHE_HOME/ptui/session.json, unless restore_session is off
+    pub fn save_session(&self) -> Result<(), Box<dyn Error>> {
+        if !self.preview_manager.get_config().get_restore_session() {
+            return Ok(());
+        }
+        let path = SessionState::default_path().ok_or("No cache directory")?;
+        self.session_state().save_to(&path)
+    }
+
+    fn launch_opener(&mut self, opener: &Opener, path: &str, file_name: &str) {
+        use fluent::fluent_args;
+        let message = match opener.launch(path) {
+            Ok(()) => {
+                let args = fluent_args!["file" => file_name, "app" => opener.name.as_str()];
+                self.localization.get_with_args("opened_with", Some(&args))
+            }
+            Err(e) => {
+                let args = fluent_args!["app" => opener.name.as_str()];
+                let error_msg = self
+                    .localization
+                    .get_with_args("open_with_failed", Some(&args));
+                format!("{}: {}", error_msg, e)
+            }
+        };
+        let current_debug = self.preview_manager.get_debug_info();
+        self.preview_manager
+            .set_timed_message(format!("{} | {}", current_debug, message));
+    }
+
+    /// Open a second file list in the current directory, or close it again
+    fn toggle_dual_pane(&mut self) {
+        match self.secondary_browser.take() {
+            Some(mut secondary) => {
+                // Whichever pane had focus, the primary one carries on
+                if secondary.active {
+                    std::mem::swap(&mut self.file_browser, &mut secondary.browser);
+                    self.reset_text_scroll();
+                    self.update_preview();
+                }
+                self.preview_manager.debug_info = self.localization.get("dual_pane_closed");
+            }
+            None => match FileBrowser::new_with_dir(&self.file_browser.current_dir) {
+                Ok(mut browser) => {
+                    browser.set_git_integration(
+                        self.preview_manager.get_config().get_git_integration(),
+                    );
+                    browser.set_show_image_counts(
+                        self.preview_manager.get_config().get_show_image_counts(),
+                    );
+                    browser.set_show_permissions(
+                        self.preview_manager.get_config().get_show_permissions(),
+                    );
+                    browser.set_locale(self.localization.current_locale());
+                    self.secondary_browser = Some(SecondaryBrowser {
+                        browser,
+                        active: false,
+                    });
+                    self.preview_manager.debug_info = self.localization.get("dual_pane_mode");
+                }
+                Err(e) => {
+                    self.preview_manager.debug_info =
+                        format!("ERROR: Failed to open a second pane: {}", e);
+                }
+            },
+        }
+        self.needs_redraw = true;
+    }
+
+    /// Move focus to the other pane; the preview follows the focused one
+    fn switch_pane_focus(&mut self) {
+        if let Some(secondary) = &mut self.secondary_browser {
+            std::mem::swap(&mut self.file_browser, &mut secondary.browser);
+            secondary.active = !secondary.active;
+            self.reset_text_scroll();
+            self.update_preview();
+        }
+    }
+
+    fn show_transfer_dialog(&mut self) {
+        if let Some(secondary) = &self.secondary_browser
+            && let Some(file) = self.file_browser.get_selected_file()
+        {
+            self.transfer_dialog = Some(TransferDialog {
+                file_path: file.path.clone(),
+                file_name: file.name.clone(),
+                target_dir: secondary.browser.current_dir.clone(),
+            });
+            self.needs_redraw = true;
+        }
+    }
+
+    #[cfg(unix)]
+    fn show_permission_dialog(&mut self) {
+        if let Some(file) = self.file_browser.get_selected_file() {
+            self.permission_dialog = Some(PermissionDialog {
+                file_path: file.path.clone(),
+                file_name: file.name.clone(),
+                mode: file.permissions,
+                input: String::new(),
+            });
+            self.needs_redraw = true;
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn show_permission_dialog(&mut self) {
+        self.preview_manager.debug_info = self.localization.get("permissions_unsupported");
+    }
+
+    fn handle_permission_dialog(&mut self, key: KeyEvent) {
+        let Some(dialog) = self.permission_dialog.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Char(c @ '0'..='7') if dialog.input.len() < 3 => dialog.input.push(c),
+            KeyCode::Backspace => {
+                dialog.input.pop();
+            }
+            KeyCode::Enter if dialog.input.len() == 3 => {
+                if let Some(dialog) = self.permission_dialog.take() {
+                    self.change_permissions(&dialog);
+                }
+            }
+            KeyCode::Esc => self.permission_dialog = None,
+            _ => {}
+        }
+        self.needs_redraw = true;
+    }
+
+    /// chmod the file to the typed mode and relist the directory to show it
+    fn change_permissions(&mut self, dialog: &PermissionDialog) {
+        use fluent::fluent_args;
+        let Ok(mode) = u32::from_str_radix(&dialog.input, 8) else {
+            return;
+        };
+
+        #[cfg(unix)]
+        let result = {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&dialog.file_path, std::fs::Permissions::from_mode(mode))
+        };
+        #[cfg(not(unix))]
+        let result: std::io::Result<()> = Err(std::io::ErrorKind::Unsupported.into());
+
+        let message = match result {
+            Ok(()) => {
+                let _ = self.file_browser.refresh_files();
+                let args = fluent_args![
+                    "file" => dialog.file_name.as_str(),
+                    "mode" => dialog.input.as_str()
+                ];
+                self.localization
+                    .get_with_args("permissions_changed", Some(&args))
+            }
+            Err(e) => {
+                let args = fluent_args![
+                    "file" => dialog.file_name.as_str(),
+                    "error" => e.to_string()
+                ];
+                self.localization
+                    .get_with_args("permissions_failed", Some(&args))
+            }
+        };
+        self.preview_manager.set_timed_message(message);
+    }
+
+    /// Offer to export every image in the current directory as ASCII art
+    fn show_batch_export_dialog(&mut self) {
+        if self.pending_batch_export.is_some() {
+            self.preview_manager.debug_info = self.localization.get("batch_export_running");
+            self.needs_redraw = true;
+            return;
+        }
+
+        let images: Vec<FileItem> = self
+            .file_browser
+            .files
+            .iter()
+            .filter(|file| !file.is_directory && file.is_image())
+            .cloned()
+            .collect();
+        if images.is_empty() {
+            self.preview_manager.debug_info = self.localization.get("batch_export_no_images");
+        } else {
+            self.batch_export_dialog = Some(images);
+        }
+        self.needs_redraw = true;
+    }
+
+    fn handle_batch_export_dialog(&mut self, key: KeyEvent) {
+        let Some(images) = self.batch_export_dialog.take() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Enter | KeyCode::Char('y') => self.start_batch_export(images, false),
+            KeyCode::Char('o') => self.start_batch_export(images, true),
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('n') => {}
+            _ => self.batch_export_dialog = Some(images),
+        }
+        self.needs_redraw = true;
+    }
+
+    fn start_batch_export(&mut self, images: Vec<FileItem>, overwrite: bool) {
+        let receiver = self.preview_manager.start_batch_export(
+            std::path::Path::new(&self.file_browser.current_dir),
+            images,
+            self.ui_layout.preview_width,
+            self.ui_layout.preview_height,
+            overwrite,
+        );
+        self.pending_batch_export = Some(PendingBatchExport {
+            receiver,
+            progress: None,
+            html_output: None,
+        });
+    }
+
+    /// Show the progress of a running batch export, and the totals once it's done
+    pub fn poll_batch_export(&mut self) {
+        use fluent::fluent_args;
+        let Some(pending) = self.pending_batch_export.as_mut() else {
+            return;
+        };
+
+        loop {
+            match pending.receiver.try_recv() {
+                Ok(ExportProgress::Exporting {
+                    position,
+                    total,
+                    file_name,
+                }) => {
+                    let args = fluent_args![
+                        "current" => position,
+                        "total" => total,
+                        "file" => file_name
+                    ];
+                    pending.progress = Some(
+                        self.localization
+                            .get_with_args("batch_export_progress", Some(&args)),
+                    );
+                    self.needs_redraw = true;
+                }
+                Ok(ExportProgress::Done {
+                    succeeded,
+                    failed,
+                    skipped,
+                }) => {
+                    match pending.html_output.take() {
+                        Some(file) if failed > 0 => {
+                            let args = fluent_args![
+                                "file" => file,
+                                "failed" => failed,
+                                "log" => EXPORT_ERRORS_FILE
+                            ];
+                            self.slideshow_notice = Some((
+                                self.localization
+                                    .get_with_args("slideshow_html_exported_partly", Some(&args)),
+                                Instant::now(),
+                            ));
+                        }
+                        Some(file) => {
+                            let args = fluent_args!["file" => file];
+                            self.slideshow_notice = Some((
+                                self.localization
+                                    .get_with_args("slideshow_html_exported", Some(&args)),
+                                Instant::now(),
+                            ));
+                        }
+                        None => {
+                            let args = fluent_args![
+                                "succeeded" => succeeded,
+                                "failed" => failed,
+                                "skipped" => skipped
+                            ];
+                            self.preview_manager.debug_info = self
+                                .localization
+                                .get_with_args("batch_export_done", Some(&args));
+                        }
+                    }
+                    self.pending_batch_export = None;
+                    // The slideshow keeps indices into the file list, so leave it be
+                    if !self.is_slideshow_mode {
+                        let _ = self.file_browser.refresh_files();
+                    }
+                    self.needs_redraw = true;
+                    return;
+                }
+                Ok(ExportProgress::Failed(error)) => {
+                    if pending.html_output.is_some() {
+                        let args = fluent_args!["error" => error];
+                        self.slideshow_notice = Some((
+                            self.localization
+                                .get_with_args("slideshow_html_failed", Some(&args)),
+                            Instant::now(),
+                        ));
+                    } else {
+                        self.preview_manager.debug_info = error;
+                    }
+                    self.pending_batch_export = None;
+                    self.needs_redraw = true;
+                    return;
+                }
+                Err(TryRecvError::Empty) => return,
+                Err(TryRecvError::Disconnected) => {
+                    self.pending_batch_export = None;
+                    self.needs_redraw = true;
+                    return;
+                }
+            }
+        }
+    }
+
+    fn handle_transfer_dialog(&mut self, key: KeyEvent) {
+        let Some(dialog) = self.transfer_dialog.take() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Char('c') => self.transfer_file(&dialog, false),
+            KeyCode::Char('m') => self.transfer_file(&dialog, true),
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('n') => {}
+            _ => self.transfer_dialog = Some(dialog),
+        }
+        self.needs_redraw = true;
+    }
+
+    /// Copy or move a file into the other pane's directory, refusing to overwrite
+    /// Copy a file, removing whatever part of the copy was written if it fails
+    fn copy_file(source: &str, target: &std::path::Path) -> std::io::Result<()> {
+        std::fs::copy(source, target).map(|_| ()).inspect_err(|_| {
+            let _ = std::fs::remove_file(target);
+        })
+    }
+
+    fn transfer_file(&mut self, dialog: &TransferDialog, move_file: bool) {
+        use fluent::fluent_args;
+        let target = std::path::Path::new(&dialog.target_dir).join(&dialog.file_name);
+        let result: Result<(), Box<dyn Error>> = if target.exists() {
+            Err(format!("{} already exists", target.display()).into())
+        } else if move_file {
+            // rename can't cross file systems, fall back to copying
+            match std::fs::rename(&dialog.file_path, &target) {
+                Err(e) if e.kind() == std::io::ErrorKind::CrossesDevices => {
+                    Self::copy_file(&dialog.file_path, &target)
+                        .and_then(|()| std::fs::remove_file(&dialog.file_path))
+                        .inspect_err(|_| {
+                            let _ = std::fs::remove_file(&target);
+                        })
+                        .map_err(Into::into)
+                }
+                other => other.map_err(Into::into),
+            }
+        } else {
+            Self::copy_file(&dialog.file_path, &target).map_err(Into::into)
+        };
+
+        let message = match result {
+            Ok(()) => {
+                if let Some(secondary) = &mut self.secondary_browser {
+                    let _ = secondary.browser.refresh_files();
+                }
+                if move_file {
+                    let _ = self.file_browser.refresh_files();
+                    self.update_preview();
+                }
+                let args = fluent_args![
+                    "file" => dialog.file_name.as_str(),
+                    "dir" => dialog.target_dir.as_str()
+                ];
+                let key = if move_file {
+                    "file_moved"
+                } else {
+                    "file_copied"
+                };
+                self.localization.get_with_args(key, Some(&args))
+            }
+            Err(e) => {
+                let args = fluent_args![
+                    "file" => dialog.file_name.as_str(),
+                    "error" => e.to_string()
+                ];
+                self.localization
+                    .get_with_args("transfer_failed", Some(&args))
+            }
+        };
+        self.preview_manager.set_timed_message(message);
+    }
+
+    fn show_open_with_picker(&mut self) {
+        let Some(file) = self.file_browser.get_selected_file() else {
+            let error_msg = self.localization.get("no_file_selected");
+            let current_debug = self.preview_manager.get_debug_info();
+            self.preview_manager
+                .set_timed_message(format!("{} | {}", current_debug, error_msg));
+            return;
+        };
+
+        let openers = detect_openers(&file.path);
+        if openers.is_empty() {
+            let message = self.localization.get("open_with_none");
+            let current_debug = self.preview_manager.get_debug_info();
+            self.preview_manager
+                .set_timed_message(format!("{} | {}", current_debug, message));
+            return;
+        }
+
+        self.open_with_picker = Some(OpenWithPicker {
+            file_path: file.path.clone(),
+            file_name: file.name.clone(),
+            openers,
+            selected: 0,
+        });
+        self.needs_redraw = true;
+    }
+
+    fn handle_open_with_picker(&mut self, key: KeyEvent) {
+        let Some(picker) = self.open_with_picker.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Down | KeyCode::Char('j') => picker.move_down(),
+            KeyCode::Up | KeyCode::Char('k') => picker.move_up(),
+            KeyCode::Enter => {
+                if let Some(picker) = self.open_with_picker.take()
+                    && let Some(opener) = picker.selected_opener()
+                {
+                    self.launch_opener(opener, &picker.file_path, &picker.file_name);
+                }
+            }
+            KeyCode::Esc | KeyCode::Char('q') => self.open_with_picker = None,
+            _ => {}
+        }
+        self.needs_redraw = true;
+    }
+
+    fn show_charset_picker(&mut self) {
+        let config = self.preview_manager.get_config();
+        self.charset_picker = Some(CharsetPicker::new(config.converter.jp2a.chars.as_deref()));
+        self.needs_redraw = true;
+    }
+
+    fn handle_charset_picker(&mut self, key: KeyEvent) {
+        let Some(picker) = self.charset_picker.as_mut() else {
+            return;
+        };
+        if let Some(input) = picker.custom_input.as_mut() {
+            match key.code {
+                KeyCode::Char(c) => input.push(c),
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Enter if !input.is_empty() => {
+                    let chars = input.clone();
+                    self.charset_picker = None;
+                    self.set_jp2a_charset(Some(chars));
+                }
+                KeyCode::Esc => picker.custom_input = None,
+                _ => {}
+            }
+            self.needs_redraw = true;
+            return;
+        }
+
+        match key.code {
+            KeyCode::Down | KeyCode::Char('j') => picker.move_down(),
+            KeyCode::Up | KeyCode::Char('k') => picker.move_up(),
+            KeyCode::Enter if picker.is_custom_selected() => picker.start_custom_input(),
+            KeyCode::Enter => {
+                let chars = picker.selected_preset();
+                self.charset_picker = None;
+                self.set_jp2a_charset(chars);
+            }
+            KeyCode::Esc | KeyCode::Char('q') => self.charset_picker = None,
+            _ => {}
+        }
+        self.needs_redraw = true;
+    }
+
+    /// Switch jp2a's character ramp, dropping previews drawn with the old one
+    fn set_jp2a_charset(&mut self, chars: Option<String>) {
+        let mut config = self.preview_manager.get_config().clone();
+        config.converter.jp2a.chars = chars;
+        self.preview_manager.update_config(config);
+        self.update_preview();
+        let message = self.jp2a_charset_message("jp2a_charset_changed");
+        self.preview_manager.set_message(message);
+    }
+
+    fn jp2a_charset_message(&self, key: &str) -> String {
+        use fluent::fluent_args;
+        let chars = self
+            .preview_manager
+            .get_config()
+            .converter
+            .jp2a
+            .chars
+            .as_deref();
+        let args = fluent_args!["charset" => converter::jp2a_charset_name(chars)];
+        self.localization.get_with_args(key, Some(&args))
+    }
+
+    #[cfg(target_os = "macos")]
+    fn open_path_in_system_browser(
+        &self,
+        dir_path: &std::path::Path,
+        file_path: Option<&std::path::Path>,
+    ) -> Result<(), Box<dyn Error>> {
+        if let Some(file) = file_path {
+            // On macOS, we can use 'open -R' to reveal the file in Finder
+            Command::new("open")
+                .args(["-R", &file.to_string_lossy()])
+                .spawn()?;
+        } else {
+            // Open directory normally
+            Command::new("open").arg(dir_path).spawn()?;
+        }
+        Ok(())
+    }
+
+    #[cfg(target_os = "windows")]
+    fn open_path_in_system_browser(
+        &self,
+        dir_path: &std::path::Path,
+        file_path: Option<&std::path::Path>,
+    ) -> Result<(), Box<dyn Error>> {
+        if let Some(file) = file_path {
+            // On Windows, we can use explorer.exe /select to open and highlight the file
+            Command::new("explorer")
+                .args(&["/select,", &file.to_string_lossy()])
+                .spawn()?;
+        } else {
+            Command::new("explorer").arg(dir_path).spawn()?;
+        }
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    fn open_path_in_system_browser(
+        &self,
+        dir_path: &std::path::Path,
+        file_path: Option<&std::path::Path>,
+    ) -> Result<(), Box<dyn Error>> {
+        // Try different file managers with file selection support where available
+        let file_managers_with_selection = [
+            ("nautilus", vec!["--select"]),
+            ("dolphin", vec!["--select"]),
+            ("thunar", vec![]), // Thunar doesn't have file selection, but we'll try to open the file directly
+        ];
+
+        let file_managers_basic = ["xdg-open", "pcmanfm"];
+
+        // First try file managers that support file selection
+        if let Some(file) = file_path {
+            for (manager, args) in &file_managers_with_selection {
+                if Command::new("which")
+                    .arg(manager)
+                    .output()?
+                    .status
+                    .success()
+                {
+                    let mut cmd = Command::new(manager);
+
+                    if !args.is_empty() {
+                        // Use selection argument with the file path
+                        cmd.args(args).arg(file);
+                    } else if *manager == "thunar" {
+                        // For thunar, try to open the file directly, then fall back to directory
+                        if Command::new("thunar").arg(file).spawn().is_err() {
+                            Command::new("thunar").arg(dir_path).spawn()?;
+                        }
+                        return Ok(());
+                    }
+
+                    if cmd.spawn().is_ok() {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+
+        // Fall back to basic file managers (just open directory)
+        for manager in &file_managers_basic {
+            if Command::new("which")
+                .arg(manager)
+                .output()?
+                .status
+                .success()
+            {
+                Command::new(manager).arg(dir_path).spawn()?;
+                return Ok(());
+            }
+        }
+
+        // Last resort: try all the managers we know about for directory opening
+        let all_managers = ["nautilus", "dolphin", "thunar", "pcmanfm"];
+        for manager in &all_managers {
+            if Command::new("which")
+                .arg(manager)
+                .output()?
+                .status
+                .success()
+            {
+                Command::new(manager).arg(dir_path).spawn()?;
+                return Ok(());
+            }
+        }
+
+        Err("No suitable file manager found".into())
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+    fn open_path_in_system_browser(
+        &self,
+        _dir_path: &std::path::Path,
+        _file_path: Option<&std::path::Path>,
+    ) -> Result<(), Box<dyn Error>> {
+        Err("Opening system file browser not supported on this platform".into())
+    }
+
+    #[allow(dead_code)]
+    pub fn is_slideshow_mode(&self) -> bool {
+        self.is_slideshow_mode
+    }
+
+    pub fn enter_slideshow_mode(&mut self) {
+        // Build list of image files starting from current selection
+        self.slideshow_image_files.clear();
+        self.slideshow_start_index = self.file_browser.selected_index;
+
+        // Find all image files in the current directory
+        for (i, file) in self.file_browser.files.iter().enumerate() {
+            if file.is_image() {
+                self.slideshow_image_files.push(i);
+            }
+        }
+
+        if self.slideshow_image_files.is_empty() {
+            // No images to show slideshow, stay in the file browser
+            self.preview_manager.debug_info = self.localization.get("slideshow_no_images");
+            self.needs_redraw = true;
+            return;
+        }
+
+        let order = self.preview_manager.get_config().get_slideshow_order();
+        self.order_slideshow_images(order, shuffle_seed());
+
+        // Find the position of current selection in image files list.
+        // A random order starts from its own first image instead.
+        if order == SlideshowOrder::Random {
+            self.slideshow_current_index = 0;
+            self.slideshow_start_index = self.slideshow_image_files[0];
+        } else if let Some(pos) = self
+            .slideshow_image_files
+            .iter()
+            .position(|&i| i == self.slideshow_start_index)
+        {
+            self.slideshow_current_index = pos;
+        } else {
+            // Current selection is not an image, start with first image
+            self.slideshow_current_index = 0;
+            // Update slideshow_start_index to the first image for consistency
+            if !self.slideshow_image_files.is_empty() {
+                self.slideshow_start_index = self.slideshow_image_files[0];
+            }
+        }
+
+        if self.slideshow_shuffle {
+            self.build_slideshow_shuffle();
+        }
+
+        self.is_slideshow_mode = true;
+        self.update_slideshow_preview();
+        self.slideshow_last_change = Instant::now();
+        self.needs_redraw = true;
+    }
+
+    fn exit_slideshow_mode(&mut self) {
+        self.is_slideshow_mode = false;
+
+        // Select the current slideshow file in the file browser
+        if !self.slideshow_image_files.is_empty()
+            && self.slideshow_current_index < self.slideshow_image_files.len()
+        {
+            let current_file_index = self.slideshow_image_files[self.slideshow_current_index];
+            self.file_browser.set_selected_index(current_file_index);
+        } else {
+            // Fallback to original selection if something went wrong
+            self.file_browser
+                .set_selected_index(self.slideshow_start_index);
+        }
+
+        self.update_preview();
+    }
+
+    fn advance_slideshow(&mut self) {
+        if !self.is_slideshow_mode || self.slideshow_image_files.is_empty() {
+            return;
+        }
+
+        // Without looping the slideshow ends after the last image
+        if !self.slideshow_loop && self.slideshow_position() + 1 >= self.slideshow_image_files.len()
+        {
+            self.exit_slideshow_mode();
+            return;
+        }
+
+        // Store current content for potential transition
+        self.slideshow_previous_content = self.preview_content.clone();
+
+        self.step_slideshow(true);
+        self.slideshow_last_change = Instant::now();
+        self.update_slideshow_preview();
+
+        // Check if we should start a transition effect
+        // Transitions only work with Text content (ASCII art), not graphical content
+        if self.transition_manager.is_enabled()
+            && self.preview_manager.converter_supports_transitions()
+            && let (Some(prev_content), Some(new_content)) =
+                (&self.slideshow_previous_content, &self.preview_content)
+            && let (PreviewContent::Text(prev_text, _), PreviewContent::Text(new_text, _)) =
+                (prev_content, new_content)
+            && self
+                .transition_manager
+                .start_transition(prev_text, new_text, Direction::Forward)
+        {
+            // Successfully started transition
+            let current_debug = self.preview_manager.get_debug_info();
+            self.preview_manager.debug_info = format!(
+                "{} | Starting {} transition",
+                current_debug,
+                self.transition_manager.get_effect_name()
+            );
+        }
+
+        self.needs_redraw = true;
+    }
+
+    /// Move to the next or previous image with wrap-around, following the shuffled
+    /// order when shuffle is on
+    fn step_slideshow(&mut self, forward: bool) {
+        let len = self.slideshow_image_files.len();
+        let step = |position: usize| {
+            if forward {
+                (position + 1) % len
+            } else {
+                (position + len - 1) % len
+            }
+        };
+
+        if self.slideshow_shuffle && self.slideshow_shuffled_indices.len() == len {
+            self.slideshow_shuffle_position = step(self.slideshow_shuffle_position);
+            self.slideshow_current_index =
+                self.slideshow_shuffled_indices[self.slideshow_shuffle_position];
+        } else {
+            self.slideshow_current_index = step(self.slideshow_current_index);
+        }
+    }
+
+    fn toggle_slideshow_shuffle(&mut self) {
+        self.slideshow_shuffle = !self.slideshow_shuffle;
+        if self.slideshow_shuffle {
+            self.build_slideshow_shuffle();
+            self.preview_manager.debug_info = self.localization.get("slideshow_shuffle_on");
+        } else {
+            // slideshow_current_index still points at the image on screen, so the
+            // original order continues from here
+            self.slideshow_shuffled_indices.clear();
+            self.preview_manager.debug_info = self.localization.get("slideshow_shuffle_off");
+        }
+        self.needs_redraw = true;
+    }
+
+    /// Put the slideshow's images in `order`. The file list keeps its own sort.
+    fn order_slideshow_images(&mut self, order: SlideshowOrder, seed: u32) {
+        let browser = &self.file_browser;
+        let sort_mode = match order {
+            SlideshowOrder::CurrentSort => return,
+            SlideshowOrder::Name => SortMode::Name,
+            SlideshowOrder::Date => SortMode::DateOldestFirst,
+            SlideshowOrder::Random => {
+                let images = std::mem::take(&mut self.slideshow_image_files);
+                self.slideshow_image_files = shuffled_order(images.len(), seed)
+                    .into_iter()
+                    .map(|position| images[position])
+                    .collect();
+                return;
+            }
+        };
+        self.slideshow_image_files.sort_by(|&a, &b| {
+            browser.compare_files(&browser.files[a], &browser.files[b], &sort_mode)
+        });
+    }
+
+    fn build_slideshow_shuffle(&mut self) {
+        self.slideshow_shuffled_indices =
+            shuffled_order(self.slideshow_image_files.len(), shuffle_seed());
+
+        // Start the shuffled order from the image currently on screen
+        if let Some(position) = self
+            .slideshow_shuffled_indices
+            .iter()
+            .position(|&i| i == self.slideshow_current_index)
+        {
+            self.slideshow_shuffled_indices.swap(0, position);
+        }
+        self.slideshow_shuffle_position = 0;
+    }
+
+    /// Lengthen or shorten the slideshow delay for this session; Ctrl-S saves it
+    fn adjust_slideshow_delay(&mut self, slower: bool) {
+        let config = self.preview_manager.get_config();
+        let factor = config.get_slideshow_delay_step_factor() as f64;
+        let current_ms = self.slideshow_delay.as_millis() as f64;
+        let new_ms = if slower {
+            current_ms * factor
+        } else {
+            current_ms / factor
+        };
+        self.slideshow_delay =
+            Duration::from_millis(config.clamp_slideshow_delay_ms(new_ms.round() as u64));
+        self.needs_redraw = true;
+    }
+
+    fn save_slideshow_delay(&mut self) {
+        let delay_ms = self.slideshow_delay.as_millis() as u64;
+        let mut config = self.preview_manager.get_config().clone();
+        config.slideshow_delay_ms = Some(delay_ms);
+
+        let result =
+            PTuiConfig::get_config_path().and_then(|path| PTuiConfig::save_config(&path, &config));
+        let message = match result {
+            Ok(()) => {
+                self.preview_manager.set_slideshow_delay_ms(delay_ms);
+                self.localization.get("slideshow_delay_saved")
+            }
+            Err(e) => format!("Failed to save config: {}", e),
+        };
+        self.preview_manager.set_timed_message(message);
+        self.needs_redraw = true;
+    }
+
+    fn slideshow_file(&self) -> Option<&FileItem> {
+        self.slideshow_image_files
+            .get(self.slideshow_current_index)
+            .and_then(|&index| self.file_browser.files.get(index))
+    }
+
+    fn slideshow_file_name(&self) -> Option<String> {
+        self.slideshow_file().map(|file| file.name.clone())
+    }
+
+    /// Save what the slideshow is showing next to the image as name.ascii. Part way
+    /// through a transition that is the transition frame; graphical previews have no
+    /// text on screen, so the image is converted instead.
+    fn save_slideshow_frame(&mut self) {
+        use fluent::fluent_args;
+        let Some(file) = self.slideshow_file().cloned() else {
+            return;
+        };
+
+        let frame = match self.transition_manager.get_current_transition_frame() {
+            Some(transition_frame) => Some(transition_frame.clone()),
+            None => match self
+                .preview_content
+                .as_ref()
+                .map(|content| content.frame(self.animation_frame_index))
+            {
+                Some(PreviewContent::Text(text, _)) => Some(text),
+                _ => None,
+            },
+        };
+        let result = match frame {
+            Some(frame) => PreviewManager::save_frame_to_file(&file, &frame).map(|_| ()),
+            None => {
+                let height = self
+                    .terminal_height
+                    .saturating_sub(self.slideshow_reserved_rows());
+                self.preview_manager
+                    .save_ascii_to_file(&file, self.terminal_width, height, &self.localization)
+                    .map(|_| ())
+                    .map_err(|e| e.to_string())
+            }
+        };
+
+        let notice = match result {
+            Ok(()) => {
+                let saved_name = std::path::Path::new(&file.name).with_extension("ascii");
+                let args = fluent_args!["file" => saved_name.to_string_lossy().into_owned()];
+                self.localization
+                    .get_with_args("slideshow_frame_saved", Some(&args))
+            }
+            Err(e) => e,
+        };
+        self.slideshow_notice = Some((notice, Instant::now()));
+        self.needs_redraw = true;
+    }
+
+    /// Export the slideshow to slideshow.html in the current directory: every image as
+    /// ASCII art in one page that plays them in turn with a CSS animation, switching at
+    /// the slideshow delay. Converts on the batch export threads and reports when done.
+    fn export_slideshow_html(&mut self) {
+        if self.pending_batch_export.is_some() {
+            self.slideshow_notice = Some((
+                self.localization.get("batch_export_running"),
+                Instant::now(),
+            ));
+            self.needs_redraw = true;
+            return;
+        }
+
+        let height = self
+            .terminal_height
+            .saturating_sub(self.slideshow_reserved_rows());
+        let images: Vec<FileItem> = self
+            .slideshow_image_files
+            .iter()
+            .filter_map(|&index| self.file_browser.files.get(index))
+            .cloned()
+            .collect();
+        let dir = std::path::Path::new(&self.file_browser.current_dir);
+        let output_path = dir.join(SLIDESHOW_HTML_FILE);
+        let receiver = self.preview_manager.start_slideshow_html_export(
+            dir,
+            images,
+            self.terminal_width,
+            height,
+            self.slideshow_delay.as_millis() as u64,
+            output_path.clone(),
+        );
+        self.pending_batch_export = Some(PendingBatchExport {
+            receiver,
+            progress: None,
+            html_output: Some(output_path.to_string_lossy().into_owned()),
+        });
+        self.needs_redraw = true;
+    }
+
+    /// Rows below the slideshow image: the status bar plus the optional filename overlay
+    fn slideshow_reserved_rows(&self) -> u16 {
+        let status_height =
+            slideshow_status_height(self.preview_manager.get_config().get_show_progress_bar());
+        status_height + u16::from(self.slideshow_show_filename)
+    }
+
+    /// Progress bar cells to fill for the time since the image changed
+    fn slideshow_progress(&self) -> u16 {
+        slideshow_progress_fill(
+            self.slideshow_last_change.elapsed(),
+            self.slideshow_delay,
+            self.terminal_width.saturating_sub(2),
+        )
+    }
+
+    fn toggle_slideshow_filename(&mut self) {
+        self.slideshow_show_filename = !self.slideshow_show_filename;
+        // The image area changed height, so render the image again to fit
+        self.update_slideshow_preview();
+        self.needs_redraw = true;
+    }
+
+    fn toggle_slideshow_loop(&mut self) {
+        self.slideshow_loop = !self.slideshow_loop;
+        self.needs_redraw = true;
+    }
+
+    /// Position of the current image in play order
+    fn slideshow_position(&self) -> usize {
+        if self.slideshow_shuffle {
+            self.slideshow_shuffle_position
+        } else {
+            self.slideshow_current_index
+        }
+    }
+
+    fn slideshow_status(&self) -> SlideshowStatus {
+        SlideshowStatus {
+            current_image: self.slideshow_position() + 1,
+            total_images: self.slideshow_image_files.len(),
+            shuffle: self.slideshow_shuffle,
+            looping: self.slideshow_loop,
+            delay: self.slideshow_delay,
+            file_name: self
+                .slideshow_show_filename
+                .then(|| self.slideshow_file_name())
+                .flatten(),
+            elapsed: self.slideshow_last_change.elapsed(),
+            show_progress_bar: self.preview_manager.get_config().get_show_progress_bar(),
+            notice: self
+                .slideshow_notice
+                .as_ref()
+                .map(|(notice, _)| notice.clone()),
+        }
+    }
+
+    fn slideshow_go_backward(&mut self) {
+        if !self.is_slideshow_mode || self.slideshow_image_files.is_empty() {
+            return;
+        }
+
+        // Without looping there is nothing before the first image
+        if !self.slideshow_loop && self.slideshow_position() == 0 {
+            return;
+        }
+
+        // Store current content for potential transition
+        self.slideshow_previous_content = self.preview_content.clone();
+
+        self.step_slideshow(false);
+        self.slideshow_last_change = Instant::now();
+        self.update_slideshow_preview();
+
+        // Check if we should start a transition effect (same as advance_slideshow)
+        // Transitions only work with Text content (ASCII art), not graphical content
+        if self.transition_manager.is_enabled()
+            && self.preview_manager.converter_supports_transitions()
+            && let (Some(prev_content), Some(new_content)) =
+                (&self.slideshow_previous_content, &self.preview_content)
+            && let (PreviewContent::Text(prev_text, _), PreviewContent::Text(new_text, _)) =
+                (prev_content, new_content)
+            && self
+                .transition_manager
+                .start_transition(prev_text, new_text, Direction::Backward)
+        {
+            // Successfully started transition
+            let current_debug = self.preview_manager.get_debug_info();
+            self.preview_manager.debug_info = format!(
+                "{} | Starting {} transition",
+                current_debug,
+                self.transition_manager.get_effect_name()
+            );
+        }
+
+        self.needs_redraw = true;
+    }
+
+    fn update_slideshow_preview(&mut self) {
+        if !self.is_slideshow_mode || self.slideshow_image_files.is_empty() {
+            return;
+        }
+
+        let file_index = self.slideshow_image_files[self.slideshow_current_index];
+        if let Some(file) = self.file_browser.files.get(file_index) {
+            // Slideshow uses full screen minus status bar (and filename overlay)
+            let height = self
+                .terminal_height
+                .saturating_sub(self.slideshow_reserved_rows());
+            self.preview_content = Some(self.preview_manager.generate_preview(
+                file,
+                self.terminal_width,
+                height,
+                0, // No text scrolling in slideshow mode
+                &self.localization,
+            ));
+            self.restart_animation();
+        }
+    }
+
+    pub fn update_slideshow(&mut self) {
+        if self.is_slideshow_mode && self.slideshow_last_change.elapsed() >= self.slideshow_delay {
+            // Only advance slideshow if no transition is in progress
+            if !self.transition_manager.is_in_transition() {
+                self.advance_slideshow();
+                self.needs_redraw = true;
+            }
+        }
+
+        if self
+            .slideshow_notice
+            .as_ref()
+            .is_some_and(|(_, shown)| shown.elapsed() >= SLIDESHOW_NOTICE_DURATION)
+        {
+            self.slideshow_notice = None;
+            self.needs_redraw = true;
+        }
+
+        // Redraw as the progress bar fills, but only when another cell is filled
+        if self.is_slideshow_mode && self.preview_manager.get_config().get_show_progress_bar() {
+            let filled = self.slideshow_progress();
+            if filled != self.slideshow_progress_drawn {
+                self.slideshow_progress_drawn = filled;
+                self.needs_redraw = true;
+            }
+        }
+    }
+
+    /// Update transitions and return true if a redraw is needed
+    pub fn update_transitions(&mut self) -> bool {
+        // Advance animated GIFs independently of slideshow transitions
+        if let Some(PreviewContent::Animated {
+            frames,
+            frame_duration_ms,
+        }) = &self.preview_content
+            && !self.animation_paused
+            && !frames.is_empty()
+            && self.animation_last_frame.elapsed() >= Duration::from_millis(*frame_duration_ms)
+        {
+            self.animation_frame_index = (self.animation_frame_index + 1) % frames.len();
+            self.animation_last_frame = Instant::now();
+            self.needs_redraw = true;
+        }
+
+        if self.transition_manager.is_in_transition() {
+            // Check if transition frame has changed
+            let _current_frame = self.transition_manager.get_current_transition_frame();
+            // A frame change or completion indicates we need to redraw
+            self.needs_redraw = true;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn draw(&mut self, f: &mut ratatui::Frame) {
+        let size = f.area();
+        self.poll_notification();
+
+        // Update terminal dimensions
+        self.terminal_width = size.width;
+        self.terminal_height = size.height;
+
+        if self.is_slideshow_mode {
+            // Check if we have a transition in progress
+            let transition_content: Option<PreviewContent>;
+            let display_content = if let Some(transition_frame) =
+                self.transition_manager.get_current_transition_frame()
+            {
+                transition_content = Some(PreviewContent::Text(
+                    transition_frame.clone(),
+                    ContentAlignment::Image,
+                ));
+                transition_content.as_ref()
+            } else {
+                transition_content = self
+                    .preview_content
+                    .as_ref()
+                    .map(|content| content.frame(self.animation_frame_index));
+                transition_content.as_ref()
+            };
+
+            // Render full-screen slideshow
+            UIRenderer::render_slideshow(
+                f,
+                size,
+                display_content,
+                &self.localization,
+                &self.slideshow_status(),
+            );
+        } else {
+            // Regular UI layout
+            // Calculate layout
+            let (file_area, preview_area, debug_area) = self.ui_layout.calculate_layout(size);
+            let icons = self.preview_manager.get_config().get_file_icons();
+            let theme = self.preview_manager.get_config().get_theme();
+
+            // Render components (the file browser is hidden in full-screen preview)
+            if self.ui_layout.view_mode == ViewMode::Grid && !self.fullscreen_preview {
+                self.draw_file_grid(f, file_area);
+            } else if !self.fullscreen_preview {
+                match &mut self.secondary_browser {
+                    Some(secondary) => {
+                        // The primary pane stays on the left whichever one has focus
+                        let (left, right) = UILayout::split_file_area(file_area);
+                        let (focused_area, other_area) = if secondary.active {
+                            (right, left)
+                        } else {
+                            (left, right)
+                        };
+                        UIRenderer::render_file_browser(
+                            f,
+                            focused_area,
+                            &mut self.file_browser,
+                            true,
+                            &icons,
+                            &theme,
+                            &self.localization,
+                        );
+                        UIRenderer::render_file_browser(
+                            f,
+                            other_area,
+                            &mut secondary.browser,
+                            false,
+                            &icons,
+                            &theme,
+                            &self.localization,
+                        );
+                    }
+                    None => UIRenderer::render_file_browser(
+                        f,
+                        file_area,
+                        &mut self.file_browser,
+                        true,
+                        &icons,
+                        &theme,
+                        &self.localization,
+                    ),
+                }
+            }
+
+            // Don't render graphical preview when dialog is showing (graphics layer sits above text)
+            let animation_frame = self
+                .preview_content
+                .as_ref()
+                .map(|content| content.frame(self.animation_frame_index));
+            let dialog_open = self.show_delete_confirmation
+                || self.open_with_picker.is_some()
+                || self.charset_picker.is_some()
+                || self.recent_files_dialog.is_some()
+                || self.transfer_dialog.is_some()
+                || self.permission_dialog.is_some()
+                || self.batch_export_dialog.is_some()
+                || self.pending_batch_export.is_some();
+            let preview_to_render = if dialog_open {
+                None
+            } else {
+                animation_frame.as_ref()
+            };
+
+            UIRenderer::render_preview(
+                f,
+                preview_area,
+                preview_to_render,
+                &self.localization,
+                self.ascii_logo.as_ref(),
+                self.help_scroll_offset,
+                &icons,
+                self.text_total_lines
+                    .map(|total_lines| (self.text_scroll_offset, total_lines)),
+            );
+
+            if let ConversionState::Loading {
+                file_name,
+                started_at,
+            } = &self.conversion_state
+                && started_at.elapsed() >= SPINNER_DELAY
+                && !self.show_delete_confirmation
+            {
+                UIRenderer::render_loading_spinner(
+                    f,
+                    preview_area,
+                    file_name,
+                    started_at.elapsed(),
+                );
+            }
+
+            if self.fullscreen_preview {
+                let file_name = self
+                    .file_browser
+                    .get_selected_file()
+                    .map(|file| file.name.clone())
+                    .unwrap_or_default();
+                UIRenderer::render_fullscreen_status(
+                    f,
+                    debug_area,
+                    &self.localization,
+                    &file_name,
+                    self.file_browser.selected_index + 1,
+                    self.file_browser.files.len(),
+                );
+            } else {
+                let avg_color = self.selected_average_color();
+                UIRenderer::render_debug_pane(
+                    f,
+                    debug_area,
+                    &self.debug_pane_text(),
+                    &self.debug_pane_mode,
+                    avg_color,
+                    &self.localization,
+                );
+            }
+        }
+
+        // Render delete confirmation dialog overlay if needed
+        if self.show_delete_confirmation
+            && let Some(ref file_name) = self.delete_target_file
+        {
+            UIRenderer::render_delete_confirmation_dialog(
+                f,
+                size,
+                file_name,
+                self.uses_trash(),
+                &self.localization,
+            );
+        }
+
+        if let Some(ref picker) = self.open_with_picker {
+            UIRenderer::render_open_with_picker(f, size, picker, &self.localization);
+        }
+
+        if let Some(ref picker) = self.charset_picker {
+            UIRenderer::render_charset_picker(f, size, picker, &self.localization);
+        }
+
+        if let Some(ref dialog) = self.transfer_dialog {
+            UIRenderer::render_transfer_dialog(
+                f,
+                size,
+                &dialog.file_name,
+                &dialog.target_dir,
+                &self.localization,
+            );
+        }
+
+        if let Some(ref dialog) = self.permission_dialog {
+            UIRenderer::render_permission_dialog(
+                f,
+                size,
+                &dialog.file_name,
+                dialog.mode,
+                &dialog.input,
+                &self.localization,
+            );
+        }
+
+        if let Some(ref images) = self.batch_export_dialog {
+            UIRenderer::render_batch_export_dialog(f, size, images.len(), &self.localization);
+        }
+
+        if let Some(progress) = self
+            .pending_batch_export
+            .as_ref()
+            .and_then(|pending| pending.progress.as_deref())
+        {
+            UIRenderer::render_batch_export_progress(f, size, progress);
+        }
+
+        if let Some(ref input) = self.command_prompt {
+            UIRenderer::render_command_prompt(f, size, input, &self.localization);
+        }
+
+        if let Some(selected) = self.recent_files_dialog {
+            UIRenderer::render_recent_files_dialog(
+                f,
+                size,
+                &self.recent_files,
+                selected,
+                &self.localization,
+            );
+        }
+    }
+
+    fn draw_file_grid(&mut self, f: &mut ratatui::Frame, area: ratatui::layout::Rect) {
+        let cell_width = self.preview_manager.get_config().get_grid_cell_width();
+        let (columns, rows) = UIRenderer::grid_dimensions(area, cell_width);
+        self.file_browser.update_max_visible_files(columns * rows);
+        self.file_browser.set_grid_columns(columns);
+
+        // Thumbnails are made by the background worker on first view and cached by the
+        // preview manager; until one arrives its cell shows the file's icon
+        let thumbnail_width = cell_width.saturating_sub(2);
+        let thumbnails: Vec<Option<Text<'static>>> = self
+            .file_browser
+            .get_display_files()
+            .map(|(_, file)| {
+                self.preview_manager.generate_thumbnail(
+                    file,
+                    thumbnail_width,
+                    GRID_THUMBNAIL_HEIGHT,
+                )
+            })
+            .collect();
+
+        let icons = self.preview_manager.get_config().get_file_icons();
+        UIRenderer::render_file_grid(f, area, &self.file_browser, &thumbnails, cell_width, &icons);
+    }
+
+    fn is_text_file_selected(&self) -> bool {
+        if let Some(file) = self.file_browser.get_selected_file() {
+            file.is_text_file() && !file.is_directory
+        } else {
+            false
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn help_scroll_offset(&self) -> usize {
+        self.help_scroll_offset
+    }
+
+    /// Lines in the help screen: the logo, a gap, then the help text
+    pub fn help_line_count(&self) -> usize {
+        let logo_lines = self
+            .ascii_logo
+            .as_ref()
+            .map_or(0, |logo| logo.lines.len() + 2);
+        logo_lines + self.localization.get_help_text().lines().count()
+    }
+
+    fn scroll_help_up(&mut self) {
+        let scroll_amount = (self.ui_layout.preview_height as usize / 2).max(1);
+        self.help_scroll_offset = self.help_scroll_offset.saturating_sub(scroll_amount);
+        self.needs_redraw = true;
+    }
+
+    fn scroll_help_down(&mut self) {
+        let scroll_amount = (self.ui_layout.preview_height as usize / 2).max(1);
+        let last_line = self.help_line_count().saturating_sub(1);
+        self.help_scroll_offset = (self.help_scroll_offset + scroll_amount).min(last_line);
+        self.needs_redraw = true;
+    }
+
+    /// How far u and Space scroll: text_scroll_lines, or half the preview
+    fn text_scroll_amount(&self) -> usize {
+        self.preview_manager
+            .get_config()
+            .get_text_scroll_lines()
+            .unwrap_or((self.ui_layout.preview_height as usize / 2).max(1))
+    }
+
+    fn scroll_text_up(&mut self) {
+        self.scroll_text_up_by(self.text_scroll_amount());
+    }
+
+    fn scroll_text_down(&mut self) {
+        self.scroll_text_down_by(self.text_scroll_amount());
+    }
+
+    fn scroll_text_up_by(&mut self, lines: usize) {
+        self.text_scroll_offset = self.text_scroll_offset.saturating_sub(lines);
+        self.update_preview();
+    }
+
+    fn scroll_text_down_by(&mut self, lines: usize) {
+        self.text_scroll_offset += lines;
+        self.update_preview();
+    }
+
+    fn reset_text_scroll(&mut self) {
+        self.text_scroll_offset = 0;
+    }
+
+    /// Cycle through available converters in order: chafa -> jp2a -> graphical -> chafa
+    fn cycle_converter(&mut self) {
+        let new_converter = match self.preview_manager.current_converter_name() {
+            "chafa" => "jp2a",
+            "jp2a" => "graphical",
+            _ => "chafa", // Default to chafa for graphical or unknown
+        };
+
+        // Switching converters also clears the cache
+        self.preview_manager.set_converter_by_name(new_converter);
+        self.update_preview();
+
+        // Show feedback in debug info
+        let message = format!("Converter switched to: {}", new_converter);
+        self.preview_manager.debug_info = message;
+    }
+
+    /// Whether a dialog is drawn over the preview, where graphics would hide it
+    fn preview_covered_by_dialog(&self) -> bool {
+        self.show_delete_confirmation
+            || self.open_with_picker.is_some()
+            || self.charset_picker.is_some()
+            || self.recent_files_dialog.is_some()
+            || self.transfer_dialog.is_some()
+            || self.permission_dialog.is_some()
+            || self.batch_export_dialog.is_some()
+            || self.pending_batch_export.is_some()
+    }
+
+    /// Whether the terminal has to be cleared before drawing. Sixel and iTerm2 images
+    /// stay on screen until their cells are written again, and ratatui only writes the
+    /// cells it thinks changed, so once the image is replaced or a dialog opens over it
+    /// everything is redrawn.
+    pub fn take_terminal_clear_request(&mut self) -> bool {
+        let Some(shown) = self.inline_image_shown.take() else {
+            return false;
+        };
+        let still_shown = matches!(
+            &self.preview_content,
+            Some(PreviewContent::Inline(current)) if Rc::ptr_eq(&shown, current)
+        ) && !self.preview_covered_by_dialog();
+        if still_shown {
+            self.inline_image_shown = Some(shown);
+        }
+        !still_shown
+    }
+
+    /// Where an inline image's top left corner goes so it sits centred in the preview,
+    /// or in the screen above the status bar during a slideshow
+    fn inline_image_position(&mut self, inline: &InlinePreview) -> (u16, u16) {
+        let screen = ratatui::layout::Rect::new(0, 0, self.terminal_width, self.terminal_height);
+        let area = if self.is_slideshow_mode {
+            ratatui::layout::Rect {
+                height: screen.height.saturating_sub(self.slideshow_reserved_rows()),
+                ..screen
+            }
+        } else {
+            let (_, preview_area, _) = self.ui_layout.calculate_layout(screen);
+            preview_area.inner(ratatui::layout::Margin::new(1, 1))
+        };
+        (
+            area.x + area.width.saturating_sub(inline.width) / 2,
+            area.y + area.height.saturating_sub(inline.height) / 2,
+        )
+    }
+
+    /// Clear Kitty graphics protocol images from the terminal
+    /// This should be called when switching from graphical to text mode
+    pub fn clear_graphics_if_needed(&self) {
+        // If we're not showing graphical content, clear any lingering images
+        // Only do this in non-test environments to avoid interfering with test output
+        #[cfg(not(test))]
+        {
+            // Check if current preview is graphical (either Graphical or Kitty)
+            let is_current_graphical = matches!(
+                &self.preview_content,
+                Some(PreviewContent::Graphical(_)) | Some(PreviewContent::Kitty(_))
+            );
+
+            // Clear graphics if not graphical content, or if delete dialog is showing
+            // (dialog needs to appear above the graphics layer)
+            if !is_current_graphical || self.preview_covered_by_dialog() {
+                use std::io::Write;
+                // Send Kitty protocol command to delete all images
+                let delete_all_cmd = "\x1b_Ga=d,d=a\x1b\\";
+                let _ = std::io::stdout().write_all(delete_all_cmd.as_bytes());
+                let _ = std::io::stdout().flush();
+            }
+        }
+    }
+
+    /// Render Kitty graphics, and image escapes from the converter, after ratatui's
+    /// frame is drawn
+    /// This must be called AFTER terminal.draw() to avoid being overwritten
+    pub fn render_kitty_post_draw(&mut self) {
+        #[cfg(not(test))]
+        {
+            // Don't render graphics when delete confirmation dialog is showing
+            if self.preview_covered_by_dialog() {
+                return;
+            }
+
+            // Check if we have a Kitty preview to render
+            if let Some(PreviewContent::Kitty(ref kitty_rc)) = self.preview_content {
+                let mut kitty = kitty_rc.borrow_mut();
+
+                // Reset rendered flag to allow re-rendering
+                kitty.rendered = false;
+
+                // Calculate position based on mode
+                let (render_x, render_y, width, height) = if self.is_slideshow_mode {
+                    // Slideshow mode: full screen with status bar at bottom
+                    let image_area = ratatui::layout::Rect::new(
+                        0,
+                        0,
+                        self.terminal_width,
+                        // Reserve rows for the status bar and filename overlay
+                        self.terminal_height
+                            .saturating_sub(self.slideshow_reserved_rows()),
+                    );
+
+                    let img_aspect = kitty.img_width as f32 / kitty.img_height as f32;
+                    let font_width = kitty.font_size.0 as f32;
+                    let font_height = kitty.font_size.1 as f32;
+                    let char_aspect = font_height / font_width;
+
+                    let display_width_cells =
+                        (image_area.height as f32 * img_aspect * char_aspect) as u16;
+
+                    let (w, h) = if display_width_cells <= image_area.width {
+                        (display_width_cells, image_area.height)
+                    } else {
+                        let display_height =
+                            (image_area.width as f32 / img_aspect / char_aspect) as u16;
+                        (image_area.width, display_height.min(image_area.height))
+                    };
+
+                    let x_offset = (image_area.width.saturating_sub(w)) / 2;
+                    let y_offset = (image_area.height.saturating_sub(h)) / 2;
+                    (image_area.x + x_offset, image_area.y + y_offset, w, h)
+                } else {
+                    // Normal mode: use preview area from layout
+                    let (_, preview_area, _) =
+                        self.ui_layout
+                            .calculate_layout(ratatui::layout::Rect::new(
+                                0,
+                                0,
+                                self.terminal_width,
+                                self.terminal_height,
+                            ));
+
+                    // Account for border
+                    let inner_area = ratatui::layout::Rect::new(
+                        preview_area.x + 1,
+                        preview_area.y + 1,
+                        preview_area.width.saturating_sub(2),
+                        preview_area.height.saturating_sub(2),
+                    );
+
+                    let img_aspect = kitty.img_width as f32 / kitty.img_height as f32;
+                    let font_width = kitty.font_size.0 as f32;
+                    let font_height = kitty.font_size.1 as f32;
+                    let char_aspect = font_height / font_width;
+
+                    let display_width_cells =
+                        (inner_area.height as f32 * img_aspect * char_aspect) as u16;
+
+                    let (w, h) = if display_width_cells <= inner_area.width {
+                        (display_width_cells, inner_area.height)
+                    } else {
+                        let display_height =
+                            (inner_area.width as f32 / img_aspect / char_aspect) as u16;
+                        (inner_area.width, display_height.min(inner_area.height))
+                    };
+
+                    let x_offset = (inner_area.width.saturating_sub(w)) / 2;
+                    let y_offset = (inner_area.height.saturating_sub(h)) / 2;
+                    (inner_area.x + x_offset, inner_area.y + y_offset, w, h)
+                };
+
+                // Update display dimensions
+                kitty.display_width = width as u32;
+                kitty.display_height = height as u32;
+
+                // Render the Kitty image (now AFTER ratatui has flushed)
+                if let Err(e) =
+                    crate::preview::PreviewManager::print_kitty_image(&mut kitty, render_x, render_y)
+                {
+                    eprintln!("[KITTY] Post-draw render error: {}", e);
+                }
+            }
+
+            // Sixel and iTerm2 escapes from the converter go in the same way
+            if let Some(PreviewContent::Inline(inline)) = &self.preview_content {
+                let inline = Rc::clone(inline);
+                let (x, y) = self.inline_image_position(&inline);
+                if let Err(e) = crate::preview::PreviewManager::print_inline_image(
+                    &mut std::io::stdout(),
+                    &inline,
+                    x,
+                    y,
+                ) {
+                    eprintln!("[INLINE] Post-draw render error: {}", e);
+                }
+                self.inline_image_shown = Some(inline);
+            }
+        }
+    }
+}
+
+/// A seed for shuffled_order that differs from one slideshow to the next
+fn shuffle_seed() -> u32 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .subsec_nanos()
+}
+
+/// Fisher-Yates shuffle of 0..len using a xorshift generator, so the same seed
+/// always gives the same order
+fn shuffled_order(len: usize, seed: u32) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..len).collect();
+    let mut state = seed.max(1);
+    for i in (1..len).rev() {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        order.swap(i, state as usize % (i + 1));
+    }
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SlideshowTransitionConfig;
+    use crate::file_browser::{ContentFilter, SortMode};
+    use crate::test_utils::helpers::*;
+
+    fn create_slideshow_app(temp_fs: &TestFileSystem, images: usize, looping: bool) -> ChafaTui {
+        for i in 0..images {
+            temp_fs
+                .create_test_image(&format!("image{}.jpg", i))
+                .unwrap();
+        }
+        let config = PTuiConfig {
+            slideshow_loop: Some(looping),
+            ..create_test_config()
+        };
+        let file_browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        let localization = Localization::new("en").unwrap();
+        ChafaTui::with_parts(config, file_browser, localization)
+    }
+
+    #[test]
+    fn test_open_with_picker_leaves_out_the_open_with_command() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_test_image("beach.jpg").unwrap();
+        let config = PTuiConfig {
+            open_with: Some(HashMap::from([(
+                "jpg".to_string(),
+                "ptui-test-viewer".to_string(),
+            )])),
+            ..create_test_config()
+        };
+        let file_browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        let localization = Localization::new("en").unwrap();
+        let mut app = ChafaTui::with_parts(config, file_browser, localization);
+        assert_eq!(
+            app.file_browser.get_selected_file().unwrap().name,
+            "beach.jpg"
+        );
+
+        press(&mut app, 'O');
+        let offered = app
+            .open_with_picker
+            .iter()
+            .flat_map(|picker| &picker.openers)
+            .any(|opener| opener.name == "ptui-test-viewer");
+        assert!(!offered);
+    }
+
+    #[cfg(not(feature = "clipboard"))]
+    #[rstest::rstest]
+    #[case(KeyModifiers::NONE)]
+    #[case(KeyModifiers::CONTROL)]
+    fn test_copy_path_without_clipboard_support(#[case] modifiers: KeyModifiers) {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 1, true);
+
+        // Ctrl+C copies too rather than quitting
+        let result = app.handle_key_event(KeyEvent::new(KeyCode::Char('c'), modifiers));
+        assert!(result.is_ok());
+        assert_eq!(
+            app.preview_manager.get_debug_info(),
+            app.localization.get("clipboard_not_compiled")
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(Some(10), true)]
+    #[case(Some(0), false)]
+    fn test_timed_message_clears_after_notification_duration(
+        #[case] duration_ms: Option<u64>,
+        #[case] clears: bool,
+    ) {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 1, true);
+        let config = PTuiConfig {
+            notification_duration_ms: duration_ms,
+            ..app.preview_manager.get_config().clone()
+        };
+        app.preview_manager.update_config(config);
+
+        app.preview_manager
+            .set_timed_message("Deleted: image0.jpg".to_string());
+        app.poll_notification();
+        assert_eq!(app.preview_manager.get_debug_info(), "Deleted: image0.jpg");
+
+        std::thread::sleep(Duration::from_millis(30));
+        app.poll_notification();
+        let expected = if clears {
+            app.localization.get("ptui_ready")
+        } else {
+            "Deleted: image0.jpg".to_string()
+        };
+        assert_eq!(app.preview_manager.get_debug_info(), expected);
+    }
+
+    #[test]
+    fn test_newer_message_is_not_cleared_with_timed_one() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 1, true);
+        let config = PTuiConfig {
+            notification_duration_ms: Some(10),
+            ..app.preview_manager.get_config().clone()
+        };
+        app.preview_manager.update_config(config);
+
+        app.preview_manager
+            .set_timed_message("Deleted: image0.jpg".to_string());
+        app.preview_manager.debug_info = "Calculating...".to_string();
+        std::thread::sleep(Duration::from_millis(30));
+        app.poll_notification();
+        assert_eq!(app.preview_manager.get_debug_info(), "Calculating...");
+    }
+
+    #[test]
+    fn test_debug_pane_cycles_through_modes() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 1, true);
+        app.preview_manager.set_message("hello".to_string());
+        assert_eq!(app.debug_pane_text(), "hello");
+
+        let press_d = |app: &mut ChafaTui| {
+            app.handle_key_event(KeyEvent::new(KeyCode::Char('D'), KeyModifiers::SHIFT))
+                .unwrap();
+        };
+
+        press_d(&mut app);
+        assert_eq!(app.debug_pane_mode, DebugPaneContent::FileInfo);
+        assert!(app.debug_pane_text().contains("image0.jpg"));
+
+        press_d(&mut app);
+        assert_eq!(app.debug_pane_mode, DebugPaneContent::ConverterInfo);
+        assert!(app.debug_pane_text().contains("chafa"));
+
+        press_d(&mut app);
+        assert_eq!(app.debug_pane_mode, DebugPaneContent::CacheStats);
+        assert!(app.debug_pane_text().starts_with("Hits:"));
+        app.preview_manager.cache_hits = 3;
+        app.preview_manager.cache_misses = 1;
+        assert!(
+            app.debug_pane_text()
+                .ends_with("Hit rate: \u{2068}75\u{2069}%")
+        );
+
+        press_d(&mut app);
+        assert_eq!(app.debug_pane_mode, DebugPaneContent::Messages);
+        assert_eq!(app.debug_pane_text(), "hello");
+    }
+
+    fn press(app: &mut ChafaTui, c: char) {
+        app.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_gg_chord_moves_to_start() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 3, true);
+        app.file_browser.move_to_end();
+
+        press(&mut app, 'g');
+        assert_eq!(app.key_chord_buffer, "g");
+        assert_eq!(app.file_browser.selected_index, 2);
+
+        press(&mut app, 'g');
+        assert!(app.key_chord_buffer.is_empty());
+        assert_eq!(app.file_browser.selected_index, 0);
+    }
+
+    #[test]
+    fn test_incomplete_chord_times_out() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 3, true);
+        // With the help up, d scrolls it instead of sorting
+        app.show_help_on_startup = false;
+
+        press(&mut app, 'd');
+        app.poll_key_chord().unwrap();
+        assert_eq!(app.key_chord_buffer, "d");
+        assert_eq!(app.file_browser.sort_mode, SortMode::Name);
+
+        std::thread::sleep(Duration::from_millis(600));
+        app.poll_key_chord().unwrap();
+        assert!(app.key_chord_buffer.is_empty());
+        // On its own d still sorts by date
+        assert_eq!(app.file_browser.sort_mode, SortMode::DateNewestFirst);
+    }
+
+    #[test]
+    fn test_chord_prefix_followed_by_another_key() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 3, true);
+        // With the help up, d scrolls it instead of sorting
+        app.show_help_on_startup = false;
+
+        press(&mut app, 'd');
+        press(&mut app, 'j');
+        assert!(app.key_chord_buffer.is_empty());
+        assert_eq!(app.file_browser.sort_mode, SortMode::DateNewestFirst);
+
+        press(&mut app, 'd');
+        press(&mut app, 'd');
+        assert!(app.show_delete_confirmation);
+    }
+
+    fn press_key(app: &mut ChafaTui, code: KeyCode, modifiers: KeyModifiers) {
+        app.handle_key_event(KeyEvent::new(code, modifiers))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_dual_pane_focus_and_close() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 3, true);
+        app.show_help_on_startup = false;
+
+        press_key(&mut app, KeyCode::Char('\\'), KeyModifiers::CONTROL);
+        assert!(app.secondary_browser.as_ref().is_some_and(|s| !s.active));
+
+        // Tab moves focus, and the preview follows the focused pane
+        press_key(&mut app, KeyCode::Tab, KeyModifiers::NONE);
+        assert!(app.secondary_browser.as_ref().unwrap().active);
+        press_key(&mut app, KeyCode::End, KeyModifiers::NONE);
+        assert_eq!(app.file_browser.selected_index, 2);
+        assert_eq!(
+            app.secondary_browser
+                .as_ref()
+                .unwrap()
+                .browser
+                .selected_index,
+            0
+        );
+
+        // Closing from the secondary pane hands focus back to the primary one
+        press_key(&mut app, KeyCode::Char('\\'), KeyModifiers::CONTROL);
+        assert!(app.secondary_browser.is_none());
+        assert_eq!(app.file_browser.selected_index, 0);
+    }
+
+    #[rstest::rstest]
+    #[case('c', true)]
+    #[case('m', false)]
+    fn test_dual_pane_copy_and_move(#[case] action: char, #[case] source_remains: bool) {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_directory("album").unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 1, true);
+        app.show_help_on_startup = false;
+
+        // Point the second pane at the album directory
+        press_key(&mut app, KeyCode::Char('\\'), KeyModifiers::CONTROL);
+        press_key(&mut app, KeyCode::Tab, KeyModifiers::NONE);
+        press_key(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+        assert!(app.file_browser.current_dir.ends_with("album"));
+        press_key(&mut app, KeyCode::Tab, KeyModifiers::NONE);
+
+        // Select the image in the primary pane and send it across
+        press_key(&mut app, KeyCode::End, KeyModifiers::NONE);
+        let source = app.file_browser.get_selected_file().unwrap().path.clone();
+        press_key(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+        assert!(app.transfer_dialog.is_some());
+        press_key(&mut app, KeyCode::Char(action), KeyModifiers::NONE);
+
+        assert!(app.transfer_dialog.is_none());
+        let target = temp_fs.get_path().join("album").join("image0.jpg");
+        assert!(target.exists());
+        assert_eq!(std::path::Path::new(&source).exists(), source_remains);
+        let secondary = &app.secondary_browser.as_ref().unwrap().browser;
+        assert!(secondary.files.iter().any(|file| file.name == "image0.jpg"));
+
+        // Never overwrite a file that is already there
+        if source_remains {
+            press_key(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+            press_key(&mut app, KeyCode::Char('c'), KeyModifiers::NONE);
+            assert!(
+                app.preview_manager
+                    .get_debug_info()
+                    .contains("already exists")
+            );
+        }
+    }
+
+    #[rstest::rstest]
+    #[case(false)]
+    #[case(true)]
+    fn test_failed_transfer_leaves_nothing_behind(#[case] move_file: bool) {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_directory("album").unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 1, true);
+
+        // The source has gone by the time the transfer runs
+        let dialog = TransferDialog {
+            file_path: temp_fs
+                .get_path()
+                .join("gone.jpg")
+                .to_string_lossy()
+                .into_owned(),
+            file_name: "gone.jpg".to_string(),
+            target_dir: temp_fs
+                .get_path()
+                .join("album")
+                .to_string_lossy()
+                .into_owned(),
+        };
+        app.transfer_file(&dialog, move_file);
+
+        assert!(!temp_fs.get_path().join("album").join("gone.jpg").exists());
+        assert!(app.preview_manager.get_debug_info().contains("gone.jpg"));
+    }
+
+    #[test]
+    fn test_config_reload_reports_changes() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 1, true);
+
+        let unchanged = app.preview_manager.get_config().clone();
+        app.handle_config_reload(unchanged).unwrap();
+        assert_eq!(
+            app.preview_manager.debug_info,
+            "Config reloaded (no changes)"
+        );
+
+        let mut changed = app.preview_manager.get_config().clone();
+        changed.slideshow_delay_ms = Some(3000);
+        changed.slideshow_loop = Some(false);
+        changed.grid_cell_width = Some(30);
+        changed.use_trash = Some(true);
+        app.handle_config_reload(changed).unwrap();
+        assert_eq!(
+            app.preview_manager.debug_info,
+            "Config reloaded: slideshow_delay_ms: 1000 → 3000 | grid_cell_width: unset → 30 \
+             | slideshow_loop: true → false | 1 more"
+        );
+    }
+
+    #[test]
+    fn test_image_counts_are_worked_out_in_background() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_directory("photos").unwrap();
+        temp_fs.create_test_image("photos/a.jpg").unwrap();
+        let config = PTuiConfig {
+            show_image_counts: Some(true),
+            ..create_test_config()
+        };
+        let file_browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        let localization = Localization::new("en").unwrap();
+        let mut app = ChafaTui::with_parts(config, file_browser, localization);
+        let photos = app
+            .file_browser
+            .files
+            .iter()
+            .find(|f| f.name == "photos")
+            .unwrap()
+            .clone();
+
+        // Nothing is shown until the count arrives
+        app.poll_image_counts();
+        assert!(app.pending_image_counts.is_some());
+        assert_eq!(app.file_browser.get_image_count(&photos), None);
+
+        let started = Instant::now();
+        while app.pending_image_counts.is_some() && started.elapsed() < Duration::from_secs(5) {
+            app.poll_image_counts();
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(app.file_browser.get_image_count(&photos), Some(1));
+    }
+
+    #[test]
+    fn test_checksum_computed_in_background_then_cached() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 1, true);
+        let file = app.file_browser.get_selected_file().unwrap().clone();
+        let expected = file.sha256().unwrap();
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('C'), KeyModifiers::SHIFT))
+            .unwrap();
+        assert_eq!(
+            app.preview_manager.get_debug_info(),
+            app.localization.get("checksum_computing")
+        );
+
+        let started = Instant::now();
+        while app.pending_checksum.is_some() && started.elapsed() < Duration::from_secs(5) {
+            app.poll_checksum();
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert!(app.preview_manager.get_debug_info().contains(&expected));
+
+        // A second press is answered from the cache
+        app.preview_manager.debug_info.clear();
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('C'), KeyModifiers::SHIFT))
+            .unwrap();
+        assert!(app.pending_checksum.is_none());
+        assert!(app.preview_manager.get_debug_info().contains(&expected));
+
+        // A different modification time means the file changed, so it's hashed again
+        app.checksums.get_mut(&file.path).unwrap().0 = UNIX_EPOCH;
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('C'), KeyModifiers::SHIFT))
+            .unwrap();
+        assert!(app.pending_checksum.is_some());
+    }
+
+    #[test]
+    fn test_directory_size_cached_until_refresh() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_directory("album").unwrap();
+        temp_fs.create_file("album/a.txt", "12345").unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 0, true);
+        assert!(app.file_browser.get_selected_file().unwrap().is_directory);
+
+        let ctrl_d = KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL);
+        app.handle_key_event(ctrl_d).unwrap();
+        assert_eq!(
+            app.preview_manager.get_debug_info(),
+            app.localization.get("directory_size_calculating")
+        );
+
+        let started = Instant::now();
+        while app.pending_directory_size.is_some() && started.elapsed() < Duration::from_secs(5) {
+            app.poll_directory_size();
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert!(app.preview_manager.get_debug_info().contains("5 B"));
+
+        // Answered from the cache until the listing is refreshed
+        app.handle_key_event(ctrl_d).unwrap();
+        assert!(app.pending_directory_size.is_none());
+        app.file_browser.refresh_files().unwrap();
+        app.handle_key_event(ctrl_d).unwrap();
+        assert!(app.pending_directory_size.is_some());
+    }
+
+    #[test]
+    fn test_writing_to_watched_file_invalidates_cache() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_test_image("photo.jpg").unwrap();
+        let config = PTuiConfig {
+            watch_current_directory: Some(true),
+            ..create_test_config()
+        };
+        let file_browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        let mut app = ChafaTui::with_parts(config, file_browser, Localization::new("en").unwrap());
+        let file = app.file_browser.get_selected_file().unwrap().clone();
+        let (width, height) = (app.ui_layout.preview_width, app.ui_layout.preview_height);
+        app.preview_manager.insert_into_cache(
+            app.preview_manager.cache_key(&file.path, width, height),
+            PreviewContent::Text(Text::from("old art"), ContentAlignment::Image),
+            file.modified,
+        );
+        // Start watching before the write
+        app.poll_directory_changes();
+
+        std::fs::write(&file.path, b"changed").unwrap();
+        let started = Instant::now();
+        while app
+            .preview_manager
+            .get_raw_preview_string(&file, width, height)
+            .is_some()
+            && started.elapsed() < Duration::from_secs(5)
+        {
+            app.poll_directory_changes();
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        assert!(
+            app.preview_manager
+                .get_raw_preview_string(&file, width, height)
+                .is_none()
+        );
+        assert_eq!(
+            app.file_browser.get_selected_file().unwrap().name,
+            "photo.jpg"
+        );
+    }
+
+    #[test]
+    fn test_new_file_in_watched_directory_is_listed() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_test_image("a.jpg").unwrap();
+        let config = PTuiConfig {
+            watch_current_directory: Some(true),
+            ..create_test_config()
+        };
+        let file_browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        let mut app = ChafaTui::with_parts(config, file_browser, Localization::new("en").unwrap());
+        app.poll_directory_changes();
+
+        temp_fs.create_test_image("b.jpg").unwrap();
+        let started = Instant::now();
+        while app.file_browser.files.len() < 2 && started.elapsed() < Duration::from_secs(5) {
+            app.poll_directory_changes();
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        assert_eq!(app.file_browser.files.len(), 2);
+        assert_eq!(app.file_browser.get_selected_file().unwrap().name, "a.jpg");
+    }
+
+    #[test]
+    fn test_removing_the_selected_file_selects_the_one_before() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        for name in ["a.jpg", "b.jpg", "c.jpg"] {
+            temp_fs.create_test_image(name).unwrap();
+        }
+        let config = PTuiConfig {
+            watch_current_directory: Some(true),
+            ..create_test_config()
+        };
+        let file_browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        let mut app = ChafaTui::with_parts(config, file_browser, Localization::new("en").unwrap());
+        let index = |app: &ChafaTui, name: &str| {
+            app.file_browser
+                .files
+                .iter()
+                .position(|file| file.name == name)
+                .unwrap()
+        };
+        app.file_browser.set_selected_index(index(&app, "b.jpg"));
+        app.poll_directory_changes();
+
+        std::fs::remove_file(temp_fs.get_path().join("b.jpg")).unwrap();
+        let started = Instant::now();
+        while app.file_browser.files.len() == 3 && started.elapsed() < Duration::from_secs(5) {
+            app.poll_directory_changes();
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        assert_eq!(app.file_browser.files.len(), 2);
+        assert_eq!(app.file_browser.get_selected_file().unwrap().name, "a.jpg");
+    }
+
+    #[test]
+    fn test_rapid_navigation_previews_once() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 5, true);
+        app.show_help_on_startup = false;
+        app.preview_manager.update_config(PTuiConfig {
+            preview_debounce_ms: Some(80),
+            ..app.preview_manager.get_config().clone()
+        });
+        let conversions = app.preview_manager.cache_misses;
+
+        for _ in 0..3 {
+            press(&mut app, 'j');
+            app.poll_pending_preview();
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(app.preview_manager.cache_misses, conversions);
+        assert!(app.pending_preview_at.is_some());
+
+        std::thread::sleep(Duration::from_millis(100));
+        app.poll_pending_preview();
+        assert!(app.pending_preview_at.is_none());
+        assert_eq!(app.preview_manager.cache_misses, conversions + 1);
+        let pending = app.pending_conversion.as_ref().unwrap();
+        assert!(pending.path.ends_with("image3.jpg"));
+    }
+
+    #[test]
+    fn test_rapid_resizes_apply_once_then_the_last_size() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 1, true);
+        app.preview_manager.update_config(PTuiConfig {
+            resize_debounce_ms: Some(80),
+            ..app.preview_manager.get_config().clone()
+        });
+
+        app.handle_resize_event(100, 30);
+        app.handle_resize_event(120, 30);
+        app.poll_pending_resize();
+        app.handle_resize_event(140, 40);
+        assert_eq!((app.terminal_width, app.terminal_height), (100, 30));
+        assert_eq!(app.pending_resize, Some((140, 40)));
+
+        std::thread::sleep(Duration::from_millis(100));
+        app.poll_pending_resize();
+        assert_eq!((app.terminal_width, app.terminal_height), (140, 40));
+        assert!(app.pending_resize.is_none());
+    }
+
+    #[test]
+    fn test_poll_interval_follows_config() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 0, true);
+        assert_eq!(app.poll_interval(), Duration::from_millis(50));
+
+        app.handle_config_reload(PTuiConfig {
+            poll_interval_ms: Some(200),
+            ..app.preview_manager.get_config().clone()
+        })
+        .unwrap();
+        assert_eq!(app.poll_interval(), Duration::from_millis(200));
+    }
+
+    #[test]
+    fn test_help_scrolls_with_u_space_and_d() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 2, true);
+        app.show_help_on_startup = true;
+        app.ui_layout.preview_height = 10;
+
+        press(&mut app, ' ');
+        assert_eq!(app.help_scroll_offset, 5);
+        assert!(!app.is_slideshow_mode);
+        press(&mut app, 'd');
+        assert_eq!(app.help_scroll_offset, 10);
+        // Neither dd nor gd reach the chords while the help is up
+        press(&mut app, 'd');
+        assert_eq!(app.help_scroll_offset, 15);
+        assert!(!app.show_delete_confirmation);
+        press(&mut app, 'g');
+        press(&mut app, 'd');
+        assert_eq!(app.help_scroll_offset, 20);
+        assert!(app.key_chord_buffer.is_empty());
+        press(&mut app, 'u');
+        press(&mut app, 'u');
+        press(&mut app, 'u');
+        assert_eq!(app.help_scroll_offset, 5);
+        assert!(app.show_help_on_startup);
+
+        for _ in 0..50 {
+            press(&mut app, ' ');
+        }
+        assert_eq!(app.help_scroll_offset, app.help_line_count() - 1);
+
+        // Dismissing and reopening the help starts it from the top
+        press(&mut app, 'j');
+        assert!(!app.show_help_on_startup);
+        press(&mut app, '?');
+        assert!(app.show_help_toggle);
+        assert_eq!(app.help_scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_cached_preview_skips_debounce() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 2, true);
+        app.show_help_on_startup = false;
+        app.preview_manager.update_config(PTuiConfig {
+            preview_debounce_ms: Some(80),
+            ..app.preview_manager.get_config().clone()
+        });
+        let next = app.file_browser.files[1].clone();
+        app.preview_manager.insert_into_cache(
+            app.preview_manager.cache_key(
+                &next.path,
+                app.ui_layout.preview_width,
+                app.ui_layout.preview_height,
+            ),
+            PreviewContent::Text(Text::from("cached art"), ContentAlignment::Image),
+            next.modified,
+        );
+
+        press(&mut app, 'j');
+        assert!(app.pending_preview_at.is_none());
+        assert!(matches!(
+            app.preview_content,
+            Some(PreviewContent::Text(ref text, _)) if text.to_string() == "cached art"
+        ));
+    }
+
+    #[test]
+    fn test_text_preview_arrives_from_its_thread() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_file("notes.txt", "hello").unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 0, true);
+        app.show_help_on_startup = false;
+        app.ui_layout.preview_height = 10;
+
+        app.update_preview();
+        assert!(app.pending_preview_handle.is_some());
+
+        let started = Instant::now();
+        while app.pending_preview_handle.is_some() && started.elapsed() < Duration::from_secs(5) {
+            app.poll_preview_handle();
+        }
+        assert!(matches!(
+            &app.preview_content,
+            Some(PreviewContent::Text(text, ContentAlignment::Text)) if text.to_string() == "hello"
+        ));
+        assert_eq!(app.text_total_lines, Some(1));
+
+        // Scrolling keeps the count, so the scrollbar stays put
+        app.update_preview();
+        assert_eq!(app.text_total_lines, Some(1));
+        // The pane keeps the text until the new read comes back
+        assert!(matches!(
+            &app.preview_content,
+            Some(PreviewContent::Text(text, ContentAlignment::Text)) if text.to_string() == "hello"
+        ));
+
+        // A newer modification time means the file changed and is counted again
+        let selected = app.file_browser.selected_index;
+        app.file_browser.files[selected].modified += Duration::from_secs(1);
+        app.update_preview();
+        assert_eq!(app.text_total_lines, None);
+    }
+
+    #[rstest::rstest]
+    #[case(None, 10)]
+    #[case(Some(5), 5)]
+    fn test_text_scroll_amount(#[case] text_scroll_lines: Option<usize>, #[case] step: usize) {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_file("notes.txt", "hello").unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 0, true);
+        app.show_help_on_startup = false;
+        app.ui_layout.preview_height = 20;
+        app.preview_manager.update_config(PTuiConfig {
+            text_scroll_lines,
+            ..app.preview_manager.get_config().clone()
+        });
+
+        press(&mut app, ' ');
+        assert_eq!(app.text_scroll_offset, step);
+
+        // J and K always move a single line
+        press(&mut app, 'J');
+        assert_eq!(app.text_scroll_offset, step + 1);
+        press(&mut app, 'K');
+        press(&mut app, 'K');
+        assert_eq!(app.text_scroll_offset, step - 1);
+
+        press(&mut app, 'u');
+        assert_eq!(app.text_scroll_offset, 0);
+    }
+
+    /// An app listing c.jpg (oldest), a.jpg and b.jpg (newest) newest first, with a
+    /// text file selected so the slideshow starts from its order's first image
+    fn create_ordered_slideshow_app(temp_fs: &TestFileSystem, order: &str) -> ChafaTui {
+        let base = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        for (age, name) in ["c.jpg", "a.jpg", "b.jpg", "notes.txt"].iter().enumerate() {
+            let path = if name.ends_with(".jpg") {
+                temp_fs.create_test_image(name).unwrap()
+            } else {
+                temp_fs.create_file(name, "hello").unwrap()
+            };
+            std::fs::File::options()
+                .write(true)
+                .open(&path)
+                .unwrap()
+                .set_modified(base + Duration::from_secs(age as u64 * 60))
+                .unwrap();
+        }
+        let config = PTuiConfig {
+            slideshow_order: Some(order.to_string()),
+            ..create_test_config()
+        };
+        let mut file_browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        file_browser.set_sort_mode(SortMode::DateNewestFirst);
+        file_browser.set_selected_index(0);
+        let localization = Localization::new("en").unwrap();
+        ChafaTui::with_parts(config, file_browser, localization)
+    }
+
+    #[rstest::rstest]
+    #[case("current_sort", ["b.jpg", "a.jpg", "c.jpg"])]
+    #[case("name", ["a.jpg", "b.jpg", "c.jpg"])]
+    #[case("date", ["c.jpg", "a.jpg", "b.jpg"])]
+    fn test_slideshow_order(#[case] order: &str, #[case] expected: [&str; 3]) {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_ordered_slideshow_app(&temp_fs, order);
+        assert_eq!(app.file_browser.files[0].name, "notes.txt");
+
+        app.enter_slideshow_mode();
+        assert_eq!(
+            app.slideshow_file().map(|f| f.name.as_str()),
+            Some(expected[0])
+        );
+        let names: Vec<&str> = app
+            .slideshow_image_files
+            .iter()
+            .map(|&i| app.file_browser.files[i].name.as_str())
+            .collect();
+        assert_eq!(names, expected);
+    }
+
+    #[test]
+    fn test_random_slideshow_order_starts_from_its_first_image() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_ordered_slideshow_app(&temp_fs, "random");
+        let sorted = vec![1, 2, 3];
+        app.slideshow_image_files = sorted.clone();
+        app.order_slideshow_images(SlideshowOrder::Random, 42);
+        let expected: Vec<usize> = shuffled_order(3, 42)
+            .into_iter()
+            .map(|position| sorted[position])
+            .collect();
+        assert_eq!(app.slideshow_image_files, expected);
+
+        app.enter_slideshow_mode();
+        assert_eq!(app.slideshow_current_index, 0);
+        let mut images = app.slideshow_image_files.clone();
+        images.sort_unstable();
+        assert_eq!(images, sorted);
+        assert_eq!(
+            app.slideshow_file().map(|f| f.path.as_str()),
+            Some(
+                app.file_browser.files[app.slideshow_image_files[0]]
+                    .path
+                    .as_str()
+            )
+        );
+    }
+
+    #[test]
+    fn test_command_prompt_runs_commands() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 3, true);
+
+        press(&mut app, ':');
+        for c in "e config".chars() {
+            press(&mut app, c);
+        }
+        assert_eq!(app.command_prompt.as_deref(), Some("e config"));
+        // Keys go to the prompt rather than moving the selection
+        assert_eq!(app.file_browser.selected_index, 0);
+        press_key(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+        assert!(app.command_prompt.is_none());
+        assert!(app.take_config_edit_request());
+        assert!(!app.take_config_edit_request());
+
+        press(&mut app, ':');
+        press(&mut app, 'w');
+        press_key(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+        assert!(
+            app.preview_manager
+                .get_debug_info()
+                .starts_with("Unknown command:")
+        );
+
+        press(&mut app, ':');
+        press(&mut app, 'q');
+        assert!(
+            app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_command_prompt_closes_without_running() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 3, true);
+
+        press(&mut app, ':');
+        press(&mut app, 'q');
+        press_key(&mut app, KeyCode::Esc, KeyModifiers::NONE);
+        assert!(app.command_prompt.is_none());
+
+        // Backspacing past the colon closes the prompt too
+        press(&mut app, ':');
+        press(&mut app, 'q');
+        press_key(&mut app, KeyCode::Backspace, KeyModifiers::NONE);
+        assert_eq!(app.command_prompt.as_deref(), Some(""));
+        press_key(&mut app, KeyCode::Backspace, KeyModifiers::NONE);
+        assert!(app.command_prompt.is_none());
+    }
+
+    #[test]
+    fn test_config_editor_failure_is_reported() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 1, true);
+
+        app.run_config_editor_with("false");
+        assert!(
+            app.preview_manager
+                .get_debug_info()
+                .starts_with("Couldn't edit the config")
+        );
+    }
+
+    #[test]
+    fn test_batch_export_dialog_counts_images() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_file("notes.txt", "hello").unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 3, true);
+
+        press(&mut app, 'B');
+        assert_eq!(app.batch_export_dialog.as_ref().map(Vec::len), Some(3));
+        press_key(&mut app, KeyCode::Esc, KeyModifiers::NONE);
+        assert!(app.batch_export_dialog.is_none());
+        assert!(app.pending_batch_export.is_none());
+    }
+
+    #[test]
+    fn test_batch_export_without_images() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_file("notes.txt", "hello").unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 0, true);
+
+        press(&mut app, 'B');
+        assert!(app.batch_export_dialog.is_none());
+        assert_eq!(
+            app.preview_manager.get_debug_info(),
+            app.localization.get("batch_export_no_images")
+        );
+    }
+
+    #[test]
+    fn test_ctrl_f_runs_fuzzy_search_when_enabled() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 3, true);
+
+        // Without fzf Ctrl+F stays page down
+        press_key(&mut app, KeyCode::Char('f'), KeyModifiers::CONTROL);
+        assert!(!app.take_fuzzy_search_request());
+
+        app.fzf_integration = true;
+        press_key(&mut app, KeyCode::Char('f'), KeyModifiers::CONTROL);
+        assert!(app.take_fuzzy_search_request());
+        assert!(!app.take_fuzzy_search_request());
+
+        // A stand-in for fzf that picks image2.jpg out of the list
+        app.run_fuzzy_search_with(&["grep", "image2"]);
+        assert_eq!(
+            app.file_browser
+                .get_selected_file()
+                .map(|f| f.name.as_str()),
+            Some("image2.jpg")
+        );
+
+        // Cancelling leaves the selection alone
+        app.run_fuzzy_search_with(&["false"]);
+        assert_eq!(app.file_browser.selected_index, 2);
+
+        app.run_fuzzy_search_with(&["ptui-no-such-picker"]);
+        assert!(
+            app.preview_manager
+                .get_debug_info()
+                .starts_with("Fuzzy search failed")
+        );
+    }
+
+    #[test]
+    fn test_angle_brackets_cycle_ico_sizes() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        image::RgbaImage::new(16, 16)
+            .save_with_format(
+                temp_fs.get_path().join("favicon.ico"),
+                image::ImageFormat::Ico,
+            )
+            .unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 0, true);
+        app.show_help_on_startup = false;
+        app.update_preview();
+
+        // As identify would list them for a two-size icon
+        app.ico_sizes = vec![
+            IcoSize {
+                index: 1,
+                width: 32,
+                height: 32,
+            },
+            IcoSize {
+                index: 0,
+                width: 16,
+                height: 16,
+            },
+        ];
+        press(&mut app, '>');
+        assert_eq!(app.ico_page, 1);
+        use fluent::fluent_args;
+        let args = fluent_args!["sizes" => "32×32, 16×16", "index" => 1];
+        assert!(
+            app.preview_manager
+                .get_debug_info()
+                .ends_with(&app.localization.get_with_args("ico_sizes", Some(&args)))
+        );
+        press(&mut app, '>');
+        assert_eq!(app.ico_page, 1);
+        press(&mut app, '<');
+        assert_eq!(app.ico_page, 0);
+
+        // Without ImageMagick there's only the image crate's pick
+        app.ico_sizes.clear();
+        press(&mut app, '>');
+        assert_eq!(
+            app.preview_manager.get_debug_info(),
+            app.localization.get("ico_sizes_unavailable")
+        );
+    }
+
+    #[test]
+    fn test_recent_files_dialog_jumps_to_file() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let album = temp_fs.create_directory("album").unwrap();
+        temp_fs.create_test_image("album/a.jpg").unwrap();
+        let b_path = temp_fs.create_test_image("album/b.jpg").unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 0, true);
+        app.show_help_on_startup = false;
+
+        // Preview b.jpg, then wander off to the top directory
+        app.file_browser
+            .open_file_in_directory(&album, "b.jpg")
+            .unwrap();
+        app.update_preview();
+        app.file_browser.go_to_parent().unwrap();
+        assert_eq!(app.recent_files.get(0).unwrap().0, b_path);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::ALT))
+            .unwrap();
+        assert_eq!(app.recent_files_dialog, Some(0));
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+            .unwrap();
+        assert!(app.recent_files_dialog.is_none());
+        assert_eq!(app.file_browser.current_dir, album);
+        assert_eq!(app.file_browser.get_selected_file().unwrap().name, "b.jpg");
+    }
+
+    #[test]
+    fn test_recent_files_dialog_missing_file_and_clear() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 1, true);
+        let dir = app.file_browser.current_dir.clone();
+        app.recent_files.push(&format!("{}/gone.jpg", dir), &dir);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::ALT))
+            .unwrap();
+        app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+            .unwrap();
+        assert!(app.preview_manager.get_debug_info().contains("gone.jpg"));
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::ALT))
+            .unwrap();
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE))
+            .unwrap();
+        assert!(app.recent_files.is_empty());
+        app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE))
+            .unwrap();
+        assert!(app.recent_files_dialog.is_none());
+    }
+
+    #[test]
+    fn test_copy_preview_without_cached_preview() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 1, true);
+        app.preview_manager.clear_cache();
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::CONTROL))
+            .unwrap();
+        assert_eq!(
+            app.preview_manager.get_debug_info(),
+            app.localization.get("clipboard_no_preview")
+        );
+    }
+
+    #[test]
+    fn test_delete_current_file() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 2, true);
+        let file = app.file_browser.get_selected_file().unwrap().clone();
+
+        app.delete_current_file(file.name.clone()).unwrap();
+        assert!(
+            app.preview_manager
+                .get_debug_info()
+                .contains(&format!("Deleted: {}", file.name))
+        );
+        assert!(!std::path::Path::new(&file.path).exists());
+        assert_eq!(app.file_browser.files.len(), 1);
+    }
+
+    #[cfg(feature = "trash-support")]
+    #[test]
+    fn test_delete_current_file_to_trash() {
+        fn fake_trash(path: &str) -> Result<(), Box<dyn Error>> {
+            std::fs::rename(path, format!("{}.trashed", path))?;
+            Ok(())
+        }
+
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 2, true);
+        app.preview_manager.update_config(PTuiConfig {
+            use_trash: Some(true),
+            ..app.preview_manager.get_config().clone()
+        });
+        app.move_to_trash = fake_trash;
+        let file = app.file_browser.get_selected_file().unwrap().clone();
+
+        app.delete_current_file(file.name.clone()).unwrap();
+        assert!(
+            app.preview_manager
+                .get_debug_info()
+                .contains(&format!("Moved to Trash: {}", file.name))
+        );
+        assert!(std::path::Path::new(&format!("{}.trashed", file.path)).exists());
+    }
+
+    #[test]
+    fn test_poll_conversion_gives_up_after_timeout() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 1, true);
+        app.preview_manager.update_config(PTuiConfig {
+            preview_loading_timeout_ms: Some(0),
+            ..app.preview_manager.get_config().clone()
+        });
+
+        // A conversion that never answers
+        let (_tx, receiver) = std::sync::mpsc::channel();
+        app.pending_conversion = Some(PendingConversion {
+            path: "slow.jpg".to_string(),
+            modified: std::time::UNIX_EPOCH,
+            width: 80,
+            height: 24,
+            receiver,
+        });
+        app.conversion_state = ConversionState::Loading {
+            file_name: "slow.jpg".to_string(),
+            started_at: Instant::now(),
+        };
+
+        app.poll_conversion();
+        assert!(app.pending_conversion.is_none());
+        assert_eq!(app.conversion_state, ConversionState::Idle);
+        assert!(app.preview_manager.get_debug_info().contains("timed out"));
+    }
+
+    #[test]
+    fn test_enter_slideshow_without_images_stays_in_browser() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_file("notes.txt", "not an image").unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 0, true);
+
+        app.enter_slideshow_mode();
+        assert!(!app.is_slideshow_mode());
+        assert_eq!(
+            app.preview_manager.get_debug_info(),
+            app.localization.get("slideshow_no_images")
+        );
     }
 
-    fn update_slideshow_preview(&mut self) {
-        if !self.is_slideshow_mode || self.slideshow_image_files.is_empty() {
-            return;
+    #[test]
+    fn test_slideshow_without_loop_exits_after_last_image() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 3, false);
+
+        app.enter_slideshow_mode();
+        assert!(app.is_slideshow_mode);
+        for _ in 0..=app.slideshow_image_files.len() {
+            app.advance_slideshow();
         }
+        assert!(!app.is_slideshow_mode);
+        // The browser is left on the last image
+        assert_eq!(
+            app.file_browser.selected_index,
+            app.slideshow_image_files[2]
+        );
+    }
 
-        let file_index = self.slideshow_image_files[self.slideshow_current_index];
-        if let Some(file) = self.file_browser.files.get(file_index) {
-            // Slideshow uses full screen minus status bar (3 rows)
-            self.preview_content = Some(self.preview_manager.generate_preview(
-                file,
-                self.terminal_width,
-                self.terminal_height.saturating_sub(3),
-                0, // No text scrolling in slideshow mode
-                &self.localization,
-            ));
-            self.is_preview_image = true;
+    #[cfg(unix)]
+    #[test]
+    fn test_permission_dialog_applies_chmod_644() {
+        use std::os::unix::fs::PermissionsExt;
+        let temp_fs = TestFileSystem::new().unwrap();
+        let notes = temp_fs.create_file("notes.txt", "content").unwrap();
+        std::fs::set_permissions(&notes, std::fs::Permissions::from_mode(0o600)).unwrap();
+        let file_browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        let mut app = ChafaTui::with_parts(
+            create_test_config(),
+            file_browser,
+            Localization::new("en").unwrap(),
+        );
+
+        press(&mut app, 'P');
+        assert_eq!(app.permission_dialog.as_ref().unwrap().mode, 0o600);
+        // Digits outside 0-7 and a fourth digit are ignored
+        for c in "6894".chars() {
+            press(&mut app, c);
         }
+        press_key(&mut app, KeyCode::Backspace, KeyModifiers::NONE);
+        press(&mut app, '4');
+        press(&mut app, '4');
+        assert_eq!(app.permission_dialog.as_ref().unwrap().input, "644");
+        press_key(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+
+        assert!(app.permission_dialog.is_none());
+        let mode = std::fs::metadata(&notes).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o644);
+        assert_eq!(app.file_browser.files[0].permissions, 0o644);
+        let args = fluent::fluent_args!["file" => "notes.txt", "mode" => "644"];
+        assert_eq!(
+            app.preview_manager.get_debug_info(),
+            app.localization
+                .get_with_args("permissions_changed", Some(&args))
+        );
+
+        // Esc leaves the mode alone
+        press(&mut app, 'P');
+        for c in "700".chars() {
+            press(&mut app, c);
+        }
+        press_key(&mut app, KeyCode::Esc, KeyModifiers::NONE);
+        let mode = std::fs::metadata(&notes).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o644);
     }
 
-    pub fn update_slideshow(&mut self) {
-        if self.is_slideshow_mode && self.slideshow_last_change.elapsed() >= self.slideshow_delay {
-            // Only advance slideshow if no transition is in progress
-            if !self.transition_manager.is_in_transition() {
-                self.advance_slideshow();
-                self.needs_redraw = true;
-            }
+    #[test]
+    fn test_a_toggles_the_average_color() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        image::RgbImage::from_pixel(4, 4, image::Rgb([0, 0, 255]))
+            .save(temp_fs.get_path().join("blue.png"))
+            .unwrap();
+        let file_browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        let mut app = ChafaTui::with_parts(
+            create_test_config(),
+            file_browser,
+            Localization::new("en").unwrap(),
+        );
+        assert_eq!(app.selected_average_color(), None);
+
+        press(&mut app, 'A');
+        assert_eq!(app.selected_average_color(), None);
+        let started = Instant::now();
+        while app.selected_average_color().is_none() && started.elapsed() < Duration::from_secs(5) {
+            app.poll_average_color();
         }
+        assert_eq!(app.selected_average_color(), Some((0, 0, 255)));
+
+        press(&mut app, 'A');
+        assert_eq!(app.selected_average_color(), None);
     }
 
-    /// Update transitions and return true if a redraw is needed
-    pub fn update_transitions(&mut self) -> bool {
-        if self.transition_manager.is_in_transition() {
-            // Check if transition frame has changed
-            let _current_frame = self.transition_manager.get_current_transition_frame();
-            // A frame change or completion indicates we need to redraw
-            self.needs_redraw = true;
-            true
-        } else {
-            false
+    #[test]
+    fn test_i_toggles_the_metadata_panel() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 2, false);
+        let metadata_title = app.localization.get("metadata_title");
+        let showing_metadata = |app: &ChafaTui| {
+            matches!(&app.preview_content,
+                Some(PreviewContent::Text(text, _)) if text.to_string().contains(&metadata_title))
+        };
+        assert!(!showing_metadata(&app));
+
+        press(&mut app, 'i');
+        assert!(showing_metadata(&app));
+
+        // The panel follows the selection
+        press_key(&mut app, KeyCode::Down, KeyModifiers::NONE);
+        assert!(showing_metadata(&app));
+
+        press(&mut app, 'i');
+        assert!(!showing_metadata(&app));
+    }
+
+    #[test]
+    fn test_charset_picker_applies_presets_and_custom_charsets() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 1, false);
+        let jp2a_chars = |app: &ChafaTui| {
+            app.preview_manager
+                .get_config()
+                .converter
+                .jp2a
+                .chars
+                .clone()
+        };
+
+        press_key(&mut app, KeyCode::Char('c'), KeyModifiers::ALT);
+        assert!(app.charset_picker.is_some());
+        press(&mut app, 'j');
+        press(&mut app, 'j');
+        press_key(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+        assert!(app.charset_picker.is_none());
+        assert_eq!(jp2a_chars(&app).as_deref(), Some("░▒▓█"));
+        let args = fluent::fluent_args!["charset" => "blocks"];
+        assert_eq!(
+            app.preview_manager.get_debug_info(),
+            app.localization
+                .get_with_args("jp2a_charset_changed", Some(&args))
+        );
+
+        // The custom entry comes after the presets and takes typed characters
+        press_key(&mut app, KeyCode::Char('c'), KeyModifiers::ALT);
+        for _ in 0..converter::JP2A_CHARSETS.len() {
+            press(&mut app, 'j');
         }
+        press_key(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+        for c in ".:x#".chars() {
+            press(&mut app, c);
+        }
+        press_key(&mut app, KeyCode::Backspace, KeyModifiers::NONE);
+        press(&mut app, '@');
+        press_key(&mut app, KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(jp2a_chars(&app).as_deref(), Some(".:x@"));
+
+        // Esc closes without changing anything
+        press_key(&mut app, KeyCode::Char('c'), KeyModifiers::ALT);
+        press(&mut app, 'k');
+        press_key(&mut app, KeyCode::Esc, KeyModifiers::NONE);
+        assert!(app.charset_picker.is_none());
+        assert_eq!(jp2a_chars(&app).as_deref(), Some(".:x@"));
     }
 
-    pub fn draw(&mut self, f: &mut ratatui::Frame) {
-        let size = f.area();
+    #[test]
+    fn test_load_ascii_logo_from_file() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let logo_path = temp_fs
+            .create_file("logo.ans", "\x1b[31mMY LOGO\x1b[0m\n{app_subtitle}")
+            .unwrap();
+        let config = PTuiConfig {
+            logo_file: Some(logo_path),
+            ..create_test_config()
+        };
 
-        // Update terminal dimensions
-        self.terminal_width = size.width;
-        self.terminal_height = size.height;
+        let (logo, warning) = ChafaTui::load_ascii_logo(&config);
+        assert_eq!(warning, None);
+        assert_eq!(logo.lines.len(), 2);
+        assert_eq!(logo.lines[0].spans[0].content, "MY LOGO");
+        assert_eq!(
+            logo.lines[0].spans[0].style.fg,
+            Some(ratatui::style::Color::Red)
+        );
 
-        if self.is_slideshow_mode {
-            // Check if we have a transition in progress
-            let transition_content: Option<PreviewContent>;
-            let display_content = if let Some(transition_frame) =
-                self.transition_manager.get_current_transition_frame()
-            {
-                transition_content = Some(PreviewContent::Text(transition_frame.clone()));
-                transition_content.as_ref()
-            } else {
-                self.preview_content.as_ref()
-            };
+        // A logo that can't be read falls back to the embedded one, saying why
+        let missing = PTuiConfig {
+            logo_file: Some(temp_fs.get_path().join("missing.ans").display().to_string()),
+            ..create_test_config()
+        };
+        let (logo, warning) = ChafaTui::load_ascii_logo(&missing);
+        assert_eq!(logo, EMBEDDED_LOGO.into_text().unwrap());
+        assert!(warning.unwrap().starts_with("Failed to read logo"));
+    }
 
-            // Render full-screen slideshow
-            UIRenderer::render_slideshow(
-                f,
-                size,
-                display_content,
-                &self.localization,
-                self.slideshow_current_index + 1,
-                self.slideshow_image_files.len(),
-            );
-        } else {
-            // Regular UI layout
-            // Calculate layout
-            let (file_area, preview_area, debug_area) = self.ui_layout.calculate_layout(size);
+    #[test]
+    fn test_config_reload_reports_an_unreadable_logo() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 1, true);
+        app.handle_config_reload(PTuiConfig {
+            logo_file: Some(temp_fs.get_path().join("missing.ans").display().to_string()),
+            ..app.preview_manager.get_config().clone()
+        })
+        .unwrap();
+        assert!(
+            app.preview_manager
+                .get_debug_info()
+                .contains("Warning: Failed to read logo")
+        );
+    }
+
+    #[test]
+    fn test_hidden_logo_is_empty() {
+        let config = PTuiConfig {
+            logo_hidden: Some(true),
+            ..create_test_config()
+        };
+        assert_eq!(ChafaTui::load_ascii_logo(&config), (Text::default(), None));
+        assert_eq!(ChafaTui::help_logo(&config), (None, None));
 
-            // Render components
-            UIRenderer::render_file_browser(f, file_area, &mut self.file_browser, true);
+        let app = ChafaTui::with_parts(
+            config,
+            FileBrowser::new_with_dir(".").unwrap(),
+            Localization::new("en").unwrap(),
+        );
+        assert_eq!(
+            app.help_line_count(),
+            app.localization.get_help_text().lines().count()
+        );
+    }
 
-            // Don't render graphical preview when dialog is showing (graphics layer sits above text)
-            let preview_to_render = if self.show_delete_confirmation {
-                None
-            } else {
-                self.preview_content.as_ref()
-            };
+    #[test]
+    fn test_ctrl_t_cycles_the_content_filter() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_file("notes.txt", "content").unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 2, false);
+        assert_eq!(app.file_browser.files.len(), 3);
 
-            UIRenderer::render_preview(
-                f,
-                preview_area,
-                preview_to_render,
-                &self.localization,
-                self.ascii_logo.as_ref(),
-                self.is_text_file
-            );
+        press_key(&mut app, KeyCode::Char('t'), KeyModifiers::CONTROL);
+        assert_eq!(app.file_browser.content_filter, ContentFilter::ImagesOnly);
+        assert!(
+            app.file_browser
+                .get_display_files()
+                .all(|(_, file)| file.name != "notes.txt")
+        );
+        assert_eq!(app.session_state().filter_mode, ContentFilter::ImagesOnly);
 
-            UIRenderer::render_debug_pane(
-                f,
-                debug_area,
-                self.preview_manager.get_debug_info(),
-                &self.localization,
-            );
+        press_key(&mut app, KeyCode::Char('t'), KeyModifiers::CONTROL);
+        assert_eq!(app.file_browser.content_filter, ContentFilter::TextOnly);
+        assert_eq!(
+            app.file_browser.get_selected_file().unwrap().name,
+            "notes.txt"
+        );
+    }
+
+    #[test]
+    fn test_session_in_a_deleted_directory_falls_back_to_the_current_one() {
+        let session = SessionState {
+            current_dir: "/nonexistent/ptui/session/dir".to_string(),
+            selected_index: 3,
+            sort_mode: SortMode::Name,
+            converter: "chafa".to_string(),
+            text_scroll_offset: 0,
+            filter_mode: ContentFilter::All,
+        };
+        let app = ChafaTui::start(
+            create_test_config(),
+            Localization::new("en").unwrap(),
+            &Args::default(),
+            Some(session),
+            RecentFiles::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            app.file_browser.current_dir,
+            std::env::current_dir().unwrap().to_string_lossy()
+        );
+    }
+
+    #[test]
+    fn test_inline_images_are_centred_and_cleared_once_replaced() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 1, true);
+        app.enter_slideshow_mode();
+        app.terminal_width = 100;
+        app.terminal_height = 40;
+        let inline = Rc::new(InlinePreview {
+            escape_sequence: String::new(),
+            width: 40,
+            height: 10,
+        });
+        let image_rows = 40 - app.slideshow_reserved_rows();
+        assert_eq!(
+            app.inline_image_position(&inline),
+            (30, (image_rows - 10) / 2)
+        );
+
+        // Nothing to clear until an image has been written
+        app.preview_content = Some(PreviewContent::Inline(Rc::clone(&inline)));
+        assert!(!app.take_terminal_clear_request());
+        app.inline_image_shown = Some(Rc::clone(&inline));
+        assert!(!app.take_terminal_clear_request());
+
+        app.preview_content = Some(PreviewContent::Text(
+            Text::from("art"),
+            ContentAlignment::Image,
+        ));
+        assert!(app.take_terminal_clear_request());
+        assert!(!app.take_terminal_clear_request());
+    }
+
+    /// Converts an image to its file name, except for image1.jpg
+    struct StubConverter;
+
+    impl converter::AsciiConverter for StubConverter {
+        fn convert_image(&self, path: &str, _width: u16, _height: u16) -> Result<String, String> {
+            let name = std::path::Path::new(path)
+                .file_name()
+                .unwrap()
+                .to_string_lossy();
+            if name == "image1.jpg" {
+                Err("no art".to_string())
+            } else {
+                Ok(format!("{} art", name))
+            }
         }
 
-        // Render delete confirmation dialog overlay if needed
-        if self.show_delete_confirmation
-            && let Some(ref file_name) = self.delete_target_file
-        {
-            UIRenderer::render_delete_confirmation_dialog(f, size, file_name, &self.localization);
+        fn get_name(&self) -> &'static str {
+            "stub"
         }
-    }
 
-    fn is_text_file_selected(&self) -> bool {
-        if let Some(file) = self.file_browser.get_selected_file() {
-            file.is_text_file() && !file.is_directory
-        } else {
+        fn supports_transitions(&self) -> bool {
             false
         }
     }
 
-    fn scroll_text_up(&mut self) {
-        let scroll_amount = (self.ui_layout.preview_height as usize / 2).max(1);
-        self.text_scroll_offset = self.text_scroll_offset.saturating_sub(scroll_amount);
-        self.update_preview();
+    fn stub_converter(_config: &PTuiConfig) -> Box<dyn converter::AsciiConverter> {
+        Box::new(StubConverter)
     }
 
-    fn scroll_text_down(&mut self) {
-        let scroll_amount = (self.ui_layout.preview_height as usize / 2).max(1);
-        self.text_scroll_offset += scroll_amount;
-        self.update_preview();
+    fn finish_batch_export(app: &mut ChafaTui) {
+        let started = Instant::now();
+        while app.pending_batch_export.is_some() && started.elapsed() < Duration::from_secs(5) {
+            app.poll_batch_export();
+        }
+        assert!(app.pending_batch_export.is_none());
     }
 
-    fn reset_text_scroll(&mut self) {
-        self.text_scroll_offset = 0;
-    }
+    #[test]
+    fn test_ctrl_e_exports_the_slideshow_as_html() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 3, true);
+        app.preview_manager.set_export_converter(stub_converter);
+        app.enter_slideshow_mode();
 
-    /// Cycle through available converters in order: chafa -> jp2a -> graphical -> chafa
-    fn cycle_converter(&mut self) {
-        let current_converter = &self.preview_manager.converter.get_name();
-        let new_converter = match *current_converter {
-            "chafa" => "jp2a",
-            "jp2a" => "graphical",
-            _ => "chafa", // Default to chafa for graphical or unknown
-        };
+        press_key(&mut app, KeyCode::Char('e'), KeyModifiers::CONTROL);
+        assert!(app.pending_batch_export.is_some());
+        finish_batch_export(&mut app);
+        assert!(app.is_slideshow_mode);
 
-        // Create a new config with the updated converter selection
-        let mut new_config = self.preview_manager.config.clone();
-        new_config.converter.selected = new_converter.to_string();
+        // The image that failed is left out and logged, the rest keep their order
+        let html = std::fs::read_to_string(temp_fs.get_path().join("slideshow.html")).unwrap();
+        let first = html.find("image0.jpg art").unwrap();
+        let last = html.find("image2.jpg art").unwrap();
+        assert!(first < last);
+        assert!(!html.contains("image1.jpg"));
+        let log = std::fs::read_to_string(temp_fs.get_path().join(EXPORT_ERRORS_FILE)).unwrap();
+        assert_eq!(log, "image1.jpg: no art\n");
 
-        // Update preview manager with new converter
-        self.preview_manager.update_config(new_config);
+        let (notice, _) = app.slideshow_notice.clone().unwrap();
+        let notice = notice.replace(['\u{2068}', '\u{2069}'], "");
+        assert!(notice.contains("slideshow.html (1 failed"));
+    }
 
-        // Clear cache and refresh preview
-        self.preview_manager.clear_cache();
-        self.update_preview();
+    #[test]
+    fn test_slideshow_html_export_without_any_art() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_test_image("image1.jpg").unwrap();
+        let file_browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        let mut app = ChafaTui::with_parts(
+            create_test_config(),
+            file_browser,
+            Localization::new("en").unwrap(),
+        );
+        app.preview_manager.set_export_converter(stub_converter);
+        app.enter_slideshow_mode();
 
-        // Show feedback in debug info
-        let message = format!("Converter switched to: {}", new_converter);
-        self.preview_manager.debug_info = message;
+        press_key(&mut app, KeyCode::Char('e'), KeyModifiers::CONTROL);
+        finish_batch_export(&mut app);
+        assert!(!temp_fs.get_path().join("slideshow.html").exists());
+        let (notice, _) = app.slideshow_notice.clone().unwrap();
+        assert!(notice.starts_with("Couldn't export slideshow"));
     }
 
-    /// Clear Kitty graphics protocol images from the terminal
-    /// This should be called when switching from graphical to text mode
-    pub fn clear_graphics_if_needed(&self) {
-        // If we're not showing graphical content, clear any lingering images
-        // Only do this in non-test environments to avoid interfering with test output
-        #[cfg(not(test))]
-        {
-            // Check if current preview is graphical (either Graphical or Kitty)
-            let is_current_graphical = matches!(
-                &self.preview_content,
-                Some(PreviewContent::Graphical(_)) | Some(PreviewContent::Kitty(_))
-            );
+    #[test]
+    fn test_slideshow_with_loop_wraps_around() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 3, true);
 
-            // Clear graphics if not graphical content, or if delete dialog is showing
-            // (dialog needs to appear above the graphics layer)
-            if !is_current_graphical || self.show_delete_confirmation {
-                use std::io::Write;
-                // Send Kitty protocol command to delete all images
-                let delete_all_cmd = "\x1b_Ga=d,d=a\x1b\\";
-                let _ = std::io::stdout().write_all(delete_all_cmd.as_bytes());
-                let _ = std::io::stdout().flush();
-            }
+        app.enter_slideshow_mode();
+        for _ in 0..=app.slideshow_image_files.len() {
+            app.advance_slideshow();
         }
+        assert!(app.is_slideshow_mode);
+        assert_eq!(app.slideshow_current_index, 1);
     }
 
-    /// Render Kitty graphics after ratatui's frame is drawn
-    /// This must be called AFTER terminal.draw() to avoid being overwritten
-    pub fn render_kitty_post_draw(&mut self) {
-        #[cfg(not(test))]
-        {
-            // Don't render graphics when delete confirmation dialog is showing
-            if self.show_delete_confirmation {
-                return;
-            }
-
-            // Check if we have a Kitty preview to render
-            if let Some(PreviewContent::Kitty(ref kitty_rc)) = self.preview_content {
-                let mut kitty = kitty_rc.borrow_mut();
+    #[test]
+    fn test_ctrl_s_in_slideshow_saves_the_frame_on_screen() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 2, true);
+        app.enter_slideshow_mode();
+        app.preview_content = Some(PreviewContent::Text(
+            Text::from("@@##"),
+            ContentAlignment::Image,
+        ));
+        let saved_path = temp_fs.get_path().join("image0.ascii");
 
-                // Reset rendered flag to allow re-rendering
-                kitty.rendered = false;
+        press_key(&mut app, KeyCode::Char('s'), KeyModifiers::CONTROL);
+        assert!(app.is_slideshow_mode);
+        assert_eq!(std::fs::read_to_string(&saved_path).unwrap(), "@@##");
+        let (notice, _) = app.slideshow_notice.clone().unwrap();
+        assert!(notice.contains("Saved frame") && notice.contains("image0.ascii"));
+        assert_eq!(app.slideshow_status().notice, Some(notice));
 
-                // Calculate position based on mode
-                let (render_x, render_y, width, height) = if self.is_slideshow_mode {
-                    // Slideshow mode: full screen with status bar at bottom
-                    let image_area = ratatui::layout::Rect::new(
-                        0,
-                        0,
-                        self.terminal_width,
-                        self.terminal_height.saturating_sub(3), // Reserve 3 rows for status bar
-                    );
+        // Saving again leaves the first file alone
+        app.preview_content = Some(PreviewContent::Text(
+            Text::from("...."),
+            ContentAlignment::Image,
+        ));
+        press_key(&mut app, KeyCode::Char('s'), KeyModifiers::CONTROL);
+        assert_eq!(std::fs::read_to_string(&saved_path).unwrap(), "@@##");
+        assert!(app.slideshow_notice.unwrap().0.contains("already exists"));
+    }
 
-                    let img_aspect = kitty.img_width as f32 / kitty.img_height as f32;
-                    let font_width = kitty.font_size.0 as f32;
-                    let font_height = kitty.font_size.1 as f32;
-                    let char_aspect = font_height / font_width;
+    #[test]
+    fn test_slideshow_frame_saved_mid_transition() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 2, true);
+        app.enter_slideshow_mode();
+        app.preview_content = Some(PreviewContent::Text(
+            Text::from("final"),
+            ContentAlignment::Image,
+        ));
+        app.transition_manager = TransitionManager::new(SlideshowTransitionConfig {
+            enabled: true,
+            frame_duration_ms: 10_000,
+            ..SlideshowTransitionConfig::default()
+        });
+        app.transition_manager.start_transition(
+            &Text::from("from from"),
+            &Text::from("to to to"),
+            Direction::Forward,
+        );
+        let expected = crate::preview::text_to_ansi(
+            app.transition_manager
+                .get_current_transition_frame()
+                .unwrap(),
+        );
 
-                    let display_width_cells =
-                        (image_area.height as f32 * img_aspect * char_aspect) as u16;
+        app.save_slideshow_frame();
+        let saved = std::fs::read_to_string(temp_fs.get_path().join("image0.ascii")).unwrap();
+        assert_eq!(saved, expected);
+        assert_ne!(saved, "final");
+    }
 
-                    let (w, h) = if display_width_cells <= image_area.width {
-                        (display_width_cells, image_area.height)
-                    } else {
-                        let display_height =
-                            (image_area.width as f32 / img_aspect / char_aspect) as u16;
-                        (image_area.width, display_height.min(image_area.height))
-                    };
+    #[test]
+    fn test_slideshow_notice_expires() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 1, true);
+        app.enter_slideshow_mode();
+        app.slideshow_notice = Some((
+            "Saved frame".to_string(),
+            Instant::now() - SLIDESHOW_NOTICE_DURATION,
+        ));
 
-                    let x_offset = (image_area.width.saturating_sub(w)) / 2;
-                    let y_offset = (image_area.height.saturating_sub(h)) / 2;
-                    (image_area.x + x_offset, image_area.y + y_offset, w, h)
-                } else {
-                    // Normal mode: use preview area from layout
-                    let (_, preview_area, _) =
-                        self.ui_layout
-                            .calculate_layout(ratatui::layout::Rect::new(
-                                0,
-                                0,
-                                self.terminal_width,
-                                self.terminal_height,
-                            ));
+        app.update_slideshow();
+        assert!(app.slideshow_notice.is_none());
+    }
 
-                    // Account for border
-                    let inner_area = ratatui::layout::Rect::new(
-                        preview_area.x + 1,
-                        preview_area.y + 1,
-                        preview_area.width.saturating_sub(2),
-                        preview_area.height.saturating_sub(2),
-                    );
+    #[test]
+    fn test_slideshow_progress_bar_redraws_as_it_fills() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 2, true);
+        app.enter_slideshow_mode();
+        app.slideshow_delay = Duration::from_secs(60);
+        app.update_slideshow();
+        app.needs_redraw();
 
-                    let img_aspect = kitty.img_width as f32 / kitty.img_height as f32;
-                    let font_width = kitty.font_size.0 as f32;
-                    let font_height = kitty.font_size.1 as f32;
-                    let char_aspect = font_height / font_width;
+        // Nothing new to fill straight away
+        app.update_slideshow();
+        assert!(!app.needs_redraw());
 
-                    let display_width_cells =
-                        (inner_area.height as f32 * img_aspect * char_aspect) as u16;
+        app.slideshow_last_change = Instant::now() - Duration::from_secs(30);
+        app.update_slideshow();
+        assert!(app.needs_redraw());
+        assert_eq!(app.slideshow_progress_drawn, 39);
+        assert_eq!(app.slideshow_reserved_rows(), 5);
+    }
 
-                    let (w, h) = if display_width_cells <= inner_area.width {
-                        (display_width_cells, inner_area.height)
-                    } else {
-                        let display_height =
-                            (inner_area.width as f32 / img_aspect / char_aspect) as u16;
-                        (inner_area.width, display_height.min(inner_area.height))
-                    };
+    #[test]
+    fn test_slideshow_delay_adjustment_is_clamped() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 1, true);
+        app.slideshow_delay = Duration::from_millis(2000);
 
-                    let x_offset = (inner_area.width.saturating_sub(w)) / 2;
-                    let y_offset = (inner_area.height.saturating_sub(h)) / 2;
-                    (inner_area.x + x_offset, inner_area.y + y_offset, w, h)
-                };
+        app.adjust_slideshow_delay(true);
+        assert_eq!(app.slideshow_delay, Duration::from_millis(2500));
+        app.adjust_slideshow_delay(false);
+        assert_eq!(app.slideshow_delay, Duration::from_millis(2000));
 
-                // Update display dimensions
-                kitty.display_width = width as u32;
-                kitty.display_height = height as u32;
+        for _ in 0..50 {
+            app.adjust_slideshow_delay(true);
+        }
+        assert_eq!(app.slideshow_delay, Duration::from_millis(30000));
 
-                // Render the Kitty image (now AFTER ratatui has flushed)
-                if let Err(e) =
-                    crate::preview::PreviewManager::print_kitty_image(&mut kitty, render_x, render_y)
-                {
-                    eprintln!("[KITTY] Post-draw render error: {}", e);
-                }
-            }
+        for _ in 0..50 {
+            app.adjust_slideshow_delay(false);
         }
+        assert_eq!(app.slideshow_delay, Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_slideshow_without_loop_stops_at_first_image() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut app = create_slideshow_app(&temp_fs, 3, false);
+
+        app.enter_slideshow_mode();
+        app.slideshow_go_backward();
+        assert!(app.is_slideshow_mode);
+        assert_eq!(app.slideshow_current_index, 0);
+
+        app.toggle_slideshow_loop();
+        app.slideshow_go_backward();
+        assert_eq!(app.slideshow_current_index, 2);
+    }
+
+    #[test]
+    fn test_shuffled_order_is_a_permutation() {
+        let mut order = shuffled_order(50, 12345);
+        assert_ne!(order, (0..50).collect::<Vec<_>>());
+        order.sort_unstable();
+        assert_eq!(order, (0..50).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_shuffled_order_is_reproducible() {
+        assert_eq!(shuffled_order(20, 42), shuffled_order(20, 42));
+        assert_ne!(shuffled_order(20, 42), shuffled_order(20, 43));
+        assert!(shuffled_order(0, 7).is_empty());
+        assert_eq!(shuffled_order(1, 0), vec![0]);
     }
 }
@@ -0,0 +1,126 @@
+use clap::{CommandFactory, Parser, ValueHint};
+use clap_complete::Shell;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Command line arguments for ptui
+#[derive(Parser, Debug, Default)]
+#[command(
+    name = "ptui",
+    version,
+    about = "Picture TUI - Terminal-based image viewer"
+)]
+pub struct Args {
+    /// Directory or image to open (defaults to the current directory)
+    #[arg(value_name = "PATH", value_hint = ValueHint::AnyPath)]
+    pub target: Option<PathBuf>,
+
+    /// Directory or image to open, same as the positional PATH
+    #[arg(long = "path", value_name = "PATH", value_hint = ValueHint::AnyPath)]
+    pub path: Option<PathBuf>,
+
+    /// Start the slideshow straight away
+    #[arg(long)]
+    pub slideshow: bool,
+
+    /// Print the default colour theme as JSON, to copy into the config's "theme"
+    #[arg(long)]
+    pub print_theme_defaults: bool,
+
+    /// Print the files in PATH as JSON and exit, for use in scripts
+    #[arg(long)]
+    pub list_files: bool,
+
+    /// Print a completion script for bash, zsh, fish or powershell and exit
+    #[arg(long, value_name = "SHELL")]
+    pub print_completions: Option<Shell>,
+
+    /// Print which converters can be used and exit
+    #[arg(long)]
+    pub list_converters: bool,
+
+    /// Print a summary of the config file, with any warnings, and exit
+    #[arg(long)]
+    pub check_config: bool,
+}
+
+impl Args {
+    /// The path to start in; --path wins over the positional PATH
+    pub fn start_path(&self) -> Option<&Path> {
+        self.path.as_deref().or(self.target.as_deref())
+    }
+
+    /// Write the completion script for a shell, built from the arguments above
+    pub fn write_completions(shell: Shell, out: &mut dyn Write) {
+        clap_complete::generate(shell, &mut Self::command(), "ptui", out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_args_defaults() {
+        let args = Args::try_parse_from(["ptui"]).unwrap();
+        assert!(!args.slideshow);
+        assert!(args.start_path().is_none());
+    }
+
+    #[test]
+    fn test_args_slideshow_with_positional_path() {
+        let args = Args::try_parse_from(["ptui", "/photos", "--slideshow"]).unwrap();
+        assert!(args.slideshow);
+        assert_eq!(args.start_path(), Some(Path::new("/photos")));
+    }
+
+    #[test]
+    fn test_args_print_theme_defaults() {
+        let args = Args::try_parse_from(["ptui", "--print-theme-defaults"]).unwrap();
+        assert!(args.print_theme_defaults);
+    }
+
+    #[test]
+    fn test_args_list_files() {
+        let args = Args::try_parse_from(["ptui", "--list-files", "/photos"]).unwrap();
+        assert!(args.list_files);
+        assert_eq!(args.start_path(), Some(Path::new("/photos")));
+    }
+
+    #[rstest::rstest]
+    #[case("bash", Shell::Bash)]
+    #[case("zsh", Shell::Zsh)]
+    #[case("fish", Shell::Fish)]
+    #[case("powershell", Shell::PowerShell)]
+    fn test_args_print_completions(#[case] name: &str, #[case] shell: Shell) {
+        let args = Args::try_parse_from(["ptui", "--print-completions", name]).unwrap();
+        assert_eq!(args.print_completions, Some(shell));
+    }
+
+    #[test]
+    fn test_zsh_completions_offer_paths() {
+        let mut out = Vec::new();
+        Args::write_completions(Shell::Zsh, &mut out);
+        let script = String::from_utf8(out).unwrap();
+        assert!(script.contains("#compdef ptui"));
+        assert!(script.contains("_files"));
+    }
+
+    #[test]
+    fn test_args_list_converters() {
+        let args = Args::try_parse_from(["ptui", "--list-converters"]).unwrap();
+        assert!(args.list_converters);
+    }
+
+    #[test]
+    fn test_args_check_config() {
+        let args = Args::try_parse_from(["ptui", "--check-config"]).unwrap();
+        assert!(args.check_config);
+    }
+
+    #[test]
+    fn test_args_path_flag_wins_over_positional() {
+        let args = Args::try_parse_from(["ptui", "/photos", "--path", "/other/a.jpg"]).unwrap();
+        assert_eq!(args.start_path(), Some(Path::new("/other/a.jpg")));
+    }
+}
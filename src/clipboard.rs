@@ -0,0 +1,49 @@
+/// Access to the system clipboard, available when built with the clipboard feature.
+/// The handle is kept open for the life of the app because on X11 the copied text
+/// is only available while its owner is still running.
+#[derive(Default)]
+pub struct SystemClipboard {
+    #[cfg(feature = "clipboard")]
+    clipboard: Option<arboard::Clipboard>,
+}
+
+impl SystemClipboard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether clipboard support was compiled in
+    pub fn is_supported() -> bool {
+        cfg!(feature = "clipboard")
+    }
+
+    #[cfg(feature = "clipboard")]
+    pub fn set_text(&mut self, text: &str) -> Result<(), String> {
+        // Opened on first use, as it fails without a display server
+        if self.clipboard.is_none() {
+            self.clipboard = Some(arboard::Clipboard::new().map_err(|e| e.to_string())?);
+        }
+        match self.clipboard.as_mut() {
+            Some(clipboard) => clipboard.set_text(text).map_err(|e| e.to_string()),
+            None => Err("clipboard not initialised".to_string()),
+        }
+    }
+
+    #[cfg(not(feature = "clipboard"))]
+    pub fn set_text(&mut self, _text: &str) -> Result<(), String> {
+        Err("clipboard support not compiled in".to_string())
+    }
+}
+
+// Only the disabled build can be tested, copying needs a display server
+#[cfg(all(test, not(feature = "clipboard")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clipboard_without_feature() {
+        let mut clipboard = SystemClipboard::new();
+        assert!(!SystemClipboard::is_supported());
+        assert!(clipboard.set_text("/photos/a.jpg").is_err());
+    }
+}
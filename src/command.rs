@@ -0,0 +1,97 @@
+use std::path::Path;
+use std::process::Command;
+
+/// Editor used when neither $VISUAL nor $EDITOR is set
+const FALLBACK_EDITOR: &str = "vi";
+
+/// A command typed at the `:` prompt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PromptCommand {
+    Quit,
+    EditConfig,
+}
+
+impl PromptCommand {
+    /// Parse what was typed after the colon, None if it isn't a command. The input is
+    /// split into words so commands taking an argument, like `:cd /path`, fit in here.
+    pub fn parse(input: &str) -> Option<Self> {
+        let words: Vec<&str> = input.split_whitespace().collect();
+        match words.as_slice() {
+            ["q"] | ["quit"] => Some(Self::Quit),
+            ["e" | "edit", "config"] => Some(Self::EditConfig),
+            _ => None,
+        }
+    }
+}
+
+/// The editor to run: $VISUAL, then $EDITOR, then vi
+pub fn editor_command(visual: Option<String>, editor: Option<String>) -> String {
+    visual
+        .into_iter()
+        .chain(editor)
+        .find(|command| !command.trim().is_empty())
+        .unwrap_or_else(|| FALLBACK_EDITOR.to_string())
+}
+
+/// Open `path` in `editor` and wait for it to exit. The editor may carry its own
+/// arguments, e.g. "code --wait".
+pub fn run_editor(editor: &str, path: &Path) -> Result<(), String> {
+    let mut words = editor.split_whitespace();
+    let program = words.next().ok_or("No editor command")?;
+    let status = Command::new(program)
+        .args(words)
+        .arg(path)
+        .status()
+        .map_err(|e| format!("{}: {}", program, e))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("{}: {}", program, status))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rstest::rstest]
+    #[case("q", Some(PromptCommand::Quit))]
+    #[case(" quit ", Some(PromptCommand::Quit))]
+    #[case("e config", Some(PromptCommand::EditConfig))]
+    #[case("edit   config", Some(PromptCommand::EditConfig))]
+    #[case("e", None)]
+    #[case("e theme", None)]
+    #[case("", None)]
+    fn test_parse(#[case] input: &str, #[case] expected: Option<PromptCommand>) {
+        assert_eq!(PromptCommand::parse(input), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(Some("code --wait"), Some("nano"), "code --wait")]
+    #[case(Some(" "), Some("nano"), "nano")]
+    #[case(None, Some("nano"), "nano")]
+    #[case(None, None, "vi")]
+    fn test_editor_command(
+        #[case] visual: Option<&str>,
+        #[case] editor: Option<&str>,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(
+            editor_command(visual.map(str::to_string), editor.map(str::to_string)),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_run_editor_reports_failures() {
+        let path = Path::new("ptui.json");
+        assert!(run_editor("true", path).is_ok());
+        assert!(
+            run_editor("false", path)
+                .unwrap_err()
+                .starts_with("false: ")
+        );
+        assert!(run_editor("ptui-no-such-editor", path).is_err());
+        assert!(run_editor("  ", path).is_err());
+    }
+}
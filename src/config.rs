@@ -1,5 +1,10 @@
+use crate::fzf;
+use crate::icons::FileIcons;
+use crate::theme::ThemeConfig;
+use image::imageops::FilterType;
 use notify::{Event, EventKind, RecursiveMode, Watcher, event::ModifyKind};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::error::Error;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -9,6 +14,20 @@ use std::thread;
 use std::time::Duration;
 
 const DEFAULT_LOCALE: &str = "en";
+const DEFAULT_TRANSITION_FRAME_COUNT: u32 = 20;
+const DEFAULT_CONVERSION_TIMEOUT_MS: u64 = 10000;
+const MIN_TRANSITION_FRAME_COUNT: u32 = 5;
+const MAX_TRANSITION_FRAME_COUNT: u32 = 120;
+const MIN_POLL_INTERVAL_MS: u64 = 10;
+const MAX_POLL_INTERVAL_MS: u64 = 500;
+const MIN_FILE_BROWSER_WIDTH_PERCENT: u16 = 5;
+const MAX_FILE_BROWSER_WIDTH_PERCENT: u16 = 50;
+const MIN_CHAR_ASPECT_RATIO: f32 = 0.5;
+const MAX_CHAR_ASPECT_RATIO: f32 = 4.0;
+const SLIDESHOW_ORDERS: &[&str] = &["current_sort", "name", "date", "random"];
+const TRANSITION_EASINGS: &[&str] = &["linear", "ease-in", "ease-out", "ease-in-out"];
+/// How long the config file has to be left alone after a write before it's reloaded
+const CONFIG_RELOAD_QUIET_PERIOD: Duration = Duration::from_millis(200);
 
 // Thread-safe lazy initialization of config directory
 // This prevents thread contention when multiple tests access the home directory simultaneously
@@ -36,7 +55,13 @@ impl Default for ChafaConfig {
     }
 }
 
-#[derive(Serialize, Debug, Clone, Deserialize)]
+impl std::fmt::Display for ChafaConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "format={}, colors={}", self.format, self.colors)
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Deserialize, PartialEq)]
 pub struct Jp2aConfig {
     pub colors: bool,
     pub invert: bool,
@@ -55,8 +80,24 @@ impl Default for Jp2aConfig {
     }
 }
 
-#[derive(Serialize, Debug, Clone, Deserialize)]
+impl std::fmt::Display for Jp2aConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "colors={}, invert={}, chars={}",
+            self.colors,
+            self.invert,
+            self.chars.as_deref().unwrap_or("default")
+        )
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Deserialize, PartialEq)]
 pub struct GraphicalConfig {
+    /// How graphical previews are scaled down: "nearest", "linear", "cubic",
+    /// "gaussian" or "lanczos3" (default). Shrinking a 12 megapixel photo to
+    /// 1024x768 takes roughly 0.2s with nearest or linear, 0.25s with cubic and
+    /// 0.35s with gaussian or lanczos3, which gives the sharpest result.
     pub filter_type: String,
     #[serde(default = "default_max_dimension")]
     pub max_dimension: u32,
@@ -73,6 +114,27 @@ fn default_auto_resize() -> bool {
     true
 }
 
+impl GraphicalConfig {
+    /// The configured resize filter, or Lanczos3 if it isn't one ptui knows
+    pub fn filter(&self) -> FilterType {
+        parse_filter_type(&self.filter_type).unwrap_or(FilterType::Lanczos3)
+    }
+}
+
+/// Parse a resize filter name. Names ignore case, and the image crate's own names
+/// ("triangle", "catmullrom") work too.
+pub fn parse_filter_type(name: &str) -> Option<FilterType> {
+    let filter = match name.trim().to_lowercase().as_str() {
+        "nearest" => FilterType::Nearest,
+        "linear" | "triangle" => FilterType::Triangle,
+        "cubic" | "catmullrom" => FilterType::CatmullRom,
+        "gaussian" => FilterType::Gaussian,
+        "lanczos3" => FilterType::Lanczos3,
+        _ => return None,
+    };
+    Some(filter)
+}
+
 impl Default for GraphicalConfig {
     fn default() -> Self {
         Self {
@@ -83,12 +145,91 @@ impl Default for GraphicalConfig {
     }
 }
 
-#[derive(Serialize, Debug, Clone, Deserialize)]
+impl std::fmt::Display for GraphicalConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "filter={}, ", self.filter_type)?;
+        if self.auto_resize {
+            write!(f, "max_dimension=auto")
+        } else {
+            write!(f, "max_dimension={}", self.max_dimension)
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Deserialize, PartialEq)]
+pub struct HeicConfig {
+    /// Binary used to decode HEIC/HEIF images to JPEG (from libheif-examples)
+    pub convert_binary: String,
+}
+
+impl Default for HeicConfig {
+    fn default() -> Self {
+        Self {
+            convert_binary: "heif-convert".to_string(),
+        }
+    }
+}
+
+/// How RAW camera files (CR2, NEF, ARW, DNG...) are turned into something previewable
+#[derive(Serialize, Debug, Clone, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct RawConfig {
+    /// "dcraw" to pull out the embedded JPEG thumbnail, or "darktable-cli" to develop
+    /// the whole image (slower). The other one is tried when this isn't installed.
+    pub extractor: String,
+}
+
+impl Default for RawConfig {
+    fn default() -> Self {
+        Self {
+            extractor: "dcraw".to_string(),
+        }
+    }
+}
+
+/// Settings for the built-in Sixel encoder (needs the sixel-converter feature)
+#[derive(Serialize, Debug, Clone, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct SixelConfig {
+    /// "floyd-steinberg" or "none"
+    pub dither: String,
+    /// Number of colours to reduce the image to, 2 to 256 (default: 256)
+    pub palette_size: u16,
+}
+
+impl Default for SixelConfig {
+    fn default() -> Self {
+        Self {
+            dither: "floyd-steinberg".to_string(),
+            palette_size: 256,
+        }
+    }
+}
+
+/// Settings for iTerm2's inline image protocol
+#[derive(Serialize, Debug, Clone, Default, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ITermConfig {
+    /// Size images in pixels rather than terminal cells (default: false)
+    pub use_pixel_dimensions: bool,
+}
+
+#[derive(Serialize, Debug, Clone, Deserialize, PartialEq)]
 pub struct ConverterConfig {
     pub chafa: ChafaConfig,
     pub jp2a: Jp2aConfig,
     pub graphical: GraphicalConfig,
-    pub selected: String, // "chafa", "jp2a", "graphical"
+    #[serde(default)]
+    pub heic: HeicConfig,
+    #[serde(default)]
+    pub raw: RawConfig,
+    #[serde(default)]
+    pub sixel: SixelConfig,
+    #[serde(default)]
+    pub iterm: ITermConfig,
+    pub selected: String, // "chafa", "jp2a", "graphical", "sixel", "iterm"
+    /// Give up on a chafa or jp2a conversion after this long (default: 10000)
+    pub conversion_timeout_ms: Option<u64>,
 }
 
 impl Default for ConverterConfig {
@@ -97,16 +238,50 @@ impl Default for ConverterConfig {
             chafa: ChafaConfig::default(),
             jp2a: Jp2aConfig::default(),
             graphical: GraphicalConfig::default(),
+            heic: HeicConfig::default(),
+            raw: RawConfig::default(),
+            sixel: SixelConfig::default(),
+            iterm: ITermConfig::default(),
             selected: "chafa".to_string(),
+            conversion_timeout_ms: Some(DEFAULT_CONVERSION_TIMEOUT_MS),
         }
     }
 }
 
-#[derive(Serialize, Debug, Clone, Deserialize)]
+impl ConverterConfig {
+    pub fn get_conversion_timeout_ms(&self) -> u64 {
+        self.conversion_timeout_ms
+            .unwrap_or(DEFAULT_CONVERSION_TIMEOUT_MS)
+    }
+}
+
+/// The order a slideshow goes through the directory's images in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlideshowOrder {
+    /// The order the file list is sorted in
+    CurrentSort,
+    Name,
+    /// Oldest first
+    Date,
+    /// Shuffled afresh each time a slideshow starts
+    Random,
+}
+
+#[derive(Serialize, Debug, Clone, Deserialize, PartialEq)]
 pub struct SlideshowTransitionConfig {
     pub enabled: bool,
-    pub effect: String, // "scattering", "typewriter", "scrolling_left", "scrolling_right", "climbing"
+    // "scattering", "typewriter", "scrolling_left", "scrolling_right", "climbing", "fade",
+    // "wipe_down", "wipe_up"
+    pub effect: String,
     pub frame_duration_ms: u64,
+    /// Number of frames in a transition, 5 to 120 (default: 20)
+    pub frame_count: Option<u32>,
+    /// Play directional effects in reverse when going back through the slideshow
+    #[serde(default)]
+    pub direction_aware: bool,
+    /// How progress speeds up through a transition: "linear" (default), "ease-in",
+    /// "ease-out" or "ease-in-out"
+    pub easing: Option<String>,
 }
 
 impl Default for SlideshowTransitionConfig {
@@ -115,16 +290,111 @@ impl Default for SlideshowTransitionConfig {
             enabled: false,
             effect: "scattering".to_string(),
             frame_duration_ms: 50,
+            frame_count: Some(DEFAULT_TRANSITION_FRAME_COUNT),
+            direction_aware: false,
+            easing: Some("linear".to_string()),
         }
     }
 }
 
-#[derive(Serialize, Debug, Clone, Deserialize)]
+impl SlideshowTransitionConfig {
+    pub fn get_frame_count(&self) -> u32 {
+        self.frame_count
+            .unwrap_or(DEFAULT_TRANSITION_FRAME_COUNT)
+            .clamp(MIN_TRANSITION_FRAME_COUNT, MAX_TRANSITION_FRAME_COUNT)
+    }
+
+    pub fn get_easing(&self) -> &str {
+        self.easing.as_deref().unwrap_or("linear")
+    }
+
+    /// How long a whole transition takes: every frame is shown for frame_duration_ms
+    pub fn total_duration_ms(&self) -> u64 {
+        self.frame_duration_ms * self.get_frame_count() as u64
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Deserialize, PartialEq)]
 pub struct PTuiConfig {
     pub converter: ConverterConfig,
     pub locale: Option<String>,
     pub slideshow_delay_ms: Option<u64>,
     pub slideshow_transitions: Option<SlideshowTransitionConfig>,
+    /// Width in columns of each thumbnail cell in grid view
+    pub grid_cell_width: Option<u16>,
+    /// Wrap around at the end of the slideshow instead of stopping (default: true)
+    pub slideshow_loop: Option<bool>,
+    /// Factor applied to the slideshow delay by + and - (default: 1.25)
+    pub slideshow_delay_step_factor: Option<f32>,
+    /// Shortest slideshow delay reachable with - (default: 100)
+    pub slideshow_delay_min_ms: Option<u64>,
+    /// Longest slideshow delay reachable with + (default: 30000)
+    pub slideshow_delay_max_ms: Option<u64>,
+    /// Give up on a background conversion after this long (default: 30000)
+    pub preview_loading_timeout_ms: Option<u64>,
+    /// Read image sizes from the file header before asking identify (default: true)
+    pub prefer_native_dimension_detection: Option<bool>,
+    /// Move deleted files to the OS trash when built with trash-support (default: true)
+    pub use_trash: Option<bool>,
+    /// Commands for o to open files with, by extension, e.g. "jpg": "feh"
+    pub open_with: Option<HashMap<String, String>>,
+    /// Show Git status letters next to files (off by default, it runs git on every listing)
+    pub git_integration: Option<bool>,
+    /// Refresh the file list when files in the current directory change
+    pub watch_current_directory: Option<bool>,
+    /// Reopen the last directory and selection on startup (default: true)
+    pub restore_session: Option<bool>,
+    /// Text for an extra debug pane mode after Messages, e.g. notes to yourself
+    pub debug_pane_text: Option<String>,
+    /// Show icons in the file list; false falls back to plain [D]/[I] markers
+    pub use_icons: Option<bool>,
+    /// Icon set to use: "emoji" or "nerd-fonts"
+    pub icon_preset: Option<String>,
+    /// Custom icons, overriding the preset
+    pub file_icons: Option<FileIcons>,
+    /// Colours for the file list
+    pub theme: Option<ThemeConfig>,
+    /// Show a bar counting down to the next slideshow image (default: true)
+    pub show_progress_bar: Option<bool>,
+    /// Images B converts at once in a batch export (default: 1)
+    pub batch_export_concurrent: Option<u8>,
+    /// Wait this long after moving through the list before previewing (default: 80)
+    pub preview_debounce_ms: Option<u64>,
+    /// Lines of a text file that can be scrolled through (default: 10000)
+    pub max_preview_lines: Option<usize>,
+    /// Apply terminal resizes at most this often (default: 100)
+    pub resize_debounce_ms: Option<u64>,
+    /// Show how many images each directory in the file list holds (default: false)
+    pub show_image_counts: Option<bool>,
+    /// How often the main loop checks for input and timers, 10-500 (default: 50)
+    pub poll_interval_ms: Option<u64>,
+    /// Open fzf with Ctrl+F (default: on when fzf is installed)
+    pub fzf_integration: Option<bool>,
+    /// Draw this ANSI/ASCII art file as the logo instead of the built-in one
+    pub logo_file: Option<String>,
+    /// Leave the logo off the help screen (default: false)
+    pub logo_hidden: Option<bool>,
+    /// Show each file's owner permissions, like rwx, in the file list (default: false)
+    pub show_permissions: Option<bool>,
+    /// Clear messages like "Deleted: foo.jpg" from the debug pane after this long, 0 keeps them (default: 0)
+    pub notification_duration_ms: Option<u64>,
+    /// Preview audio files as a waveform drawn by ffmpeg instead of their tags (default: false)
+    pub audio_waveform: Option<bool>,
+    /// Width of the file browser as a percentage of the screen, 5 to 50; also the narrowest [ can make it (default: 10 on wide screens, 15 on narrow ones)
+    pub file_browser_width_percent: Option<u16>,
+    /// file_browser_width_percent for screens wider than 120 columns only
+    pub file_browser_width_percent_wide: Option<u16>,
+    /// file_browser_width_percent for screens up to 120 columns wide only
+    pub file_browser_width_percent_narrow: Option<u16>,
+    /// How many times taller than wide a terminal character cell is, for keeping ASCII art in proportion. Unset, it's measured from the terminal when possible, otherwise 2.0
+    pub char_aspect_ratio: Option<f32>,
+    /// Order of the slideshow: "current_sort" (the file list's order), "name",
+    /// "date" (oldest first) or "random"
+    pub slideshow_order: Option<String>,
+    /// Lines u and Space scroll a text file by (default: half the preview height)
+    pub text_scroll_lines: Option<usize>,
+    /// Preview sizes are rounded down to a multiple of this for the cache, so small resizes reuse previews (default: 10, 1 for exact sizes)
+    pub cache_size_rounding: Option<u16>,
     // Keep the old chafa field for backward compatibility
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chafa: Option<ChafaConfig>,
@@ -137,12 +407,81 @@ impl Default for PTuiConfig {
             locale: Some(DEFAULT_LOCALE.to_string()),
             slideshow_delay_ms: Some(2000), // Default 2 seconds
             slideshow_transitions: Some(SlideshowTransitionConfig::default()),
+            grid_cell_width: Some(18),
+            slideshow_loop: Some(true),
+            slideshow_delay_step_factor: Some(1.25),
+            slideshow_delay_min_ms: Some(100),
+            slideshow_delay_max_ms: Some(30000),
+            preview_loading_timeout_ms: Some(30000),
+            prefer_native_dimension_detection: Some(true),
+            use_trash: Some(true),
+            open_with: Some(HashMap::new()),
+            git_integration: Some(false),
+            watch_current_directory: Some(false),
+            restore_session: Some(true),
+            debug_pane_text: None,
+            use_icons: Some(true),
+            icon_preset: Some("emoji".to_string()),
+            file_icons: None,
+            theme: None,
+            show_progress_bar: Some(true),
+            batch_export_concurrent: Some(1),
+            preview_debounce_ms: Some(80),
+            max_preview_lines: Some(10000),
+            resize_debounce_ms: Some(100),
+            show_image_counts: Some(false),
+            poll_interval_ms: Some(50),
+            fzf_integration: None,
+            logo_file: None,
+            logo_hidden: Some(false),
+            show_permissions: Some(false),
+            notification_duration_ms: Some(0),
+            audio_waveform: Some(false),
+            file_browser_width_percent: None,
+            file_browser_width_percent_wide: None,
+            file_browser_width_percent_narrow: None,
+            char_aspect_ratio: None,
+            slideshow_order: Some("current_sort".to_string()),
+            text_scroll_lines: None,
+            cache_size_rounding: Some(10),
             chafa: None, // Deprecated, use converter.chafa instead
         }
     }
 }
 
+/// A short readable summary of the settings that matter most, one per line
+impl std::fmt::Display for PTuiConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let converter = &self.converter;
+        match converter.selected.as_str() {
+            "chafa" => writeln!(f, "Converter: chafa ({})", converter.chafa)?,
+            "jp2a" => writeln!(f, "Converter: jp2a ({})", converter.jp2a)?,
+            "graphical" => writeln!(f, "Converter: graphical ({})", converter.graphical)?,
+            other => writeln!(f, "Converter: {}", other)?,
+        }
+        writeln!(f, "Locale: {}", self.resolve_locale())?;
+        writeln!(f, "Slideshow delay: {}ms", self.get_slideshow_delay_ms())?;
+        let transitions = self.get_slideshow_transitions();
+        write!(
+            f,
+            "Transitions: {} (effect={})",
+            if transitions.enabled {
+                "enabled"
+            } else {
+                "disabled"
+            },
+            transitions.effect
+        )
+    }
+}
+
 impl PTuiConfig {
+    /// Same as formatting with {}, for callers that want a String
+    #[allow(dead_code)] // For library users; the binary formats the config directly
+    pub fn to_summary_string(&self) -> String {
+        self.to_string()
+    }
+
     pub fn load() -> Result<Self, Box<dyn Error>> {
         let config_dir = get_config_dir()?;
         let config_path = config_dir.join("ptui").join("ptui.json");
@@ -157,7 +496,12 @@ impl PTuiConfig {
                     let _ = Self::save_config(&config_path, &config);
                 }
                 #[cfg(not(test))]
-                println!("Loaded config from: {:?}", config_path);
+                {
+                    println!("Loaded config from: {:?}", config_path);
+                    for warning in config.validate() {
+                        eprintln!("Warning: {}", warning);
+                    }
+                }
                 return Ok(config);
             }
         }
@@ -187,6 +531,40 @@ impl PTuiConfig {
             .unwrap_or_else(|| DEFAULT_LOCALE.to_string())
     }
 
+    /// The locale to start with: the config file's, then the system's, then English
+    pub fn resolve_locale(&self) -> String {
+        if self.locale.is_none()
+            && let Some(detected) = Self::detect_system_locale()
+        {
+            return detected;
+        }
+        self.get_locale()
+    }
+
+    /// The embedded locale matching `LANGUAGE` or `LANG`, or English when there is no
+    /// translation for it. None when neither variable names a language.
+    pub fn detect_system_locale() -> Option<String> {
+        let language = std::env::var("LANGUAGE").ok();
+        let lang = std::env::var("LANG").ok();
+        Self::locale_from_env(language.as_deref(), lang.as_deref())
+    }
+
+    fn locale_from_env(language: Option<&str>, lang: Option<&str>) -> Option<String> {
+        // LANGUAGE is a colon separated priority list, e.g. "de_DE:en"
+        let value = [language.and_then(|value| value.split(':').next()), lang]
+            .into_iter()
+            .flatten()
+            .map(str::trim)
+            .find(|value| !value.is_empty() && *value != "C" && *value != "POSIX")?;
+        let tag = value.split(['.', '@']).next().unwrap_or_default();
+
+        Some(
+            crate::localization::find_embedded_locale(tag)
+                .unwrap_or(DEFAULT_LOCALE)
+                .to_string(),
+        )
+    }
+
     pub fn get_slideshow_delay_ms(&self) -> u64 {
         self.slideshow_delay_ms.unwrap_or(2000)
     }
@@ -195,6 +573,543 @@ impl PTuiConfig {
         self.slideshow_transitions.clone().unwrap_or_default()
     }
 
+    pub fn get_grid_cell_width(&self) -> u16 {
+        // Anything narrower can't fit a readable thumbnail
+        self.grid_cell_width.unwrap_or(18).max(8)
+    }
+
+    pub fn get_slideshow_loop(&self) -> bool {
+        self.slideshow_loop.unwrap_or(true)
+    }
+
+    pub fn get_slideshow_delay_step_factor(&self) -> f32 {
+        // A factor of 1 or less would never change the delay (or invert + and -)
+        self.slideshow_delay_step_factor
+            .filter(|factor| *factor > 1.0)
+            .unwrap_or(1.25)
+    }
+
+    pub fn get_slideshow_delay_min_ms(&self) -> u64 {
+        self.slideshow_delay_min_ms.unwrap_or(100)
+    }
+
+    pub fn get_slideshow_delay_max_ms(&self) -> u64 {
+        self.slideshow_delay_max_ms
+            .unwrap_or(30000)
+            .max(self.get_slideshow_delay_min_ms())
+    }
+
+    pub fn get_watch_current_directory(&self) -> bool {
+        self.watch_current_directory.unwrap_or(false)
+    }
+
+    pub fn get_git_integration(&self) -> bool {
+        self.git_integration.unwrap_or(false)
+    }
+
+    pub fn get_debug_pane_text(&self) -> Option<&str> {
+        self.debug_pane_text
+            .as_deref()
+            .filter(|text| !text.is_empty())
+    }
+
+    /// The icons for the file list: plain markers when icons are off, otherwise the
+    /// custom set or the named preset, with the emoji set as the fallback
+    pub fn get_file_icons(&self) -> FileIcons {
+        if !self.use_icons.unwrap_or(true) {
+            return FileIcons::ascii();
+        }
+        self.file_icons.clone().unwrap_or_else(|| {
+            self.icon_preset
+                .as_deref()
+                .and_then(FileIcons::preset)
+                .unwrap_or_default()
+        })
+    }
+
+    pub fn get_theme(&self) -> ThemeConfig {
+        self.theme.clone().unwrap_or_default()
+    }
+
+    pub fn get_show_progress_bar(&self) -> bool {
+        self.show_progress_bar.unwrap_or(true)
+    }
+
+    pub fn get_batch_export_concurrent(&self) -> u8 {
+        self.batch_export_concurrent.unwrap_or(1).max(1)
+    }
+
+    pub fn get_preview_debounce(&self) -> Duration {
+        Duration::from_millis(self.preview_debounce_ms.unwrap_or(80))
+    }
+
+    pub fn get_max_preview_lines(&self) -> usize {
+        self.max_preview_lines.unwrap_or(10000)
+    }
+
+    pub fn get_resize_debounce(&self) -> Duration {
+        Duration::from_millis(self.resize_debounce_ms.unwrap_or(100))
+    }
+
+    /// How long the main loop waits for an event before checking its timers.
+    /// Lower values cost more CPU, higher ones make slideshows and transitions choppier.
+    pub fn get_poll_interval(&self) -> Duration {
+        Duration::from_millis(
+            self.poll_interval_ms
+                .unwrap_or(50)
+                .clamp(MIN_POLL_INTERVAL_MS, MAX_POLL_INTERVAL_MS),
+        )
+    }
+
+    /// Whether Ctrl+F opens fzf. Unless set, it's on when fzf is installed.
+    pub fn get_fzf_integration(&self) -> bool {
+        self.fzf_integration.unwrap_or_else(fzf::is_installed)
+    }
+
+    pub fn get_logo_file(&self) -> Option<&str> {
+        self.logo_file.as_deref()
+    }
+
+    pub fn get_logo_hidden(&self) -> bool {
+        self.logo_hidden.unwrap_or(false)
+    }
+
+    pub fn get_show_permissions(&self) -> bool {
+        self.show_permissions.unwrap_or(false)
+    }
+
+    /// How long feedback stays in the debug pane, None when it stays until replaced
+    pub fn get_notification_duration(&self) -> Option<Duration> {
+        match self.notification_duration_ms.unwrap_or(0) {
+            0 => None,
+            ms => Some(Duration::from_millis(ms)),
+        }
+    }
+
+    /// Configured file browser widths for (wide, narrow) screens, 5 to 50. None leaves
+    /// that one to the layout's default.
+    pub fn get_file_browser_width_percents(&self) -> (Option<u16>, Option<u16>) {
+        let clamp = |percent: Option<u16>| {
+            percent.or(self.file_browser_width_percent).map(|p| {
+                p.clamp(
+                    MIN_FILE_BROWSER_WIDTH_PERCENT,
+                    MAX_FILE_BROWSER_WIDTH_PERCENT,
+                )
+            })
+        };
+        (
+            clamp(self.file_browser_width_percent_wide),
+            clamp(self.file_browser_width_percent_narrow),
+        )
+    }
+
+    pub fn get_audio_waveform(&self) -> bool {
+        self.audio_waveform.unwrap_or(false)
+    }
+
+    /// The configured character cell aspect ratio, 0.5 to 4.0. None leaves it to be
+    /// measured from the terminal.
+    pub fn get_char_aspect_ratio(&self) -> Option<f32> {
+        self.char_aspect_ratio
+            .filter(|ratio| ratio.is_finite())
+            .map(|ratio| ratio.clamp(MIN_CHAR_ASPECT_RATIO, MAX_CHAR_ASPECT_RATIO))
+    }
+
+    /// The slideshow order; unknown values keep the file list's order
+    pub fn get_slideshow_order(&self) -> SlideshowOrder {
+        match self.slideshow_order.as_deref() {
+            Some("name") => SlideshowOrder::Name,
+            Some("date") => SlideshowOrder::Date,
+            Some("random") => SlideshowOrder::Random,
+            _ => SlideshowOrder::CurrentSort,
+        }
+    }
+
+    /// Previews are cached at sizes rounded down to a multiple of this
+    pub fn get_cache_size_rounding(&self) -> u16 {
+        self.cache_size_rounding.unwrap_or(10).max(1)
+    }
+
+    /// Lines u and Space scroll a text file by, None to scroll half the preview
+    pub fn get_text_scroll_lines(&self) -> Option<usize> {
+        self.text_scroll_lines.filter(|&lines| lines > 0)
+    }
+
+    pub fn get_show_image_counts(&self) -> bool {
+        self.show_image_counts.unwrap_or(false)
+    }
+
+    pub fn get_restore_session(&self) -> bool {
+        self.restore_session.unwrap_or(true)
+    }
+
+    pub fn get_open_with(&self) -> HashMap<String, String> {
+        self.open_with.clone().unwrap_or_default()
+    }
+
+    pub fn get_use_trash(&self) -> bool {
+        self.use_trash.unwrap_or(true)
+    }
+
+    pub fn get_prefer_native_dimension_detection(&self) -> bool {
+        self.prefer_native_dimension_detection.unwrap_or(true)
+    }
+
+    pub fn get_preview_loading_timeout_ms(&self) -> u64 {
+        self.preview_loading_timeout_ms.unwrap_or(30000)
+    }
+
+    /// Keep a slideshow delay within the configured min/max range
+    pub fn clamp_slideshow_delay_ms(&self, delay_ms: u64) -> u64 {
+        delay_ms.clamp(
+            self.get_slideshow_delay_min_ms(),
+            self.get_slideshow_delay_max_ms(),
+        )
+    }
+
+    /// Check for settings that are valid on their own but don't work well together.
+    /// Returns a warning message for each problem found.
+    pub fn validate(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        let transitions = self.get_slideshow_transitions();
+        let delay_ms = self.get_slideshow_delay_ms();
+        if transitions.enabled && transitions.total_duration_ms() > delay_ms {
+            warnings.push(format!(
+                "Slideshow transition ({} frames x {}ms) outlasts the slideshow delay of {}ms",
+                transitions.get_frame_count(),
+                transitions.frame_duration_ms,
+                delay_ms
+            ));
+        }
+
+        if !TRANSITION_EASINGS.contains(&transitions.get_easing()) {
+            warnings.push(format!(
+                "Unknown slideshow_transitions.easing \"{}\", expected one of {}",
+                transitions.get_easing(),
+                TRANSITION_EASINGS.join(", ")
+            ));
+        }
+
+        if let Some(order) = self.slideshow_order.as_deref()
+            && !SLIDESHOW_ORDERS.contains(&order)
+        {
+            warnings.push(format!(
+                "Unknown slideshow_order \"{}\", expected one of {}",
+                order,
+                SLIDESHOW_ORDERS.join(", ")
+            ));
+        }
+
+        warnings
+    }
+
+    /// The settings that differ between two configs, one "name: old → new" line each,
+    /// for telling the user what a reload changed
+    pub fn diff(old: &Self, new: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+        let mut compare = |name: &str, old: &dyn ConfigValue, new: &dyn ConfigValue| {
+            let (old, new) = (old.describe(), new.describe());
+            if old != new {
+                changes.push(format!("{}: {} → {}", name, old, new));
+            }
+        };
+
+        let (converter, new_converter) = (&old.converter, &new.converter);
+        compare(
+            "converter.selected",
+            &converter.selected,
+            &new_converter.selected,
+        );
+        compare(
+            "converter.conversion_timeout_ms",
+            &converter.conversion_timeout_ms,
+            &new_converter.conversion_timeout_ms,
+        );
+        compare(
+            "converter.chafa.format",
+            &converter.chafa.format,
+            &new_converter.chafa.format,
+        );
+        compare(
+            "converter.chafa.colors",
+            &converter.chafa.colors,
+            &new_converter.chafa.colors,
+        );
+        compare(
+            "converter.jp2a.colors",
+            &converter.jp2a.colors,
+            &new_converter.jp2a.colors,
+        );
+        compare(
+            "converter.jp2a.invert",
+            &converter.jp2a.invert,
+            &new_converter.jp2a.invert,
+        );
+        compare(
+            "converter.jp2a.dither",
+            &converter.jp2a.dither,
+            &new_converter.jp2a.dither,
+        );
+        compare(
+            "converter.jp2a.chars",
+            &converter.jp2a.chars,
+            &new_converter.jp2a.chars,
+        );
+        compare(
+            "converter.graphical.filter_type",
+            &converter.graphical.filter_type,
+            &new_converter.graphical.filter_type,
+        );
+        compare(
+            "converter.graphical.max_dimension",
+            &converter.graphical.max_dimension,
+            &new_converter.graphical.max_dimension,
+        );
+        compare(
+            "converter.graphical.auto_resize",
+            &converter.graphical.auto_resize,
+            &new_converter.graphical.auto_resize,
+        );
+        compare(
+            "converter.heic.convert_binary",
+            &converter.heic.convert_binary,
+            &new_converter.heic.convert_binary,
+        );
+        compare(
+            "converter.raw.extractor",
+            &converter.raw.extractor,
+            &new_converter.raw.extractor,
+        );
+        compare(
+            "converter.sixel.dither",
+            &converter.sixel.dither,
+            &new_converter.sixel.dither,
+        );
+        compare(
+            "converter.sixel.palette_size",
+            &converter.sixel.palette_size,
+            &new_converter.sixel.palette_size,
+        );
+        compare(
+            "converter.iterm.use_pixel_dimensions",
+            &converter.iterm.use_pixel_dimensions,
+            &new_converter.iterm.use_pixel_dimensions,
+        );
+
+        compare("locale", &old.locale, &new.locale);
+        compare(
+            "slideshow_delay_ms",
+            &old.slideshow_delay_ms,
+            &new.slideshow_delay_ms,
+        );
+        let (transitions, new_transitions) = (
+            old.get_slideshow_transitions(),
+            new.get_slideshow_transitions(),
+        );
+        compare(
+            "slideshow_transitions.enabled",
+            &transitions.enabled,
+            &new_transitions.enabled,
+        );
+        compare(
+            "slideshow_transitions.effect",
+            &transitions.effect,
+            &new_transitions.effect,
+        );
+        compare(
+            "slideshow_transitions.frame_duration_ms",
+            &transitions.frame_duration_ms,
+            &new_transitions.frame_duration_ms,
+        );
+        compare(
+            "slideshow_transitions.frame_count",
+            &transitions.frame_count,
+            &new_transitions.frame_count,
+        );
+        compare(
+            "slideshow_transitions.direction_aware",
+            &transitions.direction_aware,
+            &new_transitions.direction_aware,
+        );
+        compare(
+            "slideshow_transitions.easing",
+            &transitions.easing,
+            &new_transitions.easing,
+        );
+        compare(
+            "grid_cell_width",
+            &old.grid_cell_width,
+            &new.grid_cell_width,
+        );
+        compare("slideshow_loop", &old.slideshow_loop, &new.slideshow_loop);
+        compare(
+            "slideshow_delay_step_factor",
+            &old.slideshow_delay_step_factor,
+            &new.slideshow_delay_step_factor,
+        );
+        compare(
+            "slideshow_delay_min_ms",
+            &old.slideshow_delay_min_ms,
+            &new.slideshow_delay_min_ms,
+        );
+        compare(
+            "slideshow_delay_max_ms",
+            &old.slideshow_delay_max_ms,
+            &new.slideshow_delay_max_ms,
+        );
+        compare(
+            "preview_loading_timeout_ms",
+            &old.preview_loading_timeout_ms,
+            &new.preview_loading_timeout_ms,
+        );
+        compare(
+            "prefer_native_dimension_detection",
+            &old.prefer_native_dimension_detection,
+            &new.prefer_native_dimension_detection,
+        );
+        compare("use_trash", &old.use_trash, &new.use_trash);
+        compare("open_with", &old.open_with, &new.open_with);
+        compare(
+            "git_integration",
+            &old.git_integration,
+            &new.git_integration,
+        );
+        compare(
+            "watch_current_directory",
+            &old.watch_current_directory,
+            &new.watch_current_directory,
+        );
+        compare(
+            "restore_session",
+            &old.restore_session,
+            &new.restore_session,
+        );
+        compare(
+            "debug_pane_text",
+            &old.debug_pane_text,
+            &new.debug_pane_text,
+        );
+        compare("use_icons", &old.use_icons, &new.use_icons);
+        compare("icon_preset", &old.icon_preset, &new.icon_preset);
+        compare("file_icons", &old.file_icons, &new.file_icons);
+        let (theme, new_theme) = (old.get_theme(), new.get_theme());
+        compare(
+            "theme.selected_file_fg",
+            &theme.selected_file_fg,
+            &new_theme.selected_file_fg,
+        );
+        compare(
+            "theme.selected_file_bg",
+            &theme.selected_file_bg,
+            &new_theme.selected_file_bg,
+        );
+        compare(
+            "theme.directory_fg",
+            &theme.directory_fg,
+            &new_theme.directory_fg,
+        );
+        compare("theme.file_fg", &theme.file_fg, &new_theme.file_fg);
+        compare("theme.image_fg", &theme.image_fg, &new_theme.image_fg);
+        compare("theme.ascii_fg", &theme.ascii_fg, &new_theme.ascii_fg);
+        compare(
+            "theme.unsupported_fg",
+            &theme.unsupported_fg,
+            &new_theme.unsupported_fg,
+        );
+        compare(
+            "show_progress_bar",
+            &old.show_progress_bar,
+            &new.show_progress_bar,
+        );
+        compare(
+            "batch_export_concurrent",
+            &old.batch_export_concurrent,
+            &new.batch_export_concurrent,
+        );
+        compare(
+            "preview_debounce_ms",
+            &old.preview_debounce_ms,
+            &new.preview_debounce_ms,
+        );
+        compare(
+            "max_preview_lines",
+            &old.max_preview_lines,
+            &new.max_preview_lines,
+        );
+        compare(
+            "resize_debounce_ms",
+            &old.resize_debounce_ms,
+            &new.resize_debounce_ms,
+        );
+        compare(
+            "poll_interval_ms",
+            &old.poll_interval_ms,
+            &new.poll_interval_ms,
+        );
+        compare(
+            "fzf_integration",
+            &old.fzf_integration,
+            &new.fzf_integration,
+        );
+        compare(
+            "notification_duration_ms",
+            &old.notification_duration_ms,
+            &new.notification_duration_ms,
+        );
+        compare("audio_waveform", &old.audio_waveform, &new.audio_waveform);
+        compare(
+            "file_browser_width_percent",
+            &old.file_browser_width_percent,
+            &new.file_browser_width_percent,
+        );
+        compare(
+            "file_browser_width_percent_wide",
+            &old.file_browser_width_percent_wide,
+            &new.file_browser_width_percent_wide,
+        );
+        compare(
+            "file_browser_width_percent_narrow",
+            &old.file_browser_width_percent_narrow,
+            &new.file_browser_width_percent_narrow,
+        );
+        compare(
+            "slideshow_order",
+            &old.slideshow_order,
+            &new.slideshow_order,
+        );
+        compare(
+            "char_aspect_ratio",
+            &old.char_aspect_ratio,
+            &new.char_aspect_ratio,
+        );
+        compare(
+            "cache_size_rounding",
+            &old.cache_size_rounding,
+            &new.cache_size_rounding,
+        );
+        compare(
+            "text_scroll_lines",
+            &old.text_scroll_lines,
+            &new.text_scroll_lines,
+        );
+        compare(
+            "show_image_counts",
+            &old.show_image_counts,
+            &new.show_image_counts,
+        );
+        compare(
+            "show_permissions",
+            &old.show_permissions,
+            &new.show_permissions,
+        );
+        compare("logo_file", &old.logo_file, &new.logo_file);
+        compare("logo_hidden", &old.logo_hidden, &new.logo_hidden);
+        compare("chafa", &old.chafa, &new.chafa);
+
+        changes
+    }
+
     pub fn get_config_path() -> Result<PathBuf, Box<dyn Error>> {
         let config_dir = get_config_dir()?;
         Ok(config_dir.join("ptui").join("ptui.json"))
@@ -225,49 +1140,17 @@ impl PTuiConfig {
     -> Result<mpsc::Receiver<Result<PTuiConfig, String>>, Box<dyn Error>> {
         let config_path = Self::get_config_path()?;
         let (tx, rx) = mpsc::channel();
-        let config_path_clone = config_path.clone();
-        let tx_clone = tx.clone();
 
         thread::spawn(move || {
-            let mut watcher =
-                match notify::recommended_watcher(move |res: Result<Event, notify::Error>| {
-                    match res {
-                        Ok(event) => {
-                            // Only react to modify events (file content changes)
-                            if let EventKind::Modify(ModifyKind::Data(_)) = event.kind {
-                                // Small delay to ensure file write is complete
-                                thread::sleep(Duration::from_millis(100));
-
-                                match PTuiConfig::try_reload_from_file(&config_path_clone) {
-                                    Ok(new_config) => {
-                                        if tx_clone.send(Ok(new_config)).is_err() {
-                                            // Channel closed, exit watcher
-                                        }
-                                    }
-                                    Err(e) => {
-                                        if tx_clone
-                                            .send(Err(format!("Failed to reload config: {}", e)))
-                                            .is_err()
-                                        {
-                                            // Channel closed, exit watcher
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            if tx_clone.send(Err(format!("Watch error: {}", e))).is_err() {
-                                // Channel closed, exit watcher
-                            }
-                        }
-                    }
-                }) {
-                    Ok(watcher) => watcher,
-                    Err(e) => {
-                        let _ = tx.send(Err(format!("Failed to create watcher: {}", e)));
-                        return;
-                    }
-                };
+            // Events are handled on this thread so a burst of them can be waited out
+            let (event_tx, event_rx) = mpsc::channel();
+            let mut watcher = match notify::recommended_watcher(event_tx) {
+                Ok(watcher) => watcher,
+                Err(e) => {
+                    let _ = tx.send(Err(format!("Failed to create watcher: {}", e)));
+                    return;
+                }
+            };
 
             // Watch the config directory (not just the file, as editors often replace files)
             if let Some(config_dir) = config_path.parent()
@@ -277,16 +1160,115 @@ impl PTuiConfig {
                 return;
             }
 
-            // Keep the watcher alive by running an infinite loop
-            loop {
-                thread::sleep(Duration::from_secs(1));
-            }
+            // Runs until the app drops its receiver; the watcher has to live as long
+            forward_config_reloads(&event_rx, &config_path, CONFIG_RELOAD_QUIET_PERIOD, &tx);
+            drop(watcher);
         });
 
         Ok(rx)
     }
 }
 
+/// Whether a file system event is a write to the config file itself, rather than
+/// to an editor's swap or backup file next to it
+fn is_config_file_event(event: &Event, config_path: &Path) -> bool {
+    matches!(event.kind, EventKind::Modify(ModifyKind::Data(_)))
+        && event.paths.iter().any(|path| path == config_path)
+}
+
+/// Reload the config once per burst of writes to it. Editors like Vim and Emacs
+/// save in several steps, so this waits until no events have come for quiet_period.
+/// Returns when either channel is closed.
+fn forward_config_reloads(
+    events: &mpsc::Receiver<notify::Result<Event>>,
+    config_path: &Path,
+    quiet_period: Duration,
+    tx: &mpsc::Sender<Result<PTuiConfig, String>>,
+) {
+    while let Ok(result) = events.recv() {
+        let event = match result {
+            Ok(event) => event,
+            Err(e) => {
+                if tx.send(Err(format!("Watch error: {}", e))).is_err() {
+                    return;
+                }
+                continue;
+            }
+        };
+        if !is_config_file_event(&event, config_path) {
+            continue;
+        }
+
+        // Soak up the rest of the burst
+        while events.recv_timeout(quiet_period).is_ok() {}
+
+        let reloaded = PTuiConfig::try_reload_from_file(config_path)
+            .map_err(|e| format!("Failed to reload config: {}", e));
+        if tx.send(reloaded).is_err() {
+            return;
+        }
+    }
+}
+
+/// How a setting is written in a config diff
+trait ConfigValue {
+    fn describe(&self) -> String;
+}
+
+macro_rules! display_config_value {
+    ($($type:ty),*) => {
+        $(impl ConfigValue for $type {
+            fn describe(&self) -> String {
+                self.to_string()
+            }
+        })*
+    };
+}
+
+display_config_value!(String, bool, u8, u16, u32, u64, usize, f32);
+
+impl<T: ConfigValue> ConfigValue for Option<T> {
+    fn describe(&self) -> String {
+        match self {
+            Some(value) => value.describe(),
+            None => "unset".to_string(),
+        }
+    }
+}
+
+impl ConfigValue for HashMap<String, String> {
+    fn describe(&self) -> String {
+        let mut entries: Vec<String> = self
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect();
+        entries.sort();
+        format!("{{{}}}", entries.join(", "))
+    }
+}
+
+impl ConfigValue for ChafaConfig {
+    fn describe(&self) -> String {
+        format!("{}/{}", self.format, self.colors)
+    }
+}
+
+impl ConfigValue for FileIcons {
+    fn describe(&self) -> String {
+        [
+            &self.directory,
+            &self.image,
+            &self.text,
+            &self.video,
+            &self.audio,
+            &self.archive,
+            &self.unknown,
+        ]
+        .map(String::as_str)
+        .join(" ")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -316,6 +1298,7 @@ mod tests {
         assert_eq!(config.selected, "chafa");
         assert_eq!(config.chafa.format, "ansi");
         assert!(config.jp2a.colors);
+        assert_eq!(config.heic.convert_binary, "heif-convert");
     }
 
     #[test]
@@ -345,6 +1328,36 @@ mod tests {
         assert_eq!(config.get_locale(), DEFAULT_LOCALE);
     }
 
+    #[rstest::rstest]
+    #[case(None, Some("de_DE.UTF-8"), Some("de"))]
+    #[case(None, Some("zh_CN.UTF-8"), Some("zh"))]
+    #[case(None, Some("pt_BR.UTF-8"), Some("pt-BR"))]
+    #[case(None, Some("xx_YY.UTF-8"), Some("en"))]
+    #[case(Some("fr_FR:en"), Some("de_DE.UTF-8"), Some("fr"))]
+    #[case(Some(""), Some("ja_JP.UTF-8"), Some("ja"))]
+    #[case(None, Some("C.UTF-8"), Some("en"))]
+    #[case(None, Some("POSIX"), None)]
+    #[case(None, None, None)]
+    fn test_locale_from_env(
+        #[case] language: Option<&str>,
+        #[case] lang: Option<&str>,
+        #[case] expected: Option<&str>,
+    ) {
+        assert_eq!(
+            PTuiConfig::locale_from_env(language, lang).as_deref(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_resolve_locale_prefers_config() {
+        let config = PTuiConfig {
+            locale: Some("ja".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(config.resolve_locale(), "ja");
+    }
+
     #[test]
     fn test_get_slideshow_delay_with_value() {
         let config = PTuiConfig {
@@ -363,6 +1376,563 @@ mod tests {
         assert_eq!(config.get_slideshow_delay_ms(), 2000);
     }
 
+    #[rstest::rstest]
+    #[case(50, 100)]
+    #[case(100, 100)]
+    #[case(2000, 2000)]
+    #[case(30000, 30000)]
+    #[case(45000, 30000)]
+    fn test_clamp_slideshow_delay_ms(#[case] delay_ms: u64, #[case] expected: u64) {
+        let config = PTuiConfig::default();
+        assert_eq!(config.clamp_slideshow_delay_ms(delay_ms), expected);
+    }
+
+    #[test]
+    fn test_slideshow_delay_range_and_step_factor() {
+        let config = PTuiConfig {
+            slideshow_delay_min_ms: Some(500),
+            slideshow_delay_max_ms: Some(200),
+            slideshow_delay_step_factor: Some(0.5),
+            ..Default::default()
+        };
+        // A max below the min collapses the range instead of panicking in clamp
+        assert_eq!(config.clamp_slideshow_delay_ms(100), 500);
+        assert_eq!(config.clamp_slideshow_delay_ms(900), 500);
+        assert_eq!(config.get_slideshow_delay_step_factor(), 1.25);
+    }
+
+    #[rstest::rstest]
+    #[case(None, true)]
+    #[case(Some(true), true)]
+    #[case(Some(false), false)]
+    fn test_get_slideshow_loop(#[case] configured: Option<bool>, #[case] expected: bool) {
+        let config = PTuiConfig {
+            slideshow_loop: configured,
+            ..Default::default()
+        };
+        assert_eq!(config.get_slideshow_loop(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(None, 80)]
+    #[case(Some(0), 0)]
+    #[case(Some(250), 250)]
+    fn test_get_preview_debounce(#[case] configured: Option<u64>, #[case] expected: u64) {
+        let config = PTuiConfig {
+            preview_debounce_ms: configured,
+            ..Default::default()
+        };
+        assert_eq!(
+            config.get_preview_debounce(),
+            Duration::from_millis(expected)
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("nearest", Some(FilterType::Nearest))]
+    #[case("Linear", Some(FilterType::Triangle))]
+    #[case("triangle", Some(FilterType::Triangle))]
+    #[case("cubic", Some(FilterType::CatmullRom))]
+    #[case("gaussian", Some(FilterType::Gaussian))]
+    #[case(" lanczos3 ", Some(FilterType::Lanczos3))]
+    #[case("bicubic-ish", None)]
+    fn test_parse_filter_type(#[case] name: &str, #[case] expected: Option<FilterType>) {
+        assert_eq!(parse_filter_type(name), expected);
+    }
+
+    #[test]
+    fn test_unknown_filter_type_uses_lanczos3() {
+        let graphical = GraphicalConfig {
+            filter_type: "blurry".to_string(),
+            ..GraphicalConfig::default()
+        };
+        assert_eq!(graphical.filter(), FilterType::Lanczos3);
+    }
+
+    #[rstest::rstest]
+    #[case(None, 10000)]
+    #[case(Some(500), 500)]
+    fn test_get_max_preview_lines(#[case] configured: Option<usize>, #[case] expected: usize) {
+        let config = PTuiConfig {
+            max_preview_lines: configured,
+            ..Default::default()
+        };
+        assert_eq!(config.get_max_preview_lines(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(None, 100)]
+    #[case(Some(0), 0)]
+    #[case(Some(500), 500)]
+    fn test_get_resize_debounce(#[case] configured: Option<u64>, #[case] expected: u64) {
+        let config = PTuiConfig {
+            resize_debounce_ms: configured,
+            ..Default::default()
+        };
+        assert_eq!(
+            config.get_resize_debounce(),
+            Duration::from_millis(expected)
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(None, 50)]
+    #[case(Some(1), 10)]
+    #[case(Some(200), 200)]
+    #[case(Some(5000), 500)]
+    fn test_get_poll_interval(#[case] configured: Option<u64>, #[case] expected: u64) {
+        let config = PTuiConfig {
+            poll_interval_ms: configured,
+            ..Default::default()
+        };
+        assert_eq!(config.get_poll_interval(), Duration::from_millis(expected));
+    }
+
+    #[rstest::rstest]
+    #[case(Some(true), true)]
+    #[case(Some(false), false)]
+    fn test_get_fzf_integration(#[case] configured: Option<bool>, #[case] expected: bool) {
+        let config = PTuiConfig {
+            fzf_integration: configured,
+            ..Default::default()
+        };
+        assert_eq!(config.get_fzf_integration(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(None, false)]
+    #[case(Some(true), true)]
+    fn test_get_logo_hidden(#[case] configured: Option<bool>, #[case] expected: bool) {
+        let config = PTuiConfig {
+            logo_hidden: configured,
+            ..Default::default()
+        };
+        assert_eq!(config.get_logo_hidden(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(None, false)]
+    #[case(Some(true), true)]
+    fn test_get_show_permissions(#[case] configured: Option<bool>, #[case] expected: bool) {
+        let config = PTuiConfig {
+            show_permissions: configured,
+            ..Default::default()
+        };
+        assert_eq!(config.get_show_permissions(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(None, 10)]
+    #[case(Some(0), 1)]
+    #[case(Some(1), 1)]
+    #[case(Some(16), 16)]
+    fn test_get_cache_size_rounding(#[case] value: Option<u16>, #[case] expected: u16) {
+        let config = PTuiConfig {
+            cache_size_rounding: value,
+            ..PTuiConfig::default()
+        };
+        assert_eq!(config.get_cache_size_rounding(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(None, None)]
+    #[case(Some(0), None)]
+    #[case(Some(2500), Some(Duration::from_millis(2500)))]
+    fn test_get_notification_duration(
+        #[case] configured: Option<u64>,
+        #[case] expected: Option<Duration>,
+    ) {
+        let config = PTuiConfig {
+            notification_duration_ms: configured,
+            ..Default::default()
+        };
+        assert_eq!(config.get_notification_duration(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(None, None, None, (None, None))]
+    #[case(Some(30), None, None, (Some(30), Some(30)))]
+    #[case(Some(30), Some(20), None, (Some(20), Some(30)))]
+    #[case(None, None, Some(25), (None, Some(25)))]
+    #[case(Some(80), Some(1), None, (Some(5), Some(50)))]
+    fn test_get_file_browser_width_percents(
+        #[case] both: Option<u16>,
+        #[case] wide: Option<u16>,
+        #[case] narrow: Option<u16>,
+        #[case] expected: (Option<u16>, Option<u16>),
+    ) {
+        let config = PTuiConfig {
+            file_browser_width_percent: both,
+            file_browser_width_percent_wide: wide,
+            file_browser_width_percent_narrow: narrow,
+            ..Default::default()
+        };
+        assert_eq!(config.get_file_browser_width_percents(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(None, false)]
+    #[case(Some(true), true)]
+    fn test_get_audio_waveform(#[case] configured: Option<bool>, #[case] expected: bool) {
+        let config = PTuiConfig {
+            audio_waveform: configured,
+            ..Default::default()
+        };
+        assert_eq!(config.get_audio_waveform(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(None, None)]
+    #[case(Some(2.2), Some(2.2))]
+    #[case(Some(0.0), Some(0.5))]
+    #[case(Some(9.0), Some(4.0))]
+    #[case(Some(f32::NAN), None)]
+    fn test_get_char_aspect_ratio(#[case] configured: Option<f32>, #[case] expected: Option<f32>) {
+        let config = PTuiConfig {
+            char_aspect_ratio: configured,
+            ..Default::default()
+        };
+        assert_eq!(config.get_char_aspect_ratio(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(None, SlideshowOrder::CurrentSort, false)]
+    #[case(Some("name"), SlideshowOrder::Name, false)]
+    #[case(Some("date"), SlideshowOrder::Date, false)]
+    #[case(Some("random"), SlideshowOrder::Random, false)]
+    #[case(Some("size"), SlideshowOrder::CurrentSort, true)]
+    fn test_get_slideshow_order(
+        #[case] configured: Option<&str>,
+        #[case] expected: SlideshowOrder,
+        #[case] warns: bool,
+    ) {
+        let config = PTuiConfig {
+            slideshow_order: configured.map(str::to_string),
+            ..Default::default()
+        };
+        assert_eq!(config.get_slideshow_order(), expected);
+        assert_eq!(
+            config
+                .validate()
+                .iter()
+                .any(|warning| warning.contains("slideshow_order")),
+            warns
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(None, None)]
+    #[case(Some(0), None)]
+    #[case(Some(5), Some(5))]
+    fn test_get_text_scroll_lines(#[case] value: Option<usize>, #[case] expected: Option<usize>) {
+        let config = PTuiConfig {
+            text_scroll_lines: value,
+            ..PTuiConfig::default()
+        };
+        assert_eq!(config.get_text_scroll_lines(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(None, false)]
+    #[case(Some(true), true)]
+    fn test_show_image_counts(#[case] value: Option<bool>, #[case] expected: bool) {
+        let config = PTuiConfig {
+            show_image_counts: value,
+            ..PTuiConfig::default()
+        };
+        assert_eq!(config.get_show_image_counts(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(None, 30000)]
+    #[case(Some(500), 500)]
+    fn test_get_preview_loading_timeout_ms(#[case] configured: Option<u64>, #[case] expected: u64) {
+        let config = PTuiConfig {
+            preview_loading_timeout_ms: configured,
+            ..Default::default()
+        };
+        assert_eq!(config.get_preview_loading_timeout_ms(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(None, 20)]
+    #[case(Some(60), 60)]
+    #[case(Some(1), 5)]
+    #[case(Some(500), 120)]
+    fn test_get_transition_frame_count(#[case] configured: Option<u32>, #[case] expected: u32) {
+        let transitions = SlideshowTransitionConfig {
+            frame_count: configured,
+            ..Default::default()
+        };
+        assert_eq!(transitions.get_frame_count(), expected);
+        assert_eq!(transitions.total_duration_ms(), 50 * expected as u64);
+    }
+
+    #[rstest::rstest]
+    #[case(None, "linear", false)]
+    #[case(Some("ease-in-out"), "ease-in-out", false)]
+    #[case(Some("bounce"), "bounce", true)]
+    fn test_transition_easing(
+        #[case] configured: Option<&str>,
+        #[case] expected: &str,
+        #[case] warns: bool,
+    ) {
+        let config = PTuiConfig {
+            slideshow_transitions: Some(SlideshowTransitionConfig {
+                easing: configured.map(str::to_string),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(config.get_slideshow_transitions().get_easing(), expected);
+        assert_eq!(
+            config
+                .validate()
+                .iter()
+                .any(|warning| warning.contains("easing")),
+            warns
+        );
+    }
+
+    #[test]
+    fn test_validate_warns_when_transition_outlasts_slide() {
+        let mut config = PTuiConfig {
+            slideshow_delay_ms: Some(2000),
+            slideshow_transitions: Some(SlideshowTransitionConfig {
+                enabled: true,
+                frame_count: Some(60),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        // 60 frames x 50ms = 3000ms
+        assert_eq!(config.validate().len(), 1);
+
+        config.slideshow_delay_ms = Some(5000);
+        assert!(config.validate().is_empty());
+        assert!(PTuiConfig::default().validate().is_empty());
+    }
+
+    #[test]
+    fn test_get_open_with() {
+        assert!(
+            PTuiConfig {
+                open_with: None,
+                ..Default::default()
+            }
+            .get_open_with()
+            .is_empty()
+        );
+
+        let config = PTuiConfig {
+            open_with: Some(HashMap::from([("jpg".to_string(), "feh".to_string())])),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.get_open_with().get("jpg").map(String::as_str),
+            Some("feh")
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(None, false)]
+    #[case(Some(true), true)]
+    fn test_get_watch_current_directory(#[case] configured: Option<bool>, #[case] expected: bool) {
+        let config = PTuiConfig {
+            watch_current_directory: configured,
+            ..Default::default()
+        };
+        assert_eq!(config.get_watch_current_directory(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(None, false)]
+    #[case(Some(true), true)]
+    fn test_get_git_integration(#[case] configured: Option<bool>, #[case] expected: bool) {
+        let config = PTuiConfig {
+            git_integration: configured,
+            ..Default::default()
+        };
+        assert_eq!(config.get_git_integration(), expected);
+    }
+
+    #[test]
+    fn test_display_summarises_config() {
+        let config = PTuiConfig {
+            locale: Some("de".to_string()),
+            ..PTuiConfig::default()
+        };
+        let summary = config.to_summary_string();
+        assert_eq!(
+            summary,
+            "Converter: chafa (format=ansi, colors=full)\n\
+             Locale: de\n\
+             Slideshow delay: 2000ms\n\
+             Transitions: disabled (effect=scattering)"
+        );
+        assert!(!summary.contains('{') && !summary.contains('}'));
+    }
+
+    #[rstest::rstest]
+    #[case("jp2a", "Converter: jp2a (colors=true, invert=false, chars=default)")]
+    #[case(
+        "graphical",
+        "Converter: graphical (filter=lanczos3, max_dimension=auto)"
+    )]
+    #[case("sixel", "Converter: sixel")]
+    fn test_display_shows_selected_converter(#[case] selected: &str, #[case] expected: &str) {
+        let mut config = PTuiConfig::default();
+        config.converter.selected = selected.to_string();
+        assert_eq!(config.to_string().lines().next(), Some(expected));
+    }
+
+    #[test]
+    fn test_diff_lists_changed_fields() {
+        let old = PTuiConfig::default();
+        let mut new = old.clone();
+        assert!(PTuiConfig::diff(&old, &new).is_empty());
+
+        new.converter.selected = "jp2a".to_string();
+        new.slideshow_delay_ms = Some(3000);
+        new.converter.jp2a.chars = Some("@#".to_string());
+        new.locale = None;
+        assert_eq!(
+            PTuiConfig::diff(&old, &new),
+            [
+                "converter.selected: chafa → jp2a",
+                "converter.jp2a.chars: unset → @#",
+                "locale: en → unset",
+                "slideshow_delay_ms: 2000 → 3000",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_open_with_and_transitions() {
+        let old = PTuiConfig::default();
+        let mut new = old.clone();
+        new.open_with = Some(HashMap::from([
+            ("png".to_string(), "gimp".to_string()),
+            ("jpg".to_string(), "feh".to_string()),
+        ]));
+        new.slideshow_transitions = None;
+        new.converter.graphical.max_dimension = 512;
+
+        assert_eq!(
+            PTuiConfig::diff(&old, &new),
+            [
+                "converter.graphical.max_dimension: 384 → 512",
+                "open_with: {} → {jpg=feh, png=gimp}",
+            ]
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(None, None)]
+    #[case(Some(""), None)]
+    #[case(Some("notes"), Some("notes"))]
+    fn test_get_debug_pane_text(#[case] configured: Option<&str>, #[case] expected: Option<&str>) {
+        let config = PTuiConfig {
+            debug_pane_text: configured.map(str::to_string),
+            ..Default::default()
+        };
+        assert_eq!(config.get_debug_pane_text(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(None, true)]
+    #[case(Some(false), false)]
+    fn test_get_restore_session(#[case] configured: Option<bool>, #[case] expected: bool) {
+        let config = PTuiConfig {
+            restore_session: configured,
+            ..Default::default()
+        };
+        assert_eq!(config.get_restore_session(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(None, true)]
+    #[case(Some(false), false)]
+    fn test_get_show_progress_bar(#[case] configured: Option<bool>, #[case] expected: bool) {
+        let config = PTuiConfig {
+            show_progress_bar: configured,
+            ..Default::default()
+        };
+        assert_eq!(config.get_show_progress_bar(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(None, 1)]
+    #[case(Some(0), 1)]
+    #[case(Some(4), 4)]
+    fn test_get_batch_export_concurrent(#[case] configured: Option<u8>, #[case] expected: u8) {
+        let config = PTuiConfig {
+            batch_export_concurrent: configured,
+            ..Default::default()
+        };
+        assert_eq!(config.get_batch_export_concurrent(), expected);
+    }
+
+    #[test]
+    fn test_get_theme_keeps_defaults_for_missing_fields() {
+        let config = PTuiConfig {
+            theme: Some(serde_json::from_str(r##"{"directory_fg": "#ff0000"}"##).unwrap()),
+            ..Default::default()
+        };
+        let theme = config.get_theme();
+        assert_eq!(theme.directory_fg, "#ff0000");
+        assert_eq!(
+            theme.selected_file_fg,
+            ThemeConfig::default().selected_file_fg
+        );
+        assert_eq!(PTuiConfig::default().get_theme(), ThemeConfig::default());
+    }
+
+    #[rstest::rstest]
+    #[case(None, None, FileIcons::default())]
+    #[case(Some(false), None, FileIcons::ascii())]
+    #[case(Some(false), Some("nerd-fonts"), FileIcons::ascii())]
+    #[case(Some(true), Some("nerd-fonts"), FileIcons::nerd_fonts())]
+    #[case(Some(true), Some("unknown"), FileIcons::default())]
+    fn test_get_file_icons(
+        #[case] use_icons: Option<bool>,
+        #[case] preset: Option<&str>,
+        #[case] expected: FileIcons,
+    ) {
+        let config = PTuiConfig {
+            use_icons,
+            icon_preset: preset.map(str::to_string),
+            ..Default::default()
+        };
+        assert_eq!(config.get_file_icons(), expected);
+    }
+
+    #[test]
+    fn test_get_file_icons_custom_overrides_preset() {
+        let custom = FileIcons {
+            directory: "dir".to_string(),
+            ..FileIcons::default()
+        };
+        let config = PTuiConfig {
+            icon_preset: Some("nerd-fonts".to_string()),
+            file_icons: Some(custom.clone()),
+            ..Default::default()
+        };
+        assert_eq!(config.get_file_icons(), custom);
+    }
+
+    #[rstest::rstest]
+    #[case(None, 18)]
+    #[case(Some(24), 24)]
+    #[case(Some(2), 8)]
+    fn test_get_grid_cell_width(#[case] configured: Option<u16>, #[case] expected: u16) {
+        let config = PTuiConfig {
+            grid_cell_width: configured,
+            ..Default::default()
+        };
+        assert_eq!(config.get_grid_cell_width(), expected);
+    }
+
     #[test]
     fn test_config_serialization() {
         let config = create_test_config();
@@ -386,6 +1956,51 @@ mod tests {
         assert_file_exists(&config_path.to_string_lossy());
     }
 
+    fn write_event(path: &Path) -> notify::Result<Event> {
+        Ok(Event::new(EventKind::Modify(ModifyKind::Data(
+            notify::event::DataChange::Content,
+        )))
+        .add_path(path.to_path_buf()))
+    }
+
+    #[test]
+    fn test_config_watcher_reloads_once_per_burst() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("ptui.json");
+        PTuiConfig::save_config(&config_path, &PTuiConfig::default()).unwrap();
+
+        let (event_tx, event_rx) = mpsc::channel();
+        for _ in 0..5 {
+            event_tx.send(write_event(&config_path)).unwrap();
+        }
+        drop(event_tx);
+
+        let (tx, rx) = mpsc::channel();
+        forward_config_reloads(&event_rx, &config_path, Duration::from_millis(20), &tx);
+        let reloads: Vec<_> = rx.try_iter().collect();
+        assert_eq!(reloads.len(), 1);
+        assert!(reloads[0].is_ok());
+    }
+
+    #[test]
+    fn test_config_watcher_ignores_other_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("ptui.json");
+        PTuiConfig::save_config(&config_path, &PTuiConfig::default()).unwrap();
+
+        let (event_tx, event_rx) = mpsc::channel();
+        for name in [".ptui.json.swp", "#ptui.json#", "ptui.json~"] {
+            event_tx
+                .send(write_event(&temp_dir.path().join(name)))
+                .unwrap();
+        }
+        drop(event_tx);
+
+        let (tx, rx) = mpsc::channel();
+        forward_config_reloads(&event_rx, &config_path, Duration::from_millis(20), &tx);
+        assert_eq!(rx.try_iter().count(), 0);
+    }
+
     #[test]
     fn test_save_and_load_config() {
         let temp_dir = TempDir::new().unwrap();
@@ -399,6 +2014,43 @@ mod tests {
             locale: Some("de".to_string()),
             slideshow_delay_ms: Some(3000),
             slideshow_transitions: Some(SlideshowTransitionConfig::default()),
+            grid_cell_width: None,
+            slideshow_loop: None,
+            slideshow_delay_step_factor: None,
+            slideshow_delay_min_ms: None,
+            slideshow_delay_max_ms: None,
+            preview_loading_timeout_ms: Some(30000),
+            prefer_native_dimension_detection: Some(true),
+            use_trash: Some(true),
+            open_with: Some(std::collections::HashMap::new()),
+            git_integration: Some(false),
+            watch_current_directory: Some(false),
+            restore_session: None,
+            debug_pane_text: None,
+            use_icons: None,
+            icon_preset: None,
+            file_icons: None,
+            theme: None,
+            show_progress_bar: None,
+            batch_export_concurrent: None,
+            preview_debounce_ms: None,
+            max_preview_lines: Some(10000),
+            resize_debounce_ms: Some(100),
+            show_image_counts: Some(false),
+            poll_interval_ms: Some(50),
+            fzf_integration: None,
+            logo_file: None,
+            logo_hidden: Some(false),
+            show_permissions: Some(false),
+            notification_duration_ms: Some(0),
+            audio_waveform: Some(false),
+            file_browser_width_percent: None,
+            file_browser_width_percent_wide: None,
+            file_browser_width_percent_narrow: None,
+            char_aspect_ratio: None,
+            slideshow_order: None,
+            text_scroll_lines: None,
+            cache_size_rounding: None,
             chafa: None,
         };
 
@@ -457,6 +2109,8 @@ mod tests {
         assert_eq!(config.converter.chafa.colors, "256");
         assert_eq!(config.locale, Some("ja".to_string()));
         assert_eq!(config.slideshow_delay_ms, Some(1500));
+        // Configs written before HEIC support still load with the default decoder
+        assert_eq!(config.converter.heic.convert_binary, "heif-convert");
     }
 
     #[test]
@@ -1,8 +1,22 @@
-use crate::config::{ChafaConfig, Jp2aConfig, PTuiConfig};
+#[cfg(feature = "sixel-converter")]
+use crate::config::SixelConfig;
+use crate::config::{ChafaConfig, HeicConfig, ITermConfig, Jp2aConfig, PTuiConfig, RawConfig};
+#[cfg(feature = "sixel-converter")]
+use crate::fast_image_loader::FastImageLoader;
+use crate::preview::TempFileGuard;
+use base64::Engine;
 #[cfg(not(test))]
 use ratatui_image::picker::Picker;
 use ratatui_image::picker::ProtocolType;
-use std::process::Command;
+use std::io::{self, Read, Write};
+use std::process::{Command, Output, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const DEFAULT_CONVERSION_TIMEOUT: Duration = Duration::from_millis(10000);
+
+/// Every converter the config's "selected" can name
+pub const CONVERTER_NAMES: &[&str] = &["chafa", "jp2a", "graphical", "sixel", "iterm"];
 
 pub trait AsciiConverter {
     fn convert_image(&self, path: &str, width: u16, height: u16) -> Result<String, String>;
@@ -11,27 +25,43 @@ pub trait AsciiConverter {
     fn is_graphical(&self) -> bool {
         false
     }
+    /// The output is an image escape sequence, written to the terminal as-is once the
+    /// frame is drawn rather than shown as text
+    fn writes_escape_sequence(&self) -> bool {
+        false
+    }
 }
 
 pub struct ChafaConverter {
     config: ChafaConfig,
+    timeout: Duration,
 }
 
 impl ChafaConverter {
     pub fn new(mut config: ChafaConfig) -> Self {
         // macOS Terminal.app doesn't support true color (24-bit), only 256 colors
         // Detect and adjust to prevent rendering artifacts
-        if let Ok(term_program) = std::env::var("TERM_PROGRAM") {
-            if term_program.contains("Apple_Terminal") && config.colors == "full" {
-                #[cfg(not(test))]
-                eprintln!(
-                    "[CHAFA] Detected macOS Terminal.app - switching from 'full' to '256' colors for compatibility"
-                );
-                config.colors = "256".to_string();
-            }
+        if let Ok(term_program) = std::env::var("TERM_PROGRAM")
+            && term_program.contains("Apple_Terminal")
+            && config.colors == "full"
+        {
+            #[cfg(not(test))]
+            eprintln!(
+                "[CHAFA] Detected macOS Terminal.app - switching from 'full' to '256' colors for compatibility"
+            );
+            config.colors = "256".to_string();
         }
 
-        Self { config }
+        Self {
+            config,
+            timeout: DEFAULT_CONVERSION_TIMEOUT,
+        }
+    }
+
+    /// Abort conversions that take longer than this
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
     }
 }
 
@@ -47,7 +77,9 @@ impl AsciiConverter for ChafaConverter {
             path.to_string(),
         ];
 
-        match Command::new("chafa").args(&args).output() {
+        let mut command = Command::new("chafa");
+        command.args(&args);
+        match run_with_timeout(&mut command, self.timeout) {
             Ok(output) => {
                 if output.status.success() {
                     Ok(String::from_utf8_lossy(&output.stdout).to_string())
@@ -58,7 +90,7 @@ impl AsciiConverter for ChafaConverter {
                     ))
                 }
             }
-            Err(e) => Err(format!("Failed to execute chafa: {}", e)),
+            Err(e) => Err(e),
         }
     }
 
@@ -75,16 +107,26 @@ impl AsciiConverter for ChafaConverter {
 
 pub struct Jp2aConverter {
     config: Jp2aConfig,
+    timeout: Duration,
 }
 
 impl Jp2aConverter {
     pub fn new(config: Jp2aConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            timeout: DEFAULT_CONVERSION_TIMEOUT,
+        }
+    }
+
+    /// Abort conversions that take longer than this
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
     }
 }
 
-impl AsciiConverter for Jp2aConverter {
-    fn convert_image(&self, path: &str, width: u16, height: u16) -> Result<String, String> {
+impl Jp2aConverter {
+    fn args(&self, path: &str, width: u16, height: u16) -> Vec<String> {
         let mut args = vec![];
 
         // jp2a uses --size=WxH format (note the equals sign)
@@ -106,8 +148,15 @@ impl AsciiConverter for Jp2aConverter {
         }
 
         args.push(path.to_string());
+        args
+    }
+}
 
-        match Command::new("jp2a").args(&args).output() {
+impl AsciiConverter for Jp2aConverter {
+    fn convert_image(&self, path: &str, width: u16, height: u16) -> Result<String, String> {
+        let mut command = Command::new("jp2a");
+        command.args(self.args(path, width, height));
+        match run_with_timeout(&mut command, self.timeout) {
             Ok(output) => {
                 if output.status.success() {
                     Ok(String::from_utf8_lossy(&output.stdout).to_string())
@@ -118,7 +167,7 @@ impl AsciiConverter for Jp2aConverter {
                     ))
                 }
             }
-            Err(e) => Err(format!("Failed to execute jp2a: {}", e)),
+            Err(e) => Err(e),
         }
     }
 
@@ -145,7 +194,7 @@ impl GraphicalConverter {
         #[cfg(test)]
         {
             let _ = fallback_config; // Suppress unused warning
-            return Err("GraphicalConverter cannot be created during tests".to_string());
+            Err("GraphicalConverter cannot be created during tests".to_string())
         }
 
         #[cfg(not(test))]
@@ -197,9 +246,444 @@ impl AsciiConverter for GraphicalConverter {
     }
 }
 
+/// Pixels per terminal cell when sizing images in pixels, a typical cell is about
+/// twice as tall as it is wide
+const CELL_PIXELS: (u32, u32) = (10, 20);
+
+/// Encodes images as Sixel graphics without an external binary. The image is reduced
+/// to an evenly spaced colour cube of up to palette_size colours.
+#[cfg(feature = "sixel-converter")]
+pub struct SixelConverter {
+    config: SixelConfig,
+}
+
+#[cfg(feature = "sixel-converter")]
+impl SixelConverter {
+    pub fn new(config: SixelConfig) -> Self {
+        Self { config }
+    }
+
+    /// Levels per channel for the colour cube, the largest cube that fits the palette
+    fn levels(&self) -> u32 {
+        let palette_size = self.config.palette_size.clamp(2, 256) as u32;
+        (2..=palette_size)
+            .take_while(|levels| levels * levels * levels <= palette_size)
+            .last()
+            .unwrap_or(2)
+    }
+
+    /// Encode RGB pixels, row by row, as a complete Sixel sequence
+    pub fn encode(&self, pixels: &[[u8; 3]], width: u32, height: u32) -> String {
+        let levels = self.levels();
+        let step = 255.0 / (levels - 1) as f32;
+        let indices = self.quantize(pixels, width, height, levels, step);
+        let palette_len = (levels * levels * levels) as usize;
+
+        let mut out = String::from("\x1bP0;1q");
+        out.push_str(&format!("\"1;1;{};{}", width, height));
+        for index in 0..palette_len {
+            let [r, g, b] = cube_color(index, levels, step);
+            let percent = |channel: u8| channel as u32 * 100 / 255;
+            out.push_str(&format!(
+                "#{};2;{};{};{}",
+                index,
+                percent(r),
+                percent(g),
+                percent(b)
+            ));
+        }
+
+        let (width, height) = (width as usize, height as usize);
+        for band_top in (0..height).step_by(6) {
+            let band_rows = (height - band_top).min(6);
+            let mut used = vec![false; palette_len];
+            for row in band_top..band_top + band_rows {
+                for &index in &indices[row * width..(row + 1) * width] {
+                    used[index] = true;
+                }
+            }
+
+            for color in (0..palette_len).filter(|&color| used[color]) {
+                out.push_str(&format!("#{}", color));
+                let columns = (0..width).map(|x| {
+                    let bits = (0..band_rows)
+                        .filter(|&dy| indices[(band_top + dy) * width + x] == color)
+                        .fold(0u8, |bits, dy| bits | (1 << dy));
+                    (0x3f + bits) as char
+                });
+                push_run_length(&mut out, columns);
+                out.push('$');
+            }
+            out.push('-');
+        }
+        out.push_str("\x1b\\");
+        out
+    }
+
+    /// Map each pixel to a palette index, spreading the error to its neighbours
+    /// when dithering
+    fn quantize(
+        &self,
+        pixels: &[[u8; 3]],
+        width: u32,
+        height: u32,
+        levels: u32,
+        step: f32,
+    ) -> Vec<usize> {
+        let dither = self.config.dither == "floyd-steinberg";
+        let (width, height) = (width as usize, height as usize);
+        let mut errors = vec![[0.0f32; 3]; pixels.len()];
+        let mut indices = Vec::with_capacity(pixels.len());
+
+        for y in 0..height {
+            for x in 0..width {
+                let i = y * width + x;
+                let mut index = 0;
+                let mut diff = [0.0f32; 3];
+                for channel in 0..3 {
+                    let value = (pixels[i][channel] as f32 + errors[i][channel]).clamp(0.0, 255.0);
+                    let level = (value / step).round();
+                    index = index * levels as usize + level as usize;
+                    diff[channel] = value - level * step;
+                }
+                indices.push(index);
+
+                if dither {
+                    let mut spread = |dx: isize, dy: usize, weight: f32| {
+                        let nx = x as isize + dx;
+                        if nx >= 0 && (nx as usize) < width && y + dy < height {
+                            let target = &mut errors[(y + dy) * width + nx as usize];
+                            for channel in 0..3 {
+                                target[channel] += diff[channel] * weight;
+                            }
+                        }
+                    };
+                    spread(1, 0, 7.0 / 16.0);
+                    spread(-1, 1, 3.0 / 16.0);
+                    spread(0, 1, 5.0 / 16.0);
+                    spread(1, 1, 1.0 / 16.0);
+                }
+            }
+        }
+        indices
+    }
+}
+
+/// The colour at a palette index of a cube with the given levels per channel
+#[cfg(feature = "sixel-converter")]
+fn cube_color(index: usize, levels: u32, step: f32) -> [u8; 3] {
+    let levels = levels as usize;
+    let level = |divisor: usize| ((index / divisor % levels) as f32 * step).round() as u8;
+    [level(levels * levels), level(levels), level(1)]
+}
+
+/// Write sixel characters, using the !count repeat form for runs longer than three
+#[cfg(feature = "sixel-converter")]
+fn push_run_length(out: &mut String, columns: impl Iterator<Item = char>) {
+    let mut run: Option<(char, usize)> = None;
+    let flush = |out: &mut String, run: Option<(char, usize)>| match run {
+        Some((c, count)) if count > 3 => out.push_str(&format!("!{}{}", count, c)),
+        Some((c, count)) => out.extend(std::iter::repeat_n(c, count)),
+        None => {}
+    };
+    for c in columns {
+        match run {
+            Some((current, ref mut count)) if current == c => *count += 1,
+            _ => {
+                flush(out, run);
+                run = Some((c, 1));
+            }
+        }
+    }
+    flush(out, run);
+}
+
+#[cfg(feature = "sixel-converter")]
+impl AsciiConverter for SixelConverter {
+    fn convert_image(&self, path: &str, width: u16, height: u16) -> Result<String, String> {
+        let max_width = (width as u32 * CELL_PIXELS.0).max(1);
+        let max_height = (height as u32 * CELL_PIXELS.1).max(1);
+        let image = FastImageLoader::load_for_display(path, max_width.max(max_height))?;
+        let image = image
+            .resize(max_width, max_height, image::imageops::FilterType::Triangle)
+            .to_rgb8();
+
+        let pixels: Vec<[u8; 3]> = image.pixels().map(|pixel| pixel.0).collect();
+        Ok(self.encode(&pixels, image.width(), image.height()))
+    }
+
+    fn get_name(&self) -> &'static str {
+        "sixel"
+    }
+
+    fn supports_transitions(&self) -> bool {
+        // Sixel output is pixels, not characters the transitions can move around
+        false
+    }
+
+    fn is_graphical(&self) -> bool {
+        true
+    }
+
+    fn writes_escape_sequence(&self) -> bool {
+        true
+    }
+}
+
+/// Sends the image file itself to iTerm2 with its inline image protocol, which decodes
+/// and scales it in the terminal
+pub struct ITermConverter {
+    config: ITermConfig,
+}
+
+impl ITermConverter {
+    pub fn new(config: ITermConfig) -> Self {
+        Self { config }
+    }
+
+    /// Wrap image bytes in the inline image escape, sized to fit width x height cells
+    pub fn encode(&self, bytes: &[u8], width: u16, height: u16) -> String {
+        let (width, height) = if self.config.use_pixel_dimensions {
+            (
+                format!("{}px", width as u32 * CELL_PIXELS.0),
+                format!("{}px", height as u32 * CELL_PIXELS.1),
+            )
+        } else {
+            (width.to_string(), height.to_string())
+        };
+        format!(
+            "\x1b]1337;File=inline=1;width={};height={}:{}\x07",
+            width,
+            height,
+            base64::engine::general_purpose::STANDARD.encode(bytes)
+        )
+    }
+}
+
+impl AsciiConverter for ITermConverter {
+    fn convert_image(&self, path: &str, width: u16, height: u16) -> Result<String, String> {
+        let bytes = std::fs::read(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        Ok(self.encode(&bytes, width, height))
+    }
+
+    fn get_name(&self) -> &'static str {
+        "iterm"
+    }
+
+    fn supports_transitions(&self) -> bool {
+        // iTerm2 draws the image itself, there are no characters to transition
+        false
+    }
+
+    fn is_graphical(&self) -> bool {
+        true
+    }
+
+    fn writes_escape_sequence(&self) -> bool {
+        true
+    }
+}
+
+/// Whether the terminal is iTerm2, from the variables it sets (LC_TERMINAL also
+/// survives ssh and tmux)
+pub fn is_iterm2() -> bool {
+    is_iterm2_env(
+        std::env::var("TERM_PROGRAM").ok().as_deref(),
+        std::env::var("LC_TERMINAL").ok().as_deref(),
+    )
+}
+
+/// What to tell the user when create_converter can't use the selected converter in
+/// this terminal and falls back to chafa
+pub fn fallback_notice(selected: &str, iterm2: bool) -> Option<String> {
+    (selected == "iterm" && !iterm2)
+        .then(|| "iTerm2 not detected. Falling back to chafa.".to_string())
+}
+
+fn is_iterm2_env(term_program: Option<&str>, lc_terminal: Option<&str>) -> bool {
+    term_program == Some("iTerm.app") || lc_terminal == Some("iTerm2")
+}
+
+/// Decodes HEIC/HEIF images to JPEG so the regular image pipeline can show them
+pub struct HeicConverter {
+    config: HeicConfig,
+}
+
+impl HeicConverter {
+    pub fn new(config: HeicConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn binary(&self) -> &str {
+        &self.config.convert_binary
+    }
+
+    /// heif-convert has no version flag, so only check that it can be launched
+    pub fn is_available(&self) -> bool {
+        Command::new(&self.config.convert_binary)
+            .arg("--help")
+            .output()
+            .is_ok()
+    }
+
+    /// Decode the image to a temporary JPEG that is removed when the guard is dropped
+    pub fn convert_to_jpeg(&self, path: &str) -> Result<TempFileGuard, String> {
+        let jpeg = TempFileGuard::new(TempFileGuard::path_for("ptui", path, "jpg"));
+
+        match Command::new(&self.config.convert_binary)
+            .arg(path)
+            .arg(jpeg.path())
+            .output()
+        {
+            Ok(output) if output.status.success() && jpeg.path().exists() => Ok(jpeg),
+            Ok(output) => Err(format!(
+                "{} error: {}",
+                self.config.convert_binary,
+                String::from_utf8_lossy(&output.stderr)
+            )),
+            Err(e) => Err(format!(
+                "Failed to execute {}: {}",
+                self.config.convert_binary, e
+            )),
+        }
+    }
+}
+
+/// Character ramps offered by the charset picker, darkest first. The default leaves
+/// jp2a to use its own.
+pub const JP2A_CHARSETS: &[(&str, Option<&str>)] = &[
+    ("default", None),
+    ("braille", Some("⠀⣀⣄⣤⣦⣶⣷⣿")),
+    ("blocks", Some("░▒▓█")),
+    ("binary", Some("01")),
+    ("hex", Some("0123456789ABCDEF")),
+];
+
+/// Name of a jp2a charset for display: the preset it matches, otherwise "custom"
+pub fn jp2a_charset_name(chars: Option<&str>) -> &'static str {
+    JP2A_CHARSETS
+        .iter()
+        .find(|(_, preset)| *preset == chars)
+        .map_or("custom", |(name, _)| name)
+}
+
+/// State of the jp2a charset dialog shown by Alt+C. After the presets comes a custom
+/// entry, whose characters are typed in while `custom_input` is set.
+pub struct CharsetPicker {
+    pub selected: usize,
+    pub custom_input: Option<String>,
+    // The custom charset in use when the dialog opened, to start editing from
+    custom: String,
+}
+
+impl CharsetPicker {
+    pub fn new(current: Option<&str>) -> Self {
+        let selected = JP2A_CHARSETS
+            .iter()
+            .position(|(_, preset)| *preset == current)
+            .unwrap_or(JP2A_CHARSETS.len());
+        let custom = if selected == JP2A_CHARSETS.len() {
+            current.unwrap_or_default().to_string()
+        } else {
+            String::new()
+        };
+        Self {
+            selected,
+            custom_input: None,
+            custom,
+        }
+    }
+
+    pub fn move_down(&mut self) {
+        if self.selected < JP2A_CHARSETS.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn move_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn is_custom_selected(&self) -> bool {
+        self.selected == JP2A_CHARSETS.len()
+    }
+
+    /// Start typing a custom charset, beginning from the one already in use
+    pub fn start_custom_input(&mut self) {
+        self.custom_input = Some(self.custom.clone());
+    }
+
+    /// The charset to apply for the selected preset, None meaning jp2a's default
+    pub fn selected_preset(&self) -> Option<String> {
+        JP2A_CHARSETS
+            .get(self.selected)
+            .and_then(|(_, chars)| chars.map(str::to_string))
+    }
+}
+
+/// Tools that can get a JPEG out of a RAW camera file, tried after the configured one
+const RAW_EXTRACTORS: &[&str] = &["dcraw", "darktable-cli"];
+
+/// Gets a JPEG out of RAW camera files so the regular image pipeline can show them
+pub struct RawConverter {
+    config: RawConfig,
+}
+
+impl RawConverter {
+    pub fn new(config: RawConfig) -> Self {
+        Self { config }
+    }
+
+    /// The configured extractor if it can be launched, otherwise the first known one that can
+    pub fn available_extractor(&self) -> Option<String> {
+        std::iter::once(self.config.extractor.as_str())
+            .chain(RAW_EXTRACTORS.iter().copied())
+            .find(|binary| Command::new(binary).arg("--version").output().is_ok())
+            .map(str::to_string)
+    }
+
+    /// Write a JPEG of the RAW file to a temporary file that is removed when the guard
+    /// is dropped. dcraw copies out the camera's embedded thumbnail; other extractors
+    /// are run like darktable-cli, with the input and output paths.
+    /// The extractor is killed if it runs longer than timeout.
+    pub fn extract_jpeg(
+        extractor: &str,
+        path: &str,
+        timeout: Duration,
+    ) -> Result<TempFileGuard, String> {
+        let jpeg = TempFileGuard::new(TempFileGuard::path_for("ptui_raw", path, "jpg"));
+
+        let mut command = Command::new(extractor);
+        if extractor == "dcraw" {
+            command.args(["-c", "-e", path]);
+        } else {
+            command.arg(path).arg(jpeg.path());
+        }
+        let output = run_with_timeout(&mut command, timeout)?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "{} error: {}",
+                extractor,
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+        if extractor == "dcraw" {
+            std::fs::write(jpeg.path(), &output.stdout)
+                .map_err(|e| format!("Failed to write thumbnail: {}", e))?;
+        }
+        if !jpeg.path().exists() {
+            return Err(format!("{} didn't write an image", extractor));
+        }
+        Ok(jpeg)
+    }
+}
+
 pub fn create_converter(config: &PTuiConfig) -> Box<dyn AsciiConverter> {
+    let timeout = Duration::from_millis(config.converter.get_conversion_timeout_ms());
+    let chafa = || ChafaConverter::new(config.converter.chafa.clone()).with_timeout(timeout);
     match config.converter.selected.as_str() {
-        "jp2a" => Box::new(Jp2aConverter::new(config.converter.jp2a.clone())),
+        "jp2a" => Box::new(Jp2aConverter::new(config.converter.jp2a.clone()).with_timeout(timeout)),
         "graphical" => {
             match GraphicalConverter::new(config.converter.chafa.clone()) {
                 Ok(converter) => {
@@ -217,15 +701,69 @@ pub fn create_converter(config: &PTuiConfig) -> Box<dyn AsciiConverter> {
                     );
                     #[cfg(test)]
                     let _ = e; // Suppress unused warning in tests
-                    Box::new(ChafaConverter::new(config.converter.chafa.clone()))
+                    Box::new(chafa())
                 }
             }
         }
-        "chafa" => Box::new(ChafaConverter::new(config.converter.chafa.clone())),
-        _ => Box::new(ChafaConverter::new(config.converter.chafa.clone())), // Default to chafa
+        #[cfg(feature = "sixel-converter")]
+        "sixel" => Box::new(SixelConverter::new(config.converter.sixel.clone())),
+        "iterm" if is_iterm2() => Box::new(ITermConverter::new(config.converter.iterm.clone())),
+        // Outside iTerm2, the preview pane reports the fallback from fallback_notice
+        "iterm" => Box::new(chafa()),
+        "chafa" => Box::new(chafa()),
+        _ => Box::new(chafa()), // Default to chafa
     }
 }
 
+/// Run a command like Command::output(), but kill it if it runs longer than timeout.
+/// Spawn failures keep the "Failed to execute" wording callers already show.
+pub fn run_with_timeout(command: &mut Command, timeout: Duration) -> Result<Output, String> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to execute {}: {}", program, e))?;
+
+    // Drain the pipes while waiting, large outputs would otherwise block the child
+    let stdout_reader = read_pipe(child.stdout.take());
+    let stderr_reader = read_pipe(child.stderr.take());
+
+    let started = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if started.elapsed() >= timeout => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!(
+                    "Conversion timed out after {}ms",
+                    timeout.as_millis()
+                ));
+            }
+            Ok(None) => thread::sleep(Duration::from_millis(5)),
+            Err(e) => return Err(format!("Failed to execute {}: {}", program, e)),
+        }
+    };
+
+    Ok(Output {
+        status,
+        stdout: stdout_reader.join().unwrap_or_default(),
+        stderr: stderr_reader.join().unwrap_or_default(),
+    })
+}
+
+fn read_pipe<R: Read + Send + 'static>(pipe: Option<R>) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buffer);
+        }
+        buffer
+    })
+}
+
 pub fn check_converter_availability(converter_name: &str) -> Result<(), String> {
     let result = match converter_name {
         "chafa" => Command::new("chafa").arg("--version").output(),
@@ -235,6 +773,13 @@ pub fn check_converter_availability(converter_name: &str) -> Result<(), String>
             // The actual protocol detection happens at runtime in GraphicalConverter::new()
             return Ok(());
         }
+        // The Sixel encoder is built in
+        #[cfg(feature = "sixel-converter")]
+        "sixel" => return Ok(()),
+        #[cfg(not(feature = "sixel-converter"))]
+        "sixel" => return Err("sixel needs the sixel-converter feature".to_string()),
+        "iterm" if is_iterm2() => return Ok(()),
+        "iterm" => return Err("iterm needs the iTerm2 terminal".to_string()),
         _ => return Err(format!("Unknown converter: {}", converter_name)),
     };
 
@@ -245,11 +790,85 @@ pub fn check_converter_availability(converter_name: &str) -> Result<(), String>
     }
 }
 
+/// One line of --list-converters: the name, then whether it can be used. Converters
+/// that run an external tool show its version when `--version` reports one.
+pub fn converter_status_line(converter_name: &str) -> String {
+    let status = match check_converter_availability(converter_name) {
+        Ok(()) if matches!(converter_name, "chafa" | "jp2a") => {
+            match tool_version(converter_name) {
+                Some(version) => format!("✓ available (v{})", version),
+                None => "✓ available".to_string(),
+            }
+        }
+        Ok(()) => "✓ built-in".to_string(),
+        Err(e) => format!("✗ {}", e),
+    };
+    format!("{:<12}{}", converter_name, status)
+}
+
+/// Print whether each converter can be used, for --list-converters
+pub fn write_converter_list(out: &mut dyn Write) -> io::Result<()> {
+    for name in CONVERTER_NAMES {
+        writeln!(out, "{}", converter_status_line(name))?;
+    }
+    Ok(())
+}
+
+fn tool_version(program: &str) -> Option<String> {
+    let output = Command::new(program).arg("--version").output().ok()?;
+    // Some tools print their version on stderr
+    parse_version(&String::from_utf8_lossy(&output.stdout))
+        .or_else(|| parse_version(&String::from_utf8_lossy(&output.stderr)))
+}
+
+/// The first word of `--version` output that looks like a version number,
+/// e.g. "1.12.4" from "Chafa version 1.12.4"
+fn parse_version(output: &str) -> Option<String> {
+    output
+        .lines()
+        .next()?
+        .split_whitespace()
+        .map(|word| word.trim_start_matches('v').trim_end_matches(','))
+        .find(|word| word.contains('.') && word.starts_with(|c: char| c.is_ascii_digit()))
+        .map(str::to_string)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::config::ConverterConfig;
 
+    #[rstest::rstest]
+    #[case("Chafa version 1.12.4", Some("1.12.4"))]
+    #[case("jp2a v1.1.1\nCopyright", Some("1.1.1"))]
+    #[case("tool 2.0, built today", Some("2.0"))]
+    #[case("no version here", None)]
+    #[case("", None)]
+    fn test_parse_version(#[case] output: &str, #[case] expected: Option<&str>) {
+        assert_eq!(parse_version(output).as_deref(), expected);
+    }
+
+    #[test]
+    fn test_converter_status_lines() {
+        assert_eq!(converter_status_line("graphical"), "graphical   ✓ built-in");
+        assert_eq!(
+            converter_status_line("viu"),
+            "viu         ✗ Unknown converter: viu"
+        );
+    }
+
+    #[test]
+    fn test_converter_list_has_a_line_per_converter() {
+        let mut out = Vec::new();
+        write_converter_list(&mut out).unwrap();
+        let listing = String::from_utf8(out).unwrap();
+        let names: Vec<&str> = listing
+            .lines()
+            .filter_map(|line| line.split_whitespace().next())
+            .collect();
+        assert_eq!(names, CONVERTER_NAMES);
+    }
+
     #[test]
     fn test_chafa_converter_creation() {
         let config = ChafaConfig {
@@ -272,6 +891,82 @@ mod tests {
         assert_eq!(converter.get_name(), "jp2a");
     }
 
+    #[test]
+    fn test_run_with_timeout_kills_slow_converter() {
+        // Stand-in for a converter that hangs on a slow file
+        let mut command = Command::new("sleep");
+        command.arg("5");
+        let started = Instant::now();
+        let error = run_with_timeout(&mut command, Duration::from_millis(50)).unwrap_err();
+        assert_eq!(error, "Conversion timed out after 50ms");
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_run_with_timeout_collects_output() {
+        let mut command = Command::new("echo");
+        command.arg("converted");
+        let output = run_with_timeout(&mut command, Duration::from_secs(5)).unwrap();
+        assert!(output.status.success());
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "converted\n");
+
+        let mut missing = Command::new("ptui-missing-converter");
+        let error = run_with_timeout(&mut missing, Duration::from_secs(5)).unwrap_err();
+        assert!(error.starts_with("Failed to execute ptui-missing-converter"));
+    }
+
+    #[test]
+    fn test_heic_converter_missing_binary() {
+        let converter = HeicConverter::new(HeicConfig {
+            convert_binary: "ptui-missing-heif-convert".to_string(),
+        });
+        assert_eq!(converter.binary(), "ptui-missing-heif-convert");
+        assert!(!converter.is_available());
+
+        let error = converter.convert_to_jpeg("photo.heic").err().unwrap();
+        assert!(error.contains("ptui-missing-heif-convert"));
+    }
+
+    #[test]
+    fn test_raw_converter_extractor_choice() {
+        let converter = RawConverter::new(RawConfig {
+            extractor: "true".to_string(),
+        });
+        assert_eq!(converter.available_extractor().as_deref(), Some("true"));
+
+        // An extractor that can't be launched falls back to the known ones
+        let converter = RawConverter::new(RawConfig {
+            extractor: "ptui-missing-extractor".to_string(),
+        });
+        assert_ne!(
+            converter.available_extractor().as_deref(),
+            Some("ptui-missing-extractor")
+        );
+    }
+
+    #[test]
+    fn test_raw_converter_extract_failures() {
+        let error = RawConverter::extract_jpeg(
+            "ptui-missing-extractor",
+            "photo.cr2",
+            DEFAULT_CONVERSION_TIMEOUT,
+        )
+        .err()
+        .unwrap();
+        assert!(error.starts_with("Failed to execute ptui-missing-extractor"));
+
+        let error = RawConverter::extract_jpeg("false", "photo.cr2", DEFAULT_CONVERSION_TIMEOUT)
+            .err()
+            .unwrap();
+        assert!(error.starts_with("false error"));
+
+        // Exits cleanly but writes nothing
+        let error = RawConverter::extract_jpeg("true", "photo.cr2", DEFAULT_CONVERSION_TIMEOUT)
+            .err()
+            .unwrap();
+        assert_eq!(error, "true didn't write an image");
+    }
+
     #[test]
     fn test_create_chafa_converter() {
         let config = PTuiConfig {
@@ -423,4 +1118,147 @@ mod tests {
         assert_eq!(converter.config.chars, chars);
         assert_eq!(converter.get_name(), "jp2a");
     }
+
+    #[rstest::rstest]
+    #[case("default", None)]
+    #[case("braille", Some("--chars=⠀⣀⣄⣤⣦⣶⣷⣿"))]
+    #[case("blocks", Some("--chars=░▒▓█"))]
+    #[case("binary", Some("--chars=01"))]
+    #[case("hex", Some("--chars=0123456789ABCDEF"))]
+    fn test_jp2a_args_for_charset_presets(#[case] name: &str, #[case] expected: Option<&str>) {
+        let (_, chars) = JP2A_CHARSETS.iter().find(|(n, _)| *n == name).unwrap();
+        let converter = Jp2aConverter::new(Jp2aConfig {
+            chars: chars.map(str::to_string),
+            ..Jp2aConfig::default()
+        });
+
+        let args = converter.args("photo.jpg", 40, 20);
+        let chars_arg = args.iter().find(|arg| arg.starts_with("--chars="));
+        assert_eq!(chars_arg.map(String::as_str), expected);
+        assert_eq!(args.first().unwrap(), "--size=40x20");
+        assert_eq!(args.last().unwrap(), "photo.jpg");
+        assert_eq!(jp2a_charset_name(*chars), name);
+    }
+
+    #[test]
+    fn test_charset_picker_selection() {
+        let mut picker = CharsetPicker::new(None);
+        assert_eq!(picker.selected, 0);
+        assert_eq!(picker.selected_preset(), None);
+
+        picker.move_down();
+        assert_eq!(picker.selected_preset().as_deref(), Some("⠀⣀⣄⣤⣦⣶⣷⣿"));
+        for _ in 0..10 {
+            picker.move_down();
+        }
+        assert!(picker.is_custom_selected());
+
+        // A charset that isn't a preset opens on the custom entry, ready to edit
+        let mut picker = CharsetPicker::new(Some("@%#*"));
+        assert!(picker.is_custom_selected());
+        assert_eq!(jp2a_charset_name(Some("@%#*")), "custom");
+        picker.start_custom_input();
+        assert_eq!(picker.custom_input.as_deref(), Some("@%#*"));
+
+        assert_eq!(CharsetPicker::new(Some("01")).selected, 3);
+    }
+
+    #[rstest::rstest]
+    #[case(Some("iTerm.app"), None, true)]
+    #[case(None, Some("iTerm2"), true)]
+    #[case(Some("tmux"), Some("iTerm2"), true)]
+    #[case(Some("Apple_Terminal"), None, false)]
+    #[case(None, None, false)]
+    fn test_is_iterm2_env(
+        #[case] term_program: Option<&str>,
+        #[case] lc_terminal: Option<&str>,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(is_iterm2_env(term_program, lc_terminal), expected);
+    }
+
+    #[rstest::rstest]
+    #[case("iterm", false, true)]
+    #[case("iterm", true, false)]
+    #[case("chafa", false, false)]
+    fn test_fallback_notice(#[case] selected: &str, #[case] iterm2: bool, #[case] notice: bool) {
+        assert_eq!(fallback_notice(selected, iterm2).is_some(), notice);
+    }
+
+    #[rstest::rstest]
+    #[case(false, "width=40;height=12:")]
+    #[case(true, "width=400px;height=240px:")]
+    fn test_iterm_encode_dimensions(#[case] use_pixel_dimensions: bool, #[case] expected: &str) {
+        let converter = ITermConverter::new(ITermConfig {
+            use_pixel_dimensions,
+        });
+        assert!(converter.is_graphical() && converter.writes_escape_sequence());
+        let output = converter.encode(b"img", 40, 12);
+        assert_eq!(
+            output,
+            format!("\x1b]1337;File=inline=1;{}aW1n\x07", expected)
+        );
+    }
+
+    #[cfg(feature = "sixel-converter")]
+    #[test]
+    fn test_sixel_converter_end_to_end() {
+        let temp_fs = crate::test_utils::helpers::TestFileSystem::new().unwrap();
+        let path = temp_fs.get_path().join("gradient.png");
+        image::RgbImage::from_fn(64, 32, |x, y| image::Rgb([x as u8 * 4, y as u8 * 8, 128]))
+            .save(&path)
+            .unwrap();
+        let config = PTuiConfig {
+            converter: ConverterConfig {
+                selected: "sixel".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let converter = create_converter(&config);
+        assert_eq!(converter.get_name(), "sixel");
+        assert!(!converter.supports_transitions());
+        assert!(converter.is_graphical() && converter.writes_escape_sequence());
+        assert_eq!(check_converter_availability("sixel"), Ok(()));
+
+        let output = converter
+            .convert_image(&path.to_string_lossy(), 8, 4)
+            .unwrap();
+        assert!(output.starts_with("\x1bP"));
+        assert!(output.ends_with("\x1b\\"));
+        // 8x4 cells fit the 2:1 image at 80x40 pixels, seven bands of six rows
+        assert!(output.contains("\"1;1;80;40"));
+        assert_eq!(output.matches('-').count(), 7);
+    }
+
+    #[cfg(feature = "sixel-converter")]
+    #[rstest::rstest]
+    #[case(256, 6)]
+    #[case(16, 2)]
+    #[case(27, 3)]
+    #[case(0, 2)]
+    #[case(1000, 6)]
+    fn test_sixel_palette_levels(#[case] palette_size: u16, #[case] expected: u32) {
+        let converter = SixelConverter::new(SixelConfig {
+            palette_size,
+            ..Default::default()
+        });
+        assert_eq!(converter.levels(), expected);
+    }
+
+    #[cfg(feature = "sixel-converter")]
+    #[test]
+    fn test_sixel_encode_run_length() {
+        let converter = SixelConverter::new(SixelConfig {
+            dither: "none".to_string(),
+            palette_size: 8,
+        });
+        // A single white row, five pixels wide
+        let output = converter.encode(&[[255, 255, 255]; 5], 5, 1);
+        assert_eq!(
+            output,
+            "\x1bP0;1q\"1;1;5;1#0;2;0;0;0#1;2;0;0;100#2;2;0;100;0#3;2;0;100;100\
+             #4;2;100;0;0#5;2;100;0;100#6;2;100;100;0#7;2;100;100;100#7!5@$-\x1b\\"
+        );
+    }
 }
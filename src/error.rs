@@ -0,0 +1,114 @@
+use std::error::Error;
+use std::fmt;
+
+/// Errors from ptui's public API, for callers that want to tell them apart.
+/// Most of the crate still returns `Box<dyn Error>`; functions move over one at a time.
+#[derive(Debug)]
+pub enum PTuiError {
+    IoError(std::io::Error),
+    ConfigError(String),
+    ConverterError { converter: String, message: String },
+    LocalizationError(String),
+    NotFound(String),
+    Unsupported(String),
+}
+
+impl PTuiError {
+    /// Take back a boxed error from code not yet moved over. PTuiErrors and IO errors
+    /// keep their variant, anything else is described with `other`.
+    pub fn from_boxed(error: Box<dyn Error>, other: fn(String) -> PTuiError) -> Self {
+        let error = match error.downcast::<PTuiError>() {
+            Ok(error) => return *error,
+            Err(error) => error,
+        };
+        match error.downcast::<std::io::Error>() {
+            Ok(error) => PTuiError::IoError(*error),
+            Err(error) => other(error.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for PTuiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PTuiError::IoError(e) => write!(f, "{}", e),
+            PTuiError::ConfigError(message) => write!(f, "Config error: {}", message),
+            PTuiError::ConverterError { converter, message } => {
+                write!(f, "{}: {}", converter, message)
+            }
+            PTuiError::LocalizationError(message) => {
+                write!(f, "Localization error: {}", message)
+            }
+            PTuiError::NotFound(message) | PTuiError::Unsupported(message) => {
+                write!(f, "{}", message)
+            }
+        }
+    }
+}
+
+impl Error for PTuiError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PTuiError::IoError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for PTuiError {
+    fn from(error: std::io::Error) -> Self {
+        PTuiError::IoError(error)
+    }
+}
+
+impl From<serde_json::Error> for PTuiError {
+    fn from(error: serde_json::Error) -> Self {
+        PTuiError::ConfigError(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_boxed_keeps_known_variants() {
+        let boxed: Box<dyn Error> = PTuiError::NotFound("gone".to_string()).into();
+        assert!(matches!(
+            PTuiError::from_boxed(boxed, PTuiError::ConfigError),
+            PTuiError::NotFound(message) if message == "gone"
+        ));
+
+        let boxed: Box<dyn Error> =
+            std::io::Error::from(std::io::ErrorKind::PermissionDenied).into();
+        assert!(matches!(
+            PTuiError::from_boxed(boxed, PTuiError::ConfigError),
+            PTuiError::IoError(e) if e.kind() == std::io::ErrorKind::PermissionDenied
+        ));
+
+        let boxed: Box<dyn Error> = "bad locale".into();
+        assert!(matches!(
+            PTuiError::from_boxed(boxed, PTuiError::LocalizationError),
+            PTuiError::LocalizationError(message) if message == "bad locale"
+        ));
+    }
+
+    #[test]
+    fn test_display_and_conversions() {
+        let error = PTuiError::ConverterError {
+            converter: "chafa".to_string(),
+            message: "exit status 1".to_string(),
+        };
+        assert_eq!(error.to_string(), "chafa: exit status 1");
+
+        let json_error = serde_json::from_str::<serde_json::Value>("{").unwrap_err();
+        assert!(matches!(
+            PTuiError::from(json_error),
+            PTuiError::ConfigError(_)
+        ));
+
+        let io_error = PTuiError::from(std::io::Error::other("disk full"));
+        assert_eq!(io_error.to_string(), "disk full");
+        assert!(io_error.source().is_some());
+    }
+}
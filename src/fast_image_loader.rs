@@ -1,5 +1,10 @@
-/// Fast image loading with turbojpeg (if available) or zune-jpeg for JPEGs
+/// Fast image loading with turbojpeg (if available) or zune-jpeg for JPEGs,
+/// image-webp for WebP when built with webp-support, qoi for QOI when built with qoi-support, and libaom for AVIF
+/// when built with avif-support
 use image::DynamicImage;
+use std::io::Read;
+
+const PNG_SIGNATURE: &[u8; 8] = b"\x89PNG\r\n\x1a\n";
 
 pub struct FastImageLoader;
 
@@ -7,25 +12,61 @@ impl FastImageLoader {
     /// Load image with optimal strategy based on format and target size
     #[allow(unused_variables)]
     pub fn load_for_display(path: &str, target_max_dimension: u32) -> Result<DynamicImage, String> {
-        #[cfg(all(not(test), feature = "debug-output"))]
-        use std::time::Instant;
-        #[cfg(all(not(test), feature = "debug-output"))]
-        let load_start = Instant::now();
+        let load_start = std::time::Instant::now();
 
-        // Detect format by extension
+        // Detect JPEGs by extension
         let path_lower = path.to_lowercase();
         let is_jpeg = path_lower.ends_with(".jpg")
             || path_lower.ends_with(".jpeg")
             || path_lower.ends_with(".JPG")
             || path_lower.ends_with(".JPEG");
+        // The rest by content, whatever their extension says
+        let is_avif = Self::is_avif(path);
+        let is_png = Self::has_png_signature(path);
+        let is_webp = Self::is_webp(path);
+        let is_qoi = Self::is_qoi(path);
 
-        let result = if is_jpeg {
+        let result = if is_webp {
+            #[cfg(feature = "webp-support")]
+            {
+                // The image crate decodes WebP with the same decoder, no point falling back
+                Self::load_webp(path)
+            }
+            #[cfg(not(feature = "webp-support"))]
+            {
+                Self::load_with_image_crate(path)
+            }
+        } else if is_qoi {
+            #[cfg(feature = "qoi-support")]
+            {
+                Self::load_qoi(path)
+            }
+            #[cfg(not(feature = "qoi-support"))]
+            {
+                Self::load_with_image_crate(path)
+            }
+        } else if is_png {
+            // Whatever the name says, a JPEG decoder can't read it
+            Self::load_with_image_crate(path)
+        } else if is_avif {
+            #[cfg(feature = "avif-support")]
+            {
+                Self::load_avif(path).or_else(|e| {
+                    debug_timing!("[AVIF] Failed: {}, falling back to image crate", e);
+                    Self::load_with_image_crate(path)
+                })
+            }
+            #[cfg(not(feature = "avif-support"))]
+            {
+                // The image crate can decode AVIF when built with its native AVIF support
+                Self::load_with_image_crate(path)
+            }
+        } else if is_jpeg {
             // Try fast decoders in order of speed
             #[cfg(feature = "fast-jpeg")]
             {
                 Self::load_jpeg_turbojpeg(path, target_max_dimension).or_else(|e| {
-                    #[cfg(not(test))]
-                    eprintln!("[TURBOJPEG] Failed: {}, falling back to image crate", e);
+                    debug_timing!("[TURBOJPEG] Failed: {}, falling back to image crate", e);
                     Self::load_with_image_crate(path)
                 })
             }
@@ -39,11 +80,15 @@ impl FastImageLoader {
             Self::load_with_image_crate(path)
         };
 
-        #[cfg(all(not(test), feature = "debug-output"))]
         match &result {
             Ok(img) => {
-                #[cfg(all(not(test), feature = "debug-output"))]
-                let decoder_name = if is_jpeg {
+                let decoder_name = if is_webp && cfg!(feature = "webp-support") {
+                    "image-webp"
+                } else if is_qoi && cfg!(feature = "qoi-support") {
+                    "qoi"
+                } else if is_avif && cfg!(feature = "avif-support") {
+                    "avif-decode"
+                } else if is_jpeg && !is_png && !is_avif {
                     #[cfg(feature = "fast-jpeg")]
                     {
                         "turbojpeg"
@@ -55,8 +100,7 @@ impl FastImageLoader {
                 } else {
                     "image-crate"
                 };
-                #[cfg(all(not(test), feature = "debug-output"))]
-                eprintln!(
+                debug_timing!(
                     "[FAST-LOADER] Loaded {}x{} in {:?} (decoder: {})",
                     img.width(),
                     img.height(),
@@ -65,7 +109,7 @@ impl FastImageLoader {
                 );
             }
             Err(e) => {
-                eprintln!("[FAST-LOADER] Failed to load: {}", e);
+                debug_timing!("[FAST-LOADER] Failed to load: {}", e);
             }
         }
 
@@ -106,10 +150,12 @@ impl FastImageLoader {
             ScalingFactor::ONE // Full size
         };
 
-        #[cfg(all(not(test), feature = "debug-output"))]
-        eprintln!(
+        debug_timing!(
             "[TURBOJPEG] Original: {}x{}, Target: {}, Scale: {:?}",
-            original_width, original_height, target_max_dimension, scaling_factor
+            original_width,
+            original_height,
+            target_max_dimension,
+            scaling_factor
         );
 
         // Set scaling factor on decompressor (THIS IS THE KEY!)
@@ -122,10 +168,10 @@ impl FastImageLoader {
         let output_width = scaled_header.width;
         let output_height = scaled_header.height;
 
-        #[cfg(all(not(test), feature = "debug-output"))]
-        eprintln!(
+        debug_timing!(
             "[TURBOJPEG] Scaled dimensions: {}x{}",
-            output_width, output_height
+            output_width,
+            output_height
         );
 
         // Allocate output buffer for scaled image
@@ -146,10 +192,10 @@ impl FastImageLoader {
             .decompress(&buffer, output_image.as_deref_mut())
             .map_err(|e| format!("JPEG decompression failed: {:?}", e))?;
 
-        #[cfg(all(not(test), feature = "debug-output"))]
-        eprintln!(
+        debug_timing!(
             "[TURBOJPEG] Successfully decoded at: {}x{}",
-            output_width, output_height
+            output_width,
+            output_height
         );
 
         // Convert to DynamicImage
@@ -160,62 +206,298 @@ impl FastImageLoader {
         Ok(DynamicImage::ImageRgb8(img_buffer))
     }
 
+    fn has_png_signature(path: &str) -> bool {
+        let mut header = [0u8; 8];
+        std::fs::File::open(path)
+            .and_then(|mut file| file.read_exact(&mut header))
+            .is_ok()
+            && &header == PNG_SIGNATURE
+    }
+
+    /// WebP files are a RIFF container with a WEBP form type
+    pub fn is_webp(path: &str) -> bool {
+        let mut header = [0u8; 12];
+        std::fs::File::open(path)
+            .and_then(|mut file| file.read_exact(&mut header))
+            .is_ok()
+            && &header[0..4] == b"RIFF"
+            && &header[8..12] == b"WEBP"
+    }
+
+    /// AVIF files are an ISO media file whose ftyp box has an avif or avis brand
+    pub fn is_avif(path: &str) -> bool {
+        let mut header = [0u8; 12];
+        std::fs::File::open(path)
+            .and_then(|mut file| file.read_exact(&mut header))
+            .is_ok()
+            && &header[4..8] == b"ftyp"
+            && matches!(&header[8..12], b"avif" | b"avis")
+    }
+
+    /// QOI files start with the "qoif" magic
+    pub fn is_qoi(path: &str) -> bool {
+        let mut header = [0u8; 4];
+        std::fs::File::open(path)
+            .and_then(|mut file| file.read_exact(&mut header))
+            .is_ok()
+            && &header == b"qoif"
+    }
+
+    /// Load QOI with the qoi crate, always as RGBA whatever channels the file has
+    #[cfg(feature = "qoi-support")]
+    pub fn load_qoi(path: &str) -> Result<DynamicImage, String> {
+        let buffer = std::fs::read(path).map_err(|e| format!("Failed to read file: {}", e))?;
+        let mut decoder = qoi::Decoder::new(&buffer)
+            .map_err(|e| format!("Failed to decode QOI: {}", e))?
+            .with_channels(qoi::Channels::Rgba);
+        let pixels = decoder
+            .decode_to_vec()
+            .map_err(|e| format!("Failed to decode QOI: {}", e))?;
+        let header = decoder.header();
+
+        image::RgbaImage::from_raw(header.width, header.height, pixels)
+            .map(DynamicImage::ImageRgba8)
+            .ok_or_else(|| "Failed to create image buffer".to_string())
+    }
+
+    /// Load lossy or lossless WebP with image-webp. Animated files give their first frame.
+    #[cfg(feature = "webp-support")]
+    fn load_webp(path: &str) -> Result<DynamicImage, String> {
+        use image_webp::WebPDecoder;
+
+        let buffer = std::fs::read(path).map_err(|e| format!("Failed to read file: {}", e))?;
+        let mut decoder = WebPDecoder::new(std::io::Cursor::new(buffer))
+            .map_err(|e| format!("Failed to decode WebP: {}", e))?;
+        let (width, height) = decoder.dimensions();
+        let size = decoder
+            .output_buffer_size()
+            .ok_or_else(|| "Failed to decode WebP: image too large".to_string())?;
+        let mut pixels = vec![0u8; size];
+        decoder
+            .read_image(&mut pixels)
+            .map_err(|e| format!("Failed to decode WebP: {}", e))?;
+
+        if decoder.has_alpha() {
+            image::RgbaImage::from_raw(width, height, pixels).map(DynamicImage::ImageRgba8)
+        } else {
+            image::RgbImage::from_raw(width, height, pixels).map(DynamicImage::ImageRgb8)
+        }
+        .ok_or_else(|| "Failed to create image buffer".to_string())
+    }
+
+    /// Load AVIF with libaom via avif-decode
+    #[cfg(feature = "avif-support")]
+    fn load_avif(path: &str) -> Result<DynamicImage, String> {
+        use avif_decode::{Decoder, Image};
+
+        let buffer = std::fs::read(path).map_err(|e| format!("Failed to read file: {}", e))?;
+        let decoded = Decoder::from_avif(&buffer)
+            .and_then(|decoder| decoder.to_image())
+            .map_err(|e| format!("Failed to decode AVIF: {}", e))?;
+
+        // Normalise every pixel layout to 8-bit RGBA
+        let (width, height, pixels): (usize, usize, Vec<u8>) = match decoded {
+            Image::Rgb8(img) => (
+                img.width(),
+                img.height(),
+                img.pixels().flat_map(|p| [p.r, p.g, p.b, 255]).collect(),
+            ),
+            Image::Rgba8(img) => (
+                img.width(),
+                img.height(),
+                img.pixels().flat_map(|p| [p.r, p.g, p.b, p.a]).collect(),
+            ),
+            Image::Rgb16(img) => (
+                img.width(),
+                img.height(),
+                img.pixels()
+                    .flat_map(|p| [(p.r >> 8) as u8, (p.g >> 8) as u8, (p.b >> 8) as u8, 255])
+                    .collect(),
+            ),
+            Image::Rgba16(img) => (
+                img.width(),
+                img.height(),
+                img.pixels()
+                    .flat_map(|p| {
+                        [
+                            (p.r >> 8) as u8,
+                            (p.g >> 8) as u8,
+                            (p.b >> 8) as u8,
+                            (p.a >> 8) as u8,
+                        ]
+                    })
+                    .collect(),
+            ),
+            Image::Gray8(img) => (
+                img.width(),
+                img.height(),
+                img.pixels().flat_map(|p| [p.0, p.0, p.0, 255]).collect(),
+            ),
+            Image::Gray16(img) => (
+                img.width(),
+                img.height(),
+                img.pixels()
+                    .flat_map(|p| {
+                        let v = (p.0 >> 8) as u8;
+                        [v, v, v, 255]
+                    })
+                    .collect(),
+            ),
+        };
+
+        let img_buffer = image::RgbaImage::from_raw(width as u32, height as u32, pixels)
+            .ok_or_else(|| "Failed to create image buffer".to_string())?;
+
+        Ok(DynamicImage::ImageRgba8(img_buffer))
+    }
+
     /// Fallback loader using image crate
     fn load_with_image_crate(path: &str) -> Result<DynamicImage, String> {
-        image::open(path).map_err(|e| format!("Failed to load image: {}", e))
+        image::ImageReader::open(path)
+            .and_then(|reader| reader.with_guessed_format())
+            .map_err(|e| format!("Failed to load image: {}", e))?
+            .decode()
+            .map_err(|e| format!("Failed to load image: {}", e))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::test_utils::helpers::TestFileSystem;
+
+    /// About 1 MB of noise, which PNG can barely compress
+    fn write_noise_png(temp_fs: &TestFileSystem, name: &str) -> String {
+        let mut seed = 0x2545_f491u32;
+        let img = image::RgbImage::from_fn(600, 600, |_, _| {
+            let mut next = || {
+                seed ^= seed << 13;
+                seed ^= seed >> 17;
+                seed ^= seed << 5;
+                seed as u8
+            };
+            image::Rgb([next(), next(), next()])
+        });
+        let path = temp_fs.get_path().join(name);
+        img.save_with_format(&path, image::ImageFormat::Png)
+            .unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn test_qoi_loads() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let path = temp_fs.get_path().join("tiny.qoi");
+        image::RgbImage::from_pixel(5, 3, image::Rgb([10, 20, 30]))
+            .save_with_format(&path, image::ImageFormat::Qoi)
+            .unwrap();
+        let path = path.to_string_lossy().to_string();
+        assert!(FastImageLoader::is_qoi(&path));
+
+        let img = FastImageLoader::load_for_display(&path, 1000).unwrap();
+        assert_eq!((img.width(), img.height()), (5, 3));
+        assert_eq!(img.to_rgb8().get_pixel(4, 2), &image::Rgb([10, 20, 30]));
+        if cfg!(feature = "qoi-support") {
+            assert!(matches!(img, DynamicImage::ImageRgba8(_)));
+        }
+
+        let jpeg = temp_fs.create_test_image("photo.jpg").unwrap();
+        assert!(!FastImageLoader::is_qoi(&jpeg));
+    }
+
+    #[test]
+    fn test_png_detected_by_content() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        // A PNG with a JPEG name still loads
+        let path = write_noise_png(&temp_fs, "mislabelled.jpg");
+        assert!(FastImageLoader::has_png_signature(&path));
+        let img = FastImageLoader::load_for_display(&path, 1000).unwrap();
+        assert_eq!((img.width(), img.height()), (600, 600));
+
+        let jpeg = temp_fs.create_test_image("photo.jpg").unwrap();
+        assert!(!FastImageLoader::has_png_signature(&jpeg));
+        assert!(!FastImageLoader::has_png_signature("/no/such/file.png"));
+    }
+
+    #[test]
+    fn test_webp_stub_is_routed_to_webp_loader() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let stub = temp_fs
+            .create_binary_file("stub.bin", b"RIFF\x04\x00\x00\x00WEBP")
+            .unwrap();
+        assert!(FastImageLoader::is_webp(&stub));
+        let error = FastImageLoader::load_for_display(&stub, 512).unwrap_err();
+        if cfg!(feature = "webp-support") {
+            assert!(error.starts_with("Failed to decode WebP"), "{}", error);
+        }
+
+        let riff = temp_fs
+            .create_binary_file("clip.avi", b"RIFF\x04\x00\x00\x00AVI ")
+            .unwrap();
+        assert!(!FastImageLoader::is_webp(&riff));
+        assert!(!FastImageLoader::is_webp("/no/such/file.webp"));
+    }
+
+    #[test]
+    fn test_avif_detected_by_ftyp_brand() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        for (name, brand) in [("still.bin", b"avif"), ("sequence.bin", b"avis")] {
+            let mut header = b"\x00\x00\x00\x1cftyp".to_vec();
+            header.extend_from_slice(brand);
+            let path = temp_fs.create_binary_file(name, &header).unwrap();
+            assert!(FastImageLoader::is_avif(&path), "{}", name);
+        }
+
+        // HEIC shares the container, and an .avif name alone isn't enough
+        let heic = temp_fs
+            .create_binary_file("photo.avif", b"\x00\x00\x00\x1cftypheic")
+            .unwrap();
+        assert!(!FastImageLoader::is_avif(&heic));
+        assert!(!FastImageLoader::is_avif("/no/such/file.avif"));
+    }
+
+    #[test]
+    fn test_lossless_webp_loads() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let path = temp_fs.get_path().join("tiny.webp");
+        image::RgbaImage::from_fn(4, 2, |x, y| {
+            image::Rgba([x as u8 * 60, y as u8 * 100, 0, 255])
+        })
+        .save_with_format(&path, image::ImageFormat::WebP)
+        .unwrap();
+
+        let img = FastImageLoader::load_for_display(&path.to_string_lossy(), 512).unwrap();
+        assert_eq!((img.width(), img.height()), (4, 2));
+        assert_eq!(img.to_rgb8().get_pixel(3, 1).0, [180, 100, 0]);
+    }
+
     #[test]
     fn test_scale_factor_calculation() {
-        // 4032px image, target 512px:
-        // Is 4032 > 512 * 8 (4096)? No
-        // Is 4032 > 512 * 4 (2048)? Yes -> Use 1/4 scale
-        assert_eq!(
-            4,
-            if 4032 > 512 * 8 {
+        let scale_for = |size: u32, target: u32| {
+            if size > target * 8 {
                 8
-            } else if 4032 > 512 * 4 {
+            } else if size > target * 4 {
                 4
-            } else if 4032 > 512 * 2 {
+            } else if size > target * 2 {
                 2
             } else {
                 1
             }
-        );
+        };
+
+        // 4032px image, target 512px:
+        // Is 4032 > 512 * 8 (4096)? No
+        // Is 4032 > 512 * 4 (2048)? Yes -> Use 1/4 scale
+        assert_eq!(4, scale_for(4032, 512));
 
         // 2048px image, target 512px:
         // Is 2048 > 512 * 8? No
         // Is 2048 > 512 * 4 (2048)? No (not strictly greater)
         // Is 2048 > 512 * 2 (1024)? Yes -> Use 1/2 scale
-        assert_eq!(
-            2,
-            if 2048 > 512 * 8 {
-                8
-            } else if 2048 > 512 * 4 {
-                4
-            } else if 2048 > 512 * 2 {
-                2
-            } else {
-                1
-            }
-        );
+        assert_eq!(2, scale_for(2048, 512));
 
         // 5000px image, target 512px: should use 1/8
         // Is 5000 > 512 * 8 (4096)? Yes -> Use 1/8 scale
-        assert_eq!(
-            8,
-            if 5000 > 512 * 8 {
-                8
-            } else if 5000 > 512 * 4 {
-                4
-            } else if 5000 > 512 * 2 {
-                2
-            } else {
-                1
-            }
-        );
+        assert_eq!(8, scale_for(5000, 512));
     }
 }
@@ -1,9 +1,16 @@
+use crate::error::PTuiError;
+use crate::git::{self, GitStatus};
+use crate::icons::FileCategory;
 use content_inspector::{ContentType, inspect};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::error::Error;
 use std::fs;
-use std::io::Read;
+use std::io::{BufReader, Read};
 use std::path::Path;
-use std::time::SystemTime;
+use std::time::{Duration, Instant, SystemTime};
+
+const DEFAULT_SORT_LOCALE: &str = "en";
 
 // Buffer size for reading file content for magic byte detection and content inspection
 // Most image formats need only a few bytes for magic byte detection:
@@ -13,25 +20,130 @@ use std::time::SystemTime;
 // - WebP: 12 bytes ("RIFF" + 4 byte size + "WEBP")
 // - BMP: 2 bytes (0x42, 0x4D)
 // - TIFF: 4 bytes (0x49, 0x49, 0x2A, 0x00 or 0x4D, 0x4D, 0x00, 0x2A)
+// - AVIF: 12 bytes (4 byte box size + "ftyp" + "avif" or "avis")
 // - SVG: may need more bytes due to XML declarations, comments, and DOCTYPE declarations
 //   before the <svg tag appears. Using 512 bytes to handle SVG files reliably.
 // - Text encoding detection also works well within this range
 // Using 512 bytes provides better SVG detection while maintaining good performance
 const CONTENT_DETECTION_BUFFER_SIZE: usize = 512;
 
-#[derive(Debug, Clone, PartialEq)]
+// Files are hashed in 64KB chunks so large files never need to be held in memory
+const CHECKSUM_CHUNK_SIZE: usize = 64 * 1024;
+
+// Reserved field then image type 1 (icon) at the start of an ICO file
+const ICO_MAGIC: &[u8; 4] = b"\x00\x00\x01\x00";
+
+// ISO BMFF brands used by HEIC/HEIF images
+const HEIC_BRANDS: &[&[u8]] = &[b"heic", b"heix", b"hevc", b"hevx", b"mif1"];
+
+/// Source code, told apart from other text by extension
+const CODE_EXTENSIONS: &[&str] = &[
+    "rs", "py", "sh", "c", "h", "cpp", "hpp", "go", "java", "js", "ts", "rb", "lua", "pl",
+];
+
+/// Start of zip, gzip, bzip2, xz, zstd, 7z and RAR files
+const ARCHIVE_MAGIC: &[&[u8]] = &[
+    b"PK\x03\x04",
+    b"\x1F\x8B",
+    b"BZh",
+    b"\xFD7zXZ\x00",
+    b"\x28\xB5\x2F\xFD",
+    b"7z\xBC\xAF\x27\x1C",
+    b"Rar!\x1A\x07",
+];
+
+// Tar has no magic at the start, only "ustar" in the header
+const TAR_MAGIC_OFFSET: usize = 257;
+
+/// RAW camera formats, recognised by extension since most of them look like TIFF inside
+const RAW_EXTENSIONS: &[&str] = &["cr2", "cr3", "nef", "arw", "dng", "orf"];
+
+// ISO BMFF brands that hold still images (AVIF/HEIF) rather than video
+const STILL_IMAGE_BRANDS: &[&[u8]] = &[
+    b"avif", b"avis", b"heic", b"heix", b"hevc", b"hevx", b"mif1", b"msf1",
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum SortMode {
     Name,
     DateNewestFirst,
     DateOldestFirst,
 }
 
-#[derive(Debug, Clone)]
+/// Which kinds of files are listed; directories are always shown so you can still navigate
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum ContentFilter {
+    #[default]
+    All,
+    ImagesOnly,
+    TextOnly,
+    NonPreviewable,
+}
+
+impl ContentFilter {
+    /// The next filter in the Ctrl+T cycle
+    pub fn next(self) -> Self {
+        match self {
+            Self::All => Self::ImagesOnly,
+            Self::ImagesOnly => Self::TextOnly,
+            Self::TextOnly => Self::NonPreviewable,
+            Self::NonPreviewable => Self::All,
+        }
+    }
+
+    pub fn matches(self, file: &FileItem) -> bool {
+        if file.is_directory {
+            return true;
+        }
+        match self {
+            Self::All => true,
+            Self::ImagesOnly => file.is_image(),
+            Self::TextOnly => !file.is_image() && file.is_text_file(),
+            Self::NonPreviewable => {
+                !file.is_image()
+                    && !file.is_text_file()
+                    && !file.is_video()
+                    && !file.is_audio()
+                    && !file.is_ascii_file()
+            }
+        }
+    }
+
+    /// Appended to the file browser's title while a filter is active
+    pub fn title_suffix(self) -> &'static str {
+        match self {
+            Self::All => "",
+            Self::ImagesOnly => " [🖼 only]",
+            Self::TextOnly => " [📄 only]",
+            Self::NonPreviewable => " [❔ only]",
+        }
+    }
+
+    /// Localization key of the message shown when switching to this filter
+    pub fn message_key(self) -> &'static str {
+        match self {
+            Self::All => "content_filter_all",
+            Self::ImagesOnly => "content_filter_images_only",
+            Self::TextOnly => "content_filter_text_only",
+            Self::NonPreviewable => "content_filter_non_previewable",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileItem {
     pub name: String,
     pub path: String,
     pub is_directory: bool,
     pub modified: SystemTime,
+    // Size of the file, or of its target for a symlink
+    #[serde(default)]
+    pub size_bytes: u64,
+    #[serde(default)]
+    pub is_symlink: bool,
+    // Unix mode bits, 0 on other systems
+    #[serde(default)]
+    pub permissions: u32,
 }
 
 impl FileItem {
@@ -41,13 +153,87 @@ impl FileItem {
             path,
             is_directory,
             modified,
+            size_bytes: 0,
+            is_symlink: false,
+            permissions: 0,
         }
     }
 
+    /// Fill in the size and symlink flag read along with the modification time
+    pub fn with_metadata(mut self, size_bytes: u64, is_symlink: bool) -> Self {
+        self.size_bytes = size_bytes;
+        self.is_symlink = is_symlink;
+        self
+    }
+
+    pub fn with_permissions(mut self, permissions: u32) -> Self {
+        self.permissions = permissions;
+        self
+    }
+
+    /// The owner's permissions as shown by ls, e.g. "rw-" or "r-x"
+    pub fn owner_permissions(&self) -> String {
+        let owner = (self.permissions >> 6) & 0o7;
+        [(0o4, 'r'), (0o2, 'w'), (0o1, 'x')]
+            .iter()
+            .map(|(bit, c)| if owner & bit != 0 { *c } else { '-' })
+            .collect()
+    }
+
+    /// SHA-256 of the file's contents as a 64 character lowercase hex string
+    pub fn sha256(&self) -> Result<String, std::io::Error> {
+        use sha2::{Digest, Sha256};
+
+        let file = fs::File::open(&self.path)?;
+        let mut reader = BufReader::with_capacity(CHECKSUM_CHUNK_SIZE, file);
+        let mut hasher = Sha256::new();
+        let mut buffer = vec![0u8; CHECKSUM_CHUNK_SIZE];
+        loop {
+            let bytes_read = reader.read(&mut buffer)?;
+            if bytes_read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..bytes_read]);
+        }
+
+        Ok(hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect())
+    }
+
+    /// Number of images directly inside this directory, by magic bytes. Reads the
+    /// directory once and doesn't descend into subdirectories.
+    pub fn count_images_shallow(&self) -> usize {
+        if !self.is_directory {
+            return 0;
+        }
+        let Ok(entries) = fs::read_dir(&self.path) else {
+            return 0;
+        };
+        entries
+            .flatten()
+            .filter(|entry| entry.file_type().is_ok_and(|kind| !kind.is_dir()))
+            .filter(|entry| {
+                FileItem::new(
+                    entry.file_name().to_string_lossy().into_owned(),
+                    entry.path().to_string_lossy().into_owned(),
+                    false,
+                    SystemTime::UNIX_EPOCH,
+                )
+                .is_image()
+            })
+            .count()
+    }
+
     pub fn is_image(&self) -> bool {
         if self.is_directory {
             return false;
         }
+        if self.is_raw_image() {
+            return true;
+        }
 
         // Read only the first few bytes for content inspection - sufficient for magic bytes and basic detection
         if let Ok(mut file) = std::fs::File::open(&self.path) {
@@ -82,12 +268,34 @@ impl FileItem {
                                 // BMP
                                 return true;
                             }
+                            if sample.starts_with(b"qoif") {
+                                // QOI
+                                return true;
+                            }
+                            if sample.starts_with(ICO_MAGIC) {
+                                // ICO
+                                return true;
+                            }
                             if sample.starts_with(&[0x49, 0x49, 0x2A, 0x00])
                                 || sample.starts_with(&[0x4D, 0x4D, 0x00, 0x2A])
                             {
                                 // TIFF
                                 return true;
                             }
+                            if sample.len() >= 12
+                                && &sample[4..8] == b"ftyp"
+                                && matches!(&sample[8..12], b"avif" | b"avis")
+                            {
+                                // AVIF (ISO BMFF container)
+                                return true;
+                            }
+                            if sample.len() >= 12
+                                && &sample[4..8] == b"ftyp"
+                                && HEIC_BRANDS.contains(&&sample[8..12])
+                            {
+                                // HEIC/HEIF (ISO BMFF container)
+                                return true;
+                            }
                         }
                         false
                     }
@@ -111,6 +319,164 @@ impl FileItem {
         }
     }
 
+    /// Name of the image format going by magic bytes (RAW files by extension), None
+    /// for anything that isn't a recognised image
+    pub fn image_format(&self) -> Option<&'static str> {
+        if self.is_directory {
+            return None;
+        }
+        if self.is_raw_image() {
+            return Some("RAW");
+        }
+
+        let mut buffer = [0u8; CONTENT_DETECTION_BUFFER_SIZE];
+        let bytes_read = std::fs::File::open(&self.path)
+            .and_then(|mut file| file.read(&mut buffer))
+            .ok()?;
+        let sample = &buffer[..bytes_read];
+        if !inspect(sample).is_binary() {
+            let content = String::from_utf8_lossy(sample).to_lowercase();
+            return content.contains("<svg").then_some("SVG");
+        }
+
+        let brand = sample
+            .get(4..8)
+            .filter(|marker| *marker == b"ftyp")
+            .and_then(|_| sample.get(8..12));
+        let format = if sample.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            "JPEG"
+        } else if sample.starts_with(&[0x89, 0x50, 0x4E, 0x47]) {
+            "PNG"
+        } else if sample.starts_with(b"GIF8") {
+            "GIF"
+        } else if sample.starts_with(b"RIFF") && sample.get(8..12) == Some(b"WEBP") {
+            "WebP"
+        } else if sample.starts_with(&[0x42, 0x4D]) {
+            "BMP"
+        } else if sample.starts_with(b"qoif") {
+            "QOI"
+        } else if sample.starts_with(ICO_MAGIC) {
+            "ICO"
+        } else if sample.starts_with(&[0x49, 0x49, 0x2A, 0x00])
+            || sample.starts_with(&[0x4D, 0x4D, 0x00, 0x2A])
+        {
+            "TIFF"
+        } else if matches!(brand, Some(b"avif" | b"avis")) {
+            "AVIF"
+        } else if brand.is_some_and(|brand| HEIC_BRANDS.contains(&brand)) {
+            "HEIC"
+        } else {
+            return None;
+        };
+        Some(format)
+    }
+
+    /// ICO files can hold several sizes, which are navigated with < and >
+    pub fn is_ico(&self) -> bool {
+        if self.is_directory {
+            return false;
+        }
+
+        let mut buffer = [0u8; 4];
+        std::fs::File::open(&self.path)
+            .and_then(|mut file| file.read_exact(&mut buffer))
+            .map(|_| &buffer == ICO_MAGIC)
+            .unwrap_or(false)
+    }
+
+    /// TIFF files can hold several pages, which are navigated with < and >
+    pub fn is_tiff(&self) -> bool {
+        if self.is_directory {
+            return false;
+        }
+
+        let mut buffer = [0u8; 4];
+        std::fs::File::open(&self.path)
+            .and_then(|mut file| file.read_exact(&mut buffer))
+            .map(|_| buffer == [0x49, 0x49, 0x2A, 0x00] || buffer == [0x4D, 0x4D, 0x00, 0x2A])
+            .unwrap_or(false)
+    }
+
+    /// RAW camera files need a JPEG extracted with dcraw or darktable-cli before they can be previewed
+    pub fn is_raw_image(&self) -> bool {
+        if self.is_directory {
+            return false;
+        }
+
+        Path::new(&self.name)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| RAW_EXTENSIONS.contains(&extension.to_lowercase().as_str()))
+    }
+
+    /// HEIC/HEIF images need decoding with heif-convert before they can be previewed
+    pub fn is_heic(&self) -> bool {
+        if self.is_directory {
+            return false;
+        }
+
+        let mut buffer = [0u8; 12];
+        std::fs::File::open(&self.path)
+            .and_then(|mut file| file.read_exact(&mut buffer))
+            .map(|_| &buffer[4..8] == b"ftyp" && HEIC_BRANDS.contains(&&buffer[8..12]))
+            .unwrap_or(false)
+    }
+
+    pub fn is_video(&self) -> bool {
+        if self.is_directory {
+            return false;
+        }
+
+        let mut buffer = [0u8; 16];
+        let Ok(bytes_read) =
+            std::fs::File::open(&self.path).and_then(|mut file| file.read(&mut buffer))
+        else {
+            return false;
+        };
+        let sample = &buffer[..bytes_read];
+        if sample.len() < 12 {
+            return false;
+        }
+
+        // MKV and WebM share the EBML header
+        if sample.starts_with(&[0x1A, 0x45, 0xDF, 0xA3]) {
+            return true;
+        }
+        // AVI
+        if sample.starts_with(b"RIFF") && &sample[8..12] == b"AVI " {
+            return true;
+        }
+        // MP4 and MOV: ISO BMFF "ftyp" box, skipping the brands used by still images
+        if &sample[4..8] == b"ftyp" {
+            return !STILL_IMAGE_BRANDS.contains(&&sample[8..12]);
+        }
+        // Older QuickTime files start straight with a movie atom
+        matches!(
+            &sample[4..8],
+            b"moov" | b"mdat" | b"wide" | b"free" | b"pnot"
+        )
+    }
+
+    /// MP3, FLAC and Ogg files, by magic bytes. Previewed with the details ffprobe reports.
+    pub fn is_audio(&self) -> bool {
+        if self.is_directory {
+            return false;
+        }
+
+        let mut buffer = [0u8; 4];
+        let Ok(bytes_read) =
+            std::fs::File::open(&self.path).and_then(|mut file| file.read(&mut buffer))
+        else {
+            return false;
+        };
+        let sample = &buffer[..bytes_read];
+        // MP3 with an ID3v2 tag, or starting straight with an MPEG-1 Layer III frame
+        sample.starts_with(b"ID3")
+            || sample.starts_with(&[0xFF, 0xFB])
+            || sample.starts_with(b"fLaC")
+            || sample.starts_with(b"OggS")
+    }
+
     pub fn is_ascii_file(&self) -> bool {
         if self.is_directory {
             return false;
@@ -139,12 +505,15 @@ impl FileItem {
             let mut buffer = [0u8; CONTENT_DETECTION_BUFFER_SIZE];
             if let Ok(bytes_read) = file.read(&mut buffer) {
                 let sample = &buffer[..bytes_read];
-                match inspect(sample) {
-                    ContentType::UTF_8 | ContentType::UTF_8_BOM => true,
-                    ContentType::UTF_16LE | ContentType::UTF_16BE => true,
-                    ContentType::UTF_32LE | ContentType::UTF_32BE => true,
-                    _ => false,
-                }
+                matches!(
+                    inspect(sample),
+                    ContentType::UTF_8
+                        | ContentType::UTF_8_BOM
+                        | ContentType::UTF_16LE
+                        | ContentType::UTF_16BE
+                        | ContentType::UTF_32LE
+                        | ContentType::UTF_32BE
+                )
             } else {
                 false
             }
@@ -153,8 +522,117 @@ impl FileItem {
         }
     }
 
+    /// Zip, tar and compressed files, by magic bytes
+    pub fn is_archive(&self) -> bool {
+        if self.is_directory {
+            return false;
+        }
+
+        let mut buffer = [0u8; CONTENT_DETECTION_BUFFER_SIZE];
+        let Ok(bytes_read) =
+            std::fs::File::open(&self.path).and_then(|mut file| file.read(&mut buffer))
+        else {
+            return false;
+        };
+        let sample = &buffer[..bytes_read];
+        ARCHIVE_MAGIC.iter().any(|magic| sample.starts_with(magic))
+            || sample
+                .get(TAR_MAGIC_OFFSET..TAR_MAGIC_OFFSET + 5)
+                .is_some_and(|magic| magic == b"ustar")
+    }
+
+    /// What the file holds, by its contents. Text is split into code and plain
+    /// text by extension since the bytes can't tell them apart.
+    pub fn category(&self) -> FileCategory {
+        if self.is_directory {
+            return FileCategory::Unknown;
+        }
+        if self.is_image() {
+            FileCategory::Image
+        } else if self.is_video() {
+            FileCategory::Video
+        } else if self.is_audio() {
+            FileCategory::Audio
+        } else if self.is_archive() {
+            FileCategory::Archive
+        } else if self.is_ascii_file() || self.is_text_file() {
+            let is_code = Path::new(&self.name)
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| {
+                    CODE_EXTENSIONS.contains(&extension.to_lowercase().as_str())
+                });
+            if is_code {
+                FileCategory::Code
+            } else {
+                FileCategory::Text
+            }
+        } else {
+            FileCategory::Unknown
+        }
+    }
+
     pub fn can_preview(&self) -> bool {
-        self.is_image() || self.is_text_file() || self.is_ascii_file()
+        self.is_image()
+            || self.is_video()
+            || self.is_audio()
+            || self.is_text_file()
+            || self.is_ascii_file()
+    }
+}
+
+/// Directories first, then files in `sort_mode` order
+fn compare_files(
+    collator: &NameCollator,
+    sort_mode: &SortMode,
+    a: &FileItem,
+    b: &FileItem,
+) -> std::cmp::Ordering {
+    // Always put directories first
+    if a.is_directory && !b.is_directory {
+        std::cmp::Ordering::Less
+    } else if !a.is_directory && b.is_directory {
+        std::cmp::Ordering::Greater
+    } else {
+        // Both are directories or both are files
+        match sort_mode {
+            SortMode::Name => collator.compare(&a.name, &b.name),
+            SortMode::DateNewestFirst => b.modified.cmp(&a.modified), // Newest first
+            SortMode::DateOldestFirst => a.modified.cmp(&b.modified), // Oldest first
+        }
+    }
+}
+
+/// Orders file names for the name sort. With the icu feature names are collated by the
+/// rules of the locale, so "ä" sorts next to "a" in German but after "z" in Swedish.
+struct NameCollator {
+    #[cfg(feature = "icu")]
+    collator: Option<icu_collator::Collator>,
+}
+
+impl NameCollator {
+    #[cfg(feature = "icu")]
+    fn new(locale: &str) -> Self {
+        let collator = locale
+            .parse::<icu_provider::DataLocale>()
+            .ok()
+            .and_then(|locale| {
+                icu_collator::Collator::try_new(&locale, icu_collator::CollatorOptions::new()).ok()
+            });
+        Self { collator }
+    }
+
+    #[cfg(not(feature = "icu"))]
+    fn new(_locale: &str) -> Self {
+        Self {}
+    }
+
+    fn compare(&self, a: &str, b: &str) -> std::cmp::Ordering {
+        #[cfg(feature = "icu")]
+        if let Some(collator) = &self.collator {
+            return collator.compare(a, b);
+        }
+        a.to_lowercase().cmp(&b.to_lowercase())
     }
 }
 
@@ -165,14 +643,57 @@ pub struct FileBrowser {
     pub scroll_offset: usize,
     pub max_visible_files: usize,
     pub sort_mode: SortMode,
+    pub content_filter: ContentFilter,
+    // Number of files per row when laid out as a thumbnail grid (1 for the list view)
+    pub grid_columns: usize,
     // Stack to track the last selected file in each directory for navigation
     dir_stack: Vec<(String, usize)>, // (directory_path, selected_index)
+    // Bumped on every refresh so callers can drop anything they cached about the listing
+    refresh_count: u64,
+    // Git status of entries by name, when Git integration is on and the directory is in a repo
+    pub git_status: Option<HashMap<String, GitStatus>>,
+    git_integration: bool,
+    // Image counts of directories by name, filled in off the UI thread as they scroll into view
+    image_counts: HashMap<String, usize>,
+    show_image_counts: bool,
+    categories: HashMap<String, FileCategory>,
+    show_permissions: bool,
+    // Locale whose collation rules order names in SortMode::Name
+    locale: String,
+    collator: NameCollator,
 }
 
 impl FileBrowser {
-    pub fn new() -> Result<Self, Box<dyn Error>> {
+    pub fn new() -> Result<Self, PTuiError> {
         let current_dir = std::env::current_dir()?.to_string_lossy().into_owned();
-        Self::new_with_dir(current_dir)
+        Self::new_with_dir(current_dir).map_err(|e| PTuiError::from_boxed(e, PTuiError::NotFound))
+    }
+
+    /// Open a directory, or the directory holding a file with that file selected
+    pub fn new_for_path<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn Error>> {
+        let path = path.as_ref();
+        if path.is_dir() {
+            return Self::new_with_dir(path);
+        }
+        if !path.exists() {
+            return Err(PTuiError::NotFound(format!(
+                "No such file or directory: {}",
+                path.display()
+            ))
+            .into());
+        }
+
+        let parent = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => std::env::current_dir()?,
+        };
+        let mut browser = Self::new_with_dir(parent)?;
+        if let Some(name) = path.file_name().map(|name| name.to_string_lossy())
+            && let Some(index) = browser.files.iter().position(|file| file.name == name)
+        {
+            browser.set_selected_index(index);
+        }
+        Ok(browser)
     }
 
     pub fn new_with_dir<P: AsRef<Path>>(dir: P) -> Result<Self, Box<dyn Error>> {
@@ -184,7 +705,18 @@ impl FileBrowser {
             scroll_offset: 0,
             max_visible_files: 20,
             sort_mode: SortMode::Name,
+            content_filter: ContentFilter::All,
+            grid_columns: 1,
             dir_stack: Vec::new(),
+            refresh_count: 0,
+            git_status: None,
+            git_integration: false,
+            image_counts: HashMap::new(),
+            show_image_counts: false,
+            categories: HashMap::new(),
+            show_permissions: false,
+            locale: DEFAULT_SORT_LOCALE.to_string(),
+            collator: NameCollator::new(DEFAULT_SORT_LOCALE),
         };
         browser.refresh_files()?;
         Ok(browser)
@@ -192,59 +724,169 @@ impl FileBrowser {
 
     pub fn refresh_files(&mut self) -> Result<(), Box<dyn Error>> {
         self.files.clear();
+        self.image_counts.clear();
+        self.categories.clear();
+        self.refresh_count += 1;
 
         let entries = fs::read_dir(&self.current_dir)?;
 
         for entry in entries {
             let entry = entry?;
-            let file_type = entry.file_type()?;
             let path = entry.path();
 
-            let mut is_directory = file_type.is_dir();
-
-            // Handle symlinks that point to directories
-            if !is_directory
-                && let Ok(metadata) = fs::symlink_metadata(&path)
-                && metadata.file_type().is_symlink()
-                && let Ok(target_metadata) = fs::metadata(&path)
-            {
-                is_directory = target_metadata.is_dir();
-            }
+            // Doesn't follow symlinks, so the modification time is the link's own
+            let link_metadata = entry.metadata()?;
+            let modified = link_metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            let is_symlink = link_metadata.file_type().is_symlink();
 
-            // Get modification time
-            let modified = entry
-                .metadata()?
-                .modified()
-                .unwrap_or(SystemTime::UNIX_EPOCH);
+            // Symlinks take their size, and whether they're a directory, from the target.
+            // Broken links fall back to the link itself.
+            let metadata = if is_symlink {
+                fs::metadata(&path).unwrap_or(link_metadata)
+            } else {
+                link_metadata
+            };
 
-            self.files.push(FileItem::new(
+            let file = FileItem::new(
                 entry.file_name().to_string_lossy().into_owned(),
                 path.to_string_lossy().into_owned(),
-                is_directory,
+                metadata.is_dir(),
                 modified,
-            ));
+            )
+            .with_metadata(metadata.len(), is_symlink);
+            #[cfg(unix)]
+            let file = {
+                use std::os::unix::fs::MetadataExt;
+                file.with_permissions(metadata.mode() & 0o7777)
+            };
+            if self.content_filter.matches(&file) {
+                self.files.push(file);
+            }
         }
 
         self.sort_files();
+        self.refresh_git_status();
         Ok(())
     }
 
-    fn sort_files(&mut self) {
-        self.files.sort_by(|a, b| {
-            // Always put directories first
-            if a.is_directory && !b.is_directory {
-                std::cmp::Ordering::Less
-            } else if !a.is_directory && b.is_directory {
-                std::cmp::Ordering::Greater
-            } else {
-                // Both are directories or both are files
-                match self.sort_mode {
-                    SortMode::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
-                    SortMode::DateNewestFirst => b.modified.cmp(&a.modified), // Newest first
-                    SortMode::DateOldestFirst => a.modified.cmp(&b.modified), // Oldest first
-                }
+    /// The files in the current directory as a pretty printed JSON array, for scripts
+    pub fn export_current_listing(&self) -> String {
+        serde_json::to_string_pretty(&self.files).unwrap_or_else(|_| "[]".to_string())
+    }
+
+    pub fn set_git_integration(&mut self, enabled: bool) {
+        if self.git_integration != enabled {
+            self.git_integration = enabled;
+            self.refresh_git_status();
+        }
+    }
+
+    fn refresh_git_status(&mut self) {
+        self.git_status = if self.git_integration {
+            git::status_for_directory(Path::new(&self.current_dir))
+        } else {
+            None
+        };
+    }
+
+    pub fn get_git_status(&self, file: &FileItem) -> Option<GitStatus> {
+        self.git_status.as_ref()?.get(&file.name).copied()
+    }
+
+    /// Show owner permissions in the list; there are none to show outside Unix
+    pub fn set_show_permissions(&mut self, enabled: bool) {
+        self.show_permissions = enabled && cfg!(unix);
+    }
+
+    pub fn shows_permissions(&self) -> bool {
+        self.show_permissions
+    }
+
+    pub fn set_show_image_counts(&mut self, enabled: bool) {
+        self.show_image_counts = enabled;
+        if !enabled {
+            self.image_counts.clear();
+        }
+    }
+
+    /// Visible directories whose images haven't been counted since the last refresh.
+    /// Counting reads every file in them, so callers do it on another thread.
+    pub fn uncounted_visible_directories(&self) -> Vec<FileItem> {
+        if !self.show_image_counts {
+            return Vec::new();
+        }
+        self.get_display_files()
+            .map(|(_, file)| file)
+            .filter(|file| file.is_directory && !self.image_counts.contains_key(&file.name))
+            .cloned()
+            .collect()
+    }
+
+    /// Store image counts of directories, by name
+    pub fn set_image_counts(&mut self, counts: &[(String, usize)]) {
+        if self.show_image_counts {
+            self.image_counts.extend(counts.iter().cloned());
+        }
+    }
+
+    pub fn get_image_count(&self, file: &FileItem) -> Option<usize> {
+        if !self.show_image_counts {
+            return None;
+        }
+        self.image_counts.get(&file.name).copied()
+    }
+
+    /// Work out the category of visible files not seen since the last refresh, so
+    /// drawing the icon column reads each file once rather than on every frame
+    pub fn update_visible_categories(&mut self) {
+        let uncategorized: Vec<(String, FileCategory)> = self
+            .get_display_files()
+            .map(|(_, file)| file)
+            .filter(|file| !file.is_directory && !self.categories.contains_key(&file.name))
+            .map(|file| (file.name.clone(), file.category()))
+            .collect();
+        self.categories.extend(uncategorized);
+    }
+
+    pub fn get_category(&self, file: &FileItem) -> Option<FileCategory> {
+        self.categories.get(&file.name).copied()
+    }
+
+    /// Sort names by the collation rules of another locale, keeping the selection
+    pub fn set_locale(&mut self, locale: &str) {
+        if self.locale == locale {
+            return;
+        }
+        self.locale = locale.to_string();
+        self.collator = NameCollator::new(locale);
+
+        if self.sort_mode == SortMode::Name {
+            let selected_file = self.get_selected_file().map(|f| f.path.clone());
+            self.sort_files();
+            if let Some(selected_path) = selected_file {
+                self.select_by_path(&selected_path);
             }
-        });
+        }
+    }
+
+    fn sort_files(&mut self) {
+        let (collator, sort_mode) = (&self.collator, &self.sort_mode);
+        self.files
+            .sort_by(|a, b| compare_files(collator, sort_mode, a, b));
+    }
+
+    /// Order two files as `sort_mode` would list them, whatever the list is sorted by
+    pub fn compare_files(
+        &self,
+        a: &FileItem,
+        b: &FileItem,
+        sort_mode: &SortMode,
+    ) -> std::cmp::Ordering {
+        compare_files(&self.collator, sort_mode, a, b)
+    }
+
+    pub fn refresh_count(&self) -> u64 {
+        self.refresh_count
     }
 
     pub fn get_selected_file(&self) -> Option<&FileItem> {
@@ -254,22 +896,48 @@ impl FileBrowser {
     pub fn move_down(&mut self) {
         if self.selected_index < self.files.len().saturating_sub(1) {
             self.selected_index += 1;
-
-            if self.selected_index >= self.scroll_offset + self.max_visible_files {
-                self.scroll_offset = self
-                    .selected_index
-                    .saturating_sub(self.max_visible_files - 1);
-            }
+            self.update_scroll_for_selection();
         }
     }
 
     pub fn move_up(&mut self) {
         if self.selected_index > 0 {
             self.selected_index -= 1;
+            self.update_scroll_for_selection();
+        }
+    }
 
-            if self.selected_index < self.scroll_offset {
-                self.scroll_offset = self.selected_index;
-            }
+    /// Move down one row in the grid view
+    pub fn move_row_down(&mut self) {
+        if self.files.is_empty() {
+            return;
+        }
+
+        let columns = self.grid_columns.max(1);
+        let last_index = self.files.len() - 1;
+        if self.selected_index + columns <= last_index {
+            self.selected_index += columns;
+        } else if self.selected_index / columns < last_index / columns {
+            // The last row is shorter than this one, so land on its final file
+            self.selected_index = last_index;
+        }
+        self.update_scroll_for_selection();
+    }
+
+    /// Move up one row in the grid view
+    pub fn move_row_up(&mut self) {
+        let columns = self.grid_columns.max(1);
+        if self.selected_index >= columns {
+            self.selected_index -= columns;
+            self.update_scroll_for_selection();
+        }
+    }
+
+    pub fn set_grid_columns(&mut self, columns: usize) {
+        let columns = columns.max(1);
+        if self.grid_columns != columns {
+            self.grid_columns = columns;
+            self.center_on_selection();
         }
     }
 
@@ -319,6 +987,11 @@ impl FileBrowser {
     }
 
     fn update_scroll_for_selection(&mut self) {
+        if self.grid_columns > 1 {
+            self.update_grid_scroll_for_selection();
+            return;
+        }
+
         if self.selected_index < self.scroll_offset {
             // Selection is above visible area, scroll up
             self.scroll_offset = self.selected_index;
@@ -330,6 +1003,22 @@ impl FileBrowser {
         }
     }
 
+    fn update_grid_scroll_for_selection(&mut self) {
+        // Scroll a whole row at a time so the grid columns stay aligned
+        let columns = self.grid_columns;
+        let visible_rows = (self.max_visible_files / columns).max(1);
+        let selected_row = self.selected_index / columns;
+        let mut first_row = self.scroll_offset / columns;
+
+        if selected_row < first_row {
+            first_row = selected_row;
+        } else if selected_row >= first_row + visible_rows {
+            first_row = selected_row + 1 - visible_rows;
+        }
+
+        self.scroll_offset = first_row * columns;
+    }
+
     pub fn jump_forward(&mut self) {
         if self.files.is_empty() {
             return;
@@ -370,6 +1059,16 @@ impl FileBrowser {
         }
     }
 
+    /// Switch to a sort mode, keeping the selected file selected
+    pub fn set_sort_mode(&mut self, sort_mode: SortMode) {
+        let selected_file = self.get_selected_file().map(|f| f.path.clone());
+        self.sort_mode = sort_mode;
+        self.sort_files();
+        if let Some(selected_path) = selected_file {
+            self.select_by_path(&selected_path);
+        }
+    }
+
     pub fn sort_by_name(&mut self) {
         if self.sort_mode == SortMode::Name {
             return; // Already sorted by name
@@ -383,7 +1082,7 @@ impl FileBrowser {
 
         // Find the file again and update selection
         if let Some(selected_path) = selected_file {
-            self.find_and_select_file(&selected_path);
+            self.select_by_path(&selected_path);
         }
     }
 
@@ -411,16 +1110,40 @@ impl FileBrowser {
 
         // Find the file again and update selection
         if let Some(selected_path) = selected_file {
-            self.find_and_select_file(&selected_path);
+            self.select_by_path(&selected_path);
         }
 
         message_key
     }
 
-    fn find_and_select_file(&mut self, file_path: &str) {
-        if let Some(index) = self.files.iter().position(|f| f.path == file_path) {
-            self.selected_index = index;
-            self.center_on_selection();
+    /// Only list files of one kind, selecting the first file that's still listed
+    pub fn set_content_filter(&mut self, filter: ContentFilter) -> Result<(), Box<dyn Error>> {
+        self.content_filter = filter;
+        self.refresh_files()?;
+        self.selected_index = self
+            .files
+            .iter()
+            .position(|file| !file.is_directory)
+            .unwrap_or(0);
+        self.center_on_selection();
+        Ok(())
+    }
+
+    pub fn cycle_content_filter(&mut self) -> Result<ContentFilter, Box<dyn Error>> {
+        self.set_content_filter(self.content_filter.next())?;
+        Ok(self.content_filter)
+    }
+
+    /// Select the listed file with this path. Returns whether it was found; the
+    /// selection is left alone when it isn't.
+    pub fn select_by_path(&mut self, path: &str) -> bool {
+        match self.files.iter().position(|f| f.path == path) {
+            Some(index) => {
+                self.selected_index = index;
+                self.center_on_selection();
+                true
+            }
+            None => false,
         }
     }
 
@@ -451,6 +1174,32 @@ impl FileBrowser {
         Ok(false)
     }
 
+    /// Jump to a file in any directory. Returns whether the file was found to select.
+    /// The way back up is forgotten since this isn't a step down the tree.
+    pub fn open_file_in_directory(
+        &mut self,
+        directory: &str,
+        file_name: &str,
+    ) -> Result<bool, Box<dyn Error>> {
+        let previous_dir = std::mem::replace(&mut self.current_dir, directory.to_string());
+        if let Err(e) = self.refresh_files() {
+            self.current_dir = previous_dir;
+            self.refresh_files()?;
+            return Err(e);
+        }
+        self.dir_stack.clear();
+        self.selected_index = 0;
+        self.scroll_offset = 0;
+
+        match self.files.iter().position(|file| file.name == file_name) {
+            Some(index) => {
+                self.set_selected_index(index);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
     pub fn go_to_parent(&mut self) -> Result<bool, Box<dyn Error>> {
         if let Some(parent) = Path::new(&self.current_dir).parent() {
             // Try to restore previous selection from stack when going back up
@@ -474,10 +1223,10 @@ impl FileBrowser {
 
             // Restore the previously selected index if available and matches, but ensure it's valid
             let mut restored_index = 0;
-            if let Some(index) = restored_selection {
-                if index < self.files.len() {
-                    restored_index = index;
-                }
+            if let Some(index) = restored_selection
+                && index < self.files.len()
+            {
+                restored_index = index;
             }
 
             self.selected_index = restored_index;
@@ -516,6 +1265,18 @@ impl FileBrowser {
             return;
         }
 
+        if self.grid_columns > 1 {
+            // Center by rows so the grid columns stay aligned
+            let columns = self.grid_columns;
+            let visible_rows = (self.max_visible_files / columns).max(1);
+            let total_rows = self.files.len().div_ceil(columns);
+            let first_row = (self.selected_index / columns)
+                .saturating_sub(visible_rows / 2)
+                .min(total_rows.saturating_sub(visible_rows));
+            self.scroll_offset = first_row * columns;
+            return;
+        }
+
         // Calculate the optimal scroll offset to center the selection
         let half_visible = self.max_visible_files / 2;
 
@@ -532,20 +1293,103 @@ impl FileBrowser {
         }
     }
 
-    pub fn get_display_files(&self) -> impl Iterator<Item = (usize, &FileItem)> {
-        self.files
-            .iter()
-            .enumerate()
-            .skip(self.scroll_offset)
-            .take(self.max_visible_files)
+    pub fn get_display_files(&self) -> impl Iterator<Item = (usize, &FileItem)> {
+        self.files
+            .iter()
+            .enumerate()
+            .skip(self.scroll_offset)
+            .take(self.max_visible_files)
+    }
+
+    pub fn get_current_dir_display(&self) -> String {
+        if self.current_dir.len() > 30 {
+            format!("...{}", &self.current_dir[self.current_dir.len() - 27..])
+        } else {
+            self.current_dir.clone()
+        }
+    }
+}
+
+/// Total size in bytes and number of files under a directory, or None if it took longer
+/// than the timeout. Symlinks are skipped and each inode is only counted once per device,
+/// so link cycles and hard links can't inflate the total. Unreadable entries are left out.
+pub fn directory_size(path: &Path, timeout: Duration) -> Option<(u64, u64)> {
+    let started = Instant::now();
+    #[cfg(unix)]
+    let mut visited: std::collections::HashSet<(u64, u64)> = std::collections::HashSet::new();
+    let mut pending = vec![path.to_path_buf()];
+    let (mut bytes, mut file_count) = (0u64, 0u64);
+
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            if started.elapsed() >= timeout {
+                return None;
+            }
+            let Ok(metadata) = fs::symlink_metadata(entry.path()) else {
+                continue;
+            };
+            if metadata.file_type().is_symlink() {
+                continue;
+            }
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::MetadataExt;
+                // Inode numbers are only unique within one filesystem
+                if !visited.insert((metadata.dev(), metadata.ino())) {
+                    continue;
+                }
+            }
+            if metadata.is_dir() {
+                pending.push(entry.path());
+            } else {
+                bytes += metadata.len();
+                file_count += 1;
+            }
+        }
+    }
+
+    Some((bytes, file_count))
+}
+
+/// Human readable size such as "1.23 GB", in binary units
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
     }
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.2} {}", size, UNITS[unit])
+}
 
-    pub fn get_current_dir_display(&self) -> String {
-        if self.current_dir.len() > 30 {
-            format!("...{}", &self.current_dir[self.current_dir.len() - 27..])
-        } else {
-            self.current_dir.clone()
+/// A count with thousands separators, e.g. 12,345
+pub fn format_count(count: u64) -> String {
+    let digits = count.to_string();
+    let mut formatted = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            formatted.push(',');
         }
+        formatted.push(digit);
+    }
+    formatted
+}
+
+/// A rough age in the largest whole unit, e.g. "45s", "12m", "3h" or "5d"
+pub fn format_age(age: Duration) -> String {
+    let seconds = age.as_secs();
+    match seconds {
+        0..60 => format!("{}s", seconds),
+        60..3600 => format!("{}m", seconds / 60),
+        3600..86400 => format!("{}h", seconds / 3600),
+        _ => format!("{}d", seconds / 86400),
     }
 }
 
@@ -570,6 +1414,32 @@ mod tests {
         assert_eq!(item.modified, UNIX_EPOCH);
     }
 
+    #[test]
+    fn test_export_current_listing_round_trip() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_test_image("photo.jpg").unwrap();
+        temp_fs.create_file("notes.txt", "hello").unwrap();
+        temp_fs.create_directory("album").unwrap();
+        let browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+
+        let listing = browser.export_current_listing();
+        let files: Vec<FileItem> = serde_json::from_str(&listing).unwrap();
+        assert_eq!(files.len(), browser.files.len());
+        for (file, original) in files.iter().zip(&browser.files) {
+            assert_eq!(file.name, original.name);
+            assert_eq!(file.path, original.path);
+            assert_eq!(file.is_directory, original.is_directory);
+            assert_eq!(file.modified, original.modified);
+            // Detection reads the file itself, so deserialized items behave the same
+            assert_eq!(file.is_image(), original.is_image());
+            assert_eq!(file.is_text_file(), original.is_text_file());
+        }
+        let photo = files.iter().find(|file| file.name == "photo.jpg").unwrap();
+        assert!(photo.is_image());
+        let notes = files.iter().find(|file| file.name == "notes.txt").unwrap();
+        assert!(notes.is_text_file() && !notes.is_image());
+    }
+
     #[test]
     fn test_file_item_is_image() {
         let temp_fs = TestFileSystem::new().unwrap();
@@ -591,6 +1461,12 @@ mod tests {
         let svg_item = FileItem::new("test.svg".to_string(), svg_path, false, UNIX_EPOCH);
         assert!(svg_item.is_image(), "Should detect SVG as image");
 
+        // QOI header of a 1x1 RGBA image, named without its extension
+        let qoi_content = b"qoif\x00\x00\x00\x01\x00\x00\x00\x01\x04\x00";
+        let qoi_path = temp_fs.create_binary_file("texture", qoi_content).unwrap();
+        let qoi_item = FileItem::new("texture".to_string(), qoi_path, false, UNIX_EPOCH);
+        assert!(qoi_item.is_image(), "Should detect QOI by its magic bytes");
+
         let dir_item = create_test_file_item("test.jpg", true);
         assert!(!dir_item.is_image(), "Directory should not be image");
 
@@ -599,6 +1475,354 @@ mod tests {
         assert!(!text_item.is_image(), "Text file should not be image");
     }
 
+    #[rstest::rstest]
+    #[case("clip.mp4", b"\x00\x00\x00\x20ftypisom\x00\x00\x02\x00".as_slice(), true)]
+    #[case("clip.mov", b"\x00\x00\x00\x14ftypqt  \x00\x00\x00\x00".as_slice(), true)]
+    #[case("old.mov", b"\x00\x00\x00\x08wide\x00\x00\x00\x00".as_slice(), true)]
+    #[case("clip.mkv", b"\x1a\x45\xdf\xa3\x9f\x42\x86\x81\x01\x42\xf7\x81".as_slice(), true)]
+    #[case("clip.avi", b"RIFF\x00\x10\x00\x00AVI LIST".as_slice(), true)]
+    #[case("photo.avif", b"\x00\x00\x00\x1cftypavif\x00\x00\x00\x00".as_slice(), false)]
+    #[case("photo.webp", b"RIFF\x00\x10\x00\x00WEBPVP8 ".as_slice(), false)]
+    #[case("short.bin", b"\x00\x01".as_slice(), false)]
+    fn test_file_item_is_video(#[case] name: &str, #[case] content: &[u8], #[case] expected: bool) {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let path = temp_fs.create_binary_file(name, content).unwrap();
+        let item = FileItem::new(name.to_string(), path, false, UNIX_EPOCH);
+        assert_eq!(item.is_video(), expected);
+
+        let dir_item = create_test_file_item(name, true);
+        assert!(!dir_item.is_video());
+    }
+
+    #[rstest::rstest]
+    #[case("song.mp3", b"ID3\x04\x00\x00\x00\x00\x00\x00".as_slice(), true)]
+    #[case("bare.mp3", b"\xff\xfb\x90\x64\x00".as_slice(), true)]
+    #[case("song.flac", b"fLaC\x00\x00\x00\x22".as_slice(), true)]
+    #[case("song.ogg", b"OggS\x00\x02\x00\x00".as_slice(), true)]
+    #[case("song.mp3", b"not really audio".as_slice(), false)]
+    #[case("short.bin", b"\xff".as_slice(), false)]
+    fn test_file_item_is_audio(#[case] name: &str, #[case] content: &[u8], #[case] expected: bool) {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let path = temp_fs.create_binary_file(name, content).unwrap();
+        let item = FileItem::new(name.to_string(), path, false, UNIX_EPOCH);
+        assert_eq!(item.is_audio(), expected);
+        if expected {
+            assert!(item.can_preview());
+        }
+
+        let dir_item = create_test_file_item(name, true);
+        assert!(!dir_item.is_audio());
+    }
+
+    #[rstest::rstest]
+    #[case("photo.bin", b"\x89PNG\r\n\x1a\n\x00\x00".as_slice(), FileCategory::Image)]
+    #[case("clip", b"\x1a\x45\xdf\xa3\x9f\x42\x86\x81\x01\x42\xf7\x81".as_slice(), FileCategory::Video)]
+    #[case("song", b"fLaC\x00\x00\x00\x22".as_slice(), FileCategory::Audio)]
+    #[case("backup.zip", b"PK\x03\x04\x14\x00\x00\x00".as_slice(), FileCategory::Archive)]
+    #[case("logs.gz", b"\x1f\x8b\x08\x00\x00\x00".as_slice(), FileCategory::Archive)]
+    #[case("notes.md", b"# Notes".as_slice(), FileCategory::Text)]
+    #[case("main.RS", b"fn main() {}".as_slice(), FileCategory::Code)]
+    #[case("data.bin", b"\x00\x01\x02\x03\xfe\xff".as_slice(), FileCategory::Unknown)]
+    fn test_file_item_category(
+        #[case] name: &str,
+        #[case] content: &[u8],
+        #[case] expected: FileCategory,
+    ) {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let path = temp_fs.create_binary_file(name, content).unwrap();
+        let item = FileItem::new(name.to_string(), path, false, UNIX_EPOCH);
+        assert_eq!(item.category(), expected);
+    }
+
+    #[test]
+    fn test_file_item_category_tar() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut header = vec![0u8; 512];
+        header[..8].copy_from_slice(b"file.txt");
+        header[257..262].copy_from_slice(b"ustar");
+        let path = temp_fs.create_binary_file("bundle", &header).unwrap();
+        let item = FileItem::new("bundle".to_string(), path, false, UNIX_EPOCH);
+        assert_eq!(item.category(), FileCategory::Archive);
+    }
+
+    #[test]
+    fn test_visible_categories_are_cached_until_refresh() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_file("notes.txt", "hello").unwrap();
+        let mut browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        let notes = browser.files[0].clone();
+        assert_eq!(browser.get_category(&notes), None);
+
+        browser.update_visible_categories();
+        assert_eq!(browser.get_category(&notes), Some(FileCategory::Text));
+
+        browser.refresh_files().unwrap();
+        assert_eq!(browser.get_category(&notes), None);
+    }
+
+    #[rstest::rstest]
+    #[case("a.jpg", b"\xff\xd8\xff\xe0\x00\x10JFIF\x00".as_slice(), Some("JPEG"))]
+    #[case("a.bin", b"\x89PNG\r\n\x1a\n\x00\x00".as_slice(), Some("PNG"))]
+    #[case("a.gif", b"GIF89a\x01\x00\x01\x00\x00\x00".as_slice(), Some("GIF"))]
+    #[case("a.heic", b"\x00\x00\x00\x18ftypheic\x00\x00".as_slice(), Some("HEIC"))]
+    #[case("a.svg", b"<?xml version=\"1.0\"?><svg></svg>".as_slice(), Some("SVG"))]
+    #[case("a.nef", b"MM\x00\x2a\x00\x00\x00\x08".as_slice(), Some("RAW"))]
+    #[case("a.txt", b"plain text".as_slice(), None)]
+    #[case("a.zip", b"PK\x03\x04\x14\x00\x00\x00".as_slice(), None)]
+    fn test_file_item_image_format(
+        #[case] name: &str,
+        #[case] content: &[u8],
+        #[case] expected: Option<&str>,
+    ) {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let path = temp_fs.create_binary_file(name, content).unwrap();
+        let item = FileItem::new(name.to_string(), path, false, UNIX_EPOCH);
+        assert_eq!(item.image_format(), expected);
+    }
+
+    #[test]
+    fn test_file_browser_new_for_path() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_test_image("a.jpg").unwrap();
+        let b_path = temp_fs.create_test_image("b.jpg").unwrap();
+
+        // A directory opens with the first entry selected
+        let browser = FileBrowser::new_for_path(temp_fs.get_path()).unwrap();
+        assert_eq!(browser.selected_index, 0);
+
+        // A file opens its directory with that file selected
+        let browser = FileBrowser::new_for_path(&b_path).unwrap();
+        assert_eq!(browser.get_selected_file().unwrap().name, "b.jpg");
+
+        assert!(FileBrowser::new_for_path(temp_fs.get_path().join("missing.jpg")).is_err());
+    }
+
+    #[rstest::rstest]
+    #[case(
+        b"",
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    )]
+    #[case(
+        b"abc",
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    )]
+    fn test_file_item_sha256(#[case] content: &[u8], #[case] expected: &str) {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let path = temp_fs.create_binary_file("data.bin", content).unwrap();
+        let item = FileItem::new("data.bin".to_string(), path, false, UNIX_EPOCH);
+        assert_eq!(item.sha256().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_file_item_sha256_spans_chunks() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        // Several chunks plus a partial one, hashed the same as in one go
+        let content = vec![7u8; CHECKSUM_CHUNK_SIZE * 3 + 100];
+        let path = temp_fs.create_binary_file("big.bin", &content).unwrap();
+        let item = FileItem::new("big.bin".to_string(), path, false, UNIX_EPOCH);
+
+        use sha2::{Digest, Sha256};
+        let expected: String = Sha256::digest(&content)
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        assert_eq!(item.sha256().unwrap(), expected);
+
+        let missing = FileItem::new(
+            "gone.bin".to_string(),
+            "/nonexistent/gone.bin".to_string(),
+            false,
+            UNIX_EPOCH,
+        );
+        assert!(missing.sha256().is_err());
+    }
+
+    #[test]
+    fn test_file_item_is_ico() {
+        let temp_fs = TestFileSystem::new().unwrap();
+
+        // Header and the start of the first directory entry (16x16)
+        let ico = temp_fs
+            .create_binary_file("favicon.ico", b"\x00\x00\x01\x00\x01\x00\x10\x10\x00\x00")
+            .unwrap();
+        let item = FileItem::new("favicon.ico".to_string(), ico, false, UNIX_EPOCH);
+        assert!(item.is_ico());
+        assert!(item.is_image());
+
+        // Cursors share the layout but aren't images we preview
+        let cursor = temp_fs
+            .create_binary_file("pointer.cur", b"\x00\x00\x02\x00\x01\x00\x10\x10\x00\x00")
+            .unwrap();
+        let item = FileItem::new("pointer.cur".to_string(), cursor, false, UNIX_EPOCH);
+        assert!(!item.is_ico());
+        assert!(!create_test_file_item("icons.ico", true).is_ico());
+    }
+
+    #[test]
+    fn test_file_item_is_tiff() {
+        let temp_fs = TestFileSystem::new().unwrap();
+
+        let little_endian = temp_fs
+            .create_binary_file("scan.tif", b"\x49\x49\x2a\x00\x08\x00\x00\x00")
+            .unwrap();
+        let item = FileItem::new("scan.tif".to_string(), little_endian, false, UNIX_EPOCH);
+        assert!(item.is_tiff());
+        assert!(item.is_image());
+
+        let big_endian = temp_fs
+            .create_binary_file("scan.tiff", b"\x4d\x4d\x00\x2a\x00\x00\x00\x08")
+            .unwrap();
+        let item = FileItem::new("scan.tiff".to_string(), big_endian, false, UNIX_EPOCH);
+        assert!(item.is_tiff());
+
+        let jpeg_path = temp_fs.create_test_image("photo.jpg").unwrap();
+        let jpeg_item = FileItem::new("photo.jpg".to_string(), jpeg_path, false, UNIX_EPOCH);
+        assert!(!jpeg_item.is_tiff());
+    }
+
+    #[rstest::rstest]
+    #[case(b"heic")]
+    #[case(b"heix")]
+    #[case(b"hevc")]
+    #[case(b"hevx")]
+    #[case(b"mif1")]
+    fn test_file_item_is_heic(#[case] brand: &[u8; 4]) {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut content = b"\x00\x00\x00\x18ftyp".to_vec();
+        content.extend_from_slice(brand);
+        content.extend_from_slice(b"\x00\x00\x00\x00mif1heic");
+        let path = temp_fs.create_binary_file("photo.heic", &content).unwrap();
+        let item = FileItem::new("photo.heic".to_string(), path, false, UNIX_EPOCH);
+
+        assert!(item.is_heic());
+        assert!(item.is_image());
+        assert!(!item.is_video());
+
+        // JPEGs and AVIF images are not HEIC
+        let jpeg_path = temp_fs.create_test_image("photo.jpg").unwrap();
+        let jpeg_item = FileItem::new("photo.jpg".to_string(), jpeg_path, false, UNIX_EPOCH);
+        assert!(!jpeg_item.is_heic());
+        let avif_path = temp_fs
+            .create_binary_file("photo.avif", b"\x00\x00\x00\x1cftypavif\x00\x00\x00\x00")
+            .unwrap();
+        let avif_item = FileItem::new("photo.avif".to_string(), avif_path, false, UNIX_EPOCH);
+        assert!(!avif_item.is_heic());
+    }
+
+    #[test]
+    fn test_refresh_files_reads_size_and_symlinks() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let notes = temp_fs.create_file("notes.txt", "some content").unwrap();
+        temp_fs.create_directory("album").unwrap();
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(&notes, temp_fs.get_path().join("link.txt")).unwrap();
+            std::os::unix::fs::symlink(
+                temp_fs.get_path().join("album"),
+                temp_fs.get_path().join("album-link"),
+            )
+            .unwrap();
+        }
+        let browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        let file = |name: &str| browser.files.iter().find(|f| f.name == name).unwrap();
+
+        assert_eq!(file("notes.txt").size_bytes, 12);
+        assert!(!file("notes.txt").is_symlink);
+        assert!(!file("album").is_symlink);
+
+        #[cfg(unix)]
+        {
+            assert!(file("link.txt").is_symlink);
+            assert_eq!(file("link.txt").size_bytes, 12);
+            assert!(file("album-link").is_symlink && file("album-link").is_directory);
+        }
+    }
+
+    #[rstest::rstest]
+    #[case(0o755, "rwx")]
+    #[case(0o644, "rw-")]
+    #[case(0o500, "r-x")]
+    #[case(0o044, "---")]
+    fn test_file_item_owner_permissions(#[case] mode: u32, #[case] expected: &str) {
+        let item = create_test_file_item("notes.txt", false).with_permissions(mode);
+        assert_eq!(item.owner_permissions(), expected);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_refresh_files_reads_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+        let temp_fs = TestFileSystem::new().unwrap();
+        let notes = temp_fs.create_file("notes.txt", "content").unwrap();
+        fs::set_permissions(&notes, fs::Permissions::from_mode(0o640)).unwrap();
+
+        let browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        assert_eq!(browser.files[0].permissions, 0o640);
+        assert_eq!(browser.files[0].owner_permissions(), "rw-");
+    }
+
+    #[test]
+    fn test_content_filter_images_only_hides_text_files() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_directory("album").unwrap();
+        temp_fs.create_file("notes.txt", "content").unwrap();
+        temp_fs.create_test_image("photo.jpg").unwrap();
+        let mut browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        let names = |browser: &FileBrowser| -> Vec<String> {
+            browser
+                .get_display_files()
+                .map(|(_, file)| file.name.clone())
+                .collect()
+        };
+        assert_eq!(names(&browser), ["album", "notes.txt", "photo.jpg"]);
+
+        browser
+            .set_content_filter(ContentFilter::ImagesOnly)
+            .unwrap();
+        assert_eq!(names(&browser), ["album", "photo.jpg"]);
+        assert_eq!(browser.get_selected_file().unwrap().name, "photo.jpg");
+
+        assert_eq!(
+            browser.cycle_content_filter().unwrap(),
+            ContentFilter::TextOnly
+        );
+        assert_eq!(names(&browser), ["album", "notes.txt"]);
+        assert_eq!(browser.get_selected_file().unwrap().name, "notes.txt");
+
+        assert_eq!(
+            browser.cycle_content_filter().unwrap(),
+            ContentFilter::NonPreviewable
+        );
+        assert_eq!(names(&browser), ["album"]);
+        assert_eq!(browser.selected_index, 0);
+
+        assert_eq!(browser.cycle_content_filter().unwrap(), ContentFilter::All);
+        assert_eq!(names(&browser), ["album", "notes.txt", "photo.jpg"]);
+    }
+
+    #[rstest::rstest]
+    #[case("backup.zip", true)]
+    #[case("art.ascii", false)]
+    fn test_non_previewable_filter(#[case] name: &str, #[case] matches: bool) {
+        let file = create_test_file_item(name, false);
+        assert_eq!(ContentFilter::NonPreviewable.matches(&file), matches);
+    }
+
+    #[rstest::rstest]
+    #[case("IMG_0001.CR2", true)]
+    #[case("IMG_0001.cr3", true)]
+    #[case("DSC_0001.nef", true)]
+    #[case("DSC00001.arw", true)]
+    #[case("photo.dng", true)]
+    #[case("P1000001.ORF", true)]
+    #[case("photo.jpg", false)]
+    #[case("raw", false)]
+    fn test_file_item_is_raw_image(#[case] name: &str, #[case] expected: bool) {
+        let item = create_test_file_item(name, false);
+        assert_eq!(item.is_raw_image(), expected);
+        assert!(!create_test_file_item(name, true).is_raw_image());
+    }
+
     #[test]
     fn test_file_item_is_text_file() {
         let temp_fs = TestFileSystem::new().unwrap();
@@ -704,6 +1928,161 @@ mod tests {
         assert_eq!(browser.sort_mode, SortMode::Name);
     }
 
+    #[test]
+    fn test_directory_size_counts_nested_files() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_file("a.txt", "12345").unwrap();
+        temp_fs.create_directory("sub/deeper").unwrap();
+        temp_fs.create_file("sub/b.txt", "123").unwrap();
+        temp_fs.create_file("sub/deeper/c.txt", "12").unwrap();
+
+        let size = directory_size(temp_fs.get_path(), Duration::from_secs(5));
+        assert_eq!(size, Some((10, 3)));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_directory_size_skips_symlinks_and_hard_links() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let file = temp_fs.create_file("a.txt", "12345").unwrap();
+        temp_fs.create_directory("sub").unwrap();
+        // A link back up the tree would loop forever if followed
+        std::os::unix::fs::symlink(temp_fs.get_path(), temp_fs.get_path().join("sub/loop"))
+            .unwrap();
+        fs::hard_link(&file, temp_fs.get_path().join("sub/same.txt")).unwrap();
+
+        let size = directory_size(temp_fs.get_path(), Duration::from_secs(5));
+        assert_eq!(size, Some((5, 1)));
+    }
+
+    #[test]
+    fn test_directory_size_times_out() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_file("a.txt", "12345").unwrap();
+        assert_eq!(directory_size(temp_fs.get_path(), Duration::ZERO), None);
+    }
+
+    #[rstest::rstest]
+    #[case(0, "0 B")]
+    #[case(1023, "1023 B")]
+    #[case(1536, "1.50 KB")]
+    #[case(1_320_702_444, "1.23 GB")]
+    fn test_format_size(#[case] bytes: u64, #[case] expected: &str) {
+        assert_eq!(format_size(bytes), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(0, "0")]
+    #[case(999, "999")]
+    #[case(12_345, "12,345")]
+    #[case(1_234_567, "1,234,567")]
+    fn test_format_count(#[case] count: u64, #[case] expected: &str) {
+        assert_eq!(format_count(count), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(0, "0s")]
+    #[case(59, "59s")]
+    #[case(60, "1m")]
+    #[case(7_200, "2h")]
+    #[case(86_400 * 3 + 5, "3d")]
+    fn test_format_age(#[case] seconds: u64, #[case] expected: &str) {
+        assert_eq!(format_age(Duration::from_secs(seconds)), expected);
+    }
+
+    #[test]
+    fn test_file_browser_git_status() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_file("new.txt", "content").unwrap();
+        let initialised = std::process::Command::new("git")
+            .args(["init", "-q"])
+            .current_dir(temp_fs.get_path())
+            .status()
+            .is_ok_and(|status| status.success());
+        if !initialised {
+            return; // git isn't installed
+        }
+
+        let mut browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        assert!(browser.git_status.is_none());
+
+        browser.set_git_integration(true);
+        let file = browser
+            .files
+            .iter()
+            .find(|f| f.name == "new.txt")
+            .unwrap()
+            .clone();
+        assert_eq!(browser.get_git_status(&file), Some(GitStatus::Untracked));
+
+        browser.set_git_integration(false);
+        assert_eq!(browser.get_git_status(&file), None);
+    }
+
+    #[test]
+    fn test_count_images_shallow() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_directory("photos/nested").unwrap();
+        temp_fs.create_test_image("photos/a.jpg").unwrap();
+        temp_fs.create_test_image("photos/no_extension").unwrap();
+        temp_fs.create_test_image("photos/nested/deep.jpg").unwrap();
+        temp_fs
+            .create_file("photos/fake.png", "not an image")
+            .unwrap();
+
+        let mut browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        let photos = browser
+            .files
+            .iter()
+            .find(|f| f.name == "photos")
+            .unwrap()
+            .clone();
+        assert_eq!(photos.count_images_shallow(), 2);
+        assert_eq!(browser.get_image_count(&photos), None);
+
+        assert!(browser.uncounted_visible_directories().is_empty());
+
+        browser.set_show_image_counts(true);
+        let uncounted = browser.uncounted_visible_directories();
+        assert_eq!(
+            uncounted
+                .iter()
+                .map(|dir| dir.name.as_str())
+                .collect::<Vec<_>>(),
+            ["photos"]
+        );
+        browser.set_image_counts(&[("photos".to_string(), 2)]);
+        assert_eq!(browser.get_image_count(&photos), Some(2));
+        assert!(browser.uncounted_visible_directories().is_empty());
+
+        // Counted again after a refresh
+        browser.refresh_files().unwrap();
+        assert_eq!(browser.get_image_count(&photos), None);
+        assert_eq!(browser.uncounted_visible_directories().len(), 1);
+    }
+
+    #[test]
+    fn test_file_browser_open_file_in_directory() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let other = temp_fs.create_directory("other").unwrap();
+        temp_fs.create_file("other/a.txt", "a").unwrap();
+        temp_fs.create_file("other/b.txt", "b").unwrap();
+        let mut browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+
+        assert!(browser.open_file_in_directory(&other, "b.txt").unwrap());
+        assert_eq!(browser.current_dir, other);
+        assert_eq!(browser.get_selected_file().unwrap().name, "b.txt");
+        assert!(!browser.open_file_in_directory(&other, "gone.txt").unwrap());
+
+        // A directory that can't be read leaves the browser where it was
+        assert!(
+            browser
+                .open_file_in_directory("/nonexistent/dir", "a.txt")
+                .is_err()
+        );
+        assert_eq!(browser.current_dir, other);
+    }
+
     #[test]
     fn test_file_browser_refresh_files() {
         let temp_fs = TestFileSystem::new().unwrap();
@@ -711,7 +2090,7 @@ mod tests {
         temp_fs.create_file("test2.jpg", "content2").unwrap();
         temp_fs.create_directory("subdir").unwrap();
 
-        let browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        let mut browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
 
         assert!(browser.files.len() >= 3);
 
@@ -720,6 +2099,10 @@ mod tests {
 
         assert_eq!(dir_count, 1);
         assert_eq!(file_count, 2);
+
+        let refreshes = browser.refresh_count();
+        browser.refresh_files().unwrap();
+        assert_eq!(browser.refresh_count(), refreshes + 1);
     }
 
     #[test]
@@ -802,6 +2185,28 @@ mod tests {
         assert_eq!(browser.selected_index, 0);
     }
 
+    #[test]
+    fn test_select_by_path() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_file("alpha.txt", "content").unwrap();
+        temp_fs.create_file("beta.txt", "content").unwrap();
+        let mut browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+
+        let beta_path = temp_fs.get_path().join("beta.txt");
+        let beta_path = beta_path.to_string_lossy();
+        let beta_index = browser
+            .files
+            .iter()
+            .position(|f| f.path == beta_path)
+            .unwrap();
+        assert!(browser.select_by_path(&beta_path));
+        assert_eq!(browser.selected_index, beta_index);
+
+        let missing_path = temp_fs.get_path().join("missing.txt");
+        assert!(!browser.select_by_path(&missing_path.to_string_lossy()));
+        assert_eq!(browser.selected_index, beta_index);
+    }
+
     #[test]
     fn test_file_browser_sorting() {
         let temp_fs = TestFileSystem::new().unwrap();
@@ -829,6 +2234,48 @@ mod tests {
         assert_eq!(first_file.name, "alpha.txt");
     }
 
+    fn names_sorted_for_locale(locale: &str) -> Vec<String> {
+        let temp_fs = TestFileSystem::new().unwrap();
+        for name in ["\u{e4}.txt", "b.txt", "a.txt"] {
+            temp_fs.create_file(name, "content").unwrap();
+        }
+
+        let mut browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        browser.set_locale(locale);
+        assert_eq!(browser.locale, locale);
+        browser.files.iter().map(|f| f.name.clone()).collect()
+    }
+
+    #[cfg(feature = "icu")]
+    #[rstest::rstest]
+    #[case("de", ["a.txt", "\u{e4}.txt", "b.txt"])]
+    #[case("sv", ["a.txt", "b.txt", "\u{e4}.txt"])]
+    fn test_name_sort_uses_locale_collation(#[case] locale: &str, #[case] expected: [&str; 3]) {
+        assert_eq!(names_sorted_for_locale(locale), expected);
+    }
+
+    #[cfg(not(feature = "icu"))]
+    #[test]
+    fn test_name_sort_without_icu_compares_lowercase() {
+        assert_eq!(
+            names_sorted_for_locale("de"),
+            ["a.txt", "b.txt", "\u{e4}.txt"]
+        );
+    }
+
+    #[test]
+    fn test_set_locale_keeps_selection() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        for name in ["a.txt", "b.txt", "c.txt"] {
+            temp_fs.create_file(name, "content").unwrap();
+        }
+        let mut browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        browser.set_selected_index(2);
+
+        browser.set_locale("ja");
+        assert_eq!(browser.get_selected_file().unwrap().name, "c.txt");
+    }
+
     #[test]
     fn test_file_browser_date_sort_toggle() {
         let temp_fs = TestFileSystem::new().unwrap();
@@ -1112,4 +2559,67 @@ mod tests {
             assert!(browser.selected_index < browser.scroll_offset + browser.max_visible_files);
         }
     }
+
+    #[test]
+    fn test_file_browser_grid_row_navigation() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        for i in 0..10 {
+            temp_fs
+                .create_file(&format!("file{:02}.txt", i), "content")
+                .unwrap();
+        }
+
+        let mut browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        browser.update_max_visible_files(6);
+        browser.set_grid_columns(3);
+
+        browser.move_row_down();
+        assert_eq!(browser.selected_index, 3);
+
+        browser.move_down();
+        assert_eq!(browser.selected_index, 4);
+
+        browser.move_row_up();
+        assert_eq!(browser.selected_index, 1);
+
+        // Can't move above the first row
+        browser.move_row_up();
+        assert_eq!(browser.selected_index, 1);
+
+        // The last row only holds file09, so moving down from file07 lands on it
+        browser.set_selected_index(7);
+        browser.move_row_down();
+        assert_eq!(browser.selected_index, 9);
+
+        browser.move_row_down();
+        assert_eq!(browser.selected_index, 9);
+    }
+
+    #[test]
+    fn test_file_browser_grid_scroll_stays_row_aligned() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        for i in 0..20 {
+            temp_fs
+                .create_file(&format!("file{:02}.txt", i), "content")
+                .unwrap();
+        }
+
+        let mut browser = FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        // Two visible rows of four files
+        browser.update_max_visible_files(8);
+        browser.set_grid_columns(4);
+
+        for _ in 0..9 {
+            browser.move_down();
+        }
+        assert_eq!(browser.selected_index, 9);
+        assert_eq!(browser.scroll_offset % 4, 0);
+        assert!(browser.selected_index >= browser.scroll_offset);
+        assert!(browser.selected_index < browser.scroll_offset + browser.max_visible_files);
+
+        browser.set_selected_index(17);
+        assert_eq!(browser.scroll_offset % 4, 0);
+        assert!(browser.selected_index >= browser.scroll_offset);
+        assert!(browser.selected_index < browser.scroll_offset + browser.max_visible_files);
+    }
 }
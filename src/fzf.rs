@@ -0,0 +1,72 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// fzf and the options it's run with. It reads names on stdin and prints the
+/// picked one on stdout, drawing its own UI on the terminal.
+pub const FZF_COMMAND: &[&str] = &["fzf", "--ansi"];
+
+/// Whether fzf can be found in PATH
+pub fn is_installed() -> bool {
+    Command::new("which")
+        .arg(FZF_COMMAND[0])
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// Pipe candidates to a picker such as fzf, one per line, and return the lines
+/// it prints. A non-zero exit means the user cancelled and nothing was picked.
+pub fn pick(command: &[&str], candidates: &[String]) -> Result<Vec<String>, String> {
+    let (program, args) = command.split_first().ok_or("No picker command")?;
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("{}: {}", program, e))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // The picker may exit before reading everything, e.g. when cancelled straight away
+        let _ = stdin.write_all(candidates.join("\n").as_bytes());
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("{}: {}", program, e))?;
+    if !output.status.success() {
+        return Ok(Vec::new());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_pick_returns_printed_lines() {
+        let candidates = names(&["a.jpg", "b.jpg", "notes.txt"]);
+        assert_eq!(
+            pick(&["grep", "jpg"], &candidates).unwrap(),
+            names(&["a.jpg", "b.jpg"])
+        );
+    }
+
+    #[test]
+    fn test_pick_cancelled() {
+        let candidates = names(&["a.jpg"]);
+        assert!(pick(&["false"], &candidates).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_pick_missing_program() {
+        assert!(pick(&["ptui-no-such-picker"], &names(&["a.jpg"])).is_err());
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Git state of an entry in the file browser
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GitStatus {
+    // Ordered by importance, so a directory shows the most pressing state of its contents
+    Modified,
+    Staged,
+    Untracked,
+    Ignored,
+}
+
+impl GitStatus {
+    /// One character indicator shown next to the file icon
+    pub fn indicator(&self) -> char {
+        match self {
+            GitStatus::Modified => 'M',
+            GitStatus::Untracked => '?',
+            GitStatus::Staged => 'A',
+            GitStatus::Ignored => '!',
+        }
+    }
+
+    /// Status from the two XY columns of a `git status --porcelain` line
+    fn from_porcelain_code(code: &str) -> Option<Self> {
+        let mut chars = code.chars();
+        let (index, worktree) = (chars.next()?, chars.next()?);
+        match (index, worktree) {
+            ('?', '?') => Some(GitStatus::Untracked),
+            ('!', '!') => Some(GitStatus::Ignored),
+            (_, worktree) if worktree != ' ' => Some(GitStatus::Modified),
+            (index, _) if index != ' ' => Some(GitStatus::Staged),
+            _ => None,
+        }
+    }
+}
+
+/// The top of the Git work tree holding a directory, found by walking up to a `.git`
+pub fn find_repo_root(dir: &Path) -> Option<PathBuf> {
+    let dir = dir.canonicalize().ok()?;
+    dir.ancestors()
+        .find(|ancestor| ancestor.join(".git").exists())
+        .map(Path::to_path_buf)
+}
+
+/// Status of each entry in a directory, keyed by entry name. Anything that goes wrong
+/// running git just means no indicators.
+pub fn status_for_directory(dir: &Path) -> Option<HashMap<String, GitStatus>> {
+    let repo_root = find_repo_root(dir)?;
+    let output = Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=all", "."])
+        .current_dir(dir)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    let dir = dir.canonicalize().ok()?;
+    Some(parse_porcelain(
+        &String::from_utf8_lossy(&output.stdout),
+        &repo_root,
+        &dir,
+    ))
+}
+
+/// Parse `git status --porcelain` output, whose paths are relative to the repo root, into
+/// statuses for the entries of `dir`. Changes inside a subdirectory mark the subdirectory.
+pub fn parse_porcelain(output: &str, repo_root: &Path, dir: &Path) -> HashMap<String, GitStatus> {
+    let mut statuses: HashMap<String, GitStatus> = HashMap::new();
+    for line in output.lines() {
+        let (Some(code), Some(path)) = (line.get(..2), line.get(3..)) else {
+            continue;
+        };
+        let Some(status) = GitStatus::from_porcelain_code(code) else {
+            continue;
+        };
+        // Renames are listed as "old -> new"
+        let path = path.rsplit(" -> ").next().unwrap_or(path).trim_matches('"');
+        let Ok(relative) = repo_root
+            .join(path)
+            .strip_prefix(dir)
+            .map(Path::to_path_buf)
+        else {
+            continue;
+        };
+        let Some(name) = relative.components().next() else {
+            continue;
+        };
+        let name = name.as_os_str().to_string_lossy().into_owned();
+        statuses
+            .entry(name)
+            .and_modify(|existing| *existing = (*existing).min(status))
+            .or_insert(status);
+    }
+    statuses
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rstest::rstest]
+    #[case(" M photo.jpg", "photo.jpg", GitStatus::Modified)]
+    #[case("MM photo.jpg", "photo.jpg", GitStatus::Modified)]
+    #[case("A  new.png", "new.png", GitStatus::Staged)]
+    #[case("M  photo.jpg", "photo.jpg", GitStatus::Staged)]
+    #[case("?? scratch.txt", "scratch.txt", GitStatus::Untracked)]
+    #[case("!! build.log", "build.log", GitStatus::Ignored)]
+    #[case("R  old.jpg -> renamed.jpg", "renamed.jpg", GitStatus::Staged)]
+    #[case("?? \"with space.jpg\"", "with space.jpg", GitStatus::Untracked)]
+    fn test_parse_porcelain_line(
+        #[case] line: &str,
+        #[case] name: &str,
+        #[case] expected: GitStatus,
+    ) {
+        let statuses = parse_porcelain(line, Path::new("/repo"), Path::new("/repo"));
+        assert_eq!(statuses.get(name), Some(&expected));
+        assert_eq!(statuses.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_porcelain_relative_to_directory() {
+        let output = "?? photos/holiday/a.jpg\n M photos/holiday/b.jpg\n M photos/c.jpg\n\
+                      ?? docs/readme.md\n";
+        let statuses = parse_porcelain(output, Path::new("/repo"), Path::new("/repo/photos"));
+
+        // The subdirectory shows its most important change, files elsewhere are left out
+        assert_eq!(statuses.get("holiday"), Some(&GitStatus::Modified));
+        assert_eq!(statuses.get("c.jpg"), Some(&GitStatus::Modified));
+        assert_eq!(statuses.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_porcelain_ignores_malformed_lines() {
+        let statuses = parse_porcelain("\nX\n  \n", Path::new("/repo"), Path::new("/repo"));
+        assert!(statuses.is_empty());
+    }
+
+    #[test]
+    fn test_find_repo_root_walks_up() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let nested = temp_dir.path().join("a/b");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::create_dir(temp_dir.path().join("a/.git")).unwrap();
+
+        let root = find_repo_root(&nested).unwrap();
+        assert_eq!(root, temp_dir.path().join("a").canonicalize().unwrap());
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Oldest entries drop off once the history is this long
+pub const MAX_RECENT_FILES: usize = 50;
+
+/// Recently previewed files as (path, directory), most recent first
+#[derive(Debug, Default)]
+pub struct RecentFiles {
+    entries: VecDeque<(String, String)>,
+}
+
+impl RecentFiles {
+    /// Where the history is kept between runs: $XDG_CACHE_HOME/ptui/recent.json on Linux
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::cache_dir().map(|dir| dir.join("ptui").join("recent.json"))
+    }
+
+    /// Load the saved history. A missing or unreadable file starts a new one.
+    pub fn load_from(path: &Path) -> Self {
+        let entries: Vec<(String, String)> = fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default();
+        Self {
+            entries: entries.into_iter().take(MAX_RECENT_FILES).collect(),
+        }
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(&self.entries)?)?;
+        Ok(())
+    }
+
+    /// Move a file to the front, dropping any older entry for the same path
+    pub fn push(&mut self, path: &str, directory: &str) {
+        if self.entries.front().is_some_and(|(front, _)| front == path) {
+            return;
+        }
+        self.entries.retain(|(existing, _)| existing != path);
+        self.entries
+            .push_front((path.to_string(), directory.to_string()));
+        self.entries.truncate(MAX_RECENT_FILES);
+    }
+
+    pub fn get(&self, index: usize) -> Option<&(String, String)> {
+        self.entries.get(index)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &(String, String)> {
+        self.entries.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recent_files_most_recent_first_without_duplicates() {
+        let mut recent = RecentFiles::default();
+        recent.push("/p/a.jpg", "/p");
+        recent.push("/p/b.jpg", "/p");
+        recent.push("/p/a.jpg", "/p");
+
+        let paths: Vec<&str> = recent.iter().map(|(path, _)| path.as_str()).collect();
+        assert_eq!(paths, ["/p/a.jpg", "/p/b.jpg"]);
+    }
+
+    #[test]
+    fn test_recent_files_capped() {
+        let mut recent = RecentFiles::default();
+        for i in 0..MAX_RECENT_FILES + 5 {
+            recent.push(&format!("/p/{}.jpg", i), "/p");
+        }
+        assert_eq!(recent.len(), MAX_RECENT_FILES);
+        assert_eq!(
+            recent.get(0).unwrap().0,
+            format!("/p/{}.jpg", MAX_RECENT_FILES + 4)
+        );
+    }
+
+    #[test]
+    fn test_recent_files_save_and_load() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("ptui").join("recent.json");
+        let mut recent = RecentFiles::default();
+        recent.push("/p/a.jpg", "/p");
+        recent.push("/q/b.txt", "/q");
+        recent.save_to(&path).unwrap();
+
+        let loaded = RecentFiles::load_from(&path);
+        assert_eq!(
+            loaded.get(0),
+            Some(&("/q/b.txt".to_string(), "/q".to_string()))
+        );
+        assert_eq!(loaded.len(), 2);
+
+        // A corrupt or missing file just means no history
+        fs::write(&path, "not json").unwrap();
+        assert!(RecentFiles::load_from(&path).is_empty());
+        assert!(RecentFiles::load_from(&temp_dir.path().join("missing.json")).is_empty());
+    }
+}
@@ -0,0 +1,214 @@
+use ansi_to_tui::IntoText;
+use ratatui::style::{Color, Modifier};
+
+/// The 16 standard terminal colours, as xterm draws them
+const BASIC_COLORS: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// CSS colour for a terminal colour, None for the default
+fn css_color(color: Color) -> Option<String> {
+    let (r, g, b) = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(index) => indexed_rgb(index),
+        Color::Black => BASIC_COLORS[0],
+        Color::Red => BASIC_COLORS[1],
+        Color::Green => BASIC_COLORS[2],
+        Color::Yellow => BASIC_COLORS[3],
+        Color::Blue => BASIC_COLORS[4],
+        Color::Magenta => BASIC_COLORS[5],
+        Color::Cyan => BASIC_COLORS[6],
+        Color::Gray => BASIC_COLORS[7],
+        Color::DarkGray => BASIC_COLORS[8],
+        Color::LightRed => BASIC_COLORS[9],
+        Color::LightGreen => BASIC_COLORS[10],
+        Color::LightYellow => BASIC_COLORS[11],
+        Color::LightBlue => BASIC_COLORS[12],
+        Color::LightMagenta => BASIC_COLORS[13],
+        Color::LightCyan => BASIC_COLORS[14],
+        Color::White => BASIC_COLORS[15],
+    };
+    Some(format!("#{:02x}{:02x}{:02x}", r, g, b))
+}
+
+/// RGB of a colour in the 256 colour palette: the basic 16, a 6x6x6 cube, then greys
+fn indexed_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => BASIC_COLORS[index as usize],
+        16..=231 => {
+            let level = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+            let cube = index - 16;
+            (level(cube / 36), level(cube / 6 % 6), level(cube % 6))
+        }
+        _ => {
+            let grey = 8 + (index - 232) * 10;
+            (grey, grey, grey)
+        }
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Convert ANSI coloured text to HTML, each styled run becoming a span with inline CSS
+pub fn ansi_to_html(ansi: &str) -> String {
+    let Ok(text) = ansi.as_bytes().into_text() else {
+        return escape_html(&crate::preview::strip_ansi_codes(ansi));
+    };
+
+    let mut html = String::new();
+    for (i, line) in text.lines.iter().enumerate() {
+        if i > 0 {
+            html.push('\n');
+        }
+        for span in &line.spans {
+            let style = line.style.patch(span.style);
+            let mut css = Vec::new();
+            css.extend(style.fg.and_then(css_color).map(|c| format!("color:{}", c)));
+            css.extend(
+                style
+                    .bg
+                    .and_then(css_color)
+                    .map(|c| format!("background:{}", c)),
+            );
+            if style.add_modifier.contains(Modifier::BOLD) {
+                css.push("font-weight:bold".to_string());
+            }
+            if style.add_modifier.contains(Modifier::ITALIC) {
+                css.push("font-style:italic".to_string());
+            }
+            if style.add_modifier.contains(Modifier::UNDERLINED) {
+                css.push("text-decoration:underline".to_string());
+            }
+
+            let content = escape_html(&span.content);
+            if css.is_empty() {
+                html.push_str(&content);
+            } else {
+                html.push_str(&format!(
+                    "<span style=\"{}\">{}</span>",
+                    css.join(";"),
+                    content
+                ));
+            }
+        }
+    }
+    html
+}
+
+/// A self-contained page that plays the frames in a loop, each shown for delay_ms
+pub fn slideshow_html(frames: &[String], delay_ms: u64) -> String {
+    let delay_ms = delay_ms.max(1);
+    let total_ms = delay_ms * frames.len().max(1) as u64;
+    // Each frame is visible for its share of the cycle, then hidden until it comes round again
+    let visible_percent = 100.0 / frames.len().max(1) as f64;
+
+    let mut html = format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>ptui slideshow</title>
+<style>
+body {{ background: #000; color: #e5e5e5; margin: 0; }}
+.slideshow {{ position: relative; }}
+.frame {{
+  position: absolute;
+  top: 0;
+  left: 0;
+  margin: 0;
+  font-family: monospace;
+  line-height: 1.1;
+  visibility: hidden;
+  animation: ptui-slideshow {total_ms}ms step-end infinite;
+}}
+@keyframes ptui-slideshow {{
+  0% {{ visibility: visible; }}
+  {visible_percent:.4}%, 100% {{ visibility: hidden; }}
+}}
+</style>
+</head>
+<body>
+<div class="slideshow">
+"#
+    );
+    for (index, frame) in frames.iter().enumerate() {
+        html.push_str(&format!(
+            "<pre class=\"frame\" data-idx=\"{}\" style=\"animation-delay: {}ms\">{}</pre>\n",
+            index,
+            index as u64 * delay_ms,
+            ansi_to_html(frame)
+        ));
+    }
+    html.push_str("</div>\n</body>\n</html>\n");
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ansi_to_html_styles_and_escapes() {
+        assert_eq!(
+            ansi_to_html("plain <text> & more"),
+            "plain &lt;text&gt; &amp; more"
+        );
+        assert_eq!(
+            ansi_to_html("\x1b[38;2;255;128;0mA\x1b[0mB"),
+            "<span style=\"color:#ff8000\">A</span>B"
+        );
+        assert_eq!(
+            ansi_to_html("\x1b[1;31;44mX\x1b[0m\nY"),
+            "<span style=\"color:#cd0000;background:#0000ee;font-weight:bold\">X</span>\nY"
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(1, (205, 0, 0))]
+    #[case(9, (255, 0, 0))]
+    #[case(16, (0, 0, 0))]
+    #[case(196, (255, 0, 0))]
+    #[case(231, (255, 255, 255))]
+    #[case(232, (8, 8, 8))]
+    #[case(255, (238, 238, 238))]
+    fn test_indexed_rgb(#[case] index: u8, #[case] expected: (u8, u8, u8)) {
+        assert_eq!(indexed_rgb(index), expected);
+    }
+
+    #[test]
+    fn test_slideshow_html_timing() {
+        let frames = vec!["one".to_string(), "\x1b[32mtwo\x1b[0m".to_string()];
+        let html = slideshow_html(&frames, 3000);
+
+        assert!(html.contains("animation: ptui-slideshow 6000ms step-end infinite"));
+        assert!(html.contains("50.0000%, 100% { visibility: hidden; }"));
+        assert!(html.contains(
+            "<pre class=\"frame\" data-idx=\"0\" style=\"animation-delay: 0ms\">one</pre>"
+        ));
+        assert!(html.contains(
+            "<pre class=\"frame\" data-idx=\"1\" style=\"animation-delay: 3000ms\"><span style=\"color:#00cd00\">two</span></pre>"
+        ));
+        // Nothing to fetch from elsewhere
+        assert!(!html.contains("src=") && !html.contains("href="));
+    }
+}
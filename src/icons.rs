@@ -0,0 +1,228 @@
+use crate::file_browser::FileItem;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+const IMAGE_EXTENSIONS: &[&str] = &[
+    "jpg", "jpeg", "png", "gif", "bmp", "webp", "tif", "tiff", "avif", "heic", "heif", "svg",
+    "ico", "qoi", "cr2", "cr3", "nef", "arw", "dng", "orf",
+];
+const TEXT_EXTENSIONS: &[&str] = &[
+    "txt", "md", "log", "csv", "json", "toml", "yaml", "yml", "xml", "html", "css", "js", "rs",
+    "py", "sh", "c", "h", "cpp",
+];
+const VIDEO_EXTENSIONS: &[&str] = &["mp4", "m4v", "mkv", "webm", "avi", "mov"];
+const AUDIO_EXTENSIONS: &[&str] = &["mp3", "flac", "wav", "ogg", "oga", "opus", "m4a", "aac"];
+const ARCHIVE_EXTENSIONS: &[&str] = &["zip", "tar", "gz", "tgz", "bz2", "xz", "zst", "7z", "rar"];
+
+/// Icons shown in front of entries in the file list and grid, by kind of file.
+/// Fields left out of the config keep their emoji default.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct FileIcons {
+    pub directory: String,
+    pub image: String,
+    pub text: String,
+    pub code: String,
+    pub video: String,
+    pub audio: String,
+    pub archive: String,
+    pub unknown: String,
+}
+
+impl Default for FileIcons {
+    fn default() -> Self {
+        Self {
+            directory: "📁".to_string(),
+            image: "🖼️".to_string(),
+            text: "📄".to_string(),
+            code: "📜".to_string(),
+            video: "🎬".to_string(),
+            audio: "🎵".to_string(),
+            archive: "📦".to_string(),
+            unknown: "❔".to_string(),
+        }
+    }
+}
+
+impl FileIcons {
+    /// Plain letters for terminals without emoji support
+    pub fn ascii() -> Self {
+        Self {
+            directory: "[D]".to_string(),
+            image: "[I]".to_string(),
+            text: "[T]".to_string(),
+            code: "[C]".to_string(),
+            video: "[V]".to_string(),
+            audio: "[A]".to_string(),
+            archive: "[Z]".to_string(),
+            unknown: "[?]".to_string(),
+        }
+    }
+
+    /// Glyphs from a Nerd Font patched terminal font
+    pub fn nerd_fonts() -> Self {
+        Self {
+            directory: "\u{F115}".to_string(),
+            image: "\u{F1C5}".to_string(),
+            text: "\u{F15C}".to_string(),
+            code: "\u{F1C9}".to_string(),
+            video: "\u{F1C8}".to_string(),
+            audio: "\u{F1C7}".to_string(),
+            archive: "\u{F1C6}".to_string(),
+            unknown: "\u{F016}".to_string(),
+        }
+    }
+
+    /// A named icon set: "emoji", "nerd-fonts", or "ascii" (also called "compact")
+    pub fn preset(name: &str) -> Option<Self> {
+        match name {
+            "emoji" => Some(Self::default()),
+            "nerd-fonts" => Some(Self::nerd_fonts()),
+            "ascii" | "compact" => Some(Self::ascii()),
+            _ => None,
+        }
+    }
+
+    /// The icon for a list entry
+    pub fn icon_for(&self, file: &FileItem) -> &str {
+        match FileKind::of(file) {
+            FileKind::Directory => &self.directory,
+            FileKind::Image => &self.image,
+            FileKind::Ascii | FileKind::Text => &self.text,
+            FileKind::Video => &self.video,
+            FileKind::Audio => &self.audio,
+            FileKind::Archive => &self.archive,
+            FileKind::Unknown => &self.unknown,
+        }
+    }
+
+    /// The icon for a category in the file list's icon column
+    pub fn icon_for_category(&self, category: FileCategory) -> &str {
+        match category {
+            FileCategory::Image => &self.image,
+            FileCategory::Video => &self.video,
+            FileCategory::Audio => &self.audio,
+            FileCategory::Text => &self.text,
+            FileCategory::Code => &self.code,
+            FileCategory::Archive => &self.archive,
+            FileCategory::Unknown => &self.unknown,
+        }
+    }
+}
+
+/// What a file holds going by its contents, for the file list's icon column.
+/// Unlike [`FileKind`] this reads the start of the file, so it's worked out once per
+/// visible file and cached by the browser.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum FileCategory {
+    Image,
+    Video,
+    Audio,
+    Text,
+    Code,
+    Archive,
+    Unknown,
+}
+
+impl FileCategory {
+    pub const ALL: [FileCategory; 7] = [
+        FileCategory::Image,
+        FileCategory::Video,
+        FileCategory::Audio,
+        FileCategory::Text,
+        FileCategory::Code,
+        FileCategory::Archive,
+        FileCategory::Unknown,
+    ];
+}
+
+/// What a list entry looks like it holds, for picking its icon and colour
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileKind {
+    Directory,
+    Image,
+    Ascii,
+    Text,
+    Video,
+    Audio,
+    Archive,
+    Unknown,
+}
+
+impl FileKind {
+    /// Files are told apart by extension so drawing the list doesn't have to read
+    /// every file
+    pub fn of(file: &FileItem) -> Self {
+        if file.is_directory {
+            return Self::Directory;
+        }
+        let extension = Path::new(&file.name)
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        let is = |extensions: &[&str]| extensions.contains(&extension.as_str());
+
+        if is(IMAGE_EXTENSIONS) {
+            Self::Image
+        } else if extension == "ascii" {
+            Self::Ascii
+        } else if is(VIDEO_EXTENSIONS) {
+            Self::Video
+        } else if is(AUDIO_EXTENSIONS) {
+            Self::Audio
+        } else if is(ARCHIVE_EXTENSIONS) {
+            Self::Archive
+        } else if is(TEXT_EXTENSIONS) {
+            Self::Text
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::helpers::create_test_file_item;
+
+    #[rstest::rstest]
+    #[case("photo.JPG", false, "[I]")]
+    #[case("notes.md", false, "[T]")]
+    #[case("logo.ascii", false, "[T]")]
+    #[case("clip.mkv", false, "[V]")]
+    #[case("song.flac", false, "[A]")]
+    #[case("backup.tar.gz", false, "[Z]")]
+    #[case("Makefile", false, "[?]")]
+    #[case("album.jpg", true, "[D]")]
+    fn test_icon_for(#[case] name: &str, #[case] is_directory: bool, #[case] expected: &str) {
+        let file = create_test_file_item(name, is_directory);
+        assert_eq!(FileIcons::ascii().icon_for(&file), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(FileCategory::Image, "[I]")]
+    #[case(FileCategory::Code, "[C]")]
+    #[case(FileCategory::Unknown, "[?]")]
+    fn test_icon_for_category(#[case] category: FileCategory, #[case] expected: &str) {
+        assert_eq!(FileIcons::ascii().icon_for_category(category), expected);
+    }
+
+    #[test]
+    fn test_presets() {
+        assert_eq!(FileIcons::preset("emoji"), Some(FileIcons::default()));
+        assert_eq!(
+            FileIcons::preset("nerd-fonts").unwrap().directory,
+            "\u{F115}"
+        );
+        assert_eq!(FileIcons::preset("compact"), Some(FileIcons::ascii()));
+        assert_eq!(FileIcons::preset("wingdings"), None);
+    }
+
+    #[test]
+    fn test_partial_icons_keep_defaults() {
+        let icons: FileIcons = serde_json::from_str(r#"{"directory": "D"}"#).unwrap();
+        assert_eq!(icons.directory, "D");
+        assert_eq!(icons.image, FileIcons::default().image);
+    }
+}
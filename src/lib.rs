@@ -1,12 +1,75 @@
+//! ptui is a terminal image browser, and its pieces can be used on their own to
+//! show image previews in other terminal applications.
+//!
+//! [`PreviewManager`](preview::PreviewManager) converts images to styled text with
+//! chafa or jp2a and caches the results, so a custom TUI only has to draw the text:
+//!
+//! ```no_run
+//! use ptui::prelude::*;
+//! use ratatui::widgets::{Block, Borders, Paragraph};
+//!
+//! let config = PTuiConfig::load().unwrap_or_default();
+//! let mut previews = PreviewManager::new(config);
+//! let localization = Localization::new("en")?;
+//! let browser = FileBrowser::new_with_dir("/home/me/Pictures")?;
+//!
+//! let mut terminal = ratatui::init();
+//! if let Some(file) = browser.get_selected_file()
+//!     && let PreviewContent::Text(text, _) = previews.generate_preview(file, 80, 24, 0, &localization)
+//! {
+//!     terminal.draw(|frame| {
+//!         let block = Block::default().borders(Borders::ALL).title(file.name.as_str());
+//!         frame.render_widget(Paragraph::new(text).block(block), frame.area());
+//!     })?;
+//! }
+//! ratatui::restore();
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [`ChafaTui`] is the whole application, for embedding the full browser.
+
+#[macro_use]
+mod macros;
+
 pub mod app;
+pub mod args;
+pub mod clipboard;
+pub mod command;
 pub mod config;
 pub mod converter;
+pub mod error;
 pub mod fast_image_loader;
 pub mod file_browser;
+pub mod fzf;
+pub mod git;
+pub mod history;
+pub mod html_export;
+pub mod icons;
 pub mod localization;
+pub mod opener;
+pub mod plugin;
 pub mod preview;
+pub mod session;
+pub mod theme;
 pub mod transitions;
 pub mod ui;
+pub mod watcher;
+
+pub use app::ChafaTui;
+
+/// The types most embedders need
+pub mod prelude {
+    pub use crate::app::ChafaTui;
+    pub use crate::config::PTuiConfig;
+    pub use crate::converter::{AsciiConverter, create_converter};
+    pub use crate::error::PTuiError;
+    pub use crate::file_browser::{FileBrowser, FileItem};
+    pub use crate::localization::Localization;
+    pub use crate::plugin::PreviewPlugin;
+    pub use crate::preview::{ContentAlignment, PreviewContent, PreviewManager};
+    pub use crate::transitions::TransitionManager;
+    pub use crate::ui::{UILayout, UIRenderer};
+}
 
 #[cfg(test)]
 mod test_utils;
@@ -1,4 +1,5 @@
-use fluent::{FluentArgs, FluentBundle, FluentResource};
+use fluent::concurrent::FluentBundle;
+use fluent::{FluentArgs, FluentResource};
 use std::error::Error;
 use unic_langid::LanguageIdentifier;
 
@@ -7,6 +8,57 @@ include!(concat!(env!("OUT_DIR"), "/locales.rs"));
 
 const DEFAULT_LOCALE: &str = "en";
 
+/// Keyboard shortcut lines shown on the help screen, in display order
+const HELP_TEXT_KEYS: &[&str] = &[
+    "keys_navigation",
+    "keys_page_navigation",
+    "keys_jump_navigation",
+    "keys_fuzzy_search",
+    "keys_command_prompt",
+    "keys_home_end_navigation",
+    "keys_chords",
+    "keys_sort",
+    "keys_content_filter",
+    "keys_enter_directory",
+    "keys_backspace_parent_dir",
+    "keys_resize_window",
+    "keys_refresh_image",
+    "keys_save_ascii",
+    "keys_batch_export",
+    "keys_delete_file",
+    "keys_copy_path",
+    "keys_copy_preview",
+    "keys_checksum",
+    "keys_directory_size",
+    "keys_open_in_browser",
+    "keys_open_with",
+    "keys_charset_picker",
+    "keys_permissions",
+    "keys_average_color",
+    "keys_metadata",
+    "keys_recent_files",
+    "keys_dual_pane",
+    "keys_slideshow",
+    "keys_slideshow_shuffle",
+    "keys_slideshow_loop",
+    "keys_slideshow_filename",
+    "keys_slideshow_speed",
+    "keys_slideshow_save_frame",
+    "keys_slideshow_export_html",
+    "keys_fullscreen",
+    "keys_grid_view",
+    "keys_debug_pane",
+    "keys_animation_pause",
+    "keys_tiff_pages",
+    "keys_text_scroll",
+    "keys_text_scroll_line",
+    "keys_converter_cycle",
+    "keys_help_toggle",
+    "help_scroll_up",
+    "help_scroll_down",
+    "keys_quit",
+];
+
 pub struct Localization {
     bundle: FluentBundle<FluentResource>,
     current_locale: String,
@@ -14,16 +66,15 @@ pub struct Localization {
 
 impl Localization {
     pub fn new(locale: &str) -> Result<Self, Box<dyn Error>> {
-        let langid: LanguageIdentifier = locale
+        let locales_map = get_embedded_locales();
+        let resolved = find_embedded_locale(locale).unwrap_or(DEFAULT_LOCALE);
+        let langid: LanguageIdentifier = resolved
             .parse()
             .unwrap_or_else(|_| DEFAULT_LOCALE.parse().unwrap());
-        let mut bundle = FluentBundle::new(vec![langid]);
+        // The concurrent bundle lets previews be made on other threads
+        let mut bundle = FluentBundle::new_concurrent(vec![langid]);
 
-        let locales_map = get_embedded_locales();
-        let resource_content = locales_map
-            .get(locale)
-            .or_else(|| locales_map.get(DEFAULT_LOCALE))
-            .ok_or("Locale not found")?;
+        let resource_content = locales_map.get(resolved).ok_or("Locale not found")?;
 
         let resource = FluentResource::try_new(resource_content.to_string())
             .map_err(|e| format!("Failed to load resource: {:?}", e))?;
@@ -69,26 +120,11 @@ impl Localization {
     }
 
     pub fn get_help_text(&self) -> String {
+        let key_lines: Vec<String> = HELP_TEXT_KEYS.iter().map(|key| self.get(key)).collect();
         format!(
-            "{}\n\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
+            "{}\n\n{}",
             self.get("select_image_to_preview"),
-            self.get("keys_navigation"),
-            self.get("keys_page_navigation"),
-            self.get("keys_jump_navigation"),
-            self.get("keys_home_end_navigation"),
-            self.get("keys_sort"),
-            self.get("keys_enter_directory"),
-            self.get("keys_backspace_parent_dir"),
-            self.get("keys_resize_window"),
-            self.get("keys_refresh_image"),
-            self.get("keys_save_ascii"),
-            self.get("keys_delete_file"),
-            self.get("keys_open_in_browser"),
-            self.get("keys_slideshow"),
-            self.get("keys_text_scroll"),
-            self.get("keys_converter_cycle"),
-            self.get("keys_help_toggle"),
-            self.get("keys_quit")
+            key_lines.join("\n")
         )
     }
 
@@ -97,6 +133,31 @@ impl Localization {
     }
 }
 
+/// The embedded locale for a locale name: an exact match, otherwise one for the same
+/// language (so "pt" and "pt_PT" get pt-BR). Encodings like ".UTF-8" are ignored.
+pub fn find_embedded_locale(locale: &str) -> Option<&'static str> {
+    let normalized = locale
+        .split(['.', '@'])
+        .next()
+        .unwrap_or_default()
+        .replace('_', "-");
+    let language = normalized.split('-').next().unwrap_or_default();
+    let mut names: Vec<&'static str> = get_embedded_locales().keys().copied().collect();
+    names.sort_unstable();
+
+    names
+        .iter()
+        .find(|name| name.eq_ignore_ascii_case(&normalized))
+        .or_else(|| {
+            names.iter().find(|name| {
+                name.split('-')
+                    .next()
+                    .is_some_and(|name_language| name_language.eq_ignore_ascii_case(language))
+            })
+        })
+        .copied()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -160,6 +221,8 @@ mod tests {
     #[case("fr")]
     #[case("ja")]
     #[case("zh")]
+    #[case("pt-BR")]
+    #[case("ru")]
     fn test_localization_supported_locales(#[case] locale: &str) {
         let result = Localization::new(locale);
         assert!(result.is_ok(), "Locale {} should be supported", locale);
@@ -210,6 +273,155 @@ mod tests {
         }
     }
 
+    #[rstest::rstest]
+    #[case("en")]
+    #[case("de")]
+    #[case("es")]
+    #[case("fr")]
+    #[case("ja")]
+    #[case("zh")]
+    #[case("pt-BR")]
+    #[case("ru")]
+    fn test_localization_help_keys_translated(#[case] locale: &str) {
+        let localization = Localization::new(locale).unwrap();
+
+        for key in HELP_TEXT_KEYS {
+            let message = localization.get(key);
+            assert_ne!(
+                message, *key,
+                "Key {} should be translated in locale {}",
+                key, locale
+            );
+        }
+    }
+
+    #[rstest::rstest]
+    #[case("pt-BR", "pt-BR")]
+    #[case("pt", "pt-BR")]
+    #[case("pt_PT", "pt-BR")]
+    #[case("PT-br", "pt-BR")]
+    #[case("de-AT", "de")]
+    #[case("ru_RU.UTF-8", "ru")]
+    fn test_find_embedded_locale(#[case] requested: &str, #[case] expected: &str) {
+        assert_eq!(find_embedded_locale(requested), Some(expected));
+    }
+
+    #[rstest::rstest]
+    #[case("xx")]
+    #[case("")]
+    fn test_find_embedded_locale_unknown(#[case] requested: &str) {
+        assert_eq!(find_embedded_locale(requested), None);
+    }
+
+    #[test]
+    fn test_portuguese_falls_through_to_brazilian() {
+        let localization = Localization::new("pt").unwrap();
+        assert_eq!(localization.get("keys_help_toggle"), "?: Mostrar ajuda");
+        assert_eq!(localization.current_locale(), "pt");
+    }
+
+    #[test]
+    fn test_russian_help_text_is_cyrillic() {
+        let localization = Localization::new("ru").unwrap();
+        let help_text = localization.get_help_text();
+
+        // Cyrillic block U+0400 to U+04FF
+        let is_cyrillic = |c: char| c.is_alphabetic() && ('\u{0400}'..='\u{04FF}').contains(&c);
+        assert!(help_text.chars().any(is_cyrillic));
+        assert!(help_text.contains("Показать справку"));
+
+        // Multi-byte values survive argument substitution intact
+        use fluent::fluent_args;
+        let args = fluent_args!["page" => 2, "total" => 5];
+        let page = localization.get_with_args("tiff_page", Some(&args));
+        assert!(page.starts_with("Страница"));
+        assert!(page.contains('5'));
+    }
+
+    #[test]
+    fn test_all_locales_have_the_same_keys_as_english() {
+        fn keys(content: &str) -> std::collections::BTreeSet<&str> {
+            content
+                .lines()
+                .filter(|line| !line.starts_with([' ', '#']))
+                .filter_map(|line| line.split_once('=').map(|(key, _)| key.trim()))
+                .collect()
+        }
+
+        let locales = get_embedded_locales();
+        let english = keys(locales[DEFAULT_LOCALE]);
+        for (locale, content) in &locales {
+            assert_eq!(
+                keys(content),
+                english,
+                "Locale {} keys differ from en",
+                locale
+            );
+        }
+    }
+
+    /// Message text without the Unicode isolation marks Fluent puts around arguments
+    fn without_isolation(text: String) -> String {
+        text.replace(['\u{2068}', '\u{2069}'], "")
+    }
+
+    #[rstest::rstest]
+    #[case("slideshow_image_of", "current", "total", "Image 3/12")]
+    #[case("tiff_page", "page", "total", "Page 3 of 12")]
+    fn test_get_with_args_substitutes_arguments(
+        #[case] key: &str,
+        #[case] first: &str,
+        #[case] second: &str,
+        #[case] expected: &str,
+    ) {
+        use fluent::fluent_args;
+        let localization = Localization::new("en").unwrap();
+        let args = fluent_args![first => 3, second => 12];
+        assert_eq!(
+            without_isolation(localization.get_with_args(key, Some(&args))),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_cache_hit_rate_substitutes_percent() {
+        use fluent::fluent_args;
+        let args = fluent_args!["percent" => 75];
+        for (locale, expected) in [("en", "Hit rate: 75%"), ("de", "Trefferquote: 75%")] {
+            let localization = Localization::new(locale).unwrap();
+            assert_eq!(
+                without_isolation(localization.get_with_args("cache_hit_rate", Some(&args))),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_files_selected_substitutes_count() {
+        use fluent::fluent_args;
+        let args = fluent_args!["count" => 4];
+        for (locale, expected) in [("en", "4 files selected"), ("ru", "Выбрано файлов: 4")]
+        {
+            let localization = Localization::new(locale).unwrap();
+            assert_eq!(
+                without_isolation(localization.get_with_args("files_selected", Some(&args))),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_with_args_missing_argument_keeps_placeholder() {
+        use fluent::fluent_args;
+        let localization = Localization::new("en").unwrap();
+        let args = fluent_args!["current" => 3];
+        let message = localization.get_with_args("slideshow_image_of", Some(&args));
+        assert_eq!(without_isolation(message), "Image 3/{$total}");
+
+        let message = localization.get_with_args("slideshow_image_of", None);
+        assert_eq!(without_isolation(message), "Image {$current}/{$total}");
+    }
+
     #[test]
     fn test_fluent_args_empty() {
         let localization = Localization::new("en").unwrap();
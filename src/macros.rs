@@ -0,0 +1,24 @@
+/// Print a timing or diagnostic line to stderr, only in builds with the
+/// `debug-output` feature. Anything printed while the TUI is up lands on the
+/// screen, so release builds stay quiet. The arguments are still type-checked
+/// but never evaluated when it's off.
+macro_rules! debug_timing {
+    ($($arg:tt)*) => {
+        if cfg!(all(not(test), feature = "debug-output")) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_debug_timing_is_silent_in_tests() {
+        let mut evaluated = 0;
+        debug_timing!("[TIMING] {}", {
+            evaluated += 1;
+            evaluated
+        });
+        assert_eq!(evaluated, 0);
+    }
+}
@@ -1,37 +1,88 @@
+#[macro_use]
+mod macros;
+
 mod app;
+mod args;
+mod clipboard;
+mod command;
 mod config;
 mod converter;
+mod error;
 mod fast_image_loader;
 mod file_browser;
+mod fzf;
+mod git;
+mod history;
+mod html_export;
+mod icons;
 mod localization;
+mod opener;
+mod plugin;
 mod preview;
+mod session;
+mod theme;
 mod transitions;
 mod ui;
+mod watcher;
 
 #[cfg(test)]
 mod test_utils;
 
 use app::ChafaTui;
-use clap::Command;
+use args::Args;
+use clap::Parser;
 use config::PTuiConfig;
 use crossterm::{
     event::{self, Event},
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
+use file_browser::FileBrowser;
 use ratatui::{Terminal, backend::CrosstermBackend};
 use std::io::stdout;
-use std::time::{Duration, Instant};
+use theme::ThemeConfig;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Parse command line arguments
-    let _matches = Command::new("ptui")
-        .version(env!("CARGO_PKG_VERSION"))
-        .about("Picture TUI - Terminal-based image viewer")
-        .get_matches();
+    let args = Args::parse();
+    if args.print_theme_defaults {
+        println!("{}", serde_json::to_string_pretty(&ThemeConfig::default())?);
+        return Ok(());
+    }
+    if let Some(shell) = args.print_completions {
+        Args::write_completions(shell, &mut stdout());
+        return Ok(());
+    }
+    if args.list_converters {
+        converter::write_converter_list(&mut stdout())?;
+        return Ok(());
+    }
+    if args.check_config {
+        let config_path = PTuiConfig::get_config_path()?;
+        let config = if config_path.exists() {
+            PTuiConfig::try_reload_from_file(&config_path)?
+        } else {
+            println!("No config file at {:?}, using the defaults", config_path);
+            PTuiConfig::default()
+        };
+        println!("{}", config);
+        for warning in config.validate() {
+            println!("Warning: {}", warning);
+        }
+        return Ok(());
+    }
+    if args.list_files {
+        let browser = match args.start_path() {
+            Some(path) => FileBrowser::new_for_path(path)?,
+            None => FileBrowser::new()?,
+        };
+        // Piping into head closes stdout early, which isn't an error for a listing
+        let _ = writeln!(stdout(), "{}", browser.export_current_listing());
+        return Ok(());
+    }
 
     // Create app
-    let mut app = ChafaTui::new()?;
+    let mut app = ChafaTui::new(&args)?;
 
     // Start config file watcher
     let config_watcher_rx = match PTuiConfig::start_config_watcher() {
@@ -49,18 +100,45 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    // Rate limiting for resize events
-    let mut last_resize_event = Instant::now();
-    let min_resize_interval = Duration::from_millis(100);
-
     // Main loop
     loop {
         // Update slideshow timing
         app.update_slideshow();
 
+        // Run a lone chord key like g or d once the chord has timed out
+        if app.poll_key_chord().is_err() {
+            break;
+        }
+
         // Update transitions and check if redraw is needed
         let _need_redraw = app.update_transitions();
 
+        // Apply a resize held back by the debounce, then preview the selection
+        // once navigation has settled
+        app.poll_pending_resize();
+        app.poll_pending_preview();
+
+        // Pick up finished background image conversions, grid thumbnails and text previews
+        app.poll_conversion();
+        app.poll_thumbnails();
+        app.poll_preview_handle();
+
+        // Pick up finished background checksums, directory sizes, image counts and
+        // average colours
+        app.poll_checksum();
+        app.poll_directory_size();
+        app.poll_image_counts();
+        app.poll_average_color();
+
+        // Update the batch export progress overlay
+        app.poll_batch_export();
+
+        // Refresh the file list when the current directory changes on disk
+        app.poll_directory_changes();
+
+        // Draw only happens when something changed, so clear expired messages here too
+        app.poll_notification();
+
         // Check for config file changes
         if let Some(ref config_rx) = config_watcher_rx
             && let Ok(config_result) = config_rx.try_recv()
@@ -81,29 +159,44 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         if app.needs_redraw() {
             // Clear Kitty graphics if switching from graphical to text mode
             app.clear_graphics_if_needed();
+            if app.take_terminal_clear_request() {
+                terminal.clear()?;
+            }
             terminal.draw(|f| app.draw(f))?;
             // Render Kitty graphics AFTER ratatui's frame is flushed
             app.render_kitty_post_draw();
         }
 
         // Handle events with timeout for slideshow
-        if event::poll(Duration::from_millis(50))? {
+        if event::poll(app.poll_interval())? {
             match event::read()? {
-                Event::Key(key) => {
-                    if app.handle_key_event(key).is_err() {
-                        break;
-                    }
-                }
-                Event::Resize(width, height) => {
-                    let now = Instant::now();
-                    if now.duration_since(last_resize_event) >= min_resize_interval {
-                        last_resize_event = now;
-                        app.handle_resize(width, height);
-                    }
+                Event::Key(key) if app.handle_key_event(key).is_err() => {
+                    break;
                 }
+                Event::Resize(width, height) => app.handle_resize_event(width, height),
                 _ => {}
             }
         }
+
+        // Hand the terminal over to fzf for a search started with Ctrl+F
+        if app.take_fuzzy_search_request() {
+            disable_raw_mode()?;
+            execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+            app.run_fuzzy_search();
+            enable_raw_mode()?;
+            execute!(terminal.backend_mut(), EnterAlternateScreen)?;
+            terminal.clear()?;
+        }
+
+        // Likewise for the editor opened by :e config
+        if app.take_config_edit_request() {
+            disable_raw_mode()?;
+            execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+            app.run_config_editor();
+            enable_raw_mode()?;
+            execute!(terminal.backend_mut(), EnterAlternateScreen)?;
+            terminal.clear()?;
+        }
     }
 
     // Cleanup: Clear screen and delete any lingering Kitty protocol images
@@ -120,5 +213,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     disable_raw_mode()?;
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+
+    // Remember recently viewed files for the next run
+    if let Err(e) = app.save_recent_files() {
+        eprintln!("Warning: Failed to save recent files: {}", e);
+    }
+    if let Err(e) = app.save_session() {
+        eprintln!("Warning: Failed to save session: {}", e);
+    }
     Ok(())
 }
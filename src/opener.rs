@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// An application that can open a file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Opener {
+    pub name: String,
+    // Program followed by its arguments, possibly with %f style placeholders for the file
+    pub command: Vec<String>,
+}
+
+impl Opener {
+    /// Build an opener from a command line such as "feh -F"
+    pub fn from_command(command: &str) -> Option<Self> {
+        let parts: Vec<String> = command.split_whitespace().map(str::to_string).collect();
+        let name = parts.first()?.clone();
+        Some(Self {
+            name,
+            command: parts,
+        })
+    }
+
+    /// The arguments to run for a file. Desktop entry field codes (%f, %F, %u, %U)
+    /// are replaced by the path; without any the path is added at the end.
+    pub fn args_for(&self, path: &str) -> Vec<String> {
+        let mut has_placeholder = false;
+        let mut args = Vec::new();
+        for arg in &self.command {
+            match arg.as_str() {
+                "%f" | "%F" | "%u" | "%U" => {
+                    has_placeholder = true;
+                    args.push(path.to_string());
+                }
+                // Other field codes (icon, name, location) have nothing to fill them
+                code if code.len() == 2 && code.starts_with('%') => {}
+                _ => args.push(arg.clone()),
+            }
+        }
+        if !has_placeholder {
+            args.push(path.to_string());
+        }
+        args
+    }
+
+    /// Start the application without waiting for it
+    pub fn launch(&self, path: &str) -> Result<(), String> {
+        let args = self.args_for(path);
+        let (program, rest) = args.split_first().ok_or("empty command")?;
+        Command::new(program)
+            .args(rest)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// The configured open_with command for a file, matched on its extension ignoring case.
+/// Keys may be written with or without the leading dot.
+pub fn open_with_command(open_with: &HashMap<String, String>, path: &str) -> Option<String> {
+    let extension = Path::new(path)
+        .extension()?
+        .to_string_lossy()
+        .to_lowercase();
+    open_with
+        .iter()
+        .find(|(key, _)| key.trim_start_matches('.').to_lowercase() == extension)
+        .map(|(_, command)| command.clone())
+}
+
+/// Applications the desktop offers for the file, for the O picker. The open_with
+/// config is left out since o already runs it.
+#[cfg(target_os = "linux")]
+pub fn detect_openers(path: &str) -> Vec<Opener> {
+    xdg_default_opener(path).into_iter().collect()
+}
+
+/// Only xdg-mime is asked so far, so other systems have nothing to offer
+#[cfg(not(target_os = "linux"))]
+pub fn detect_openers(_path: &str) -> Vec<Opener> {
+    Vec::new()
+}
+
+/// Ask xdg-mime for the default application for the file's type
+#[cfg(target_os = "linux")]
+fn xdg_default_opener(path: &str) -> Option<Opener> {
+    let query = |args: &[&str]| {
+        let output = Command::new("xdg-mime").args(args).output().ok()?;
+        let answer = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        (output.status.success() && !answer.is_empty()).then_some(answer)
+    };
+    let mime_type = query(&["query", "filetype", path])?;
+    let desktop_id = query(&["query", "default", &mime_type])?;
+
+    let contents = application_dirs()
+        .into_iter()
+        .find_map(|dir| std::fs::read_to_string(dir.join(&desktop_id)).ok())?;
+    parse_desktop_entry(&contents)
+}
+
+/// Where desktop entries live, most specific first
+#[cfg(target_os = "linux")]
+fn application_dirs() -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = Vec::new();
+    if let Some(data_home) = std::env::var_os("XDG_DATA_HOME") {
+        dirs.push(PathBuf::from(data_home));
+    } else if let Some(home) = dirs::home_dir() {
+        dirs.push(home.join(".local/share"));
+    }
+    let data_dirs = std::env::var("XDG_DATA_DIRS")
+        .unwrap_or_else(|_| "/usr/local/share:/usr/share".to_string());
+    dirs.extend(
+        data_dirs
+            .split(':')
+            .filter(|d| !d.is_empty())
+            .map(PathBuf::from),
+    );
+    dirs.into_iter()
+        .map(|dir| dir.join("applications"))
+        .collect()
+}
+
+/// Read the Name and Exec keys of a .desktop file's [Desktop Entry] section
+pub fn parse_desktop_entry(contents: &str) -> Option<Opener> {
+    let mut in_entry = false;
+    let mut name = None;
+    let mut exec = None;
+    for line in contents.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_entry = line == "[Desktop Entry]";
+            continue;
+        }
+        if !in_entry {
+            continue;
+        }
+        // Only the untranslated Name, not Name[de] and friends
+        if let Some(value) = line.strip_prefix("Name=") {
+            name.get_or_insert_with(|| value.to_string());
+        } else if let Some(value) = line.strip_prefix("Exec=") {
+            exec.get_or_insert_with(|| value.replace('"', ""));
+        }
+    }
+
+    let mut opener = Opener::from_command(&exec?)?;
+    if let Some(name) = name {
+        opener.name = name;
+    }
+    Some(opener)
+}
+
+/// State of the open-with dialog shown by O
+pub struct OpenWithPicker {
+    pub file_path: String,
+    pub file_name: String,
+    pub openers: Vec<Opener>,
+    pub selected: usize,
+}
+
+impl OpenWithPicker {
+    pub fn move_down(&mut self) {
+        if self.selected + 1 < self.openers.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn move_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn selected_opener(&self) -> Option<&Opener> {
+        self.openers.get(self.selected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open_with_map(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries
+            .iter()
+            .map(|(ext, cmd)| (ext.to_string(), cmd.to_string()))
+            .collect()
+    }
+
+    #[rstest::rstest]
+    #[case("/photos/beach.jpg", Some("feh"))]
+    #[case("/photos/BEACH.JPG", Some("feh"))]
+    #[case("/docs/manual.pdf", Some("zathura --fork"))]
+    #[case("/docs/notes.txt", None)]
+    #[case("/docs/README", None)]
+    fn test_open_with_command_lookup(#[case] path: &str, #[case] expected: Option<&str>) {
+        let open_with = open_with_map(&[("jpg", "feh"), (".PDF", "zathura --fork")]);
+        assert_eq!(open_with_command(&open_with, path).as_deref(), expected);
+    }
+
+    #[test]
+    fn test_opener_args_for() {
+        let opener = Opener::from_command("zathura --fork").unwrap();
+        assert_eq!(opener.name, "zathura");
+        assert_eq!(opener.args_for("/a.pdf"), ["zathura", "--fork", "/a.pdf"]);
+
+        let desktop = Opener::from_command("eog --new-instance %U %i").unwrap();
+        assert_eq!(
+            desktop.args_for("/a.png"),
+            ["eog", "--new-instance", "/a.png"]
+        );
+
+        assert!(Opener::from_command("   ").is_none());
+    }
+
+    #[test]
+    fn test_parse_desktop_entry() {
+        let contents = "[Desktop Entry]\nName=Image Viewer\nName[de]=Bildbetrachter\n\
+                        Exec=\"eog\" %U\n\n[Desktop Action new-window]\nExec=eog --new-window";
+        let opener = parse_desktop_entry(contents).unwrap();
+        assert_eq!(opener.name, "Image Viewer");
+        assert_eq!(opener.command, ["eog", "%U"]);
+
+        assert!(parse_desktop_entry("[Desktop Entry]\nName=No Exec").is_none());
+    }
+
+    #[test]
+    fn test_open_with_picker_selection() {
+        let mut picker = OpenWithPicker {
+            file_path: "/a.jpg".to_string(),
+            file_name: "a.jpg".to_string(),
+            openers: vec![
+                Opener::from_command("feh").unwrap(),
+                Opener::from_command("eog").unwrap(),
+            ],
+            selected: 0,
+        };
+        picker.move_up();
+        assert_eq!(picker.selected, 0);
+        picker.move_down();
+        picker.move_down();
+        assert_eq!(picker.selected_opener().unwrap().name, "eog");
+    }
+}
@@ -0,0 +1,98 @@
+use crate::file_browser::FileItem;
+use crate::localization::Localization;
+use crate::preview::ContentAlignment;
+use ansi_to_tui::IntoText;
+use ratatui::text::Text;
+
+/// A handler for a kind of file the preview pane doesn't know about. Plugins are
+/// asked in the order they were registered, before the built-in image and text
+/// previews, and the first one whose `can_handle` returns true makes the preview.
+pub trait PreviewPlugin {
+    fn can_handle(&self, file: &FileItem) -> bool;
+
+    /// The whole preview for the file. Scrolling is applied afterwards by
+    /// dropping lines from the top.
+    fn generate_preview(
+        &self,
+        file: &FileItem,
+        width: u16,
+        height: u16,
+    ) -> Result<Text<'static>, String>;
+
+    /// What the debug pane shows while this plugin's preview is on screen
+    fn description(&self, file: &FileItem, _localization: &Localization) -> String {
+        file.name.clone()
+    }
+
+    /// How the preview lines up in the pane; plugins show text by default
+    fn alignment(&self) -> ContentAlignment {
+        ContentAlignment::Text
+    }
+}
+
+/// Shows .ascii files as they are, keeping any ANSI colour codes in them
+pub struct AsciiFilePlugin;
+
+impl PreviewPlugin for AsciiFilePlugin {
+    fn can_handle(&self, file: &FileItem) -> bool {
+        file.is_ascii_file()
+    }
+
+    fn generate_preview(
+        &self,
+        file: &FileItem,
+        _width: u16,
+        _height: u16,
+    ) -> Result<Text<'static>, String> {
+        let content = std::fs::read_to_string(&file.path)
+            .map_err(|_| "Could not read ASCII file".to_string())?;
+        // If ANSI parsing fails, display as plain text
+        Ok(content
+            .as_bytes()
+            .into_text()
+            .unwrap_or_else(|_| Text::from(content.clone())))
+    }
+
+    fn description(&self, file: &FileItem, localization: &Localization) -> String {
+        format!("{}{}", localization.get("ascii_file_prefix"), file.name)
+    }
+
+    fn alignment(&self) -> ContentAlignment {
+        ContentAlignment::Image
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::helpers::{TestFileSystem, create_test_file_item};
+
+    #[rstest::rstest]
+    #[case("logo.ascii", true)]
+    #[case("notes.txt", false)]
+    #[case("photo.png", false)]
+    fn test_ascii_plugin_can_handle(#[case] name: &str, #[case] expected: bool) {
+        let file = create_test_file_item(name, false);
+        assert_eq!(AsciiFilePlugin.can_handle(&file), expected);
+    }
+
+    #[test]
+    fn test_ascii_plugin_keeps_colours() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let mut file = create_test_file_item("logo.ascii", false);
+        file.path = temp_fs
+            .create_file("logo.ascii", "\x1b[31mred\x1b[0m\nplain")
+            .unwrap();
+
+        let text = AsciiFilePlugin.generate_preview(&file, 80, 24).unwrap();
+        assert_eq!(text.lines.len(), 2);
+        assert_eq!(text.lines[0].spans[0].content, "red");
+        assert_ne!(text.lines[0].spans[0].style, Default::default());
+    }
+
+    #[test]
+    fn test_ascii_plugin_missing_file() {
+        let file = create_test_file_item("/nonexistent/logo.ascii", false);
+        assert!(AsciiFilePlugin.generate_preview(&file, 80, 24).is_err());
+    }
+}
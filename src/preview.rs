@@ -1,23 +1,36 @@
 use crate::config::PTuiConfig;
-use crate::converter::{self, AsciiConverter};
+use crate::converter::{self, AsciiConverter, HeicConverter, RawConverter};
+use crate::error::PTuiError;
 use crate::fast_image_loader::FastImageLoader;
-use crate::file_browser::FileItem;
+use crate::file_browser::{FileItem, format_age, format_size};
+use crate::html_export;
 use crate::localization::Localization;
+use crate::plugin::{AsciiFilePlugin, PreviewPlugin};
 use ansi_to_tui::IntoText;
-use base64::{engine::general_purpose, Engine};
-use image::DynamicImage;
-use ratatui::text::Text;
+use base64::{Engine, engine::general_purpose};
+use fluent::FluentArgs;
+use image::{DynamicImage, GenericImageView};
+use ratatui::layout::Alignment;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span, Text};
 use ratatui_image::picker::Picker;
 #[cfg(not(test))]
 use ratatui_image::picker::ProtocolType;
 use ratatui_image::protocol::StatefulProtocol;
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fs;
-use std::io::{BufRead, BufReader, Write};
-use std::path::Path;
+use std::hash::{Hash, Hasher};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(test, allow(dead_code))]
@@ -29,15 +42,409 @@ pub enum TerminalGraphicsSupport {
     None, // Fallback to text mode (chafa)
 }
 
+/// How text in the preview pane is lined up, decided by what it shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentAlignment {
+    /// ASCII art and short messages, centred in the pane
+    Image,
+    /// The contents of a text file, read from the left
+    Text,
+    /// The help screen shown when nothing is selected
+    Help,
+}
+
+impl ContentAlignment {
+    pub fn alignment(self) -> Alignment {
+        match self {
+            ContentAlignment::Image => Alignment::Center,
+            ContentAlignment::Text | ContentAlignment::Help => Alignment::Left,
+        }
+    }
+}
+
 #[derive(Clone)]
 #[allow(dead_code)]
 pub enum PreviewContent {
-    Text(Text<'static>),
+    Text(Text<'static>, ContentAlignment),
     Graphical(Rc<RefCell<GraphicalPreview>>),
     /// Fast Kitty rendering using viuer-style pre-encoding
     Kitty(Rc<RefCell<KittyPreview>>),
+    /// A Sixel or iTerm2 image escape made by the converter, written after the frame is drawn
+    Inline(Rc<InlinePreview>),
+    /// Pre-rendered frames of an animated GIF, cycled by the app
+    Animated {
+        frames: Vec<Text<'static>>,
+        frame_duration_ms: u64,
+    },
+}
+
+impl PreviewContent {
+    /// How the preview lines up in the pane; only text previews can be left-aligned
+    pub fn alignment(&self) -> ContentAlignment {
+        match self {
+            PreviewContent::Text(_, alignment) => *alignment,
+            _ => ContentAlignment::Image,
+        }
+    }
+
+    /// The content to draw for the given animation frame; still previews are returned as-is
+    pub fn frame(&self, index: usize) -> PreviewContent {
+        match self {
+            PreviewContent::Animated { frames, .. } if !frames.is_empty() => PreviewContent::Text(
+                frames[index % frames.len()].clone(),
+                ContentAlignment::Image,
+            ),
+            other => other.clone(),
+        }
+    }
+}
+
+/// Result of a background conversion, sent back to the UI thread
+pub enum ConvertedPreview {
+    Still(Text<'static>),
+    Animated {
+        frames: Vec<Text<'static>>,
+        frame_duration_ms: u64,
+    },
+    // The converter failed or timed out
+    Failed(String),
+    /// A RAW file's extracted JPEG and how converting it went
+    Raw {
+        jpeg: TempFileGuard,
+        converted: Box<ConvertedPreview>,
+    },
+    /// What ffprobe found about an audio file, and its waveform with how converting it went
+    Audio {
+        info: Option<AudioInfo>,
+        waveform: Option<(TempFileGuard, Box<ConvertedPreview>)>,
+    },
+    /// There was nothing to convert, with the message to show instead
+    Unavailable(String),
+}
+
+/// A preview being made off the UI thread, from request_preview_async.
+/// The sender is dropped without sending when the preview isn't text.
+/// Text files also send how many lines they have, for the scrollbar.
+pub struct PreviewHandle {
+    receiver: Receiver<(Text<'static>, Option<usize>)>,
+}
+
+impl PreviewHandle {
+    pub fn try_recv(&self) -> Result<(Text<'static>, Option<usize>), mpsc::TryRecvError> {
+        self.receiver.try_recv()
+    }
+}
+
+/// Progress of a batch ASCII export, sent back to the UI thread
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExportProgress {
+    /// Started on a file; position counts from 1
+    Exporting {
+        position: usize,
+        total: usize,
+        file_name: String,
+    },
+    Done {
+        succeeded: usize,
+        failed: usize,
+        skipped: usize,
+    },
+    /// Nothing was written, e.g. no slideshow image could be converted
+    Failed(String),
+}
+
+/// What became of one file in a batch export
+enum ExportOutcome {
+    Saved,
+    // It already had a .ascii file and overwriting was off
+    Skipped,
+    Failed(String),
+}
+
+/// What the background worker converts for a request
+enum ConversionSource {
+    /// The image at the request's path, at the request's size
+    Image,
+    /// A RAW camera file, converted once its JPEG is extracted and fitted within the
+    /// request's size. The message is shown when no extractor is installed.
+    Raw { no_extractor_message: String },
+    /// An audio file, probed with ffprobe and, when waveform is set, drawn by ffmpeg
+    /// and converted within the request's size
+    Audio { waveform: bool },
+}
+
+/// A conversion for the background worker thread, answered on response_tx
+struct ConversionRequest {
+    config: PTuiConfig,
+    path: String,
+    source: ConversionSource,
+    width: u16,
+    height: u16,
+    animate: bool,
+    response_tx: Sender<ConvertedPreview>,
+}
+
+/// A grid thumbnail for the background worker, answered with its cache key on response_tx
+struct ThumbnailRequest {
+    config: PTuiConfig,
+    path: String,
+    width: u16,
+    height: u16,
+    cache_key: String,
+    response_tx: Sender<(String, Option<Text<'static>>)>,
+}
+
+/// Work for the background worker thread
+enum WorkerRequest {
+    Preview(ConversionRequest),
+    Thumbnail(ThumbnailRequest),
+}
+
+/// Deletes a temporary file when dropped, so extracted frames don't outlive the app
+pub struct TempFileGuard {
+    path: PathBuf,
+}
+
+impl TempFileGuard {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// A stable temp file path derived from the source file, e.g. /tmp/ptui_thumb_<hash>.jpg
+    pub fn path_for(prefix: &str, source: &str, extension: &str) -> PathBuf {
+        let mut hasher = DefaultHasher::new();
+        source.hash(&mut hasher);
+        std::env::temp_dir().join(format!("{}_{:016x}.{}", prefix, hasher.finish(), extension))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempFileGuard {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// A frame extracted from a video along with the details shown in the debug pane
+struct VideoThumbnail {
+    frame: TempFileGuard,
+    summary: String,
+}
+
+/// Tags and stream details of an audio file, as reported by ffprobe
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AudioInfo {
+    title: Option<String>,
+    artist: Option<String>,
+    album: Option<String>,
+    duration_secs: Option<f64>,
+    bit_rate: Option<u64>,
+    sample_rate: Option<u32>,
+}
+
+/// Format a length in seconds as "m:ss"
+fn format_duration(secs: f64) -> String {
+    let secs = secs.round() as u64;
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+/// Move past `limit` lines of a reader, returning how many there were. A last
+/// line without a newline still counts.
+fn skip_lines<R: BufRead>(reader: &mut R, limit: usize) -> std::io::Result<usize> {
+    let mut count = 0;
+    let mut partial_line = false;
+    while count < limit {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            return Ok(count + usize::from(partial_line));
+        }
+        let mut used = buffer.len();
+        for (i, byte) in buffer.iter().enumerate() {
+            if *byte == b'\n' {
+                count += 1;
+                if count == limit {
+                    used = i + 1;
+                    break;
+                }
+            }
+        }
+        partial_line = buffer[used - 1] != b'\n';
+        reader.consume(used);
+    }
+    Ok(count)
+}
+
+/// Where an image's ASCII art is saved: next to it, with an .ascii extension
+fn ascii_path_for(file: &FileItem) -> Result<PathBuf, String> {
+    let path = Path::new(&file.path);
+    let Some(stem) = path.file_stem() else {
+        return Err("Could not determine output filename".to_string());
+    };
+    let name = format!("{}.ascii", stem.to_string_lossy());
+    Ok(match path.parent() {
+        Some(parent) => parent.join(name),
+        None => PathBuf::from(name),
+    })
+}
+
+/// ascii_path_for, refusing paths that already exist
+fn ascii_output_path(file: &FileItem) -> Result<PathBuf, PTuiError> {
+    let output_path = ascii_path_for(file).map_err(PTuiError::Unsupported)?;
+
+    // Check if file already exists
+    if output_path.exists() {
+        return Err(PTuiError::IoError(std::io::Error::new(
+            std::io::ErrorKind::AlreadyExists,
+            format!("File already exists: {}", output_path.display()),
+        )));
+    }
+    Ok(output_path)
+}
+
+/// Rebuild an ANSI string from styled text, the reverse of ansi_to_tui's into_text
+pub fn text_to_ansi(text: &Text) -> String {
+    use ratatui::style::{Color, Modifier};
+
+    fn color_code(color: Color, foreground: bool) -> Option<String> {
+        let base = if foreground { 30 } else { 40 };
+        let offset = match color {
+            Color::Reset => return None,
+            Color::Indexed(index) => return Some(format!("{};5;{}", base + 8, index)),
+            Color::Rgb(r, g, b) => return Some(format!("{};2;{};{};{}", base + 8, r, g, b)),
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::Gray => 7,
+            Color::DarkGray => 60,
+            Color::LightRed => 61,
+            Color::LightGreen => 62,
+            Color::LightYellow => 63,
+            Color::LightBlue => 64,
+            Color::LightMagenta => 65,
+            Color::LightCyan => 66,
+            Color::White => 67,
+        };
+        Some((base + offset).to_string())
+    }
+
+    let mut output = String::new();
+    for (i, line) in text.lines.iter().enumerate() {
+        if i > 0 {
+            output.push('\n');
+        }
+        for span in &line.spans {
+            let style = line.style.patch(span.style);
+            let mut codes: Vec<String> = Vec::new();
+            for (modifier, code) in [
+                (Modifier::BOLD, "1"),
+                (Modifier::DIM, "2"),
+                (Modifier::ITALIC, "3"),
+                (Modifier::UNDERLINED, "4"),
+                (Modifier::REVERSED, "7"),
+            ] {
+                if style.add_modifier.contains(modifier) {
+                    codes.push(code.to_string());
+                }
+            }
+            codes.extend(style.fg.and_then(|fg| color_code(fg, true)));
+            codes.extend(style.bg.and_then(|bg| color_code(bg, false)));
+
+            if codes.is_empty() {
+                output.push_str(&span.content);
+            } else {
+                output.push_str(&format!("\x1b[{}m{}\x1b[0m", codes.join(";"), span.content));
+            }
+        }
+    }
+    output
+}
+
+/// Remove ANSI escape sequences, leaving only the printable text
+pub fn strip_ansi_codes(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            output.push(c);
+            continue;
+        }
+        match chars.peek() {
+            // CSI: parameters up to a final byte in @..~
+            Some('[') => {
+                chars.next();
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC and APC (Kitty graphics): up to BEL or ESC \
+            Some(']') | Some('_') => {
+                chars.next();
+                while let Some(c) = chars.next() {
+                    if c == '\x07' || (c == '\x1b' && chars.next_if_eq(&'\\').is_some()) {
+                        break;
+                    }
+                }
+            }
+            _ => {
+                chars.next();
+            }
+        }
+    }
+    output
+}
+
+/// A bold title and the blank line under it, to start the metadata panel
+fn metadata_heading(title: String) -> Vec<Line<'static>> {
+    vec![
+        Line::from(Span::styled(
+            title,
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::default(),
+    ]
 }
 
+/// The EXIF tags exiftool finds in a file as "Tag: value" lines. None when exiftool
+/// isn't installed or the file has no EXIF data.
+fn read_exif_tags(path: &str) -> Option<Vec<String>> {
+    let output = Command::new("exiftool")
+        .args(["-S", "-EXIF:all"])
+        .arg(path)
+        .output()
+        .ok()?;
+    let tags: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(str::to_string)
+        .collect();
+    (output.status.success() && !tags.is_empty()).then_some(tags)
+}
+
+/// Failed batch exports are listed in this file in the exported directory
+pub const EXPORT_ERRORS_FILE: &str = "ptui_export_errors.txt";
+
+/// How much of a file get_dimensions_native reads looking for the size
+const NATIVE_DIMENSION_READ_LIMIT: u64 = 256 * 1024;
+
+// Images with more pixels than this are averaged over a quarter-size thumbnail
+const AVERAGE_COLOR_FULL_SIZE_PIXELS: u64 = 1024 * 1024;
+
+/// Frame delay used when ffprobe can't report the GIF's frame rate
+const DEFAULT_FRAME_DURATION_MS: u64 = 100;
+/// Upper bound on extracted frames so long animations don't exhaust memory
+const MAX_ANIMATION_FRAMES: usize = 120;
+/// Character cells are about twice as tall as wide in most terminal fonts
+const DEFAULT_CHAR_ASPECT_RATIO: f32 = 2.0;
+
 /// Pre-encoded Kitty image for fast rendering
 #[allow(dead_code)]
 pub struct KittyPreview {
@@ -57,6 +464,14 @@ pub struct KittyPreview {
     pub font_size: (u16, u16),
 }
 
+/// Output of a converter that writes escape sequences, and the size in cells it was
+/// converted for
+pub struct InlinePreview {
+    pub escape_sequence: String,
+    pub width: u16,
+    pub height: u16,
+}
+
 pub struct GraphicalPreview {
     #[allow(dead_code)]
     pub path: String,
@@ -71,17 +486,66 @@ pub struct GraphicalPreview {
     pub font_size: (u16, u16),                  // Font size for iTerm2 cell calculations
 }
 
+/// One of the images in an ICO file
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IcoSize {
+    pub index: usize, // Position in the file, as ImageMagick numbers them
+    pub width: u32,
+    pub height: u32,
+}
+
+type Rgb = (u8, u8, u8);
+
+/// An average colour being computed on a background thread
+struct PendingAverageColor {
+    path: String,
+    modified: SystemTime,
+    receiver: Receiver<Option<Rgb>>,
+}
+
+/// A cached preview and the modification time of the file it was made from
+struct CacheEntry {
+    content: PreviewContent,
+    mtime: SystemTime,
+}
+
 pub struct PreviewManager {
-    cache: HashMap<String, PreviewContent>,
+    cache: HashMap<String, CacheEntry>,
+    thumbnail_cache: HashMap<String, Option<Text<'static>>>, // Small previews for the grid view
+    queued_thumbnails: HashSet<String>, // Thumbnail cache keys waiting on the worker
+    thumbnail_tx: Sender<(String, Option<Text<'static>>)>,
+    thumbnail_rx: Receiver<(String, Option<Text<'static>>)>,
+    video_thumbnails: HashMap<String, VideoThumbnail>, // Extracted video frames keyed by video path
+    audio_infos: HashMap<String, Option<AudioInfo>>,   // ffprobe's report keyed by audio path
+    audio_waveforms: HashMap<String, TempFileGuard>, // Waveform PNGs drawn by ffmpeg keyed by audio path
+    heic_images: HashMap<String, TempFileGuard>, // HEIC images decoded to JPEG keyed by source path
+    raw_images: HashMap<String, (SystemTime, TempFileGuard)>, // JPEGs extracted from RAW camera files and when the file was modified, keyed by its path
+    extracted_pages: HashMap<String, TempFileGuard>, // TIFF pages and ICO sizes keyed by "path[index]"
+    tiff_page_counts: HashMap<String, usize>,
+    ico_sizes: HashMap<String, Vec<IcoSize>>,
+    average_colors: HashMap<String, (SystemTime, Option<Rgb>)>, // For the debug pane, keyed by image path with its modification time
+    pending_average_color: Option<PendingAverageColor>,
     cache_order: Vec<String>, // Track insertion order for LRU eviction
     max_cache_size: usize,
-    pub converter: Box<dyn AsciiConverter>,
+    converter: Box<dyn AsciiConverter>,
     pub graphical_max_dimension: u32,
     pub debug_info: String,
+    message_set_at: Option<Instant>, // When debug_info last got a message that should time out
+    timed_message: String,           // That message, so one set since isn't cleared with it
     graphics_support: TerminalGraphicsSupport,
     picker: Option<Picker>, // For creating terminal-specific image protocols
     font_size: (u16, u16),  // Cached font size (width, height) in pixels
-    pub config: PTuiConfig, // Store the config for converter switching
+    config: PTuiConfig,     // Store the config for converter switching
+    export_converter: fn(&PTuiConfig) -> Box<dyn AsciiConverter>, // Makes the converters exports use
+    conversion_worker: Option<Sender<WorkerRequest>>,             // Started on first use
+    // Statistics for the debug pane, gathered while generating previews
+    pub timing_ms: u64, // How long the last conversion took
+    pub cache_hits: u64,
+    pub cache_misses: u64,
+    pub last_output_size: Option<(u16, u16)>, // Size in cells asked of the converter
+    conversion_started: Option<Instant>,
+    image_dimensions: HashMap<String, (u32, u32)>, // Pixel sizes of images seen so far
+    plugins: Vec<Box<dyn PreviewPlugin>>,          // Asked in order before the built-in previews
 }
 
 impl PreviewManager {
@@ -107,8 +571,24 @@ impl PreviewManager {
             font_size.0, font_size.1
         );
 
+        let (thumbnail_tx, thumbnail_rx) = mpsc::channel();
+
         Self {
             cache: HashMap::new(),
+            thumbnail_cache: HashMap::new(),
+            queued_thumbnails: HashSet::new(),
+            thumbnail_tx,
+            thumbnail_rx,
+            video_thumbnails: HashMap::new(),
+            audio_infos: HashMap::new(),
+            audio_waveforms: HashMap::new(),
+            heic_images: HashMap::new(),
+            raw_images: HashMap::new(),
+            extracted_pages: HashMap::new(),
+            tiff_page_counts: HashMap::new(),
+            ico_sizes: HashMap::new(),
+            average_colors: HashMap::new(),
+            pending_average_color: None,
             cache_order: Vec::new(),
             // Keep only last 5 graphical previews to avoid memory explosion
             // Each can be 30-80MB (image + base64), so 5 = ~150-400MB max
@@ -116,19 +596,37 @@ impl PreviewManager {
             converter,
             graphical_max_dimension,
             debug_info: String::new(),
+            message_set_at: None,
+            timed_message: String::new(),
             graphics_support,
             picker,
             font_size,
             config, // Store the config for later use in converter switching
+            export_converter: converter::create_converter,
+            conversion_worker: None,
+            timing_ms: 0,
+            cache_hits: 0,
+            cache_misses: 0,
+            last_output_size: None,
+            conversion_started: None,
+            image_dimensions: HashMap::new(),
+            plugins: vec![Box::new(AsciiFilePlugin)],
         }
     }
 
+    /// Add a handler for a kind of file. It's asked after the plugins already
+    /// registered, including the built-in one for .ascii files.
+    #[allow(dead_code)] // For library users; the binary only uses the built-in plugins
+    pub fn register_plugin(&mut self, plugin: Box<dyn PreviewPlugin>) {
+        self.plugins.push(plugin);
+    }
+
     /// Detect what graphics protocols the terminal supports
     fn detect_graphics_support() -> (TerminalGraphicsSupport, Option<Picker>) {
         // Skip graphics detection during tests to avoid terminal access issues
         #[cfg(test)]
         {
-            return (TerminalGraphicsSupport::None, None);
+            (TerminalGraphicsSupport::None, None)
         }
 
         #[cfg(not(test))]
@@ -142,14 +640,12 @@ impl PreviewManager {
 
             // Skip if running under cargo test (integration tests)
             // Cargo test sets CARGO or the binary name contains "test"
-            if std::env::var("CARGO").is_ok() {
-                if let Ok(exe) = std::env::current_exe() {
-                    if let Some(name) = exe.file_name() {
-                        if name.to_string_lossy().contains("test") {
-                            return (TerminalGraphicsSupport::None, None);
-                        }
-                    }
-                }
+            if std::env::var("CARGO").is_ok()
+                && let Ok(exe) = std::env::current_exe()
+                && let Some(name) = exe.file_name()
+                && name.to_string_lossy().contains("test")
+            {
+                return (TerminalGraphicsSupport::None, None);
             }
         }
 
@@ -207,29 +703,57 @@ impl PreviewManager {
 
     /// Calculate optimal max_dimension based on terminal size
     fn calculate_optimal_dimension(config: &PTuiConfig) -> u32 {
-        if !config.converter.graphical.auto_resize {
-            return config.converter.graphical.max_dimension;
+        let graphical = &config.converter.graphical;
+        if !graphical.auto_resize {
+            return graphical.max_dimension;
         }
 
-        // Get terminal size in characters (use fallback during tests)
+        // Use a fixed size during tests
         #[cfg(test)]
-        let (term_cols, term_rows) = (80, 24);
+        let window = crossterm::terminal::WindowSize {
+            rows: 24,
+            columns: 80,
+            width: 0,
+            height: 0,
+        };
 
         #[cfg(not(test))]
-        let (term_cols, term_rows) = crossterm::terminal::size().unwrap_or((80, 24));
-
-        // Conservative font size estimate (optimized for speed)
-        // Smaller estimates = faster encoding, still looks good in terminal
-        let estimated_char_width = 8; // pixels (conservative)
-        let estimated_char_height = 16; // pixels (conservative)
-
-        // Preview pane typically uses 70-85% of terminal width
-        let preview_cols = ((term_cols as f32) * 0.75) as u32;
-        let preview_rows = ((term_rows as f32) * 0.85) as u32;
+        let window =
+            crossterm::terminal::window_size().unwrap_or(crossterm::terminal::WindowSize {
+                rows: 0,
+                columns: 0,
+                width: 0,
+                height: 0,
+            });
+
+        Self::optimal_dimension_for(&window, graphical.max_dimension)
+    }
 
-        // Calculate display size in pixels
-        let display_width = preview_cols * estimated_char_width;
-        let display_height = preview_rows * estimated_char_height;
+    /// The max_dimension for a terminal window. Terminals that report their size in
+    /// pixels are measured directly; otherwise the pixel size is estimated from the
+    /// character size, and `fallback` is used when neither is known.
+    fn optimal_dimension_for(window: &crossterm::terminal::WindowSize, fallback: u32) -> u32 {
+        let (display_width, display_height) = if window.width > 0 && window.height > 0 {
+            // Preview pane typically uses 70-85% of the terminal
+            (
+                (window.width as f32 * 0.75) as u32,
+                (window.height as f32 * 0.85) as u32,
+            )
+        } else if window.columns > 0 && window.rows > 0 {
+            // Conservative font size estimate (optimized for speed)
+            // Smaller estimates = faster encoding, still looks good in terminal
+            let estimated_char_width = 8; // pixels (conservative)
+            let estimated_char_height = 16; // pixels (conservative)
+
+            let preview_cols = ((window.columns as f32) * 0.75) as u32;
+            let preview_rows = ((window.rows as f32) * 0.85) as u32;
+            (
+                preview_cols * estimated_char_width,
+                preview_rows * estimated_char_height,
+            )
+        } else {
+            return fallback;
+        };
 
         // Use 0.9x multiplier for speed optimization
         // Terminal graphics don't need high DPI - Kitty/iTerm scale well
@@ -242,13 +766,59 @@ impl PreviewManager {
 
         #[cfg(all(not(test), feature = "debug-output"))]
         eprintln!(
-            "[AUTO-RESIZE] Terminal: {}x{} chars, Display: ~{}x{}px, Optimal: {} (capped: {})",
-            term_cols, term_rows, display_width, display_height, optimal, capped
+            "[AUTO-RESIZE] Terminal: {}x{} chars, {}x{}px, Display: ~{}x{}px, Optimal: {} (capped: {})",
+            window.columns,
+            window.rows,
+            window.width,
+            window.height,
+            display_width,
+            display_height,
+            optimal,
+            capped
         );
 
         capped
     }
 
+    /// How many times taller than wide a character cell is: the configured ratio,
+    /// otherwise measured from the terminal, otherwise the usual 2.0
+    fn char_aspect_ratio(config: &PTuiConfig) -> f32 {
+        if let Some(ratio) = config.get_char_aspect_ratio() {
+            return ratio;
+        }
+
+        // Don't depend on the terminal running the tests
+        #[cfg(test)]
+        let window = crossterm::terminal::WindowSize {
+            rows: 24,
+            columns: 80,
+            width: 0,
+            height: 0,
+        };
+
+        #[cfg(not(test))]
+        let window =
+            crossterm::terminal::window_size().unwrap_or(crossterm::terminal::WindowSize {
+                rows: 0,
+                columns: 0,
+                width: 0,
+                height: 0,
+            });
+
+        Self::char_aspect_ratio_for(&window).unwrap_or(DEFAULT_CHAR_ASPECT_RATIO)
+    }
+
+    /// The cell aspect ratio of a terminal that reports its size in both characters
+    /// and pixels
+    fn char_aspect_ratio_for(window: &crossterm::terminal::WindowSize) -> Option<f32> {
+        if window.width == 0 || window.height == 0 || window.columns == 0 || window.rows == 0 {
+            return None;
+        }
+        let cell_width = window.width as f32 / window.columns as f32;
+        let cell_height = window.height as f32 / window.rows as f32;
+        Some(cell_height / cell_width)
+    }
+
     /// Encode image to Kitty escape sequence for direct transmission
     fn encode_kitty_remote(
         img: &DynamicImage,
@@ -344,6 +914,20 @@ impl PreviewManager {
         Ok(())
     }
 
+    /// Write an inline image escape with its top left corner at x, y, leaving the
+    /// cursor where it was
+    pub fn print_inline_image(
+        out: &mut impl Write,
+        preview: &InlinePreview,
+        x: u16,
+        y: u16,
+    ) -> std::io::Result<()> {
+        write!(out, "\x1b[s\x1b[{};{}H", y + 1, x + 1)?;
+        write!(out, "{}", preview.escape_sequence)?;
+        write!(out, "\x1b[u")?;
+        out.flush()
+    }
+
     pub fn get_debug_info(&self) -> &str {
         &self.debug_info
     }
@@ -354,135 +938,656 @@ impl PreviewManager {
 
     pub fn set_message(&mut self, message: String) {
         self.debug_info = message;
+        self.message_set_at = None;
     }
 
-    pub fn clear_cache(&mut self) {
-        self.cache.clear();
-        self.cache_order.clear();
+    /// Show feedback that goes away after notification_duration_ms
+    pub fn set_timed_message(&mut self, message: String) {
+        self.timed_message = message.clone();
+        self.debug_info = message;
+        self.message_set_at = Some(Instant::now());
     }
 
-    pub fn remove_from_cache(&mut self, file: &FileItem, width: u16, height: u16) {
-        let cache_key = format!("{}:{}x{}", file.path, width, height);
-        self.cache.remove(&cache_key);
-        self.cache_order.retain(|k| k != &cache_key);
+    /// Whether the last timed message has been up longer than notification_duration_ms.
+    /// Messages set directly on debug_info since then don't count.
+    pub fn timed_message_expired(&self) -> bool {
+        let (Some(set_at), Some(duration)) =
+            (self.message_set_at, self.config.get_notification_duration())
+        else {
+            return false;
+        };
+        self.debug_info == self.timed_message && set_at.elapsed() > duration
     }
 
-    pub fn save_ascii_to_file(
-        &mut self,
+    pub fn cache_len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn max_cache_size(&self) -> usize {
+        self.max_cache_size
+    }
+
+    /// Pixel size of an image, if it has been previewed
+    pub fn image_dimensions(&self, path: &str) -> Option<(u32, u32)> {
+        self.image_dimensions.get(path).copied()
+    }
+
+    /// The `i` panel: EXIF tags from exiftool when it's installed and finds some,
+    /// otherwise what ptui can read itself. SVGs, GIFs and the like carry no EXIF, so
+    /// they get the native panel either way.
+    pub fn generate_metadata_panel(
+        &self,
         file: &FileItem,
-        width: u16,
-        height: u16,
         localization: &Localization,
-    ) -> Result<String, String> {
-        if !file.is_image() {
-            return Err(localization.get("selected_file_not_image").to_string());
+    ) -> Text<'static> {
+        match read_exif_tags(&file.path) {
+            Some(tags) => {
+                let mut lines = metadata_heading(localization.get("metadata_exif_title"));
+                lines.extend(tags.into_iter().map(Line::from));
+                Text::from(lines)
+            }
+            None => self.generate_native_metadata_panel(file, localization),
+        }
+    }
+
+    /// Format, dimensions, size and modification time, none of which need an
+    /// external tool
+    pub fn generate_native_metadata_panel(
+        &self,
+        file: &FileItem,
+        localization: &Localization,
+    ) -> Text<'static> {
+        use fluent::fluent_args;
+        let unknown = localization.get("metadata_unknown");
+
+        let prefer_native = self.config.get_prefer_native_dimension_detection();
+        let dimensions = match ImageDimensions::get_dimensions(&file.path, prefer_native) {
+            (0, _) | (_, 0) => unknown.clone(),
+            (width, height) => format!("{} × {}", width, height),
+        };
+        let size = fs::metadata(&file.path)
+            .map(|metadata| format_size(metadata.len()))
+            .unwrap_or_else(|_| unknown.clone());
+        let age = SystemTime::now()
+            .duration_since(file.modified)
+            .unwrap_or_default();
+        let age_args = fluent_args!["age" => format_age(age)];
+        let fields = [
+            (
+                "metadata_format",
+                file.image_format()
+                    .map_or_else(|| unknown.clone(), str::to_string),
+            ),
+            ("metadata_dimensions", dimensions),
+            ("metadata_size", size),
+            (
+                "metadata_modified",
+                localization.get_with_args("metadata_modified_ago", Some(&age_args)),
+            ),
+        ];
+
+        // Line the values up after the longest label
+        let labels: Vec<String> = fields
+            .iter()
+            .map(|(key, _)| localization.get(key))
+            .collect();
+        let label_width = labels
+            .iter()
+            .map(|label| Span::raw(label.as_str()).width())
+            .max()
+            .unwrap_or(0);
+        let mut lines = metadata_heading(localization.get("metadata_title"));
+        for (label, (_, value)) in labels.into_iter().zip(fields) {
+            let padding = label_width - Span::raw(label.as_str()).width();
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{}{}  ", label, " ".repeat(padding)),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::raw(value),
+            ]));
         }
+        Text::from(lines)
+    }
 
-        // Generate output filename with .ascii extension
-        let path = Path::new(&file.path);
-        let output_path = if let Some(stem) = path.file_stem() {
-            if let Some(parent) = path.parent() {
-                parent.join(format!("{}.ascii", stem.to_string_lossy()))
+    /// The size previews are converted at: rounded down to a multiple of
+    /// cache_size_rounding, so resizing the terminal by a column or two reuses them.
+    /// Sizes under one step are kept as they are.
+    fn rounded_size(&self, width: u16, height: u16) -> (u16, u16) {
+        let rounding = self.config.get_cache_size_rounding();
+        let round = |cells: u16| {
+            if cells < rounding {
+                cells
             } else {
-                Path::new(&format!("{}.ascii", stem.to_string_lossy())).to_path_buf()
+                cells / rounding * rounding
             }
-        } else {
-            return Err("Could not determine output filename".to_string());
         };
+        (round(width), round(height))
+    }
 
-        // Check if file already exists
-        if output_path.exists() {
-            return Err(format!("File already exists: {}", output_path.display()));
-        }
-
-        // Generate ASCII content using selected converter
-        let (converter_width, converter_height) =
-            self.calculate_converter_dimensions(&file.path, width, height, localization);
-        let ascii_content =
-            self.generate_ascii_content(&file.path, converter_width, converter_height)?;
+    /// Key a preview is cached under, by the rounded size it's converted at
+    pub fn cache_key(&self, path: &str, width: u16, height: u16) -> String {
+        let (width, height) = self.rounded_size(width, height);
+        format!("{}:{}x{}", path, width, height)
+    }
 
-        // Save to file
-        match fs::write(&output_path, ascii_content) {
-            Ok(_) => Ok(format!(
-                "{} {}",
-                localization.get("saved_to"),
-                output_path.display()
-            )),
-            Err(e) => Err(format!("Failed to write file: {}", e)),
+    /// Look a preview up in the cache, counting the hit or miss and starting the
+    /// conversion timer on a miss. Entries made before the file was last modified
+    /// are dropped so the preview is generated again.
+    fn cached_preview(&mut self, cache_key: &str, modified: SystemTime) -> Option<PreviewContent> {
+        match self.cache.get(cache_key) {
+            Some(entry) if entry.mtime == modified => {
+                self.cache_hits += 1;
+                Some(entry.content.clone())
+            }
+            stale => {
+                if stale.is_some() {
+                    self.cache.remove(cache_key);
+                    self.cache_order.retain(|k| k != cache_key);
+                }
+                self.cache_misses += 1;
+                self.conversion_started = Some(Instant::now());
+                None
+            }
         }
     }
 
-    fn generate_ascii_content(
-        &self,
-        path: &str,
-        width: u16,
-        height: u16,
-    ) -> Result<String, String> {
-        self.converter.convert_image(path, width, height)
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+        self.cache_order.clear();
+        self.thumbnail_cache.clear();
+        self.queued_thumbnails.clear();
     }
 
-    pub fn generate_preview(
+    /// The small text preview for the grid view, or None until the background worker
+    /// has made it. Also None for files that can't be shown as text art, including
+    /// everything when a graphical converter is active.
+    pub fn generate_thumbnail(
         &mut self,
         file: &FileItem,
         width: u16,
         height: u16,
-        text_scroll_offset: usize,
-        localization: &Localization,
-    ) -> PreviewContent {
-        if file.is_directory {
-            self.debug_info = localization.get("directory_selected");
-            return PreviewContent::Text(Text::from(localization.get("directory_selected")));
+    ) -> Option<Text<'static>> {
+        if file.is_directory || !file.is_image() || self.converter.is_graphical() {
+            return None;
         }
 
-        if file.is_image() {
-            self.generate_image_preview(&file.path, width, height, localization)
-        } else if file.is_ascii_file() {
-            self.debug_info = format!("{}{}", localization.get("ascii_file_prefix"), file.name);
-            PreviewContent::Text(self.generate_ascii_preview(&file.path, text_scroll_offset))
-        } else if file.is_text_file() {
-            self.debug_info = format!("{}{}", localization.get("text_file_prefix"), file.name);
-            PreviewContent::Text(self.generate_text_preview(&file.path, text_scroll_offset, height))
-        } else {
-            self.debug_info = localization.get("file_type_not_supported");
-            PreviewContent::Text(Text::from(localization.get("not_supported_file_type")))
+        let cache_key = format!("{}:{}x{}", file.path, width, height);
+        if let Some(cached) = self.thumbnail_cache.get(&cache_key) {
+            return cached.clone();
         }
+
+        if self.queued_thumbnails.insert(cache_key.clone()) {
+            // The converter keeps the aspect ratio within the requested box
+            self.send_to_worker(WorkerRequest::Thumbnail(ThumbnailRequest {
+                config: self.config.clone(),
+                path: file.path.clone(),
+                width,
+                height,
+                cache_key,
+                response_tx: self.thumbnail_tx.clone(),
+            }));
+        }
+        None
     }
 
-    fn generate_image_preview(
-        &mut self,
-        path: &str,
-        width: u16,
-        height: u16,
-        localization: &Localization,
-    ) -> PreviewContent {
-        let cache_key = format!("{}:{}x{}", path, width, height);
+    /// Store the thumbnails the worker has finished. Returns true if there were any.
+    pub fn poll_thumbnails(&mut self) -> bool {
+        let mut received = false;
+        while let Ok((cache_key, thumbnail)) = self.thumbnail_rx.try_recv() {
+            // Dropped if the cache was cleared while it was being made
+            if self.queued_thumbnails.remove(&cache_key) {
+                self.thumbnail_cache.insert(cache_key, thumbnail);
+                received = true;
+            }
+        }
+        received
+    }
 
-        if let Some(cached) = self.cache.get(&cache_key) {
-            return cached.clone();
+    pub fn remove_from_cache(&mut self, file: &FileItem, width: u16, height: u16) {
+        // Videos, audio waveforms and RAW files are cached under their temp file, which
+        // is made again on refresh
+        self.audio_infos.remove(&file.path);
+        let preview_path = self
+            .video_thumbnails
+            .remove(&file.path)
+            .map(|thumbnail| thumbnail.frame)
+            .or_else(|| self.audio_waveforms.remove(&file.path))
+            .or_else(|| self.raw_images.remove(&file.path).map(|(_, jpeg)| jpeg))
+            .map_or_else(
+                || file.path.clone(),
+                |temp_file| temp_file.path().to_string_lossy().to_string(),
+            );
+        let cache_key = self.cache_key(&preview_path, width, height);
+        self.cache.remove(&cache_key);
+        self.cache_order.retain(|k| k != &cache_key);
+
+        // Extracted TIFF pages and ICO sizes are cached under their own temp files
+        let page_prefix = format!("{}[", file.path);
+        let page_paths: Vec<String> = self
+            .extracted_pages
+            .keys()
+            .filter(|key| key.starts_with(&page_prefix))
+            .cloned()
+            .collect();
+        for page_key in page_paths {
+            if let Some(page) = self.extracted_pages.remove(&page_key) {
+                let cache_key = self.cache_key(&page.path().to_string_lossy(), width, height);
+                self.cache.remove(&cache_key);
+                self.cache_order.retain(|k| k != &cache_key);
+            }
         }
+    }
 
-        let (converter_width, converter_height) =
-            self.calculate_converter_dimensions(path, width, height, localization);
+    /// Number of pages in a TIFF, read with tiffinfo and cached per file
+    pub fn tiff_page_count(&mut self, path: &str) -> usize {
+        if let Some(count) = self.tiff_page_counts.get(path) {
+            return *count;
+        }
 
-        // Check if converter is graphical AND terminal supports graphics
-        let result = if self.converter.is_graphical()
-            && self.graphics_support != TerminalGraphicsSupport::None
+        let count = Command::new("tiffinfo")
+            .args(["-s", path])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| Self::count_tiff_directories(&String::from_utf8_lossy(&output.stdout)))
+            .unwrap_or(1);
+        self.tiff_page_counts.insert(path.to_string(), count);
+        count
+    }
+
+    /// tiffinfo prints a "TIFF Directory at offset ..." header for every page
+    fn count_tiff_directories(output: &str) -> usize {
+        output
+            .lines()
+            .filter(|line| line.trim_start().starts_with("TIFF Directory"))
+            .count()
+            .max(1)
+    }
+
+    /// Average colour of an image, computed once until the file changes. Decoding can
+    /// take a while, so it happens on another thread and this is None until
+    /// poll_average_color has picked the result up.
+    pub fn average_color(&mut self, path: &str, modified: SystemTime) -> Option<(u8, u8, u8)> {
+        if let Some((cached_modified, color)) = self.average_colors.get(path)
+            && *cached_modified == modified
         {
-            // Use graphical protocol based on terminal capabilities
-            #[cfg(all(not(test), feature = "debug-output"))]
-            use std::time::Instant;
-            #[cfg(all(not(test), feature = "debug-output"))]
-            let total_start = Instant::now();
+            return *color;
+        }
 
-            #[cfg(all(not(test), feature = "debug-output"))]
-            let load_start = Instant::now();
-            // Use fast loader with subsampling based on target dimension
-            match FastImageLoader::load_for_display(path, self.graphical_max_dimension) {
-                Ok(img) => {
+        let already_pending = self
+            .pending_average_color
+            .as_ref()
+            .is_some_and(|pending| pending.path == path && pending.modified == modified);
+        if !already_pending {
+            let (sender, receiver) = mpsc::channel();
+            let image_path = path.to_string();
+            thread::spawn(move || {
+                let _ = sender.send(Self::compute_average_color(&image_path));
+            });
+            self.pending_average_color = Some(PendingAverageColor {
+                path: path.to_string(),
+                modified,
+                receiver,
+            });
+        }
+        None
+    }
+
+    /// Cache a finished average colour. Returns true when one arrived, so whatever
+    /// shows it can be drawn again.
+    pub fn poll_average_color(&mut self) -> bool {
+        let Some(pending) = self.pending_average_color.take() else {
+            return false;
+        };
+        match pending.receiver.try_recv() {
+            Ok(color) => {
+                self.average_colors
+                    .insert(pending.path, (pending.modified, color));
+                true
+            }
+            Err(mpsc::TryRecvError::Empty) => {
+                self.pending_average_color = Some(pending);
+                false
+            }
+            Err(mpsc::TryRecvError::Disconnected) => false,
+        }
+    }
+
+    /// Mean of the red, green and blue components of every pixel, or None if the
+    /// image can't be decoded
+    pub fn compute_average_color(path: &str) -> Option<(u8, u8, u8)> {
+        let image = image::open(path).ok()?;
+        let (width, height) = image.dimensions();
+        let image = if width as u64 * height as u64 > AVERAGE_COLOR_FULL_SIZE_PIXELS {
+            image.thumbnail((width / 4).max(1), (height / 4).max(1))
+        } else {
+            image
+        };
+
+        let (mut red, mut green, mut blue, mut count) = (0u64, 0u64, 0u64, 0u64);
+        for (_, _, pixel) in image.pixels() {
+            red += pixel[0] as u64;
+            green += pixel[1] as u64;
+            blue += pixel[2] as u64;
+            count += 1;
+        }
+        if count == 0 {
+            return None;
+        }
+        Some((
+            (red / count) as u8,
+            (green / count) as u8,
+            (blue / count) as u8,
+        ))
+    }
+
+    /// Sizes stored in an ICO file, largest first, read with identify and cached
+    /// per file. Empty when ImageMagick isn't installed.
+    pub fn ico_sizes(&mut self, path: &str) -> Vec<IcoSize> {
+        if let Some(sizes) = self.ico_sizes.get(path) {
+            return sizes.clone();
+        }
+
+        let sizes = Command::new("identify")
+            .args(["-format", "%wx%h\n", path])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| Self::parse_ico_sizes(&String::from_utf8_lossy(&output.stdout)))
+            .unwrap_or_default();
+        self.ico_sizes.insert(path.to_string(), sizes.clone());
+        sizes
+    }
+
+    /// identify prints a "WxH" line for each image in the file
+    fn parse_ico_sizes(output: &str) -> Vec<IcoSize> {
+        let mut sizes: Vec<IcoSize> = output
+            .lines()
+            .enumerate()
+            .filter_map(|(index, line)| {
+                let (width, height) = line.trim().split_once('x')?;
+                Some(IcoSize {
+                    index,
+                    width: width.parse().ok()?,
+                    height: height.parse().ok()?,
+                })
+            })
+            .collect();
+        sizes.sort_by_key(|size| std::cmp::Reverse(size.width as u64 * size.height as u64));
+        sizes
+    }
+
+    /// Preview one page of a multi-page TIFF, or one size of an ICO, by
+    /// extracting it with ImageMagick
+    pub fn generate_page_preview(
+        &mut self,
+        file: &FileItem,
+        page: usize,
+        width: u16,
+        height: u16,
+        localization: &Localization,
+    ) -> PreviewContent {
+        let page_key = format!("{}[{}]", file.path, page);
+        if !self.extracted_pages.contains_key(&page_key) {
+            let page_image =
+                TempFileGuard::new(TempFileGuard::path_for("ptui_page", &page_key, "png"));
+            let extracted = Command::new("convert")
+                .arg(&page_key)
+                .arg(page_image.path())
+                .output();
+            match extracted {
+                Ok(output) if output.status.success() && page_image.path().exists() => {
+                    self.extracted_pages.insert(page_key.clone(), page_image);
+                }
+                Ok(output) => {
+                    let message =
+                        format!("convert error: {}", String::from_utf8_lossy(&output.stderr));
+                    self.debug_info = message.clone();
+                    return PreviewContent::Text(Text::from(message), ContentAlignment::Image);
+                }
+                Err(e) => {
+                    let message = format!("Failed to execute convert: {}", e);
+                    self.debug_info = message.clone();
+                    return PreviewContent::Text(Text::from(message), ContentAlignment::Image);
+                }
+            }
+        }
+
+        let page_path = self.extracted_pages[&page_key]
+            .path()
+            .to_string_lossy()
+            .to_string();
+        let preview =
+            self.generate_image_preview(&page_path, file.modified, width, height, localization);
+        self.debug_info = format!("{}{}", localization.get("image_file_prefix"), file.name);
+        preview
+    }
+
+    pub fn save_ascii_to_file(
+        &mut self,
+        file: &FileItem,
+        width: u16,
+        height: u16,
+        localization: &Localization,
+    ) -> Result<String, PTuiError> {
+        if !file.is_image() {
+            return Err(PTuiError::Unsupported(
+                localization.get("selected_file_not_image"),
+            ));
+        }
+
+        let output_path = ascii_output_path(file)?;
+
+        // Generate ASCII content using selected converter
+        let (converter_width, converter_height) =
+            self.calculate_converter_dimensions(&file.path, width, height, localization);
+        let ascii_content = self
+            .generate_ascii_content(&file.path, converter_width, converter_height)
+            .map_err(|message| PTuiError::ConverterError {
+                converter: self.converter.get_name().to_string(),
+                message,
+            })?;
+
+        fs::write(&output_path, ascii_content)?;
+        Ok(format!(
+            "{} {}",
+            localization.get("saved_to"),
+            output_path.display()
+        ))
+    }
+
+    /// Save a frame that is already on screen, such as a slideshow transition part way
+    /// through, next to the image as name.ascii. Returns the path written.
+    pub fn save_frame_to_file(file: &FileItem, frame: &Text) -> Result<PathBuf, String> {
+        let output_path = ascii_output_path(file).map_err(|e| e.to_string())?;
+        fs::write(&output_path, text_to_ansi(frame))
+            .map_err(|e| format!("Failed to write file: {}", e))?;
+        Ok(output_path)
+    }
+
+    /// Whether an up to date preview of the file at this size is cached
+    pub fn is_cached(&self, file: &FileItem, width: u16, height: u16) -> bool {
+        let cache_key = self.cache_key(&file.path, width, height);
+        self.cache
+            .get(&cache_key)
+            .is_some_and(|entry| entry.mtime == file.modified)
+    }
+
+    /// The cached text preview of a file as an ANSI string, without converting it again.
+    /// Returns None when nothing is cached at that size or the preview isn't text.
+    pub fn get_raw_preview_string(
+        &self,
+        file: &FileItem,
+        width: u16,
+        height: u16,
+    ) -> Option<String> {
+        let cache_key = self.cache_key(&file.path, width, height);
+        match self.cache.get(&cache_key)? {
+            CacheEntry {
+                content: PreviewContent::Text(text, _),
+                mtime,
+            } if *mtime == file.modified => Some(text_to_ansi(text)),
+            _ => None,
+        }
+    }
+
+    fn generate_ascii_content(
+        &self,
+        path: &str,
+        width: u16,
+        height: u16,
+    ) -> Result<String, String> {
+        self.converter.convert_image(path, width, height)
+    }
+
+    pub fn generate_preview(
+        &mut self,
+        file: &FileItem,
+        width: u16,
+        height: u16,
+        text_scroll_offset: usize,
+        localization: &Localization,
+    ) -> PreviewContent {
+        if file.is_directory {
+            self.debug_info = localization.get("directory_selected");
+            return PreviewContent::Text(
+                Text::from(localization.get("directory_selected")),
+                ContentAlignment::Image,
+            );
+        }
+
+        if let Some(plugin) = self.plugins.iter().find(|plugin| plugin.can_handle(file)) {
+            self.debug_info = plugin.description(file, localization);
+            let text = match plugin.generate_preview(file, width, height) {
+                Ok(mut text) => {
+                    if text_scroll_offset > 0 && text_scroll_offset < text.lines.len() {
+                        text.lines.drain(0..text_scroll_offset);
+                    }
+                    text
+                }
+                Err(e) => Text::from(format!("Error: {}", e)),
+            };
+            return PreviewContent::Text(text, plugin.alignment());
+        }
+
+        if file.is_image() {
+            if file.is_raw_image() {
+                return self.generate_raw_preview(file, width, height, localization);
+            }
+            if file.is_heic() {
+                return self.generate_heic_preview(file, width, height, localization);
+            }
+            if Self::is_gif(&file.path)
+                && !self.converter.is_graphical()
+                && let Some(animated) = self.generate_animated_preview(
+                    &file.path,
+                    file.modified,
+                    width,
+                    height,
+                    localization,
+                )
+            {
+                return animated;
+            }
+            self.generate_image_preview(&file.path, file.modified, width, height, localization)
+        } else if file.is_video() {
+            self.generate_video_preview(file, width, height, localization)
+        } else if file.is_audio() {
+            self.generate_audio_preview(file, width, height, localization)
+        } else if file.is_text_file() {
+            self.debug_info = format!("{}{}", localization.get("text_file_prefix"), file.name);
+            PreviewContent::Text(
+                self.generate_text_preview(&file.path, text_scroll_offset, height, localization),
+                ContentAlignment::Text,
+            )
+        } else {
+            self.debug_info = localization.get("file_type_not_supported");
+            PreviewContent::Text(
+                Text::from(localization.get("not_supported_file_type")),
+                ContentAlignment::Image,
+            )
+        }
+    }
+
+    /// Make a preview without holding up the UI thread. Text files are read on
+    /// a thread of their own, which also counts their lines when count_lines is set
+    /// (the count is None otherwise); anything else is made here with generate_preview,
+    /// answered from the cache when it can be, and sent straight away. Previews
+    /// that aren't text (graphical and animated ones) are never sent, so the
+    /// handle disconnects and the caller should use generate_preview instead.
+    pub fn request_preview_async(
+        &mut self,
+        file: &FileItem,
+        width: u16,
+        height: u16,
+        text_scroll_offset: usize,
+        count_lines: bool,
+        localization: Arc<Localization>,
+    ) -> PreviewHandle {
+        let (tx, receiver) = mpsc::channel();
+        let handled_by_plugin = self.plugins.iter().any(|plugin| plugin.can_handle(file));
+
+        if !handled_by_plugin && file.is_text_file() {
+            self.debug_info = format!("{}{}", localization.get("text_file_prefix"), file.name);
+            let path = file.path.clone();
+            let max_lines = self.config.get_max_preview_lines();
+            thread::spawn(move || {
+                let text =
+                    Self::text_preview(&path, text_scroll_offset, height, max_lines, &localization);
+                let total_lines = count_lines
+                    .then(|| Self::count_text_lines(&path, max_lines))
+                    .flatten();
+                // The receiver is dropped if the user has moved on to another file
+                let _ = tx.send((text, total_lines));
+            });
+        } else if let PreviewContent::Text(text, _) =
+            self.generate_preview(file, width, height, text_scroll_offset, &localization)
+        {
+            let _ = tx.send((text, None));
+        }
+
+        PreviewHandle { receiver }
+    }
+
+    fn generate_image_preview(
+        &mut self,
+        path: &str,
+        modified: SystemTime,
+        width: u16,
+        height: u16,
+        localization: &Localization,
+    ) -> PreviewContent {
+        let cache_key = self.cache_key(path, width, height);
+
+        if let Some(cached) = self.cached_preview(&cache_key, modified) {
+            return cached;
+        }
+        let (width, height) = self.rounded_size(width, height);
+
+        let (converter_width, converter_height) =
+            self.calculate_converter_dimensions(path, width, height, localization);
+
+        // Check if converter is graphical AND terminal supports graphics
+        let result = if self.converter.writes_escape_sequence() {
+            self.render_escape_sequence(path, converter_width, converter_height)
+        } else if self.converter.is_graphical()
+            && self.graphics_support != TerminalGraphicsSupport::None
+        {
+            // Use graphical protocol based on terminal capabilities
+            #[cfg(all(not(test), feature = "debug-output"))]
+            let total_start = Instant::now();
+
+            #[cfg(all(not(test), feature = "debug-output"))]
+            let load_start = Instant::now();
+            // Use fast loader with subsampling based on target dimension
+            match FastImageLoader::load_for_display(path, self.graphical_max_dimension) {
+                Ok(img) => {
                     #[cfg(all(not(test), feature = "debug-output"))]
                     let load_time = load_start.elapsed();
                     let original_w = img.width();
                     let original_h = img.height();
+                    let filter = self.config.converter.graphical.filter();
                     #[cfg(all(not(test), feature = "debug-output"))]
                     eprintln!(
                         "[TIMING] Total image load ({}x{}): {:?}",
@@ -530,15 +1635,12 @@ impl PreviewManager {
                             );
 
                             // Resize if needed
-                            let final_img = if resize_width < original_w || resize_height < original_h {
-                                img.resize(
-                                    resize_width,
-                                    resize_height,
-                                    image::imageops::FilterType::Triangle,
-                                )
-                            } else {
-                                img
-                            };
+                            let final_img =
+                                if resize_width < original_w || resize_height < original_h {
+                                    img.resize(resize_width, resize_height, filter)
+                                } else {
+                                    img
+                                };
 
                             let img_w = final_img.width();
                             let img_h = final_img.height();
@@ -599,14 +1701,7 @@ impl PreviewManager {
                             let result = PreviewContent::Kitty(Rc::new(RefCell::new(kitty_preview)));
 
                             // Cache and return
-                            if self.cache.len() >= self.max_cache_size {
-                                if let Some(oldest_key) = self.cache_order.first().cloned() {
-                                    self.cache.remove(&oldest_key);
-                                    self.cache_order.remove(0);
-                                }
-                            }
-                            self.cache.insert(cache_key.clone(), result.clone());
-                            self.cache_order.push(cache_key);
+                            self.insert_into_cache(cache_key, result.clone(), modified);
                             return result;
                         }
                         TerminalGraphicsSupport::Iterm2 => {
@@ -670,11 +1765,8 @@ impl PreviewManager {
                                 );
 
                                 // Resize the image
-                                let resized_img = img.resize_exact(
-                                    resize_width,
-                                    resize_height,
-                                    image::imageops::FilterType::Lanczos3,
-                                );
+                                let resized_img =
+                                    img.resize_exact(resize_width, resize_height, filter);
 
                                 // Update final dimensions to match resized image
                                 final_img_w = resize_width;
@@ -689,11 +1781,14 @@ impl PreviewManager {
                             } else {
                                 #[cfg(all(not(test), feature = "debug-output"))]
                                 eprintln!("[PROTOCOL] No picker available, falling back to text");
-                                return PreviewContent::Text(self.render_with_converter(
-                                    path,
-                                    converter_width,
-                                    converter_height,
-                                ));
+                                return PreviewContent::Text(
+                                    self.render_with_converter(
+                                        path,
+                                        converter_width,
+                                        converter_height,
+                                    ),
+                                    ContentAlignment::Image,
+                                );
                             }
                         }
                         TerminalGraphicsSupport::Sixel => {
@@ -702,21 +1797,19 @@ impl PreviewManager {
                                 "[PROTOCOL] Using Sixel protocol (not yet implemented, falling back to text)"
                             );
                             // TODO: Implement Sixel support
-                            return PreviewContent::Text(self.render_with_converter(
-                                path,
-                                converter_width,
-                                converter_height,
-                            ));
+                            return PreviewContent::Text(
+                                self.render_with_converter(path, converter_width, converter_height),
+                                ContentAlignment::Image,
+                            );
                         }
                         TerminalGraphicsSupport::None => {
                             // Should not reach here due to outer if condition
                             #[cfg(not(test))]
                             eprintln!("[PROTOCOL] No graphics support, using text");
-                            return PreviewContent::Text(self.render_with_converter(
-                                path,
-                                converter_width,
-                                converter_height,
-                            ));
+                            return PreviewContent::Text(
+                                self.render_with_converter(path, converter_width, converter_height),
+                                ContentAlignment::Image,
+                            );
                         }
                     };
 
@@ -745,7 +1838,10 @@ impl PreviewManager {
                 Err(e) => {
                     // Fallback to error text if image can't be loaded
                     self.debug_info = format!("Failed to load image: {}", e);
-                    PreviewContent::Text(Text::from(format!("Failed to load image: {}", e)))
+                    PreviewContent::Text(
+                        Text::from(format!("Failed to load image: {}", e)),
+                        ContentAlignment::Image,
+                    )
                 }
             }
         } else {
@@ -754,175 +1850,1392 @@ impl PreviewManager {
             if self.graphics_support == TerminalGraphicsSupport::None {
                 eprintln!("[RENDER] Using text-based converter (graphics not supported)");
             }
-            PreviewContent::Text(self.render_with_converter(
-                path,
-                converter_width,
-                converter_height,
-            ))
+            PreviewContent::Text(
+                self.render_with_converter(path, converter_width, converter_height),
+                ContentAlignment::Image,
+            )
         };
 
-        // LRU cache eviction: remove oldest entry if cache is full
-        if self.cache.len() >= self.max_cache_size {
-            if let Some(oldest_key) = self.cache_order.first().cloned() {
-                self.cache.remove(&oldest_key);
-                self.cache_order.remove(0);
-                #[cfg(all(not(test), feature = "debug-output"))]
-                eprintln!("[CACHE] Evicted oldest entry: {}", oldest_key);
-            }
-        }
-
-        self.cache.insert(cache_key.clone(), result.clone());
-        self.cache_order.push(cache_key);
+        self.insert_into_cache(cache_key, result.clone(), modified);
         result
     }
 
-    fn generate_ascii_preview(&self, path: &str, scroll_offset: usize) -> Text<'static> {
-        match std::fs::read_to_string(path) {
-            Ok(content) => {
-                // Parse ANSI codes in ASCII files and convert to Text
-                match content.as_bytes().into_text() {
-                    Ok(mut text) => {
-                        // Apply scroll offset to ASCII files as well
-                        if scroll_offset > 0 && scroll_offset < text.lines.len() {
-                            text.lines.drain(0..scroll_offset);
-                        }
-                        text
-                    }
-                    Err(_) => {
-                        // If ANSI parsing fails, display as plain text with scroll offset
-                        let lines: Vec<&str> = content.lines().collect();
-                        let scrolled_lines: Vec<String> = if scroll_offset < lines.len() {
-                            lines
-                                .into_iter()
-                                .skip(scroll_offset)
-                                .map(|s| s.to_string())
-                                .collect()
-                        } else {
-                            vec!["(End of file)".to_string()]
-                        };
-                        Text::from(scrolled_lines.join("\n"))
-                    }
+    /// Preview a HEIC/HEIF image by decoding it to JPEG first
+    fn generate_heic_preview(
+        &mut self,
+        file: &FileItem,
+        width: u16,
+        height: u16,
+        localization: &Localization,
+    ) -> PreviewContent {
+        if !self.heic_images.contains_key(&file.path) {
+            let heic = HeicConverter::new(self.config.converter.heic.clone());
+            if !heic.is_available() {
+                let mut args = FluentArgs::new();
+                args.set("binary", heic.binary().to_string());
+                let message = localization.get_with_args("heic_requires_converter", Some(&args));
+                self.debug_info = message.clone();
+                return PreviewContent::Text(Text::from(message), ContentAlignment::Image);
+            }
+
+            match heic.convert_to_jpeg(&file.path) {
+                Ok(jpeg) => {
+                    self.heic_images.insert(file.path.clone(), jpeg);
+                }
+                Err(e) => {
+                    self.debug_info = e.clone();
+                    return PreviewContent::Text(Text::from(e), ContentAlignment::Image);
                 }
             }
-            Err(_) => Text::from("Error: Could not read ASCII file"),
         }
+
+        let jpeg_path = self.heic_images[&file.path]
+            .path()
+            .to_string_lossy()
+            .to_string();
+        let preview =
+            self.generate_image_preview(&jpeg_path, file.modified, width, height, localization);
+        self.debug_info = format!("{}{}", localization.get("image_file_prefix"), file.name);
+        preview
     }
 
-    fn generate_text_preview(
-        &self,
-        path: &str,
-        scroll_offset: usize,
-        visible_height: u16,
-    ) -> Text<'static> {
-        match std::fs::File::open(path) {
-            Ok(file) => {
-                let reader = BufReader::new(file);
-                let mut all_lines: Vec<String> = Vec::new();
-
-                // Read all lines first
-                for line in reader.lines() {
-                    if let Ok(content) = line {
-                        all_lines.push(content);
-
-                        // Still limit total lines to prevent excessive memory usage
-                        if all_lines.len() > 10000 {
-                            all_lines.push(
-                                "... (file too large for scrolling, showing first 10000 lines)"
-                                    .to_string(),
-                            );
-                            break;
-                        }
-                    } else {
-                        all_lines.push("Error reading file".to_string());
-                        break;
+    /// Preview a RAW camera file through the JPEG that dcraw or darktable-cli extracts from it
+    fn generate_raw_preview(
+        &mut self,
+        file: &FileItem,
+        width: u16,
+        height: u16,
+        localization: &Localization,
+    ) -> PreviewContent {
+        let jpeg_path = match self.extracted_raw_jpeg(file) {
+            Some(jpeg_path) => jpeg_path,
+            None => {
+                let no_extractor_message = localization.get("raw_requires_extractor");
+                match Self::extract_raw_jpeg(&self.config, &file.path, &no_extractor_message) {
+                    Ok(jpeg) => {
+                        let jpeg_path = jpeg.path().to_string_lossy().to_string();
+                        self.raw_images
+                            .insert(file.path.clone(), (file.modified, jpeg));
+                        jpeg_path
+                    }
+                    Err(e) => {
+                        self.debug_info = e.clone();
+                        return PreviewContent::Text(Text::from(e), ContentAlignment::Image);
                     }
                 }
+            }
+        };
+        let preview =
+            self.generate_image_preview(&jpeg_path, file.modified, width, height, localization);
+        self.debug_info = format!("{}{}", localization.get("image_file_prefix"), file.name);
+        preview
+    }
 
-                // Apply scroll offset and visible height
-                let display_lines = if scroll_offset >= all_lines.len() {
-                    // If scrolled past the end, show "end of file" message
-                    vec!["(End of file)".to_string()]
-                } else {
-                    // Take lines starting from scroll_offset
-                    let end_line = (scroll_offset + visible_height as usize).min(all_lines.len());
-                    all_lines[scroll_offset..end_line].to_vec()
-                };
-
-                Text::from(display_lines.join("\n"))
+    /// The JPEG already extracted from this version of a RAW file
+    fn extracted_raw_jpeg(&mut self, file: &FileItem) -> Option<String> {
+        match self.raw_images.get(&file.path) {
+            Some((modified, jpeg)) if *modified == file.modified => {
+                Some(jpeg.path().to_string_lossy().to_string())
+            }
+            _ => {
+                // The file has changed, so the next extraction is written in its place
+                self.raw_images.remove(&file.path);
+                None
             }
-            Err(_) => Text::from("Error: Could not open file"),
         }
     }
 
-    fn calculate_converter_dimensions(
-        &mut self,
+    /// Extract the JPEG a RAW file is previewed with, or the message to show instead
+    fn extract_raw_jpeg(
+        config: &PTuiConfig,
         path: &str,
-        max_width: u16,
-        max_height: u16,
+        no_extractor_message: &str,
+    ) -> Result<TempFileGuard, String> {
+        let raw = RawConverter::new(config.converter.raw.clone());
+        let extractor = raw
+            .available_extractor()
+            .ok_or_else(|| no_extractor_message.to_string())?;
+        let timeout = Duration::from_millis(config.converter.get_conversion_timeout_ms());
+        RawConverter::extract_jpeg(&extractor, path, timeout)
+    }
+
+    /// Preview a video by extracting a single frame with ffmpeg and rendering it like an image
+    fn generate_video_preview(
+        &mut self,
+        file: &FileItem,
+        width: u16,
+        height: u16,
         localization: &Localization,
-    ) -> (u16, u16) {
-        let (img_width, img_height) = ImageDimensions::get_dimensions(path);
+    ) -> PreviewContent {
+        if !self.video_thumbnails.contains_key(&file.path) {
+            match Self::extract_video_thumbnail(&file.path) {
+                Some(thumbnail) => {
+                    self.video_thumbnails.insert(file.path.clone(), thumbnail);
+                }
+                None => {
+                    self.debug_info =
+                        format!("{}{}", localization.get("video_file_prefix"), file.name);
+                    return PreviewContent::Text(
+                        Text::from(localization.get("video_preview_failed")),
+                        ContentAlignment::Image,
+                    );
+                }
+            }
+        }
+
+        let thumbnail = &self.video_thumbnails[&file.path];
+        let frame_path = thumbnail.frame.path().to_string_lossy().to_string();
+        let summary = thumbnail.summary.clone();
 
+        let preview =
+            self.generate_image_preview(&frame_path, file.modified, width, height, localization);
         self.debug_info = format!(
-            "{}{}",
-            localization.get("image_file_prefix"),
-            std::path::Path::new(path)
-                .file_name()
-                .unwrap_or_default()
-                .to_string_lossy()
+            "{}{} | {}",
+            localization.get("video_file_prefix"),
+            file.name,
+            summary
         );
+        preview
+    }
 
-        if img_width == 0 || img_height == 0 {
-            self.debug_info = format!("{} | Using fallback dimensions", self.debug_info);
-            return (max_width, max_height);
+    fn extract_video_thumbnail(path: &str) -> Option<VideoThumbnail> {
+        let frame_path = TempFileGuard::path_for("ptui_thumb", path, "jpg");
+
+        // Skip the first second to avoid black intro frames, but fall back to the
+        // very first frame for clips shorter than that
+        for seek in ["00:00:01", "00:00:00"] {
+            let extracted = Command::new("ffmpeg")
+                .args(["-v", "error", "-i", path, "-ss", seek, "-frames:v", "1"])
+                .args(["-f", "image2"])
+                .arg(&frame_path)
+                .arg("-y")
+                .status()
+                .map(|status| status.success())
+                .unwrap_or(false);
+            if extracted && frame_path.exists() {
+                return Some(VideoThumbnail {
+                    frame: TempFileGuard::new(frame_path),
+                    summary: Self::probe_video_summary(path),
+                });
+            }
         }
 
-        let char_aspect_ratio_height = 3.0;
-        let effective_max_width = max_width;
-        let effective_max_height = max_height;
-
-        let img_aspect_ratio = img_width as f32 / img_height as f32;
-
-        let width_constrained_width = effective_max_width;
-        let width_constrained_height = ((width_constrained_width as f32) / img_aspect_ratio) as u16;
+        // Clean up any partial output
+        drop(TempFileGuard::new(frame_path));
+        None
+    }
 
-        let height_constrained_height = effective_max_height;
-        let height_constrained_width = ((height_constrained_height as f32)
-            * img_aspect_ratio
-            * char_aspect_ratio_height) as u16;
+    /// Ask ffprobe for the codec and duration of the first video stream
+    fn probe_video_summary(path: &str) -> String {
+        let output = Command::new("ffprobe")
+            .args([
+                "-v",
+                "error",
+                "-select_streams",
+                "v:0",
+                "-show_entries",
+                "stream=codec_name:format=duration",
+                "-of",
+                "default=noprint_wrappers=1",
+                path,
+            ])
+            .output()
+            .map(|output| String::from_utf8_lossy(&output.stdout).to_string())
+            .unwrap_or_default();
+        Self::parse_video_summary(&output)
+    }
 
-        let (final_width, final_height) = if width_constrained_height <= effective_max_height {
-            (width_constrained_width, width_constrained_height)
-        } else {
-            (
-                height_constrained_width.min(effective_max_width),
-                height_constrained_height,
-            )
-        };
+    /// Format ffprobe's key=value output as "codec | m:ss"
+    fn parse_video_summary(output: &str) -> String {
+        let mut codec = None;
+        let mut duration = None;
+        for line in output.lines() {
+            match line.trim().split_once('=') {
+                Some(("codec_name", value)) => codec = Some(value.to_string()),
+                Some(("duration", value)) => duration = value.parse::<f64>().ok(),
+                _ => {}
+            }
+        }
 
-        (final_width, final_height)
+        let duration = duration
+            .map(format_duration)
+            .unwrap_or_else(|| "?:??".to_string());
+        format!(
+            "{} | {}",
+            codec.unwrap_or_else(|| "unknown".to_string()),
+            duration
+        )
     }
 
-    fn render_with_converter(&mut self, path: &str, width: u16, height: u16) -> Text<'static> {
-        match self.converter.convert_image(path, width, height) {
-            Ok(output) => match output.as_bytes().into_text() {
-                Ok(text) => text,
-                Err(_) => Text::from("Failed to parse ANSI output"),
-            },
-            Err(e) => {
-                self.debug_info = format!("{} error: {}", self.converter.get_name(), e);
-                Text::from(format!(
-                    "Failed to execute {}: {}",
-                    self.converter.get_name(),
+    /// Preview an audio file with its tags and stream details, or with a waveform
+    /// drawn by ffmpeg when audio_waveform is set
+    fn generate_audio_preview(
+        &mut self,
+        file: &FileItem,
+        width: u16,
+        height: u16,
+        localization: &Localization,
+    ) -> PreviewContent {
+        let timeout = Duration::from_millis(self.config.converter.get_conversion_timeout_ms());
+        if !self.audio_infos.contains_key(&file.path) {
+            let info = Self::probe_audio_info(&file.path, timeout);
+            self.audio_infos.insert(file.path.clone(), info);
+        }
+
+        if self.config.get_audio_waveform() {
+            if !self.audio_waveforms.contains_key(&file.path)
+                && let Some(waveform) = Self::render_audio_waveform(&file.path, timeout)
+            {
+                self.audio_waveforms.insert(file.path.clone(), waveform);
+            }
+            if let Some(waveform) = self.audio_waveforms.get(&file.path) {
+                let waveform_path = waveform.path().to_string_lossy().to_string();
+                let preview = self.generate_image_preview(
+                    &waveform_path,
+                    file.modified,
+                    width,
+                    height,
+                    localization,
+                );
+                self.debug_info = self.audio_debug_info(&file.path, localization);
+                return preview;
+            }
+        }
+
+        self.audio_info_preview(&file.path, file.modified, width, height, localization)
+    }
+
+    /// The details ffprobe found about an audio file. It's cached under the file so
+    /// the report is dropped along with it.
+    fn audio_info_preview(
+        &mut self,
+        path: &str,
+        modified: SystemTime,
+        width: u16,
+        height: u16,
+        localization: &Localization,
+    ) -> PreviewContent {
+        self.debug_info = self.audio_debug_info(path, localization);
+        let text = match self.audio_infos.get(path) {
+            Some(Some(info)) => Self::audio_info_text(info, localization),
+            _ => localization.get("audio_preview_failed"),
+        };
+        let preview = PreviewContent::Text(Text::from(text), ContentAlignment::Text);
+        let cache_key = self.cache_key(path, width, height);
+        self.insert_into_cache(cache_key, preview.clone(), modified);
+        preview
+    }
+
+    /// The debug pane line for an audio file, with its length when a waveform is shown
+    /// in place of the details
+    fn audio_debug_info(&self, path: &str, localization: &Localization) -> String {
+        let name = Path::new(path).file_name().unwrap_or_default();
+        let line = format!(
+            "{}{}",
+            localization.get("audio_file_prefix"),
+            name.to_string_lossy()
+        );
+        if !self.audio_waveforms.contains_key(path) {
+            return line;
+        }
+        let duration = self
+            .audio_infos
+            .get(path)
+            .and_then(|info| info.as_ref()?.duration_secs)
+            .map(format_duration)
+            .unwrap_or_else(|| "?:??".to_string());
+        format!("{} | {}", line, duration)
+    }
+
+    /// Ask ffprobe about the first audio stream and the tags of the file
+    fn probe_audio_info(path: &str, timeout: Duration) -> Option<AudioInfo> {
+        let mut command = Command::new("ffprobe");
+        command
+            .args(["-v", "error", "-show_streams", "-show_format"])
+            .args(["-select_streams", "a:0", "-print_format", "json", path]);
+        let output = converter::run_with_timeout(&mut command, timeout).ok()?;
+        Self::parse_audio_info(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    /// Read ffprobe's JSON. Stream details win over the container's, which fill in
+    /// what the stream leaves out (FLAC streams have no bit rate, for one).
+    /// Tag names vary in case between formats.
+    fn parse_audio_info(json: &str) -> Option<AudioInfo> {
+        let report: serde_json::Value = serde_json::from_str(json).ok()?;
+        let stream = report["streams"].get(0)?;
+        let format = &report["format"];
+
+        let number = |key: &str| {
+            [stream, format]
+                .iter()
+                .find_map(|section| section[key].as_str()?.parse::<f64>().ok())
+        };
+        let tag = |name: &str| {
+            [stream, format].iter().find_map(|section| {
+                section["tags"]
+                    .as_object()?
+                    .iter()
+                    .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                    .and_then(|(_, value)| value.as_str())
+                    .map(str::to_string)
+            })
+        };
+
+        Some(AudioInfo {
+            title: tag("title"),
+            artist: tag("artist"),
+            album: tag("album"),
+            duration_secs: number("duration"),
+            bit_rate: number("bit_rate").map(|rate| rate as u64),
+            sample_rate: number("sample_rate").map(|rate| rate as u32),
+        })
+    }
+
+    /// One line per detail that ffprobe found
+    fn audio_info_text(info: &AudioInfo, localization: &Localization) -> String {
+        use fluent::fluent_args;
+        let mut lines = Vec::new();
+        for (key, value) in [
+            ("audio_title", &info.title),
+            ("audio_artist", &info.artist),
+            ("audio_album", &info.album),
+        ] {
+            if let Some(value) = value {
+                let args = fluent_args!["value" => value.as_str()];
+                lines.push(localization.get_with_args(key, Some(&args)));
+            }
+        }
+        if let Some(secs) = info.duration_secs {
+            let args = fluent_args!["value" => format_duration(secs)];
+            lines.push(localization.get_with_args("audio_duration", Some(&args)));
+        }
+        if let Some(bit_rate) = info.bit_rate {
+            let args = fluent_args!["kbps" => bit_rate / 1000];
+            lines.push(localization.get_with_args("audio_bitrate", Some(&args)));
+        }
+        if let Some(sample_rate) = info.sample_rate {
+            let args = fluent_args!["hz" => sample_rate];
+            lines.push(localization.get_with_args("audio_sample_rate", Some(&args)));
+        }
+        lines.join("\n")
+    }
+
+    /// Draw the waveform of an audio file to a PNG with ffmpeg
+    fn render_audio_waveform(path: &str, timeout: Duration) -> Option<TempFileGuard> {
+        let waveform = TempFileGuard::new(TempFileGuard::path_for("ptui_wave", path, "png"));
+        let mut command = Command::new("ffmpeg");
+        command
+            .args(["-v", "error", "-i", path])
+            .args(["-filter_complex", "showwavespic=s=80x8", "-frames:v", "1"])
+            .arg(waveform.path())
+            .arg("-y");
+        let drawn = converter::run_with_timeout(&mut command, timeout)
+            .is_ok_and(|output| output.status.success());
+        // Dropping the guard cleans up any partial output
+        (drawn && waveform.path().exists()).then_some(waveform)
+    }
+
+    /// Start converting an image on a background thread so the UI can keep drawing.
+    /// RAW files have their JPEG extracted there too.
+    /// Returns None when the preview is cached or needs the graphical pipeline,
+    /// in which case the caller should use generate_preview directly.
+    pub fn start_background_conversion(
+        &mut self,
+        file: &FileItem,
+        width: u16,
+        height: u16,
+        localization: &Localization,
+    ) -> Option<Receiver<ConvertedPreview>> {
+        // Graphical previews hold terminal protocol state and must be built on the main thread
+        if self.converter.is_graphical() {
+            return None;
+        }
+
+        let (path, source) = if file.is_audio() {
+            match self.audio_waveforms.get(&file.path) {
+                Some(waveform) if self.config.get_audio_waveform() => (
+                    waveform.path().to_string_lossy().to_string(),
+                    ConversionSource::Image,
+                ),
+                // The details are quick to show again once ffprobe has reported
+                _ if self.audio_infos.contains_key(&file.path)
+                    && !self.config.get_audio_waveform() =>
+                {
+                    return None;
+                }
+                _ => (
+                    file.path.clone(),
+                    ConversionSource::Audio {
+                        waveform: self.config.get_audio_waveform(),
+                    },
+                ),
+            }
+        } else if !file.is_raw_image() {
+            (file.path.clone(), ConversionSource::Image)
+        } else if let Some(jpeg_path) = self.extracted_raw_jpeg(file) {
+            (jpeg_path, ConversionSource::Image)
+        } else {
+            let no_extractor_message = localization.get("raw_requires_extractor");
+            (
+                file.path.clone(),
+                ConversionSource::Raw {
+                    no_extractor_message,
+                },
+            )
+        };
+
+        let cache_key = self.cache_key(&path, width, height);
+        if self
+            .cache
+            .get(&cache_key)
+            .is_some_and(|entry| entry.mtime == file.modified)
+        {
+            return None;
+        }
+
+        let (width, height) = self.rounded_size(width, height);
+        // A RAW file's JPEG and an audio file's waveform don't exist yet, so the worker
+        // fits them once they do
+        let (converter_width, converter_height) = match source {
+            ConversionSource::Image => {
+                self.calculate_converter_dimensions(&path, width, height, localization)
+            }
+            ConversionSource::Raw { .. } | ConversionSource::Audio { .. } => (width, height),
+        };
+        if file.is_raw_image() {
+            self.debug_info = format!("{}{}", localization.get("image_file_prefix"), file.name);
+        } else if file.is_audio() {
+            self.debug_info = self.audio_debug_info(&file.path, localization);
+        }
+        self.cache_misses += 1;
+        self.conversion_started = Some(Instant::now());
+
+        let (response_tx, rx) = mpsc::channel();
+        let request = ConversionRequest {
+            config: self.config.clone(),
+            animate: Self::is_gif(&path),
+            path,
+            source,
+            width: converter_width,
+            height: converter_height,
+            response_tx,
+        };
+
+        self.send_to_worker(WorkerRequest::Preview(request));
+
+        Some(rx)
+    }
+
+    fn send_to_worker(&mut self, request: WorkerRequest) {
+        let worker = self
+            .conversion_worker
+            .get_or_insert_with(Self::spawn_conversion_worker);
+        if let Err(mpsc::SendError(request)) = worker.send(request) {
+            // The worker died (a converter panicked), start a fresh one
+            let worker = Self::spawn_conversion_worker();
+            let _ = worker.send(request);
+            self.conversion_worker = Some(worker);
+        }
+    }
+
+    /// Start the thread that runs conversions one at a time, the selected file's preview
+    /// ahead of grid thumbnails. It stops when the PreviewManager (and with it the
+    /// request sender) is dropped.
+    fn spawn_conversion_worker() -> Sender<WorkerRequest> {
+        let (tx, rx) = mpsc::channel::<WorkerRequest>();
+        thread::spawn(move || {
+            let mut preview: Option<ConversionRequest> = None;
+            let mut thumbnails: VecDeque<ThumbnailRequest> = VecDeque::new();
+            loop {
+                if preview.is_none() && thumbnails.is_empty() {
+                    match rx.recv() {
+                        Ok(request) => Self::queue_request(request, &mut preview, &mut thumbnails),
+                        Err(_) => break,
+                    }
+                }
+                while let Ok(request) = rx.try_recv() {
+                    Self::queue_request(request, &mut preview, &mut thumbnails);
+                }
+
+                if let Some(request) = preview.take() {
+                    let converted = Self::run_conversion(&request);
+                    // The receiver is dropped if the user has moved on to another file
+                    let _ = request.response_tx.send(converted);
+                } else if let Some(request) = thumbnails.pop_front() {
+                    let converter = converter::create_converter(&request.config);
+                    let thumbnail = Self::convert_to_text(
+                        converter.as_ref(),
+                        &request.path,
+                        request.width,
+                        request.height,
+                    )
+                    .ok();
+                    let _ = request.response_tx.send((request.cache_key, thumbnail));
+                }
+            }
+        });
+        tx
+    }
+
+    fn queue_request(
+        request: WorkerRequest,
+        preview: &mut Option<ConversionRequest>,
+        thumbnails: &mut VecDeque<ThumbnailRequest>,
+    ) {
+        match request {
+            // Only the newest preview is still wanted, the UI drops the older receivers
+            WorkerRequest::Preview(request) => *preview = Some(request),
+            WorkerRequest::Thumbnail(request) => thumbnails.push_back(request),
+        }
+    }
+
+    fn run_conversion(request: &ConversionRequest) -> ConvertedPreview {
+        let config = &request.config;
+        match &request.source {
+            ConversionSource::Image => Self::convert_image(
+                config,
+                &request.path,
+                request.width,
+                request.height,
+                request.animate,
+            ),
+            ConversionSource::Raw {
+                no_extractor_message,
+            } => match Self::extract_raw_jpeg(config, &request.path, no_extractor_message) {
+                Ok(jpeg) => {
+                    let jpeg_path = jpeg.path().to_string_lossy().to_string();
+                    let (width, height) =
+                        Self::fitted_dimensions(config, &jpeg_path, request.width, request.height);
+                    let converted = Self::convert_image(config, &jpeg_path, width, height, false);
+                    ConvertedPreview::Raw {
+                        jpeg,
+                        converted: Box::new(converted),
+                    }
+                }
+                Err(e) => ConvertedPreview::Unavailable(e),
+            },
+            ConversionSource::Audio { waveform } => {
+                let timeout = Duration::from_millis(config.converter.get_conversion_timeout_ms());
+                let info = Self::probe_audio_info(&request.path, timeout);
+                let waveform = waveform
+                    .then(|| Self::render_audio_waveform(&request.path, timeout))
+                    .flatten()
+                    .map(|waveform| {
+                        let waveform_path = waveform.path().to_string_lossy().to_string();
+                        let (width, height) = Self::fitted_dimensions(
+                            config,
+                            &waveform_path,
+                            request.width,
+                            request.height,
+                        );
+                        let converted =
+                            Self::convert_image(config, &waveform_path, width, height, false);
+                        (waveform, Box::new(converted))
+                    });
+                ConvertedPreview::Audio { info, waveform }
+            }
+        }
+    }
+
+    fn convert_image(
+        config: &PTuiConfig,
+        path: &str,
+        width: u16,
+        height: u16,
+        animate: bool,
+    ) -> ConvertedPreview {
+        let converter = converter::create_converter(config);
+        if animate
+            && let Some((frames, frame_duration_ms)) =
+                Self::convert_animation_frames(converter.as_ref(), path, width, height)
+        {
+            return ConvertedPreview::Animated {
+                frames,
+                frame_duration_ms,
+            };
+        }
+
+        match Self::convert_to_text(converter.as_ref(), path, width, height) {
+            Ok(text) => ConvertedPreview::Still(text),
+            Err(e) => ConvertedPreview::Failed(e),
+        }
+    }
+
+    /// The converter size for an image that only exists on the worker, fitted within
+    /// max_width x max_height like calculate_converter_dimensions does
+    fn fitted_dimensions(
+        config: &PTuiConfig,
+        path: &str,
+        max_width: u16,
+        max_height: u16,
+    ) -> (u16, u16) {
+        let prefer_native = config.get_prefer_native_dimension_detection();
+        let (img_width, img_height) = ImageDimensions::get_dimensions(path, prefer_native);
+        Self::fit_converter_dimensions(
+            img_width,
+            img_height,
+            max_width,
+            max_height,
+            Self::char_aspect_ratio(config),
+        )
+    }
+
+    /// Export ASCII art for each file to name.ascii next to it, on background threads.
+    /// Files that fail are listed in EXPORT_ERRORS_FILE in dir and the rest carry on.
+    pub fn start_batch_export(
+        &self,
+        dir: &Path,
+        files: Vec<FileItem>,
+        width: u16,
+        height: u16,
+        overwrite: bool,
+    ) -> Receiver<ExportProgress> {
+        let config = self.export_config();
+        let create_converter = self.export_converter;
+        let errors_path = dir.join(EXPORT_ERRORS_FILE);
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let outcomes = Self::run_export_workers(
+                &config,
+                create_converter,
+                files,
+                &tx,
+                move |converter, file, prefer_native, char_aspect_ratio| {
+                    Self::export_file(
+                        converter,
+                        file,
+                        width,
+                        height,
+                        overwrite,
+                        prefer_native,
+                        char_aspect_ratio,
+                    )
+                },
+            );
+
+            let (mut succeeded, mut skipped, mut errors) = (0, 0, Vec::new());
+            for (file, outcome) in outcomes {
+                match outcome {
+                    ExportOutcome::Saved => succeeded += 1,
+                    ExportOutcome::Skipped => skipped += 1,
+                    ExportOutcome::Failed(e) => errors.push(format!("{}: {}", file.name, e)),
+                }
+            }
+
+            Self::log_export_errors(&errors_path, &errors);
+            let _ = tx.send(ExportProgress::Done {
+                succeeded,
+                failed: errors.len(),
+                skipped,
+            });
+        });
+        rx
+    }
+
+    /// Write the files as ASCII art into one HTML page that plays them in turn, switching
+    /// every delay_ms. Converts on the batch export threads; images that fail are left
+    /// out and listed in EXPORT_ERRORS_FILE in dir.
+    pub fn start_slideshow_html_export(
+        &self,
+        dir: &Path,
+        files: Vec<FileItem>,
+        width: u16,
+        height: u16,
+        delay_ms: u64,
+        output_path: PathBuf,
+    ) -> Receiver<ExportProgress> {
+        let config = self.export_config();
+        let create_converter = self.export_converter;
+        let errors_path = dir.join(EXPORT_ERRORS_FILE);
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let results = Self::run_export_workers(
+                &config,
+                create_converter,
+                files,
+                &tx,
+                move |converter, file, prefer_native, char_aspect_ratio| {
+                    Self::convert_for_export(
+                        converter,
+                        file,
+                        width,
+                        height,
+                        prefer_native,
+                        char_aspect_ratio,
+                    )
+                },
+            );
+
+            let (mut frames, mut errors) = (Vec::new(), Vec::new());
+            for (file, result) in results {
+                match result {
+                    Ok(frame) => frames.push(frame),
+                    Err(e) => errors.push(format!("{}: {}", file.name, e)),
+                }
+            }
+            Self::log_export_errors(&errors_path, &errors);
+
+            let update = if frames.is_empty() {
+                ExportProgress::Failed(errors.first().cloned().unwrap_or_default())
+            } else {
+                match fs::write(&output_path, html_export::slideshow_html(&frames, delay_ms)) {
+                    Ok(()) => ExportProgress::Done {
+                        succeeded: frames.len(),
+                        failed: errors.len(),
+                        skipped: 0,
+                    },
+                    Err(e) => ExportProgress::Failed(e.to_string()),
+                }
+            };
+            let _ = tx.send(update);
+        });
+        rx
+    }
+
+    /// Run job on every file across the configured number of export threads, each with
+    /// its own converter, sending progress as files are started. Results come back in
+    /// the order of files.
+    fn run_export_workers<T, F>(
+        config: &PTuiConfig,
+        create_converter: fn(&PTuiConfig) -> Box<dyn AsciiConverter>,
+        files: Vec<FileItem>,
+        tx: &Sender<ExportProgress>,
+        job: F,
+    ) -> Vec<(FileItem, T)>
+    where
+        T: Send + 'static,
+        F: Fn(&dyn AsciiConverter, &FileItem, bool, f32) -> T + Send + Sync + 'static,
+    {
+        let total = files.len();
+        let files = Arc::new(files);
+        let next_index = Arc::new(AtomicUsize::new(0));
+        let job = Arc::new(job);
+        let thread_count = (config.get_batch_export_concurrent() as usize).min(total.max(1));
+
+        let workers: Vec<_> = (0..thread_count)
+            .map(|_| {
+                let (files, next_index, tx, config, job) = (
+                    files.clone(),
+                    next_index.clone(),
+                    tx.clone(),
+                    config.clone(),
+                    job.clone(),
+                );
+                thread::spawn(move || {
+                    let converter = create_converter(&config);
+                    let prefer_native = config.get_prefer_native_dimension_detection();
+                    let char_aspect_ratio = Self::char_aspect_ratio(&config);
+                    let mut results = Vec::new();
+                    loop {
+                        let index = next_index.fetch_add(1, Ordering::SeqCst);
+                        let Some(file) = files.get(index) else {
+                            break;
+                        };
+                        let _ = tx.send(ExportProgress::Exporting {
+                            position: index + 1,
+                            total,
+                            file_name: file.name.clone(),
+                        });
+                        let result =
+                            job(converter.as_ref(), file, prefer_native, char_aspect_ratio);
+                        results.push((index, result));
+                    }
+                    results
+                })
+            })
+            .collect();
+
+        let mut results: Vec<(usize, T)> = workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap_or_default())
+            .collect();
+        results.sort_by_key(|(index, _)| *index);
+        results
+            .into_iter()
+            .map(|(index, result)| (files[index].clone(), result))
+            .collect()
+    }
+
+    fn log_export_errors(errors_path: &Path, errors: &[String]) {
+        if !errors.is_empty()
+            && let Ok(mut log) = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(errors_path)
+        {
+            for error in errors {
+                let _ = writeln!(log, "{}", error);
+            }
+        }
+    }
+
+    /// The config exports convert with. Graphical previews, Sixel and iTerm2 escapes need
+    /// the terminal, so they export through chafa like the graphical fallback does.
+    fn export_config(&self) -> PTuiConfig {
+        let mut config = self.config.clone();
+        if matches!(
+            config.converter.selected.as_str(),
+            "graphical" | "sixel" | "iterm"
+        ) {
+            config.converter.selected = "chafa".to_string();
+        }
+        config
+    }
+
+    /// Have exports convert with something other than the configured converter
+    #[cfg(test)]
+    pub(crate) fn set_export_converter(
+        &mut self,
+        create_converter: fn(&PTuiConfig) -> Box<dyn AsciiConverter>,
+    ) {
+        self.export_converter = create_converter;
+    }
+
+    fn convert_for_export(
+        converter: &dyn AsciiConverter,
+        file: &FileItem,
+        width: u16,
+        height: u16,
+        prefer_native: bool,
+        char_aspect_ratio: f32,
+    ) -> Result<String, String> {
+        let (img_width, img_height) = ImageDimensions::get_dimensions(&file.path, prefer_native);
+        let (converter_width, converter_height) =
+            Self::fit_converter_dimensions(img_width, img_height, width, height, char_aspect_ratio);
+        converter.convert_image(&file.path, converter_width, converter_height)
+    }
+
+    fn export_file(
+        converter: &dyn AsciiConverter,
+        file: &FileItem,
+        width: u16,
+        height: u16,
+        overwrite: bool,
+        prefer_native: bool,
+        char_aspect_ratio: f32,
+    ) -> ExportOutcome {
+        let output_path = match ascii_path_for(file) {
+            Ok(path) => path,
+            Err(e) => return ExportOutcome::Failed(e),
+        };
+        if output_path.exists() && !overwrite {
+            return ExportOutcome::Skipped;
+        }
+
+        let result = Self::convert_for_export(
+            converter,
+            file,
+            width,
+            height,
+            prefer_native,
+            char_aspect_ratio,
+        )
+        .and_then(|content| {
+            fs::write(&output_path, content).map_err(|e| format!("Failed to write file: {}", e))
+        });
+        match result {
+            Ok(()) => ExportOutcome::Saved,
+            Err(e) => ExportOutcome::Failed(e),
+        }
+    }
+
+    /// Store the result of a background conversion in the cache
+    pub fn finish_background_conversion(
+        &mut self,
+        path: &str,
+        modified: SystemTime,
+        width: u16,
+        height: u16,
+        converted: ConvertedPreview,
+        localization: &Localization,
+    ) -> PreviewContent {
+        let cache_key = self.cache_key(path, width, height);
+        let result = match converted {
+            ConvertedPreview::Raw { jpeg, converted } => {
+                // Cached under the JPEG like a preview made on the main thread
+                let jpeg_path = jpeg.path().to_string_lossy().to_string();
+                self.raw_images.insert(path.to_string(), (modified, jpeg));
+                return self.finish_background_conversion(
+                    &jpeg_path,
+                    modified,
+                    width,
+                    height,
+                    *converted,
+                    localization,
+                );
+            }
+            ConvertedPreview::Audio { info, waveform } => {
+                self.audio_infos.insert(path.to_string(), info);
+                let Some((waveform, converted)) = waveform else {
+                    return self.audio_info_preview(path, modified, width, height, localization);
+                };
+                // Cached under the waveform like a preview made on the main thread
+                let waveform_path = waveform.path().to_string_lossy().to_string();
+                self.audio_waveforms.insert(path.to_string(), waveform);
+                let preview = self.finish_background_conversion(
+                    &waveform_path,
+                    modified,
+                    width,
+                    height,
+                    *converted,
+                    localization,
+                );
+                self.debug_info = self.audio_debug_info(path, localization);
+                return preview;
+            }
+            ConvertedPreview::Unavailable(message) => {
+                // Not cached, so it's tried again next time like on the main thread
+                self.debug_info = message.clone();
+                return PreviewContent::Text(Text::from(message), ContentAlignment::Image);
+            }
+            ConvertedPreview::Still(text) => PreviewContent::Text(text, ContentAlignment::Image),
+            ConvertedPreview::Animated {
+                frames,
+                frame_duration_ms,
+            } => PreviewContent::Animated {
+                frames,
+                frame_duration_ms,
+            },
+            ConvertedPreview::Failed(e) => {
+                // Same messages as a conversion on the main thread
+                self.debug_info = format!("{} error: {}", self.converter.get_name(), e);
+                PreviewContent::Text(
+                    Text::from(format!(
+                        "Failed to execute {}: {}",
+                        self.converter.get_name(),
+                        e
+                    )),
+                    ContentAlignment::Image,
+                )
+            }
+        };
+        self.insert_into_cache(cache_key, result.clone(), modified);
+        result
+    }
+
+    /// Convert every frame of an animated GIF to text.
+    /// Returns None for single-frame GIFs or when ffmpeg is unavailable,
+    /// in which case the caller falls back to a static preview.
+    pub fn generate_animated_preview(
+        &mut self,
+        path: &str,
+        modified: SystemTime,
+        width: u16,
+        height: u16,
+        localization: &Localization,
+    ) -> Option<PreviewContent> {
+        let cache_key = self.cache_key(path, width, height);
+        if let Some(cached) = self.cached_preview(&cache_key, modified) {
+            return Some(cached);
+        }
+        let (width, height) = self.rounded_size(width, height);
+
+        let (converter_width, converter_height) =
+            self.calculate_converter_dimensions(path, width, height, localization);
+        let (frames, frame_duration_ms) = Self::convert_animation_frames(
+            self.converter.as_ref(),
+            path,
+            converter_width,
+            converter_height,
+        )?;
+
+        let result = PreviewContent::Animated {
+            frames,
+            frame_duration_ms,
+        };
+        self.insert_into_cache(cache_key, result.clone(), modified);
+        Some(result)
+    }
+
+    fn convert_animation_frames(
+        converter: &dyn AsciiConverter,
+        path: &str,
+        width: u16,
+        height: u16,
+    ) -> Option<(Vec<Text<'static>>, u64)> {
+        let frame_duration_ms = Self::gif_frame_duration_ms(path);
+        let fps = 1000.0 / frame_duration_ms as f64;
+
+        // TempDir removes the extracted frames when it goes out of scope
+        let temp_dir = tempfile::TempDir::new().ok()?;
+        let frame_pattern = temp_dir.path().join("frame%04d.png");
+        let status = Command::new("ffmpeg")
+            .args(["-v", "error", "-i", path, "-vf"])
+            .arg(format!("fps=fps={:.3}", fps))
+            .args(["-frames:v", &MAX_ANIMATION_FRAMES.to_string()])
+            .arg(&frame_pattern)
+            .status()
+            .ok()?;
+        if !status.success() {
+            return None;
+        }
+
+        let mut frame_paths: Vec<_> = fs::read_dir(temp_dir.path())
+            .ok()?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .collect();
+        frame_paths.sort();
+
+        // A single frame is just a still image
+        if frame_paths.len() < 2 {
+            return None;
+        }
+
+        let mut frames = Vec::with_capacity(frame_paths.len());
+        for frame_path in &frame_paths {
+            let text =
+                Self::convert_to_text(converter, &frame_path.to_string_lossy(), width, height)
+                    .ok()?;
+            frames.push(text);
+        }
+
+        #[cfg(all(not(test), feature = "debug-output"))]
+        eprintln!(
+            "[ANIMATION] Extracted {} frames at {}ms from {}",
+            frames.len(),
+            frame_duration_ms,
+            path
+        );
+
+        Some((frames, frame_duration_ms))
+    }
+
+    /// Read the GIF frame rate with ffprobe, falling back to a sensible default
+    fn gif_frame_duration_ms(path: &str) -> u64 {
+        Command::new("ffprobe")
+            .args([
+                "-v",
+                "error",
+                "-select_streams",
+                "v:0",
+                "-show_entries",
+                "stream=avg_frame_rate",
+                "-of",
+                "default=noprint_wrappers=1:nokey=1",
+                path,
+            ])
+            .output()
+            .ok()
+            .and_then(|output| Self::parse_frame_rate(&String::from_utf8_lossy(&output.stdout)))
+            .unwrap_or(DEFAULT_FRAME_DURATION_MS)
+    }
+
+    /// Turn an ffprobe rate such as "25/2" into a frame duration in milliseconds
+    fn parse_frame_rate(rate: &str) -> Option<u64> {
+        let (num, den) = rate.trim().split_once('/')?;
+        let num: f64 = num.parse().ok()?;
+        let den: f64 = den.parse().ok()?;
+        if num <= 0.0 || den <= 0.0 {
+            return None;
+        }
+        Some(((1000.0 * den / num).round() as u64).max(20))
+    }
+
+    fn is_gif(path: &str) -> bool {
+        let mut header = [0u8; 6];
+        fs::File::open(path)
+            .and_then(|mut file| std::io::Read::read_exact(&mut file, &mut header))
+            .map(|_| header.starts_with(b"GIF8"))
+            .unwrap_or(false)
+    }
+
+    pub(crate) fn insert_into_cache(
+        &mut self,
+        cache_key: String,
+        content: PreviewContent,
+        mtime: SystemTime,
+    ) {
+        // Replacing a stale entry shouldn't leave its key in the LRU order twice
+        if self.cache.contains_key(&cache_key) {
+            self.cache_order.retain(|k| k != &cache_key);
+        }
+
+        // LRU cache eviction: remove oldest entry if cache is full
+        if self.cache.len() >= self.max_cache_size
+            && let Some(oldest_key) = self.cache_order.first().cloned()
+        {
+            self.cache.remove(&oldest_key);
+            self.cache_order.remove(0);
+            // Video frames, ffprobe's reports, waveforms and RAW JPEGs are only needed
+            // while their preview is cached
+            let evicted = |temp_file: &TempFileGuard| {
+                oldest_key.starts_with(&format!("{}:", temp_file.path().to_string_lossy()))
+            };
+            self.audio_infos.retain(|path, _| {
+                !oldest_key.starts_with(&format!("{}:", path))
+                    && !self.audio_waveforms.get(path).is_some_and(evicted)
+            });
+            self.audio_waveforms
+                .retain(|_, waveform| !evicted(waveform));
+            self.video_thumbnails
+                .retain(|_, thumbnail| !evicted(&thumbnail.frame));
+            self.raw_images.retain(|_, (_, jpeg)| !evicted(jpeg));
+            #[cfg(all(not(test), feature = "debug-output"))]
+            eprintln!("[CACHE] Evicted oldest entry: {}", oldest_key);
+        }
+
+        self.cache
+            .insert(cache_key.clone(), CacheEntry { content, mtime });
+        self.cache_order.push(cache_key);
+
+        if let Some(started) = self.conversion_started.take() {
+            self.timing_ms = started.elapsed().as_millis() as u64;
+        }
+    }
+
+    fn generate_text_preview(
+        &self,
+        path: &str,
+        scroll_offset: usize,
+        visible_height: u16,
+        localization: &Localization,
+    ) -> Text<'static> {
+        Self::text_preview(
+            path,
+            scroll_offset,
+            visible_height,
+            self.config.get_max_preview_lines(),
+            localization,
+        )
+    }
+
+    fn text_preview(
+        path: &str,
+        scroll_offset: usize,
+        visible_height: u16,
+        max_lines: usize,
+        localization: &Localization,
+    ) -> Text<'static> {
+        match std::fs::File::open(path) {
+            Ok(file) => {
+                let lines = Self::read_text_window(
+                    BufReader::new(file),
+                    scroll_offset,
+                    visible_height as usize,
+                    max_lines,
+                );
+                Text::from(lines.join("\n"))
+            }
+            Err(_) => Text::from(localization.get("text_file_unreadable")),
+        }
+    }
+
+    /// How many lines a text file has, counting no further than scrolling goes
+    fn count_text_lines(path: &str, max_lines: usize) -> Option<usize> {
+        let file = std::fs::File::open(path).ok()?;
+        skip_lines(&mut BufReader::new(file), max_lines).ok()
+    }
+
+    /// The lines of a text file visible at a scroll offset. Lines above the window
+    /// are skipped without being kept, so only what's on screen is held in memory
+    /// however large the file is. Scrolling stops at `max_lines`.
+    fn read_text_window<R: BufRead>(
+        mut reader: R,
+        scroll_offset: usize,
+        visible_height: usize,
+        max_lines: usize,
+    ) -> Vec<String> {
+        let start = scroll_offset.min(max_lines);
+        match skip_lines(&mut reader, start) {
+            Ok(skipped) if skipped == start => {}
+            Ok(_) => return vec!["(End of file)".to_string()],
+            Err(_) => return vec!["Error reading file".to_string()],
+        }
+
+        let end = scroll_offset.saturating_add(visible_height).min(max_lines);
+        let mut lines = Vec::new();
+        let mut buffer = Vec::new();
+        while start + lines.len() < end {
+            buffer.clear();
+            match reader.read_until(b'\n', &mut buffer) {
+                Ok(0) => break,
+                Ok(_) => {
+                    while matches!(buffer.last(), Some(b'\n' | b'\r')) {
+                        buffer.pop();
+                    }
+                    match String::from_utf8(std::mem::take(&mut buffer)) {
+                        Ok(line) => lines.push(line),
+                        Err(_) => {
+                            lines.push("Error reading file".to_string());
+                            return lines;
+                        }
+                    }
+                }
+                Err(_) => {
+                    lines.push("Error reading file".to_string());
+                    return lines;
+                }
+            }
+        }
+
+        if start + lines.len() == max_lines {
+            // Count the rest without keeping it, for the truncation message
+            let remaining = skip_lines(&mut reader, usize::MAX).unwrap_or(0);
+            if remaining > 0 {
+                lines.push(format!(
+                    "... (file too large for scrolling, showing first {} of {} lines)",
+                    max_lines,
+                    max_lines + remaining
+                ));
+            }
+        }
+        if lines.is_empty() {
+            lines.push("(End of file)".to_string());
+        }
+        lines
+    }
+
+    fn calculate_converter_dimensions(
+        &mut self,
+        path: &str,
+        max_width: u16,
+        max_height: u16,
+        localization: &Localization,
+    ) -> (u16, u16) {
+        let prefer_native = self.config.get_prefer_native_dimension_detection();
+        let (img_width, img_height) = ImageDimensions::get_dimensions(path, prefer_native);
+        if img_width > 0 && img_height > 0 {
+            self.image_dimensions
+                .insert(path.to_string(), (img_width, img_height));
+        }
+
+        self.debug_info = format!(
+            "{}{}",
+            localization.get("image_file_prefix"),
+            std::path::Path::new(path)
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+        );
+
+        if img_width == 0 || img_height == 0 {
+            self.debug_info = format!("{} | Using fallback dimensions", self.debug_info);
+            self.last_output_size = Some((max_width, max_height));
+            return (max_width, max_height);
+        }
+
+        let (final_width, final_height) = Self::fit_converter_dimensions(
+            img_width,
+            img_height,
+            max_width,
+            max_height,
+            Self::char_aspect_ratio(&self.config),
+        );
+        self.last_output_size = Some((final_width, final_height));
+        (final_width, final_height)
+    }
+
+    /// The largest converter output size within max_width x max_height that keeps the
+    /// image's shape, allowing for characters being `char_aspect_ratio` times taller
+    /// than wide
+    fn fit_converter_dimensions(
+        img_width: u32,
+        img_height: u32,
+        max_width: u16,
+        max_height: u16,
+        char_aspect_ratio: f32,
+    ) -> (u16, u16) {
+        if img_width == 0 || img_height == 0 {
+            return (max_width, max_height);
+        }
+
+        let img_aspect_ratio = img_width as f32 / img_height as f32;
+
+        let width_constrained_width = max_width;
+        let width_constrained_height =
+            ((width_constrained_width as f32) / img_aspect_ratio / char_aspect_ratio) as u16;
+
+        let height_constrained_height = max_height;
+        let height_constrained_width =
+            ((height_constrained_height as f32) * img_aspect_ratio * char_aspect_ratio) as u16;
+
+        if width_constrained_height <= max_height {
+            (width_constrained_width, width_constrained_height)
+        } else {
+            (
+                height_constrained_width.min(max_width),
+                height_constrained_height,
+            )
+        }
+    }
+
+    fn convert_to_text(
+        converter: &dyn AsciiConverter,
+        path: &str,
+        width: u16,
+        height: u16,
+    ) -> Result<Text<'static>, String> {
+        let output = converter.convert_image(path, width, height)?;
+        match output.as_bytes().into_text() {
+            Ok(text) => Ok(text),
+            Err(_) => Ok(Text::from("Failed to parse ANSI output")),
+        }
+    }
+
+    fn render_with_converter(&mut self, path: &str, width: u16, height: u16) -> Text<'static> {
+        match Self::convert_to_text(self.converter.as_ref(), path, width, height) {
+            Ok(text) => text,
+            Err(e) => {
+                self.debug_info = format!("{} error: {}", self.converter.get_name(), e);
+                Text::from(format!(
+                    "Failed to execute {}: {}",
+                    self.converter.get_name(),
                     e
                 ))
             }
         }
     }
 
+    /// Like render_with_converter, for converters whose output is an escape sequence
+    fn render_escape_sequence(&mut self, path: &str, width: u16, height: u16) -> PreviewContent {
+        match self.converter.convert_image(path, width, height) {
+            Ok(escape_sequence) => PreviewContent::Inline(Rc::new(InlinePreview {
+                escape_sequence,
+                width,
+                height,
+            })),
+            Err(e) => {
+                self.debug_info = format!("{} error: {}", self.converter.get_name(), e);
+                PreviewContent::Text(
+                    Text::from(format!(
+                        "Failed to execute {}: {}",
+                        self.converter.get_name(),
+                        e
+                    )),
+                    ContentAlignment::Image,
+                )
+            }
+        }
+    }
+
+    pub fn get_config(&self) -> &PTuiConfig {
+        &self.config
+    }
+
+    /// Switch to another converter ("chafa", "jp2a", "graphical", ...) keeping the
+    /// rest of the config
+    pub fn set_converter_by_name(&mut self, name: &str) {
+        let mut config = self.config.clone();
+        config.converter.selected = name.to_string();
+        self.update_config(config);
+    }
+
+    pub fn current_converter_name(&self) -> &str {
+        self.converter.get_name()
+    }
+
+    /// Remember a saved slideshow delay without rebuilding the converter
+    pub fn set_slideshow_delay_ms(&mut self, delay_ms: u64) {
+        self.config.slideshow_delay_ms = Some(delay_ms);
+    }
+
     pub fn update_config(&mut self, config: PTuiConfig) {
         self.graphical_max_dimension = Self::calculate_optimal_dimension(&config);
         self.converter = converter::create_converter(&config);
+        // Keep the stored config in sync so background conversions use the same converter
+        self.config = config;
         // Clear cache since converter settings changed
         self.clear_cache();
     }
@@ -931,7 +3244,11 @@ impl PreviewManager {
 struct ImageDimensions;
 
 impl ImageDimensions {
-    fn get_dimensions(path: &str) -> (u32, u32) {
+    fn get_dimensions(path: &str, prefer_native: bool) -> (u32, u32) {
+        if prefer_native && let Some(dimensions) = Self::get_dimensions_native(path) {
+            return dimensions;
+        }
+
         if let Ok(output) = Command::new("identify")
             .args(["-format", "%w %h", path])
             .output()
@@ -946,16 +3263,151 @@ impl ImageDimensions {
             }
         }
 
-        if let Ok(output) = Command::new("file").arg(path).output()
-            && output.status.success()
-        {
-            let output_str = String::from_utf8_lossy(&output.stdout);
-            if let Some(dimensions) = Self::extract_dimensions_from_file_output(&output_str) {
-                return dimensions;
+        if !prefer_native && let Some(dimensions) = Self::get_dimensions_native(path) {
+            return dimensions;
+        }
+
+        if let Ok(output) = Command::new("file").arg(path).output()
+            && output.status.success()
+        {
+            let output_str = String::from_utf8_lossy(&output.stdout);
+            if let Some(dimensions) = Self::extract_dimensions_from_file_output(&output_str) {
+                return dimensions;
+            }
+        }
+
+        (800, 600) // Default fallback
+    }
+
+    /// Read the image size straight from the file header, without running identify.
+    /// Handles JPEG, PNG, GIF, WebP, QOI and AVIF.
+    pub fn get_dimensions_native(path: &str) -> Option<(u32, u32)> {
+        // JPEG size markers usually come after EXIF data, which is capped at 64KB
+        let mut data = Vec::new();
+        fs::File::open(path)
+            .ok()?
+            .take(NATIVE_DIMENSION_READ_LIMIT)
+            .read_to_end(&mut data)
+            .ok()?;
+
+        let dimensions = if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+            Self::extract_dimensions_from_png(&data)
+        } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+            Self::extract_dimensions_from_gif(&data)
+        } else if data.starts_with(&[0xFF, 0xD8]) {
+            Self::extract_dimensions_from_jpeg(&data)
+        } else if data.len() >= 12 && &data[0..4] == b"RIFF" && &data[8..12] == b"WEBP" {
+            Self::extract_dimensions_from_webp(&data)
+        } else if data.starts_with(b"qoif") {
+            Self::extract_dimensions_from_qoi(&data)
+        } else {
+            // AVIF stores its size in the "ispe" box
+            Self::extract_dimensions_from_avif(&data)
+        };
+        dimensions.filter(|&(width, height)| width > 0 && height > 0)
+    }
+
+    /// Width and height are the first fields of the IHDR chunk
+    fn extract_dimensions_from_png(data: &[u8]) -> Option<(u32, u32)> {
+        if data.get(12..16)? != b"IHDR" {
+            return None;
+        }
+        let width = u32::from_be_bytes(data.get(16..20)?.try_into().ok()?);
+        let height = u32::from_be_bytes(data.get(20..24)?.try_into().ok()?);
+        Some((width, height))
+    }
+
+    /// Width and height follow the magic as big-endian u32s
+    fn extract_dimensions_from_qoi(data: &[u8]) -> Option<(u32, u32)> {
+        let width = u32::from_be_bytes(data.get(4..8)?.try_into().ok()?);
+        let height = u32::from_be_bytes(data.get(8..12)?.try_into().ok()?);
+        Some((width, height))
+    }
+
+    /// The logical screen size follows the GIF signature
+    fn extract_dimensions_from_gif(data: &[u8]) -> Option<(u32, u32)> {
+        let width = u16::from_le_bytes(data.get(6..8)?.try_into().ok()?);
+        let height = u16::from_le_bytes(data.get(8..10)?.try_into().ok()?);
+        Some((width as u32, height as u32))
+    }
+
+    /// Walk the JPEG segments until a start-of-frame (SOFn) marker
+    fn extract_dimensions_from_jpeg(data: &[u8]) -> Option<(u32, u32)> {
+        let mut i = 2;
+        while i + 1 < data.len() {
+            if data[i] != 0xFF {
+                return None;
+            }
+            let marker = data[i + 1];
+            // Fill bytes before a marker
+            if marker == 0xFF {
+                i += 1;
+                continue;
+            }
+            // Markers without a length field
+            if marker == 0x01 || (0xD0..=0xD8).contains(&marker) {
+                i += 2;
+                continue;
+            }
+
+            let length = u16::from_be_bytes(data.get(i + 2..i + 4)?.try_into().ok()?) as usize;
+            // SOF0-SOF15, except DHT (C4), JPG (C8) and DAC (CC)
+            if (0xC0..=0xCF).contains(&marker) && !matches!(marker, 0xC4 | 0xC8 | 0xCC) {
+                let height = u16::from_be_bytes(data.get(i + 5..i + 7)?.try_into().ok()?);
+                let width = u16::from_be_bytes(data.get(i + 7..i + 9)?.try_into().ok()?);
+                return Some((width as u32, height as u32));
+            }
+            i += 2 + length;
+        }
+        None
+    }
+
+    /// Read the frame size from a lossy (VP8), lossless (VP8L) or extended (VP8X) WebP
+    fn extract_dimensions_from_webp(data: &[u8]) -> Option<(u32, u32)> {
+        match data.get(12..16)? {
+            b"VP8 " => {
+                // Key frame start code, then 14-bit width and height
+                if data.get(23..26)? != [0x9D, 0x01, 0x2A] {
+                    return None;
+                }
+                let width = u16::from_le_bytes(data.get(26..28)?.try_into().ok()?) & 0x3FFF;
+                let height = u16::from_le_bytes(data.get(28..30)?.try_into().ok()?) & 0x3FFF;
+                Some((width as u32, height as u32))
+            }
+            b"VP8L" => {
+                if *data.get(20)? != 0x2F {
+                    return None;
+                }
+                // 14 bits each of width - 1 and height - 1
+                let bits = u32::from_le_bytes(data.get(21..25)?.try_into().ok()?);
+                Some(((bits & 0x3FFF) + 1, ((bits >> 14) & 0x3FFF) + 1))
             }
+            b"VP8X" => {
+                // 24-bit canvas width - 1 and height - 1
+                let canvas = data.get(24..30)?;
+                let width = u32::from_le_bytes([canvas[0], canvas[1], canvas[2], 0]) + 1;
+                let height = u32::from_le_bytes([canvas[3], canvas[4], canvas[5], 0]) + 1;
+                Some((width, height))
+            }
+            _ => None,
         }
+    }
 
-        (800, 600) // Default fallback
+    /// Read width and height from the image spatial extents ("ispe") property of an AVIF file
+    fn extract_dimensions_from_avif(data: &[u8]) -> Option<(u32, u32)> {
+        if data.len() < 12 || &data[4..8] != b"ftyp" || !matches!(&data[8..12], b"avif" | b"avis") {
+            return None;
+        }
+
+        // ispe: box type, 4 bytes version/flags, then big-endian width and height
+        let start = data.windows(4).position(|window| window == b"ispe")? + 8;
+        let extents = data.get(start..start + 8)?;
+        let width = u32::from_be_bytes(extents[0..4].try_into().ok()?);
+        let height = u32::from_be_bytes(extents[4..8].try_into().ok()?);
+        if width == 0 || height == 0 {
+            return None;
+        }
+        Some((width, height))
     }
 
     fn extract_dimensions_from_file_output(output: &str) -> Option<(u32, u32)> {
@@ -981,68 +3433,590 @@ impl ImageDimensions {
                 }
             }
 
-            if let Some(x_pos) = word.find('×') {
-                let (w_str, h_str) = word.split_at(x_pos);
-                let h_str = &h_str[3..]; // Remove the '×' (3 bytes in UTF-8)
-                if let (Ok(w), Ok(h)) = (w_str.parse::<u32>(), h_str.parse::<u32>()) {
-                    return Some((w, h));
-                }
+            if let Some(x_pos) = word.find('×') {
+                let (w_str, h_str) = word.split_at(x_pos);
+                let h_str = &h_str[3..]; // Remove the '×' (3 bytes in UTF-8)
+                if let (Ok(w), Ok(h)) = (w_str.parse::<u32>(), h_str.parse::<u32>()) {
+                    return Some((w, h));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::localization::Localization;
+    use crate::test_utils::helpers::*;
+
+    #[test]
+    fn test_compute_average_color() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let red = temp_fs.get_path().join("red.png");
+        image::RgbImage::from_pixel(8, 8, image::Rgb([255, 0, 0]))
+            .save(&red)
+            .unwrap();
+        let red = red.to_string_lossy().to_string();
+        assert_eq!(
+            PreviewManager::compute_average_color(&red),
+            Some((255, 0, 0))
+        );
+
+        // Half black, half white
+        let split = temp_fs.get_path().join("split.png");
+        image::RgbImage::from_fn(4, 2, |x, _| {
+            if x < 2 {
+                image::Rgb([0, 0, 0])
+            } else {
+                image::Rgb([255, 255, 255])
+            }
+        })
+        .save(&split)
+        .unwrap();
+        let split = split.to_string_lossy().to_string();
+        assert_eq!(
+            PreviewManager::compute_average_color(&split),
+            Some((127, 127, 127))
+        );
+
+        let notes = temp_fs.create_file("notes.txt", "not an image").unwrap();
+        assert_eq!(PreviewManager::compute_average_color(&notes), None);
+
+        // Computed in the background, then cached until the file changes
+        let mut manager = PreviewManager::new(create_test_config());
+        let mut average_color = |modified: SystemTime| {
+            let started = Instant::now();
+            while manager.average_color(&red, modified).is_none()
+                && started.elapsed() < Duration::from_secs(5)
+            {
+                manager.poll_average_color();
+            }
+            manager.average_color(&red, modified)
+        };
+        let first = SystemTime::UNIX_EPOCH;
+        assert_eq!(average_color(first), Some((255, 0, 0)));
+        std::fs::copy(&split, &red).unwrap();
+        assert_eq!(average_color(first), Some((255, 0, 0)));
+        assert_eq!(
+            average_color(first + Duration::from_secs(1)),
+            Some((127, 127, 127))
+        );
+    }
+
+    #[test]
+    fn test_preview_manager_creation() {
+        let config = create_test_config();
+        let manager = PreviewManager::new(config);
+
+        assert!(manager.cache.is_empty());
+        assert_eq!(manager.debug_info, "");
+        assert_eq!(manager.converter.get_name(), "chafa");
+    }
+
+    #[test]
+    fn test_set_converter_by_name_keeps_config() {
+        let mut manager = PreviewManager::new(create_test_config());
+        manager.insert_into_cache(
+            "/test/a.jpg:80x24".to_string(),
+            PreviewContent::Text(Text::from("art"), ContentAlignment::Image),
+            std::time::UNIX_EPOCH,
+        );
+
+        manager.set_converter_by_name("jp2a");
+        assert_eq!(manager.current_converter_name(), "jp2a");
+        assert_eq!(manager.get_config().converter.selected, "jp2a");
+        assert_eq!(manager.get_config().locale.as_deref(), Some("en"));
+        assert_eq!(manager.cache_len(), 0);
+
+        manager.set_slideshow_delay_ms(4000);
+        assert_eq!(manager.get_config().slideshow_delay_ms, Some(4000));
+        assert_eq!(manager.current_converter_name(), "jp2a");
+    }
+
+    #[test]
+    fn test_preview_manager_cache_operations() {
+        let config = create_test_config();
+        let mut manager = PreviewManager::new(config);
+        let file_item = create_test_image_file_item("test");
+
+        manager.remove_from_cache(&file_item, 80, 24);
+
+        manager.clear_cache();
+        assert!(manager.cache.is_empty());
+    }
+
+    #[test]
+    fn test_preview_manager_directory_preview() {
+        let config = create_test_config();
+        let mut manager = PreviewManager::new(config);
+        let localization = Localization::new("en").unwrap();
+        let dir_item = create_test_directory_item("test_dir");
+
+        let preview = manager.generate_preview(&dir_item, 80, 24, 0, &localization);
+
+        assert_eq!(manager.debug_info, localization.get("directory_selected"));
+        match preview {
+            PreviewContent::Text(text, _) => assert!(!text.lines.is_empty()),
+            PreviewContent::Graphical(_)
+            | PreviewContent::Kitty(_)
+            | PreviewContent::Inline(_)
+            | PreviewContent::Animated { .. } => panic!("Expected text preview for directory"),
+        }
+    }
+
+    #[test]
+    fn test_preview_manager_text_file_preview() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let file_path = temp_fs
+            .create_file("test.txt", "Line 1\nLine 2\nLine 3")
+            .unwrap();
+
+        let config = create_test_config();
+        let mut manager = PreviewManager::new(config);
+        let localization = Localization::new("en").unwrap();
+
+        let file_item = FileItem::new(
+            "test.txt".to_string(),
+            file_path,
+            false,
+            std::time::UNIX_EPOCH,
+        );
+
+        let preview = manager.generate_preview(&file_item, 80, 24, 0, &localization);
+
+        assert!(manager.debug_info.contains("test.txt"));
+        match preview {
+            PreviewContent::Text(text, _) => assert!(!text.lines.is_empty()),
+            PreviewContent::Graphical(_)
+            | PreviewContent::Kitty(_)
+            | PreviewContent::Inline(_)
+            | PreviewContent::Animated { .. } => panic!("Expected text preview for text file"),
+        }
+    }
+
+    #[rstest::rstest]
+    #[case("notes.txt", "some notes", ContentAlignment::Text)]
+    #[case("logo.ascii", "@@##", ContentAlignment::Image)]
+    #[case("data.xyz", "\0\0\0", ContentAlignment::Image)]
+    fn test_preview_alignment_follows_content(
+        #[case] name: &str,
+        #[case] content: &str,
+        #[case] expected: ContentAlignment,
+    ) {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let file_path = temp_fs.create_file(name, content).unwrap();
+        let file_item = FileItem::new(name.to_string(), file_path, false, std::time::UNIX_EPOCH);
+        let mut manager = PreviewManager::new(create_test_config());
+        let localization = Localization::new("en").unwrap();
+
+        let preview = manager.generate_preview(&file_item, 80, 24, 0, &localization);
+        assert_eq!(preview.alignment(), expected);
+    }
+
+    #[rstest::rstest]
+    // 80x24 characters, estimated at 8x16 pixels each
+    #[case(80, 24, 0, 0, 512)]
+    // 1920x1080 pixels reported: 1440x918 pane, 0.9 of the longer side
+    #[case(240, 60, 1920, 1080, 1024)]
+    #[case(100, 30, 1000, 600, 675)]
+    // Nothing known: the configured max_dimension
+    #[case(0, 0, 0, 0, 384)]
+    fn test_optimal_dimension_for_window(
+        #[case] columns: u16,
+        #[case] rows: u16,
+        #[case] width: u16,
+        #[case] height: u16,
+        #[case] expected: u32,
+    ) {
+        let window = crossterm::terminal::WindowSize {
+            rows,
+            columns,
+            width,
+            height,
+        };
+        assert_eq!(
+            PreviewManager::optimal_dimension_for(&window, 384),
+            expected
+        );
+    }
+
+    #[rstest::rstest]
+    // 8x16 pixel cells
+    #[case(80, 24, 640, 384, Some(2.0))]
+    // 10x22 pixel cells
+    #[case(100, 30, 1000, 660, Some(2.2))]
+    // No pixel size reported
+    #[case(80, 24, 0, 0, None)]
+    #[case(0, 0, 640, 384, None)]
+    fn test_char_aspect_ratio_for_window(
+        #[case] columns: u16,
+        #[case] rows: u16,
+        #[case] width: u16,
+        #[case] height: u16,
+        #[case] expected: Option<f32>,
+    ) {
+        let window = crossterm::terminal::WindowSize {
+            rows,
+            columns,
+            width,
+            height,
+        };
+        assert_eq!(PreviewManager::char_aspect_ratio_for(&window), expected);
+    }
+
+    #[rstest::rstest]
+    // A square image is half as many rows as columns with the usual 2:1 cells
+    #[case(100, 100, 80, 100, 2.0, (80, 40))]
+    #[case(100, 100, 200, 40, 2.0, (80, 40))]
+    #[case(200, 100, 80, 100, 2.0, (80, 20))]
+    #[case(100, 100, 80, 100, 2.5, (80, 32))]
+    #[case(0, 100, 80, 24, 2.0, (80, 24))]
+    fn test_fit_converter_dimensions(
+        #[case] img_width: u32,
+        #[case] img_height: u32,
+        #[case] max_width: u16,
+        #[case] max_height: u16,
+        #[case] char_aspect_ratio: f32,
+        #[case] expected: (u16, u16),
+    ) {
+        assert_eq!(
+            PreviewManager::fit_converter_dimensions(
+                img_width,
+                img_height,
+                max_width,
+                max_height,
+                char_aspect_ratio,
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_configured_char_aspect_ratio_wins() {
+        let mut config = create_test_config();
+        assert_eq!(
+            PreviewManager::char_aspect_ratio(&config),
+            DEFAULT_CHAR_ASPECT_RATIO
+        );
+        config.char_aspect_ratio = Some(1.8);
+        assert_eq!(PreviewManager::char_aspect_ratio(&config), 1.8);
+    }
+
+    #[test]
+    fn test_preview_manager_ascii_file_preview() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let ascii_content = "\x1b[31mRed\x1b[0m \x1b[32mGreen\x1b[0m";
+        let file_path = temp_fs.create_file("test.ascii", ascii_content).unwrap();
+
+        let config = create_test_config();
+        let mut manager = PreviewManager::new(config);
+        let localization = Localization::new("en").unwrap();
+
+        let file_item = FileItem::new(
+            "test.ascii".to_string(),
+            file_path,
+            false,
+            std::time::UNIX_EPOCH,
+        );
+
+        let preview = manager.generate_preview(&file_item, 80, 24, 0, &localization);
+
+        assert!(manager.debug_info.contains("test.ascii"));
+        match preview {
+            PreviewContent::Text(text, _) => assert!(!text.lines.is_empty()),
+            PreviewContent::Graphical(_)
+            | PreviewContent::Kitty(_)
+            | PreviewContent::Inline(_)
+            | PreviewContent::Animated { .. } => panic!("Expected text preview for ascii file"),
+        }
+    }
+
+    struct TodoPlugin;
+
+    impl PreviewPlugin for TodoPlugin {
+        fn can_handle(&self, file: &FileItem) -> bool {
+            file.name.ends_with(".todo")
+        }
+
+        fn generate_preview(
+            &self,
+            file: &FileItem,
+            _width: u16,
+            _height: u16,
+        ) -> Result<Text<'static>, String> {
+            let content = fs::read_to_string(&file.path).map_err(|e| e.to_string())?;
+            Ok(Text::from(
+                content
+                    .lines()
+                    .map(|line| format!("[ ] {}", line))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ))
+        }
+    }
+
+    #[test]
+    fn test_registered_plugin_takes_priority() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let file_path = temp_fs.create_file("list.todo", "milk\neggs").unwrap();
+        let file_item = FileItem::new(
+            "list.todo".to_string(),
+            file_path,
+            false,
+            std::time::UNIX_EPOCH,
+        );
+        let mut manager = PreviewManager::new(create_test_config());
+        let localization = Localization::new("en").unwrap();
+
+        let text_of = |preview: PreviewContent| match preview {
+            PreviewContent::Text(text, _) => text.to_string(),
+            _ => panic!("Expected a text preview"),
+        };
+        let before = text_of(manager.generate_preview(&file_item, 80, 24, 0, &localization));
+        assert!(!before.contains("[ ]"));
+
+        manager.register_plugin(Box::new(TodoPlugin));
+        assert_eq!(
+            text_of(manager.generate_preview(&file_item, 80, 24, 0, &localization)),
+            "[ ] milk\n[ ] eggs"
+        );
+        // Scrolling drops lines from the top of a plugin's preview
+        assert_eq!(
+            text_of(manager.generate_preview(&file_item, 80, 24, 1, &localization)),
+            "[ ] eggs"
+        );
+        assert!(manager.debug_info.contains("list.todo"));
+    }
+
+    #[test]
+    fn test_preview_manager_unsupported_file() {
+        let config = create_test_config();
+        let mut manager = PreviewManager::new(config);
+        let localization = Localization::new("en").unwrap();
+        let unsupported_item = create_test_file_item("test.xyz", false);
+
+        let preview = manager.generate_preview(&unsupported_item, 80, 24, 0, &localization);
+
+        assert_eq!(
+            manager.debug_info,
+            localization.get("file_type_not_supported")
+        );
+        match preview {
+            PreviewContent::Text(text, _) => assert!(!text.lines.is_empty()),
+            PreviewContent::Graphical(_)
+            | PreviewContent::Kitty(_)
+            | PreviewContent::Inline(_)
+            | PreviewContent::Animated { .. } => {
+                panic!("Expected text preview for unsupported file")
+            }
+        }
+    }
+
+    #[test]
+    fn test_preview_manager_image_caching() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let image_path = temp_fs.create_test_image("test.jpg").unwrap();
+
+        let config = create_test_config();
+        let mut manager = PreviewManager::new(config);
+        let localization = Localization::new("en").unwrap();
+
+        let file_item = FileItem::new(
+            "test.jpg".to_string(),
+            image_path,
+            false,
+            std::time::UNIX_EPOCH,
+        );
+
+        let _ = manager.generate_preview(&file_item, 80, 24, 0, &localization);
+        let cache_size_after_first = manager.cache.len();
+
+        let _ = manager.generate_preview(&file_item, 80, 24, 0, &localization);
+        let cache_size_after_second = manager.cache.len();
+
+        assert_eq!(cache_size_after_first, cache_size_after_second);
+    }
+
+    #[test]
+    fn test_preview_manager_save_ascii_to_file() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let image_path = temp_fs.create_test_image("source.jpg").unwrap();
+
+        let config = create_test_config();
+        let mut manager = PreviewManager::new(config);
+        let localization = Localization::new("en").unwrap();
+
+        let file_item = FileItem::new(
+            "source.jpg".to_string(),
+            image_path,
+            false,
+            std::time::UNIX_EPOCH,
+        );
+
+        let result = manager.save_ascii_to_file(&file_item, 80, 24, &localization);
+
+        match result {
+            Ok(message) => {
+                assert!(message.contains(localization.get("saved_to").as_str()));
+            }
+            Err(e) => {
+                assert!(matches!(e, PTuiError::ConverterError { .. }));
             }
         }
-
-        None
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::localization::Localization;
-    use crate::test_utils::helpers::*;
 
     #[test]
-    fn test_preview_manager_creation() {
+    fn test_preview_manager_save_ascii_non_image_file() {
         let config = create_test_config();
-        let manager = PreviewManager::new(config);
+        let mut manager = PreviewManager::new(config);
+        let localization = Localization::new("en").unwrap();
+        let text_item = create_test_text_file_item("document");
 
-        assert!(manager.cache.is_empty());
-        assert_eq!(manager.debug_info, "");
-        assert_eq!(manager.converter.get_name(), "chafa");
+        let result = manager.save_ascii_to_file(&text_item, 80, 24, &localization);
+
+        let error = result.unwrap_err();
+        assert!(matches!(error, PTuiError::Unsupported(_)));
+        assert!(error.to_string().contains("not an image"));
     }
 
     #[test]
-    fn test_preview_manager_cache_operations() {
-        let config = create_test_config();
-        let mut manager = PreviewManager::new(config);
-        let file_item = create_test_image_file_item("test");
+    fn test_preview_manager_save_ascii_keeps_an_existing_file() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let image_path = temp_fs.create_test_image("source.jpg").unwrap();
+        temp_fs.create_file("source.ascii", "old art").unwrap();
+        let mut manager = PreviewManager::new(create_test_config());
+        let localization = Localization::new("en").unwrap();
+        let file_item = FileItem::new(
+            "source.jpg".to_string(),
+            image_path,
+            false,
+            std::time::UNIX_EPOCH,
+        );
 
-        manager.remove_from_cache(&file_item, 80, 24);
+        let result = manager.save_ascii_to_file(&file_item, 80, 24, &localization);
 
-        manager.clear_cache();
-        assert!(manager.cache.is_empty());
+        let Err(PTuiError::IoError(error)) = result else {
+            panic!("expected an IO error, got {:?}", result);
+        };
+        assert_eq!(error.kind(), std::io::ErrorKind::AlreadyExists);
+        let kept = fs::read_to_string(Path::new(&temp_fs.get_path()).join("source.ascii"));
+        assert_eq!(kept.unwrap(), "old art");
+    }
+
+    #[rstest::rstest]
+    #[case("graphical", "chafa")]
+    #[case("sixel", "chafa")]
+    #[case("iterm", "chafa")]
+    #[case("jp2a", "jp2a")]
+    fn test_export_config_avoids_terminal_converters(
+        #[case] selected: &str,
+        #[case] exported: &str,
+    ) {
+        let mut config = create_test_config();
+        config.converter.selected = selected.to_string();
+        let manager = PreviewManager::new(config);
+        assert_eq!(manager.export_config().converter.selected, exported);
     }
 
+    #[cfg(feature = "sixel-converter")]
     #[test]
-    fn test_preview_manager_directory_preview() {
-        let config = create_test_config();
+    fn test_sixel_preview_is_written_after_drawing() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let image_path = temp_fs.get_path().join("test.png");
+        image::RgbImage::from_pixel(32, 16, image::Rgb([200, 40, 40]))
+            .save(&image_path)
+            .unwrap();
+        let mut config = create_test_config();
+        config.converter.selected = "sixel".to_string();
         let mut manager = PreviewManager::new(config);
         let localization = Localization::new("en").unwrap();
-        let dir_item = create_test_directory_item("test_dir");
+        let file_item = FileItem::new(
+            "test.png".to_string(),
+            image_path.to_string_lossy().into_owned(),
+            false,
+            std::time::UNIX_EPOCH,
+        );
 
-        let preview = manager.generate_preview(&dir_item, 80, 24, 0, &localization);
+        match manager.generate_preview(&file_item, 80, 24, 0, &localization) {
+            PreviewContent::Inline(inline) => {
+                assert!(inline.escape_sequence.starts_with("\x1bP"));
+                assert!(inline.width <= 80 && inline.height <= 24);
+            }
+            _ => panic!("Expected the Sixel escape to be kept for the post-draw pass"),
+        }
+    }
 
-        assert_eq!(manager.debug_info, localization.get("directory_selected"));
-        match preview {
-            PreviewContent::Text(text) => assert!(!text.lines.is_empty()),
-            PreviewContent::Graphical(_) | PreviewContent::Kitty(_) => panic!("Expected text preview for directory"),
+    #[test]
+    fn test_print_inline_image_restores_the_cursor() {
+        let inline = InlinePreview {
+            escape_sequence: "\x1bPq#0!4~-\x1b\\".to_string(),
+            width: 4,
+            height: 1,
+        };
+        let mut out = Vec::new();
+        PreviewManager::print_inline_image(&mut out, &inline, 3, 1).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1b[s\x1b[2;4H\x1bPq#0!4~-\x1b\\\x1b[u"
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(false)]
+    #[case(true)]
+    fn test_batch_export_skips_or_overwrites_existing(#[case] overwrite: bool) {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let a_path = temp_fs.create_test_image("a.jpg").unwrap();
+        temp_fs.create_file("a.ascii", "old art").unwrap();
+        let b_path = temp_fs.create_test_image("b.jpg").unwrap();
+        let files = vec![
+            FileItem::new("a.jpg".to_string(), a_path, false, std::time::UNIX_EPOCH),
+            FileItem::new("b.jpg".to_string(), b_path, false, std::time::UNIX_EPOCH),
+        ];
+        let manager = PreviewManager::new(create_test_config());
+        let dir = temp_fs.get_path();
+
+        let receiver = manager.start_batch_export(Path::new(&dir), files, 80, 24, overwrite);
+        let updates: Vec<ExportProgress> = receiver.iter().collect();
+
+        let exporting = updates
+            .iter()
+            .filter(|update| matches!(update, ExportProgress::Exporting { total: 2, .. }))
+            .count();
+        assert_eq!(exporting, 2);
+        let Some(ExportProgress::Done {
+            succeeded,
+            failed,
+            skipped,
+        }) = updates.last().cloned()
+        else {
+            panic!("export didn't finish: {:?}", updates);
+        };
+        assert_eq!(skipped, if overwrite { 0 } else { 1 });
+        assert_eq!(succeeded + failed + skipped, 2);
+        // Conversions can fail without a converter installed, but those get logged
+        assert_eq!(
+            Path::new(&dir).join(EXPORT_ERRORS_FILE).exists(),
+            failed > 0
+        );
+        if !overwrite {
+            let kept = fs::read_to_string(Path::new(&dir).join("a.ascii")).unwrap();
+            assert_eq!(kept, "old art");
         }
     }
 
     #[test]
-    fn test_preview_manager_text_file_preview() {
+    fn test_preview_manager_text_file_scrolling() {
         let temp_fs = TestFileSystem::new().unwrap();
+        let test_content = (0..50)
+            .map(|i| format!("Line {}", i))
+            .collect::<Vec<_>>()
+            .join("\n");
         let file_path = temp_fs
-            .create_file("test.txt", "Line 1\nLine 2\nLine 3")
+            .create_file("scrollable.txt", &test_content)
             .unwrap();
 
         let config = create_test_config();
@@ -1050,243 +4024,773 @@ mod tests {
         let localization = Localization::new("en").unwrap();
 
         let file_item = FileItem::new(
-            "test.txt".to_string(),
+            "scrollable.txt".to_string(),
             file_path,
             false,
             std::time::UNIX_EPOCH,
         );
 
-        let preview = manager.generate_preview(&file_item, 80, 24, 0, &localization);
+        // Test scrolling from the beginning (scroll_offset = 0)
+        let preview1 = manager.generate_preview(&file_item, 80, 10, 0, &localization);
+        let content1 = match preview1 {
+            PreviewContent::Text(text, _) => text
+                .lines
+                .iter()
+                .map(|line| {
+                    line.spans
+                        .iter()
+                        .map(|span| span.content.as_ref())
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            PreviewContent::Graphical(_)
+            | PreviewContent::Kitty(_)
+            | PreviewContent::Inline(_)
+            | PreviewContent::Animated { .. } => panic!("Expected text preview"),
+        };
 
-        assert!(manager.debug_info.contains("test.txt"));
-        match preview {
-            PreviewContent::Text(text) => assert!(!text.lines.is_empty()),
-            PreviewContent::Graphical(_) | PreviewContent::Kitty(_) => panic!("Expected text preview for text file"),
-        }
+        // Test scrolling with offset
+        let preview2 = manager.generate_preview(&file_item, 80, 10, 5, &localization);
+        let content2 = match preview2 {
+            PreviewContent::Text(text, _) => text
+                .lines
+                .iter()
+                .map(|line| {
+                    line.spans
+                        .iter()
+                        .map(|span| span.content.as_ref())
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            PreviewContent::Graphical(_)
+            | PreviewContent::Kitty(_)
+            | PreviewContent::Inline(_)
+            | PreviewContent::Animated { .. } => panic!("Expected text preview"),
+        };
+
+        // The first preview should start with "Line 0"
+        assert!(content1.contains("Line 0"));
+        // With height=10, we should only see lines 0-9, not line 10 or higher
+        assert!(!content1.contains("Line 10"));
+
+        // The second preview should start with "Line 5" due to scroll offset
+        assert!(content2.contains("Line 5"));
+        assert!(!content2.contains("Line 0"));
     }
 
     #[test]
-    fn test_preview_manager_ascii_file_preview() {
+    fn test_preview_manager_text_file_line_limit() {
         let temp_fs = TestFileSystem::new().unwrap();
-        let ascii_content = "\x1b[31mRed\x1b[0m \x1b[32mGreen\x1b[0m";
-        let file_path = temp_fs.create_file("test.ascii", ascii_content).unwrap();
+        // Create a file with more than 10000 lines to trigger the limit
+        let large_content = (0..10002)
+            .map(|i| format!("Line {}", i))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let file_path = temp_fs.create_file("large.txt", &large_content).unwrap();
 
         let config = create_test_config();
         let mut manager = PreviewManager::new(config);
         let localization = Localization::new("en").unwrap();
 
         let file_item = FileItem::new(
-            "test.ascii".to_string(),
+            "large.txt".to_string(),
             file_path,
             false,
             std::time::UNIX_EPOCH,
         );
 
-        let preview = manager.generate_preview(&file_item, 80, 24, 0, &localization);
+        // Test with a large height parameter to see if limit is reached
+        let preview = manager.generate_preview(&file_item, 80, 15000, 0, &localization);
+        let content = match preview {
+            PreviewContent::Text(text, _) => text
+                .lines
+                .iter()
+                .map(|line| {
+                    line.spans
+                        .iter()
+                        .map(|span| span.content.as_ref())
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            PreviewContent::Graphical(_)
+            | PreviewContent::Kitty(_)
+            | PreviewContent::Inline(_)
+            | PreviewContent::Animated { .. } => panic!("Expected text preview"),
+        };
 
-        assert!(manager.debug_info.contains("test.ascii"));
-        match preview {
-            PreviewContent::Text(text) => assert!(!text.lines.is_empty()),
-            PreviewContent::Graphical(_) | PreviewContent::Kitty(_) => panic!("Expected text preview for ascii file"),
+        // Should show the limit message since we have more than 10000 lines
+        assert!(content.contains("file too large for scrolling"));
+    }
+
+    #[test]
+    fn test_text_window_reads_only_visible_lines() {
+        let content = (0..10010)
+            .map(|i| format!("Line {}", i))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let lines = PreviewManager::read_text_window(content.as_bytes(), 9990, 10, 20000);
+        assert_eq!(lines.len(), 10);
+        assert!(lines.capacity() < 100);
+        assert_eq!(lines.first().unwrap(), "Line 9990");
+        assert_eq!(lines.last().unwrap(), "Line 9999");
+    }
+
+    #[rstest::rstest]
+    #[case(0, 10, 100, &["a", "b", "c", "d", "e"])]
+    #[case(1, 1, 100, &["b"])]
+    #[case(5, 10, 100, &["(End of file)"])]
+    #[case(2, 10, 3, &["c", "... (file too large for scrolling, showing first 3 of 5 lines)"])]
+    #[case(4, 10, 3, &["... (file too large for scrolling, showing first 3 of 5 lines)"])]
+    fn test_text_window(
+        #[case] scroll_offset: usize,
+        #[case] visible_height: usize,
+        #[case] max_lines: usize,
+        #[case] expected: &[&str],
+    ) {
+        let content: &[u8] = b"a\r\nb\r\nc\nd\ne";
+        assert_eq!(
+            PreviewManager::read_text_window(content, scroll_offset, visible_height, max_lines),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_text_preview_respects_max_preview_lines() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let file_path = temp_fs.create_file("log.txt", "1\n2\n3\n4\n").unwrap();
+        let config = PTuiConfig {
+            max_preview_lines: Some(2),
+            ..create_test_config()
+        };
+        let manager = PreviewManager::new(config);
+        let localization = Localization::new("en").unwrap();
+
+        let text = manager.generate_text_preview(&file_path, 0, 10, &localization);
+        assert_eq!(
+            text.to_string(),
+            "1\n2\n... (file too large for scrolling, showing first 2 of 4 lines)"
+        );
+    }
+
+    #[test]
+    fn test_request_preview_async() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let file_path = temp_fs.create_file("notes.txt", "1\n2\n3\n").unwrap();
+        let text_file = FileItem::new(
+            "notes.txt".to_string(),
+            file_path,
+            false,
+            std::time::UNIX_EPOCH,
+        );
+        let mut manager = PreviewManager::new(create_test_config());
+        let localization = Arc::new(Localization::new("en").unwrap());
+
+        let handle =
+            manager.request_preview_async(&text_file, 80, 2, 1, true, localization.clone());
+        let (text, total_lines) = handle
+            .receiver
+            .recv_timeout(std::time::Duration::from_secs(5))
+            .expect("the text preview should arrive");
+        assert_eq!(text.to_string(), "2\n3");
+        assert_eq!(total_lines, Some(3));
+
+        // A caller that already knows the count doesn't have it read again
+        let handle =
+            manager.request_preview_async(&text_file, 80, 2, 0, false, localization.clone());
+        let (text, total_lines) = handle
+            .receiver
+            .recv_timeout(std::time::Duration::from_secs(5))
+            .expect("the text preview should arrive");
+        assert_eq!(text.to_string(), "1\n2");
+        assert_eq!(total_lines, None);
+
+        // Directories are previewed on the spot
+        let dir = FileItem::new(
+            "album".to_string(),
+            temp_fs.get_path().to_string_lossy().into_owned(),
+            true,
+            std::time::UNIX_EPOCH,
+        );
+        let handle = manager.request_preview_async(&dir, 80, 24, 0, true, localization.clone());
+        let (text, total_lines) = handle.try_recv().unwrap();
+        assert_eq!(text.to_string(), localization.get("directory_selected"));
+        assert_eq!(total_lines, None);
+    }
+
+    #[test]
+    fn test_preview_manager_background_conversion() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let image_path = temp_fs.create_test_image("background.jpg").unwrap();
+
+        let config = create_test_config();
+        let mut manager = PreviewManager::new(config);
+        let localization = Localization::new("en").unwrap();
+        let modified = fs::metadata(&image_path).unwrap().modified().unwrap();
+        let file = FileItem::new(
+            "background.jpg".to_string(),
+            image_path.clone(),
+            false,
+            modified,
+        );
+
+        let receiver = manager
+            .start_background_conversion(&file, 80, 24, &localization)
+            .expect("text converters should convert in the background");
+        let converted = receiver
+            .recv_timeout(std::time::Duration::from_secs(10))
+            .expect("background conversion should send a result");
+        match &converted {
+            ConvertedPreview::Still(text) => assert!(!text.lines.is_empty()),
+            ConvertedPreview::Animated { .. } => panic!("JPEG previews should not animate"),
+            // The converter isn't installed everywhere the tests run
+            ConvertedPreview::Failed(error) => assert!(!error.is_empty()),
+            _ => panic!("JPEG previews need no extraction"),
         }
+
+        manager.finish_background_conversion(
+            &image_path,
+            modified,
+            80,
+            24,
+            converted,
+            &localization,
+        );
+        assert_eq!(manager.cache.len(), 1);
+
+        // Cached previews don't need another background conversion
+        assert!(
+            manager
+                .start_background_conversion(&file, 80, 24, &localization)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_finish_background_conversion_reports_failure() {
+        let mut manager = PreviewManager::new(create_test_config());
+        let converted = ConvertedPreview::Failed("Conversion timed out after 10000ms".to_string());
+
+        let content = manager.finish_background_conversion(
+            "slow.jpg",
+            std::time::UNIX_EPOCH,
+            80,
+            24,
+            converted,
+            &Localization::new("en").unwrap(),
+        );
+        assert!(
+            manager
+                .get_debug_info()
+                .contains("Conversion timed out after 10000ms")
+        );
+        match content {
+            PreviewContent::Text(text, _) => {
+                assert!(text.lines[0].to_string().contains("timed out"));
+            }
+            _ => panic!("a failed conversion should show its error"),
+        }
+    }
+
+    #[test]
+    fn test_text_to_ansi_round_trip() {
+        let ansi = "\x1b[1;31mred\x1b[0m plain \x1b[38;2;1;2;3mrgb\x1b[0m\nline two";
+        let text = ansi.as_bytes().into_text().unwrap();
+
+        let rebuilt = text_to_ansi(&text);
+        assert_eq!(rebuilt.into_text().unwrap(), text);
+        assert_eq!(strip_ansi_codes(&rebuilt), "red plain rgb\nline two");
+    }
+
+    #[test]
+    fn test_strip_ansi_codes() {
+        assert_eq!(strip_ansi_codes("\x1b[38;5;196m#\x1b[0m#"), "##");
+        assert_eq!(
+            strip_ansi_codes("\x1b_Ga=d\x1b\\ok\x1b]0;title\x07!"),
+            "ok!"
+        );
+        assert_eq!(strip_ansi_codes("no escapes"), "no escapes");
+    }
+
+    #[test]
+    fn test_get_raw_preview_string_uses_cache_only() {
+        let mut manager = PreviewManager::new(create_test_config());
+        let file = create_test_image_file_item("cached.jpg");
+        assert_eq!(manager.get_raw_preview_string(&file, 80, 24), None);
+
+        let text = "\x1b[32mart\x1b[0m".as_bytes().into_text().unwrap();
+        manager.insert_into_cache(
+            manager.cache_key(&file.path, 80, 24),
+            PreviewContent::Text(text, ContentAlignment::Image),
+            file.modified,
+        );
+        assert_eq!(
+            manager.get_raw_preview_string(&file, 80, 24).as_deref(),
+            Some("\x1b[32mart\x1b[0m")
+        );
+        // Other sizes aren't converted on demand
+        assert_eq!(manager.get_raw_preview_string(&file, 40, 12), None);
+    }
+
+    #[test]
+    fn test_modified_file_is_previewed_again() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let image_path = temp_fs.create_test_image("edited.jpg").unwrap();
+        let file_item = |path: &str| {
+            let modified = fs::metadata(path).unwrap().modified().unwrap();
+            FileItem::new("edited.jpg".to_string(), path.to_string(), false, modified)
+        };
+        let mut manager = PreviewManager::new(create_test_config());
+        let localization = Localization::new("en").unwrap();
+
+        let file = file_item(&image_path);
+        manager.generate_preview(&file, 80, 24, 0, &localization);
+        manager.generate_preview(&file, 80, 24, 0, &localization);
+        assert_eq!((manager.cache_hits, manager.cache_misses), (1, 1));
+
+        let later = file.modified + std::time::Duration::from_secs(60);
+        fs::File::options()
+            .write(true)
+            .open(&image_path)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        let edited = file_item(&image_path);
+        assert_eq!(edited.modified, later);
+
+        manager.generate_preview(&edited, 80, 24, 0, &localization);
+        assert_eq!((manager.cache_hits, manager.cache_misses), (1, 2));
+        assert_eq!(manager.cache_len(), 1);
+        assert_eq!(manager.cache_order.len(), 1);
+        assert!(manager.get_raw_preview_string(&file, 80, 24).is_none());
+    }
+
+    #[test]
+    fn test_cache_statistics_and_conversion_timing() {
+        let mut manager = PreviewManager::new(create_test_config());
+        let cache_key = "/test/stats.jpg:80x24";
+
+        assert!(
+            manager
+                .cached_preview(cache_key, std::time::UNIX_EPOCH)
+                .is_none()
+        );
+        assert_eq!((manager.cache_hits, manager.cache_misses), (0, 1));
+        assert!(manager.conversion_started.is_some());
+
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        manager.insert_into_cache(
+            cache_key.to_string(),
+            PreviewContent::Text(Text::from("x"), ContentAlignment::Image),
+            std::time::UNIX_EPOCH,
+        );
+        assert!(manager.timing_ms >= 5);
+        assert!(manager.conversion_started.is_none());
+
+        assert!(
+            manager
+                .cached_preview(cache_key, std::time::UNIX_EPOCH)
+                .is_some()
+        );
+        assert_eq!((manager.cache_hits, manager.cache_misses), (1, 1));
+        assert_eq!(manager.cache_len(), 1);
+    }
+
+    #[rstest::rstest]
+    #[case("10/1", Some(100))]
+    #[case("25/2", Some(80))]
+    #[case("1000/1", Some(20))]
+    #[case("0/0", None)]
+    #[case("garbage", None)]
+    fn test_parse_frame_rate(#[case] rate: &str, #[case] expected: Option<u64>) {
+        assert_eq!(PreviewManager::parse_frame_rate(rate), expected);
     }
 
     #[test]
-    fn test_preview_manager_unsupported_file() {
+    fn test_preview_manager_animated_preview_requires_gif_frames() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let image_path = temp_fs.create_test_image("still.jpg").unwrap();
+
+        assert!(!PreviewManager::is_gif(&image_path));
+
         let config = create_test_config();
         let mut manager = PreviewManager::new(config);
         let localization = Localization::new("en").unwrap();
-        let unsupported_item = create_test_file_item("test.xyz", false);
 
-        let preview = manager.generate_preview(&unsupported_item, 80, 24, 0, &localization);
+        // A JPEG has no frames to extract, so callers fall back to a static preview
+        assert!(
+            manager
+                .generate_animated_preview(
+                    &image_path,
+                    std::time::UNIX_EPOCH,
+                    80,
+                    24,
+                    &localization
+                )
+                .is_none()
+        );
+        assert!(manager.cache.is_empty());
+    }
 
+    #[test]
+    fn test_parse_audio_info() {
+        let json = r#"{
+            "streams": [{
+                "codec_name": "flac",
+                "sample_rate": "44100",
+                "duration": "245.6",
+                "tags": {"TITLE": "Song", "ARTIST": "Band"}
+            }],
+            "format": {
+                "duration": "245.6",
+                "bit_rate": "912345",
+                "tags": {"album": "Record", "title": "Ignored"}
+            }
+        }"#;
         assert_eq!(
-            manager.debug_info,
-            localization.get("file_type_not_supported")
+            PreviewManager::parse_audio_info(json),
+            Some(AudioInfo {
+                title: Some("Song".to_string()),
+                artist: Some("Band".to_string()),
+                album: Some("Record".to_string()),
+                duration_secs: Some(245.6),
+                bit_rate: Some(912345),
+                sample_rate: Some(44100),
+            })
         );
-        match preview {
-            PreviewContent::Text(text) => assert!(!text.lines.is_empty()),
-            PreviewContent::Graphical(_) | PreviewContent::Kitty(_) => panic!("Expected text preview for unsupported file"),
-        }
+
+        // No audio stream, or ffprobe not installed
+        assert_eq!(
+            PreviewManager::parse_audio_info(r#"{"streams": [], "format": {}}"#),
+            None
+        );
+        assert_eq!(PreviewManager::parse_audio_info(""), None);
     }
 
     #[test]
-    fn test_preview_manager_image_caching() {
-        let temp_fs = TestFileSystem::new().unwrap();
-        let image_path = temp_fs.create_test_image("test.jpg").unwrap();
-
-        let config = create_test_config();
-        let mut manager = PreviewManager::new(config);
+    fn test_audio_info_text_skips_missing_details() {
+        use fluent::fluent_args;
         let localization = Localization::new("en").unwrap();
-
-        let file_item = FileItem::new(
-            "test.jpg".to_string(),
-            image_path,
-            false,
-            std::time::UNIX_EPOCH,
+        let info = AudioInfo {
+            artist: Some("Band".to_string()),
+            duration_secs: Some(65.4),
+            bit_rate: Some(320_000),
+            ..Default::default()
+        };
+        let expected = [
+            localization.get_with_args("audio_artist", Some(&fluent_args!["value" => "Band"])),
+            localization.get_with_args("audio_duration", Some(&fluent_args!["value" => "1:05"])),
+            localization.get_with_args("audio_bitrate", Some(&fluent_args!["kbps" => 320])),
+        ]
+        .join("\n");
+        assert_eq!(
+            PreviewManager::audio_info_text(&info, &localization),
+            expected
         );
+    }
 
-        let _ = manager.generate_preview(&file_item, 80, 24, 0, &localization);
-        let cache_size_after_first = manager.cache.len();
+    #[test]
+    fn test_audio_waveform_removed_when_its_preview_is_evicted() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let waveform_path = temp_fs.create_file("wave.png", "png").unwrap();
+        let mut manager = PreviewManager::new(create_test_config());
+        manager.audio_waveforms.insert(
+            "/music/song.mp3".to_string(),
+            TempFileGuard::new(PathBuf::from(&waveform_path)),
+        );
 
-        let _ = manager.generate_preview(&file_item, 80, 24, 0, &localization);
-        let cache_size_after_second = manager.cache.len();
+        manager.insert_into_cache(
+            format!("{}:80x24", waveform_path),
+            PreviewContent::Text(Text::from("wave"), ContentAlignment::Image),
+            SystemTime::UNIX_EPOCH,
+        );
+        for i in 0..manager.max_cache_size() {
+            manager.insert_into_cache(
+                format!("/other{}.jpg:80x24", i),
+                PreviewContent::Text(Text::from("other"), ContentAlignment::Image),
+                SystemTime::UNIX_EPOCH,
+            );
+        }
 
-        assert_eq!(cache_size_after_first, cache_size_after_second);
+        assert!(manager.audio_waveforms.is_empty());
+        assert!(!Path::new(&waveform_path).exists());
     }
 
     #[test]
-    fn test_preview_manager_save_ascii_to_file() {
+    fn test_video_frame_removed_when_its_preview_is_evicted() {
         let temp_fs = TestFileSystem::new().unwrap();
-        let image_path = temp_fs.create_test_image("source.jpg").unwrap();
+        let frame_path = temp_fs.create_file("frame.jpg", "jpeg").unwrap();
+        let mut manager = PreviewManager::new(create_test_config());
+        manager.video_thumbnails.insert(
+            "/videos/clip.mp4".to_string(),
+            VideoThumbnail {
+                frame: TempFileGuard::new(PathBuf::from(&frame_path)),
+                summary: String::new(),
+            },
+        );
 
-        let config = create_test_config();
-        let mut manager = PreviewManager::new(config);
-        let localization = Localization::new("en").unwrap();
+        manager.insert_into_cache(
+            format!("{}:80x24", frame_path),
+            PreviewContent::Text(Text::from("frame"), ContentAlignment::Image),
+            SystemTime::UNIX_EPOCH,
+        );
+        for i in 0..manager.max_cache_size() {
+            manager.insert_into_cache(
+                format!("/other{}.jpg:80x24", i),
+                PreviewContent::Text(Text::from("other"), ContentAlignment::Image),
+                SystemTime::UNIX_EPOCH,
+            );
+        }
 
-        let file_item = FileItem::new(
-            "source.jpg".to_string(),
-            image_path,
+        assert!(manager.video_thumbnails.is_empty());
+        assert!(!Path::new(&frame_path).exists());
+    }
+
+    #[test]
+    fn test_audio_details_are_kept_while_their_preview_is_cached() {
+        let file = FileItem::new(
+            "song.mp3".to_string(),
+            "/music/song.mp3".to_string(),
             false,
-            std::time::UNIX_EPOCH,
+            SystemTime::UNIX_EPOCH,
         );
+        let mut manager = PreviewManager::new(create_test_config());
+        let localization = Localization::new("en").unwrap();
+        let info = AudioInfo {
+            artist: Some("Band".to_string()),
+            ..Default::default()
+        };
 
-        let result = manager.save_ascii_to_file(&file_item, 80, 24, &localization);
-
-        match result {
-            Ok(message) => {
-                assert!(message.contains(localization.get("saved_to").as_str()));
-            }
-            Err(e) => {
-                assert!(e.contains("chafa") || e.contains("Failed"));
+        let content = manager.finish_background_conversion(
+            &file.path,
+            file.modified,
+            80,
+            24,
+            ConvertedPreview::Audio {
+                info: Some(info.clone()),
+                waveform: None,
+            },
+            &localization,
+        );
+        match content {
+            PreviewContent::Text(text, ContentAlignment::Text) => {
+                assert_eq!(
+                    text.lines[0].to_string(),
+                    PreviewManager::audio_info_text(&info, &localization)
+                );
             }
+            _ => panic!("audio without a waveform should show its details"),
+        }
+        // ffprobe isn't run again while the details are known
+        assert!(
+            manager
+                .start_background_conversion(&file, 80, 24, &localization)
+                .is_none()
+        );
+
+        for i in 0..manager.max_cache_size() {
+            manager.insert_into_cache(
+                format!("/other{}.jpg:80x24", i),
+                PreviewContent::Text(Text::from("other"), ContentAlignment::Image),
+                SystemTime::UNIX_EPOCH,
+            );
         }
+        assert!(manager.audio_infos.is_empty());
     }
 
     #[test]
-    fn test_preview_manager_save_ascii_non_image_file() {
-        let config = create_test_config();
-        let mut manager = PreviewManager::new(config);
+    fn test_audio_waveform_from_the_background_is_cached_under_the_waveform() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let waveform_path = temp_fs.create_file("wave.png", "png").unwrap();
+        let mut manager = PreviewManager::new(create_test_config());
         let localization = Localization::new("en").unwrap();
-        let text_item = create_test_text_file_item("document");
+        let info = AudioInfo {
+            duration_secs: Some(65.4),
+            ..Default::default()
+        };
 
-        let result = manager.save_ascii_to_file(&text_item, 80, 24, &localization);
+        manager.finish_background_conversion(
+            "/music/song.mp3",
+            SystemTime::UNIX_EPOCH,
+            80,
+            24,
+            ConvertedPreview::Audio {
+                info: Some(info),
+                waveform: Some((
+                    TempFileGuard::new(PathBuf::from(&waveform_path)),
+                    Box::new(ConvertedPreview::Still(Text::from("wave"))),
+                )),
+            },
+            &localization,
+        );
+        assert!(manager.get_debug_info().ends_with("song.mp3 | 1:05"));
+        assert!(
+            manager
+                .cache
+                .contains_key(&manager.cache_key(&waveform_path, 80, 24))
+        );
 
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("not an image"));
+        for i in 0..manager.max_cache_size() {
+            manager.insert_into_cache(
+                format!("/other{}.jpg:80x24", i),
+                PreviewContent::Text(Text::from("other"), ContentAlignment::Image),
+                SystemTime::UNIX_EPOCH,
+            );
+        }
+        assert!(manager.audio_infos.is_empty());
+        assert!(manager.audio_waveforms.is_empty());
+        assert!(!Path::new(&waveform_path).exists());
     }
 
     #[test]
-    fn test_preview_manager_text_file_scrolling() {
+    fn test_raw_jpeg_is_kept_while_its_preview_is_cached() {
         let temp_fs = TestFileSystem::new().unwrap();
-        let test_content = (0..50)
-            .map(|i| format!("Line {}", i))
-            .collect::<Vec<_>>()
-            .join("\n");
-        let file_path = temp_fs
-            .create_file("scrollable.txt", &test_content)
-            .unwrap();
-
-        let config = create_test_config();
-        let mut manager = PreviewManager::new(config);
+        let jpeg_path = temp_fs.create_file("extracted.jpg", "jpeg").unwrap();
+        let file = FileItem::new(
+            "photo.cr2".to_string(),
+            "/photos/photo.cr2".to_string(),
+            false,
+            SystemTime::UNIX_EPOCH,
+        );
+        let mut manager = PreviewManager::new(create_test_config());
         let localization = Localization::new("en").unwrap();
 
-        let file_item = FileItem::new(
-            "scrollable.txt".to_string(),
-            file_path,
+        manager.finish_background_conversion(
+            &file.path,
+            file.modified,
+            80,
+            24,
+            ConvertedPreview::Raw {
+                jpeg: TempFileGuard::new(PathBuf::from(&jpeg_path)),
+                converted: Box::new(ConvertedPreview::Still(Text::from("photo"))),
+            },
+            &localization,
+        );
+        // Cached under the JPEG, which is reused without extracting it again
+        assert!(
+            manager
+                .start_background_conversion(&file, 80, 24, &localization)
+                .is_none()
+        );
+
+        for i in 0..manager.max_cache_size() {
+            manager.insert_into_cache(
+                format!("/other{}.jpg:80x24", i),
+                PreviewContent::Text(Text::from("other"), ContentAlignment::Image),
+                SystemTime::UNIX_EPOCH,
+            );
+        }
+        assert!(manager.raw_images.is_empty());
+        assert!(!Path::new(&jpeg_path).exists());
+    }
+
+    #[test]
+    fn test_raw_jpeg_is_extracted_again_when_the_file_changes() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let jpeg_path = temp_fs.create_file("extracted.jpg", "jpeg").unwrap();
+        let mut file = FileItem::new(
+            "photo.cr2".to_string(),
+            "/photos/photo.cr2".to_string(),
             false,
-            std::time::UNIX_EPOCH,
+            SystemTime::UNIX_EPOCH,
         );
+        let mut manager = PreviewManager::new(create_test_config());
+        manager.raw_images.insert(
+            file.path.clone(),
+            (file.modified, TempFileGuard::new(PathBuf::from(&jpeg_path))),
+        );
+        assert_eq!(manager.extracted_raw_jpeg(&file), Some(jpeg_path.clone()));
 
-        // Test scrolling from the beginning (scroll_offset = 0)
-        let preview1 = manager.generate_preview(&file_item, 80, 10, 0, &localization);
-        let content1 = match preview1 {
-            PreviewContent::Text(text) => text
-                .lines
-                .iter()
-                .map(|line| {
-                    line.spans
-                        .iter()
-                        .map(|span| span.content.as_ref())
-                        .collect::<String>()
-                })
-                .collect::<Vec<_>>()
-                .join("\n"),
-            PreviewContent::Graphical(_) | PreviewContent::Kitty(_) => panic!("Expected text preview"),
-        };
+        file.modified += Duration::from_secs(60);
+        assert_eq!(manager.extracted_raw_jpeg(&file), None);
+        assert!(!Path::new(&jpeg_path).exists());
+    }
 
-        // Test scrolling with offset
-        let preview2 = manager.generate_preview(&file_item, 80, 10, 5, &localization);
-        let content2 = match preview2 {
-            PreviewContent::Text(text) => text
-                .lines
-                .iter()
-                .map(|line| {
-                    line.spans
-                        .iter()
-                        .map(|span| span.content.as_ref())
-                        .collect::<String>()
-                })
-                .collect::<Vec<_>>()
-                .join("\n"),
-            PreviewContent::Graphical(_) | PreviewContent::Kitty(_) => panic!("Expected text preview"),
-        };
+    #[test]
+    fn test_parse_video_summary() {
+        let output = "codec_name=h264\nduration=65.4\n";
+        assert_eq!(PreviewManager::parse_video_summary(output), "h264 | 1:05");
+        assert_eq!(PreviewManager::parse_video_summary(""), "unknown | ?:??");
+    }
 
-        // The first preview should start with "Line 0"
-        assert!(content1.contains("Line 0"));
-        // With height=10, we should only see lines 0-9, not line 10 or higher
-        assert!(!content1.contains("Line 10"));
+    #[test]
+    fn test_count_tiff_directories() {
+        let output = "TIFF Directory at offset 0x8 (8)\n  Image Width: 100\n\
+                      TIFF Directory at offset 0x1a2 (418)\n  Image Width: 100\n\
+                      TIFF Directory at offset 0x33c (828)\n";
+        assert_eq!(PreviewManager::count_tiff_directories(output), 3);
+        assert_eq!(PreviewManager::count_tiff_directories(""), 1);
+    }
 
-        // The second preview should start with "Line 5" due to scroll offset
-        assert!(content2.contains("Line 5"));
-        assert!(!content2.contains("Line 0"));
+    #[test]
+    fn test_parse_ico_sizes() {
+        let output = "32x32\n256x256\n16x16\nbroken\n64x64\n";
+        let sizes: Vec<(usize, u32)> = PreviewManager::parse_ico_sizes(output)
+            .iter()
+            .map(|size| (size.index, size.width))
+            .collect();
+        assert_eq!(sizes, vec![(1, 256), (4, 64), (0, 32), (2, 16)]);
+        assert!(PreviewManager::parse_ico_sizes("").is_empty());
     }
 
     #[test]
-    fn test_preview_manager_text_file_line_limit() {
+    fn test_temp_file_guard_removes_file_on_drop() {
         let temp_fs = TestFileSystem::new().unwrap();
-        // Create a file with more than 10000 lines to trigger the limit
-        let large_content = (0..10002)
-            .map(|i| format!("Line {}", i))
-            .collect::<Vec<_>>()
-            .join("\n");
-        let file_path = temp_fs.create_file("large.txt", &large_content).unwrap();
+        let path = temp_fs.create_file("frame.jpg", "frame").unwrap();
+
+        let guard = TempFileGuard::new(PathBuf::from(&path));
+        assert!(guard.path().exists());
+        drop(guard);
+        assert!(!Path::new(&path).exists());
+    }
 
+    #[test]
+    fn test_preview_manager_thumbnail_skips_non_images() {
         let config = create_test_config();
         let mut manager = PreviewManager::new(config);
-        let localization = Localization::new("en").unwrap();
 
-        let file_item = FileItem::new(
-            "large.txt".to_string(),
-            file_path,
+        let dir_item = create_test_directory_item("photos");
+        assert!(manager.generate_thumbnail(&dir_item, 16, 8).is_none());
+
+        let text_item = create_test_text_file_item("notes");
+        assert!(manager.generate_thumbnail(&text_item, 16, 8).is_none());
+        assert!(manager.thumbnail_cache.is_empty());
+    }
+
+    #[test]
+    fn test_preview_manager_thumbnail_is_made_in_the_background() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let image_path = temp_fs.create_test_image("grid.jpg").unwrap();
+        let file = FileItem::new(
+            "grid.jpg".to_string(),
+            image_path.clone(),
             false,
             std::time::UNIX_EPOCH,
         );
+        let mut manager = PreviewManager::new(create_test_config());
 
-        // Test with a large height parameter to see if limit is reached
-        let preview = manager.generate_preview(&file_item, 80, 15000, 0, &localization);
-        let content = match preview {
-            PreviewContent::Text(text) => text
-                .lines
-                .iter()
-                .map(|line| {
-                    line.spans
-                        .iter()
-                        .map(|span| span.content.as_ref())
-                        .collect::<String>()
-                })
-                .collect::<Vec<_>>()
-                .join("\n"),
-            PreviewContent::Graphical(_) | PreviewContent::Kitty(_) => panic!("Expected text preview"),
-        };
+        // Nothing to draw yet, and asking again doesn't queue it twice
+        assert!(manager.generate_thumbnail(&file, 16, 8).is_none());
+        assert!(manager.generate_thumbnail(&file, 16, 8).is_none());
+        assert_eq!(manager.queued_thumbnails.len(), 1);
 
-        // Should show the limit message since we have more than 10000 lines
-        assert!(content.contains("file too large for scrolling"));
+        while !manager.poll_thumbnails() {
+            thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert!(manager.queued_thumbnails.is_empty());
+        assert!(
+            manager
+                .thumbnail_cache
+                .contains_key(&format!("{}:16x8", image_path))
+        );
     }
 
     #[test]
     fn test_image_dimensions_fallback() {
-        let (width, height) = ImageDimensions::get_dimensions("nonexistent_file.jpg");
+        let (width, height) = ImageDimensions::get_dimensions("nonexistent_file.jpg", true);
         assert_eq!(width, 800);
         assert_eq!(height, 600);
     }
@@ -1312,6 +4816,116 @@ mod tests {
         assert_eq!(result, Some((1920, 1080)));
     }
 
+    #[rstest::rstest]
+    #[case("real.png", image::ImageFormat::Png)]
+    #[case("real.jpg", image::ImageFormat::Jpeg)]
+    #[case("real.gif", image::ImageFormat::Gif)]
+    #[case("real.webp", image::ImageFormat::WebP)]
+    #[case("real.qoi", image::ImageFormat::Qoi)]
+    fn test_image_dimensions_native_real_files(
+        #[case] name: &str,
+        #[case] format: image::ImageFormat,
+    ) {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let path = temp_fs.get_path().join(name);
+        image::RgbImage::new(37, 21)
+            .save_with_format(&path, format)
+            .unwrap();
+
+        assert_eq!(
+            ImageDimensions::get_dimensions_native(path.to_str().unwrap()),
+            Some((37, 21))
+        );
+    }
+
+    #[test]
+    fn test_image_dimensions_native_headers() {
+        let temp_fs = TestFileSystem::new().unwrap();
+
+        // JPEG with an APP0 segment before SOF2 (progressive)
+        let jpeg = temp_fs
+            .create_binary_file(
+                "progressive.jpg",
+                b"\xff\xd8\xff\xe0\x00\x04JF\xff\xc2\x00\x11\x08\x04\x38\x07\x80\x03",
+            )
+            .unwrap();
+        assert_eq!(
+            ImageDimensions::get_dimensions_native(&jpeg),
+            Some((1920, 1080))
+        );
+
+        // Lossy WebP key frame
+        let mut vp8 = b"RIFF\x00\x00\x00\x00WEBPVP8 \x00\x00\x00\x00\x00\x00\x00".to_vec();
+        vp8.extend_from_slice(b"\x9d\x01\x2a");
+        vp8.extend_from_slice(&640u16.to_le_bytes());
+        vp8.extend_from_slice(&480u16.to_le_bytes());
+        let vp8 = temp_fs.create_binary_file("lossy.webp", &vp8).unwrap();
+        assert_eq!(
+            ImageDimensions::get_dimensions_native(&vp8),
+            Some((640, 480))
+        );
+
+        // Extended WebP canvas
+        let mut vp8x = b"RIFF\x00\x00\x00\x00WEBPVP8X\x0a\x00\x00\x00\x10\x00\x00\x00".to_vec();
+        vp8x.extend_from_slice(&[0xFF, 0x0F, 0x00, 0x37, 0x08, 0x00]);
+        let vp8x = temp_fs.create_binary_file("extended.webp", &vp8x).unwrap();
+        assert_eq!(
+            ImageDimensions::get_dimensions_native(&vp8x),
+            Some((4096, 2104))
+        );
+
+        // Unknown formats and truncated headers are left to identify
+        let text = temp_fs.create_file("notes.txt", "hello").unwrap();
+        assert_eq!(ImageDimensions::get_dimensions_native(&text), None);
+        let truncated = temp_fs
+            .create_binary_file("cut.png", b"\x89PNG\r\n\x1a\n")
+            .unwrap();
+        assert_eq!(ImageDimensions::get_dimensions_native(&truncated), None);
+        assert_eq!(ImageDimensions::get_dimensions_native("missing.png"), None);
+    }
+
+    #[test]
+    fn test_native_metadata_panel() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let jpeg = temp_fs
+            .create_binary_file(
+                "photo.jpg",
+                b"\xff\xd8\xff\xe0\x00\x04JF\xff\xc0\x00\x11\x08\x02\x58\x03\x20\x03",
+            )
+            .unwrap();
+        let file = FileItem::new("photo.jpg".to_string(), jpeg, false, SystemTime::now());
+        let manager = PreviewManager::new(create_test_config());
+        let text = manager
+            .generate_native_metadata_panel(&file, &Localization::new("en").unwrap())
+            .to_string();
+
+        assert!(text.contains("JPEG"));
+        assert!(text.contains("800 × 600"));
+        assert!(text.contains("18 B"));
+    }
+
+    #[test]
+    fn test_image_dimensions_extract_from_avif() {
+        let mut avif = b"\x00\x00\x00\x1cftypavif\x00\x00\x00\x00mif1miaf".to_vec();
+        avif.extend_from_slice(b"\x00\x00\x00\x14ispe\x00\x00\x00\x00");
+        avif.extend_from_slice(&1920u32.to_be_bytes());
+        avif.extend_from_slice(&1080u32.to_be_bytes());
+        assert_eq!(
+            ImageDimensions::extract_dimensions_from_avif(&avif),
+            Some((1920, 1080))
+        );
+
+        // Truncated ispe box
+        assert_eq!(
+            ImageDimensions::extract_dimensions_from_avif(&avif[..avif.len() - 4]),
+            None
+        );
+
+        // Not an AVIF file
+        let png = b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDRispe";
+        assert_eq!(ImageDimensions::extract_dimensions_from_avif(png), None);
+    }
+
     #[test]
     fn test_preview_manager_debug_info() {
         let config = create_test_config();
@@ -1345,6 +4959,83 @@ mod tests {
         assert!(manager.cache.is_empty());
     }
 
+    #[rstest::rstest]
+    #[case(Some(10), "/img.jpg:80x20", true)]
+    #[case(Some(1), "/img.jpg:82x24", false)]
+    fn test_cache_key_rounds_the_size(
+        #[case] cache_size_rounding: Option<u16>,
+        #[case] key: &str,
+        #[case] reused_after_resize: bool,
+    ) {
+        let manager = PreviewManager::new(PTuiConfig {
+            cache_size_rounding,
+            ..create_test_config()
+        });
+        assert_eq!(manager.cache_key("/img.jpg", 82, 24), key);
+        assert_eq!(
+            manager.cache_key("/img.jpg", 80, 24) == manager.cache_key("/img.jpg", 82, 24),
+            reused_after_resize
+        );
+    }
+
+    #[test]
+    fn test_resizing_by_two_columns_hits_the_cache() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let image_path = temp_fs.create_test_image("resize.jpg").unwrap();
+        let file = FileItem::new(
+            "resize.jpg".to_string(),
+            image_path.clone(),
+            false,
+            std::time::UNIX_EPOCH,
+        );
+        let mut manager = PreviewManager::new(create_test_config());
+        manager.insert_into_cache(
+            manager.cache_key(&image_path, 80, 24),
+            PreviewContent::Text(Text::from("art"), ContentAlignment::Image),
+            file.modified,
+        );
+        assert!(manager.is_cached(&file, 82, 24));
+
+        let misses = manager.cache_misses;
+        manager.generate_preview(&file, 82, 24, 0, &Localization::new("en").unwrap());
+        assert_eq!(manager.cache_misses, misses);
+
+        manager.remove_from_cache(&file, 82, 24);
+        assert!(!manager.is_cached(&file, 80, 24));
+    }
+
+    #[test]
+    fn test_previews_are_converted_at_the_cached_size() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let image_path = temp_fs.create_test_image("shrink.jpg").unwrap();
+        let file = FileItem::new(
+            "shrink.jpg".to_string(),
+            image_path.clone(),
+            false,
+            std::time::UNIX_EPOCH,
+        );
+        let mut manager = PreviewManager::new(create_test_config());
+        let localization = Localization::new("en").unwrap();
+        let fitted = |width, height| {
+            PreviewManager::new(create_test_config()).calculate_converter_dimensions(
+                &image_path,
+                width,
+                height,
+                &localization,
+            )
+        };
+        let (within_80x20, within_8x6) = (fitted(80, 20), fitted(8, 6));
+
+        // Converted at 80x20, so shrinking the pane to 80 columns can reuse it without overflowing
+        manager.generate_preview(&file, 89, 24, 0, &localization);
+        assert_eq!(manager.last_output_size, Some(within_80x20));
+        assert!(manager.is_cached(&file, 80, 24));
+
+        // A pane smaller than one rounding step keeps its size
+        manager.generate_preview(&file, 8, 6, 0, &localization);
+        assert_eq!(manager.last_output_size, Some(within_8x6));
+    }
+
     #[test]
     fn test_preview_manager_calculate_converter_dimensions() {
         let temp_fs = TestFileSystem::new().unwrap();
@@ -1384,8 +5075,11 @@ mod tests {
 
         let preview = manager.generate_preview(&file_item, 80, 24, 0, &localization);
         match preview {
-            PreviewContent::Text(text) => assert!(!text.lines.is_empty()),
-            PreviewContent::Graphical(_) | PreviewContent::Kitty(_) => {
+            PreviewContent::Text(text, _) => assert!(!text.lines.is_empty()),
+            PreviewContent::Graphical(_)
+            | PreviewContent::Kitty(_)
+            | PreviewContent::Inline(_)
+            | PreviewContent::Animated { .. } => {
                 // Graphical/Kitty preview is also valid for images
             }
         }
@@ -1409,8 +5103,11 @@ mod tests {
 
         let preview = manager.generate_preview(&file_item, 80, 24, 0, &localization);
         match preview {
-            PreviewContent::Text(text) => assert!(!text.lines.is_empty()),
-            PreviewContent::Graphical(_) | PreviewContent::Kitty(_) => {
+            PreviewContent::Text(text, _) => assert!(!text.lines.is_empty()),
+            PreviewContent::Graphical(_)
+            | PreviewContent::Kitty(_)
+            | PreviewContent::Inline(_)
+            | PreviewContent::Animated { .. } => {
                 panic!("Expected text preview for empty file")
             }
         }
@@ -0,0 +1,104 @@
+use crate::file_browser::{ContentFilter, SortMode};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Where the user left off, saved on quit so the next run can pick up in the same place.
+/// Only the last directory is kept; the recent files history is separate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionState {
+    pub current_dir: String,
+    pub selected_index: usize,
+    pub sort_mode: SortMode,
+    pub converter: String,
+    pub text_scroll_offset: usize,
+    // Missing from sessions saved before content filters existed
+    #[serde(default)]
+    pub filter_mode: ContentFilter,
+}
+
+impl SessionState {
+    /// Where the session is kept between runs: $XDG_CACHE_HOME/ptui/session.json on Linux
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::cache_dir().map(|dir| dir.join("ptui").join("session.json"))
+    }
+
+    /// Load the saved session, if there is one and its directory still exists
+    pub fn load_from(path: &Path) -> Option<Self> {
+        let contents = fs::read_to_string(path).ok()?;
+        let session: Self = serde_json::from_str(&contents).ok()?;
+        Path::new(&session.current_dir).is_dir().then_some(session)
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session_in(dir: &Path) -> SessionState {
+        SessionState {
+            current_dir: dir.to_string_lossy().into_owned(),
+            selected_index: 3,
+            sort_mode: SortMode::DateOldestFirst,
+            converter: "jp2a".to_string(),
+            text_scroll_offset: 12,
+            filter_mode: ContentFilter::ImagesOnly,
+        }
+    }
+
+    #[test]
+    fn test_session_state_json_round_trip() {
+        let session = session_in(Path::new("/tmp/photos"));
+        let json = serde_json::to_string(&session).unwrap();
+        let restored: SessionState = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, session);
+    }
+
+    #[test]
+    fn test_session_state_save_and_load() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("ptui").join("session.json");
+        let session = session_in(temp_dir.path());
+
+        session.save_to(&path).unwrap();
+        assert_eq!(SessionState::load_from(&path), Some(session));
+    }
+
+    #[test]
+    fn test_session_state_for_a_removed_directory_is_ignored() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("session.json");
+        session_in(&temp_dir.path().join("gone"))
+            .save_to(&path)
+            .unwrap();
+
+        assert_eq!(SessionState::load_from(&path), None);
+    }
+
+    #[test]
+    fn test_session_state_without_filter_mode() {
+        let json = r#"{"current_dir": "/tmp", "selected_index": 0, "sort_mode": "Name",
+            "converter": "chafa", "text_scroll_offset": 0}"#;
+        let session: SessionState = serde_json::from_str(json).unwrap();
+        assert_eq!(session.filter_mode, ContentFilter::All);
+    }
+
+    #[test]
+    fn test_session_state_missing_or_corrupt_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("session.json");
+        assert_eq!(SessionState::load_from(&path), None);
+
+        fs::write(&path, "not json").unwrap();
+        assert_eq!(SessionState::load_from(&path), None);
+    }
+}
@@ -21,11 +21,55 @@ pub mod helpers {
                     chars: None,
                 },
                 graphical: crate::config::GraphicalConfig::default(),
+                heic: crate::config::HeicConfig::default(),
+                raw: crate::config::RawConfig::default(),
+                sixel: crate::config::SixelConfig::default(),
+                iterm: crate::config::ITermConfig::default(),
                 selected: "chafa".to_string(),
+                conversion_timeout_ms: Some(10000),
             },
             locale: Some("en".to_string()),
             slideshow_delay_ms: Some(1000),
             slideshow_transitions: Some(crate::config::SlideshowTransitionConfig::default()),
+            grid_cell_width: None,
+            slideshow_loop: None,
+            slideshow_delay_step_factor: None,
+            slideshow_delay_min_ms: None,
+            slideshow_delay_max_ms: None,
+            preview_loading_timeout_ms: Some(30000),
+            prefer_native_dimension_detection: Some(true),
+            // Never the real trash; tests that need it swap in their own
+            use_trash: Some(false),
+            open_with: Some(std::collections::HashMap::new()),
+            git_integration: Some(false),
+            watch_current_directory: Some(false),
+            restore_session: None,
+            debug_pane_text: None,
+            use_icons: None,
+            icon_preset: None,
+            file_icons: None,
+            theme: None,
+            show_progress_bar: None,
+            batch_export_concurrent: None,
+            // Preview straight away so key handling tests see the result
+            preview_debounce_ms: Some(0),
+            max_preview_lines: Some(10000),
+            resize_debounce_ms: Some(100),
+            show_image_counts: Some(false),
+            poll_interval_ms: Some(50),
+            fzf_integration: Some(false),
+            logo_file: None,
+            logo_hidden: Some(false),
+            show_permissions: Some(false),
+            notification_duration_ms: Some(0),
+            audio_waveform: Some(false),
+            file_browser_width_percent: None,
+            file_browser_width_percent_wide: None,
+            file_browser_width_percent_narrow: None,
+            char_aspect_ratio: None,
+            slideshow_order: None,
+            text_scroll_lines: None,
+            cache_size_rounding: None,
             chafa: None,
         }
     }
@@ -0,0 +1,178 @@
+use crate::file_browser::FileItem;
+use crate::icons::{FileCategory, FileIcons, FileKind};
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Colours for the file list. Values are colour names ("yellow", "dark gray"),
+/// "#rrggbb" hex, or "reset" for the terminal's own colour. Fields left out of
+/// the config keep their default.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct ThemeConfig {
+    pub selected_file_fg: String,
+    pub selected_file_bg: String,
+    pub directory_fg: String,
+    pub file_fg: String,
+    pub image_fg: String,
+    pub ascii_fg: String,
+    pub unsupported_fg: String,
+    /// Icons for the file list's icon column by category, overriding the icon set
+    pub icons: HashMap<FileCategory, String>,
+}
+
+impl Default for ThemeConfig {
+    fn default() -> Self {
+        Self {
+            selected_file_fg: "yellow".to_string(),
+            selected_file_bg: "reset".to_string(),
+            directory_fg: "reset".to_string(),
+            file_fg: "reset".to_string(),
+            image_fg: "reset".to_string(),
+            ascii_fg: "reset".to_string(),
+            unsupported_fg: "reset".to_string(),
+            icons: HashMap::new(),
+        }
+    }
+}
+
+impl ThemeConfig {
+    /// Foreground colour for a list entry by its kind. Colours that don't parse
+    /// fall back to the terminal's own.
+    pub fn file_fg(&self, file: &FileItem) -> Color {
+        let color = match FileKind::of(file) {
+            FileKind::Directory => &self.directory_fg,
+            FileKind::Image => &self.image_fg,
+            FileKind::Ascii => &self.ascii_fg,
+            FileKind::Text | FileKind::Video => &self.file_fg,
+            FileKind::Audio | FileKind::Archive | FileKind::Unknown => &self.unsupported_fg,
+        };
+        parse_color(color).unwrap_or(Color::Reset)
+    }
+
+    /// The icon column entry for a category: the theme's own icon if it sets one,
+    /// otherwise the one from the icon set
+    pub fn category_icon<'a>(&'a self, icons: &'a FileIcons, category: FileCategory) -> &'a str {
+        self.icons
+            .get(&category)
+            .map(String::as_str)
+            .unwrap_or_else(|| icons.icon_for_category(category))
+    }
+
+    pub fn selected_fg(&self) -> Color {
+        parse_color(&self.selected_file_fg).unwrap_or(Color::Yellow)
+    }
+
+    pub fn selected_bg(&self) -> Color {
+        parse_color(&self.selected_file_bg).unwrap_or(Color::Reset)
+    }
+}
+
+/// Parse a colour name or "#rrggbb" hex value. Names ignore case, spaces, dashes
+/// and underscores, so "Light Blue", "light-blue" and "lightblue" all work.
+pub fn parse_color(value: &str) -> Option<Color> {
+    let value = value.trim();
+    if let Some(hex) = value.strip_prefix('#') {
+        if hex.len() != 6 {
+            return None;
+        }
+        let channel = |range| u8::from_str_radix(hex.get(range)?, 16).ok();
+        return Some(Color::Rgb(channel(0..2)?, channel(2..4)?, channel(4..6)?));
+    }
+
+    let name: String = value
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .collect::<String>()
+        .to_lowercase();
+    let color = match name.as_str() {
+        "reset" | "default" => Color::Reset,
+        "black" => Color::Black,
+        "red" => Color::Red,
+        "green" => Color::Green,
+        "yellow" => Color::Yellow,
+        "blue" => Color::Blue,
+        "magenta" => Color::Magenta,
+        "cyan" => Color::Cyan,
+        "gray" | "grey" => Color::Gray,
+        "darkgray" | "darkgrey" => Color::DarkGray,
+        "lightred" => Color::LightRed,
+        "lightgreen" => Color::LightGreen,
+        "lightyellow" => Color::LightYellow,
+        "lightblue" => Color::LightBlue,
+        "lightmagenta" => Color::LightMagenta,
+        "lightcyan" => Color::LightCyan,
+        "white" => Color::White,
+        _ => return None,
+    };
+    Some(color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::helpers::create_test_file_item;
+
+    #[rstest::rstest]
+    #[case("#ff0000", Some(Color::Rgb(255, 0, 0)))]
+    #[case("#0A0b0C", Some(Color::Rgb(10, 11, 12)))]
+    #[case("Light Blue", Some(Color::LightBlue))]
+    #[case("dark_grey", Some(Color::DarkGray))]
+    #[case("reset", Some(Color::Reset))]
+    #[case("#ff00", None)]
+    #[case("#gg0000", None)]
+    #[case("chartreuse", None)]
+    fn test_parse_color(#[case] value: &str, #[case] expected: Option<Color>) {
+        assert_eq!(parse_color(value), expected);
+    }
+
+    #[rstest::rstest]
+    #[case("album", true, Color::Red)]
+    #[case("photo.png", false, Color::Green)]
+    #[case("logo.ascii", false, Color::Cyan)]
+    #[case("notes.txt", false, Color::White)]
+    #[case("song.mp3", false, Color::DarkGray)]
+    fn test_file_fg_by_kind(
+        #[case] name: &str,
+        #[case] is_directory: bool,
+        #[case] expected: Color,
+    ) {
+        let theme = ThemeConfig {
+            directory_fg: "red".to_string(),
+            image_fg: "green".to_string(),
+            ascii_fg: "cyan".to_string(),
+            file_fg: "white".to_string(),
+            unsupported_fg: "dark gray".to_string(),
+            ..ThemeConfig::default()
+        };
+        let file = create_test_file_item(name, is_directory);
+        assert_eq!(theme.file_fg(&file), expected);
+    }
+
+    #[test]
+    fn test_invalid_colors_fall_back() {
+        let theme = ThemeConfig {
+            selected_file_fg: "nope".to_string(),
+            directory_fg: "nope".to_string(),
+            ..ThemeConfig::default()
+        };
+        assert_eq!(theme.selected_fg(), Color::Yellow);
+        assert_eq!(
+            theme.file_fg(&create_test_file_item("album", true)),
+            Color::Reset
+        );
+    }
+
+    #[test]
+    fn test_category_icons_override_icon_set() {
+        let theme: ThemeConfig =
+            serde_json::from_str(r#"{"icons": {"code": "</>", "archive": "Z"}}"#).unwrap();
+        let icons = FileIcons::default();
+        assert_eq!(theme.category_icon(&icons, FileCategory::Code), "</>");
+        assert_eq!(theme.category_icon(&icons, FileCategory::Archive), "Z");
+        assert_eq!(
+            theme.category_icon(&icons, FileCategory::Image),
+            icons.image
+        );
+    }
+}
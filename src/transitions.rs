@@ -1,30 +1,116 @@
 use crate::config::SlideshowTransitionConfig;
 use ansi_to_tui::IntoText;
 use ratatui::text::Text;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::time::{Duration, Instant};
 
+/// Small linear congruential generator, so a transition between the same two
+/// images always plays out the same way
+struct Lcg(u64);
+
+impl Lcg {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        self.0
+    }
+
+    /// Shuffled order of 0..len (Fisher-Yates)
+    fn permutation(&mut self, len: usize) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..len).collect();
+        for i in (1..len).rev() {
+            let j = (self.next_u64() >> 33) as usize % (i + 1);
+            order.swap(i, j);
+        }
+        order
+    }
+}
+
+/// Which way the slideshow is moving, so directional effects can play in reverse
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Forward,
+    Backward,
+}
+
+impl Direction {
+    fn reversed(self) -> Self {
+        match self {
+            Direction::Forward => Direction::Backward,
+            Direction::Backward => Direction::Forward,
+        }
+    }
+}
+
+/// Which way the new image is wiped in over the old one
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WipeDirection {
+    Up,
+    Down,
+}
+
+impl WipeDirection {
+    fn from_effect(effect: &str) -> Option<Self> {
+        match effect {
+            "wipe_down" => Some(WipeDirection::Down),
+            "wipe_up" => Some(WipeDirection::Up),
+            _ => None,
+        }
+    }
+}
+
+/// Reshape linear progress `t` (0.0 to 1.0) with an easing curve. Unknown
+/// easings stay linear.
+pub fn apply_easing(t: f32, easing: &str) -> f32 {
+    match easing {
+        "ease-in" => t * t,
+        "ease-out" => 1.0 - (1.0 - t) * (1.0 - t),
+        // Cubic Hermite (smoothstep): slow at both ends
+        "ease-in-out" => t * t * (3.0 - 2.0 * t),
+        _ => t,
+    }
+}
+
 pub struct TransitionManager {
     config: SlideshowTransitionConfig,
     transition_start_time: Option<Instant>,
     cached_frames: Vec<Text<'static>>,
     current_frame_index: usize,
     total_transition_duration: Duration,
+    // Text of the image being transitioned away from, needed by effects like fade
+    from_str: Option<String>,
+    // Set when the effect is one of the wipes
+    wipe_direction: Option<WipeDirection>,
+    // Direction of the current transition, always Forward unless direction_aware is set
+    direction: Direction,
 }
 
 impl TransitionManager {
     pub fn new(config: SlideshowTransitionConfig) -> Self {
-        let total_duration = Duration::from_millis(config.frame_duration_ms * 20); // 20 frames total
+        let total_duration = Duration::from_millis(config.total_duration_ms());
+        let wipe_direction = WipeDirection::from_effect(&config.effect);
         Self {
             config,
             transition_start_time: None,
             cached_frames: Vec::new(),
             current_frame_index: 0,
             total_transition_duration: total_duration,
+            from_str: None,
+            wipe_direction,
+            direction: Direction::Forward,
         }
     }
 
     pub fn update_config(&mut self, config: SlideshowTransitionConfig) {
-        self.total_transition_duration = Duration::from_millis(config.frame_duration_ms * 20);
+        self.total_transition_duration = Duration::from_millis(config.total_duration_ms());
+        self.wipe_direction = WipeDirection::from_effect(&config.effect);
         self.config = config;
         // Clear any ongoing transition when config changes
         self.reset_transition();
@@ -39,14 +125,26 @@ impl TransitionManager {
     }
 
     /// Start a new transition animation from one content to another
-    pub fn start_transition(&mut self, from_content: &Text, to_content: &Text) -> bool {
+    pub fn start_transition(
+        &mut self,
+        from_content: &Text,
+        to_content: &Text,
+        direction: Direction,
+    ) -> bool {
         if !self.config.enabled {
             return false;
         }
 
+        self.direction = if self.config.direction_aware {
+            direction
+        } else {
+            Direction::Forward
+        };
+
         // Convert Text to string for terani
-        let _from_str = self.text_to_string(from_content);
+        let from_str = self.text_to_string(from_content);
         let to_str = self.text_to_string(to_content);
+        self.from_str = Some(from_str);
 
         // Start the transition
         self.transition_start_time = Some(Instant::now());
@@ -85,6 +183,12 @@ impl TransitionManager {
         }
     }
 
+    /// Number of pre-rendered frames for the current transition
+    #[allow(dead_code)]
+    pub fn cached_frame_count(&self) -> usize {
+        self.cached_frames.len()
+    }
+
     /// Check if we're currently in the middle of a transition
     pub fn is_in_transition(&self) -> bool {
         self.transition_start_time.is_some() && !self.cached_frames.is_empty()
@@ -95,6 +199,7 @@ impl TransitionManager {
         self.transition_start_time = None;
         self.cached_frames.clear();
         self.current_frame_index = 0;
+        self.from_str = None;
     }
 
     /// Pre-render all animation frames for smooth playback using terani-inspired effects
@@ -102,9 +207,9 @@ impl TransitionManager {
         self.cached_frames.clear();
 
         // Create multiple frames for smooth animation (simulate terani effects)
-        let num_frames = 20; // Configurable number of frames
+        let num_frames = self.config.get_frame_count();
         for i in 0..=num_frames {
-            let progress = i as f32 / num_frames as f32;
+            let progress = apply_easing(i as f32 / num_frames as f32, self.config.get_easing());
             let frame_text = self.create_transition_frame(target_text, progress);
 
             if let Ok(text) = frame_text.into_text() {
@@ -118,17 +223,34 @@ impl TransitionManager {
     }
 
     /// Create a single transition frame with the given progress (0.0 to 1.0)
-    fn create_transition_frame(&self, text: &str, progress: f32) -> String {
+    pub(crate) fn create_transition_frame(&self, text: &str, progress: f32) -> String {
         match self.config.effect.as_str() {
             "scattering" => self.simulate_scattering_frame(text, progress),
             "typewriter" => self.simulate_typewriter_frame(text, progress),
-            "scrolling_left" | "scrolling_right" => self.simulate_scrolling_frame(text, progress),
-            "climbing" => self.simulate_climbing_frame(text, progress),
+            "scrolling_left" => self.simulate_scrolling_frame(text, progress, self.direction),
+            // Scrolls the other way only when transitions follow the slideshow's direction
+            "scrolling_right" if self.config.direction_aware => {
+                self.simulate_scrolling_frame(text, progress, self.direction.reversed())
+            }
+            "scrolling_right" => self.simulate_scrolling_frame(text, progress, Direction::Forward),
+            "climbing" => self.simulate_climbing_frame(text, progress, self.direction),
+            "fade" => self.simulate_fade_frame(text, progress),
+            "wipe_down" | "wipe_up" => {
+                let from = self.from_str.as_deref().unwrap_or("");
+                let mut direction = self.wipe_direction.unwrap_or(WipeDirection::Down);
+                if self.direction == Direction::Backward {
+                    direction = match direction {
+                        WipeDirection::Down => WipeDirection::Up,
+                        WipeDirection::Up => WipeDirection::Down,
+                    };
+                }
+                self.simulate_wipe_frame(from, text, progress, direction)
+            }
             _ => text.to_string(),
         }
     }
 
-    fn simulate_scattering_frame(&self, text: &str, progress: f32) -> String {
+    pub(crate) fn simulate_scattering_frame(&self, text: &str, progress: f32) -> String {
         // Simple scattering simulation: gradually reveal characters
         let total_chars = text.chars().count();
         let visible_chars = (total_chars as f32 * progress) as usize;
@@ -143,18 +265,101 @@ impl TransitionManager {
             + if progress < 1.0 { "█" } else { "" }
     }
 
-    fn simulate_scrolling_frame(&self, text: &str, progress: f32) -> String {
+    fn simulate_scrolling_frame(&self, text: &str, progress: f32, direction: Direction) -> String {
         // Simple scrolling effect: shift text position
         let shift = (20.0 * (1.0 - progress)) as usize;
-        let padding: String = " ".repeat(shift);
-        format!("{}{}", padding, text)
+        match direction {
+            // Enter from the right, moving right to left
+            Direction::Forward => {
+                let padding: String = " ".repeat(shift);
+                format!("{}{}", padding, text)
+            }
+            // Enter from the left, moving left to right
+            Direction::Backward => text
+                .lines()
+                .map(|line| line.chars().skip(shift).collect::<String>())
+                .collect::<Vec<String>>()
+                .join("\n"),
+        }
     }
 
-    fn simulate_climbing_frame(&self, text: &str, progress: f32) -> String {
-        // Climbing effect: move text up gradually
+    fn simulate_climbing_frame(&self, text: &str, progress: f32, direction: Direction) -> String {
+        // Climbing effect: move text up gradually (or down when going backward)
         let lines_shift = (5.0 * (1.0 - progress)) as usize;
-        let padding: String = "\n".repeat(lines_shift);
-        format!("{}{}", padding, text)
+        match direction {
+            Direction::Forward => {
+                let padding: String = "\n".repeat(lines_shift);
+                format!("{}{}", padding, text)
+            }
+            Direction::Backward => text
+                .lines()
+                .skip(lines_shift)
+                .collect::<Vec<&str>>()
+                .join("\n"),
+        }
+    }
+
+    fn simulate_fade_frame(&self, text: &str, progress: f32) -> String {
+        // Fade to black: dissolve the old image in the first half, then reveal the new one
+        let from = self.from_str.as_deref().unwrap_or("");
+        let mut hasher = DefaultHasher::new();
+        from.hash(&mut hasher);
+        text.hash(&mut hasher);
+        let mut rng = Lcg::new(hasher.finish());
+
+        let from_chars: Vec<char> = from.chars().collect();
+        let to_chars: Vec<char> = text.chars().collect();
+        let from_order = rng.permutation(from_chars.len());
+        let to_order = rng.permutation(to_chars.len());
+
+        let progress = progress.clamp(0.0, 1.0);
+        if progress < 0.5 {
+            let blanked = (from_chars.len() as f32 * progress * 2.0) as usize;
+            let mut frame = from_chars;
+            for &i in &from_order[..blanked] {
+                if !frame[i].is_whitespace() {
+                    frame[i] = ' ';
+                }
+            }
+            frame.into_iter().collect()
+        } else {
+            // Reveal in the reverse of the dissolve order
+            let revealed = (to_chars.len() as f32 * (progress - 0.5) * 2.0) as usize;
+            let mut frame: Vec<char> = to_chars
+                .iter()
+                .map(|&c| if c.is_whitespace() { c } else { ' ' })
+                .collect();
+            for &i in to_order.iter().rev().take(revealed) {
+                frame[i] = to_chars[i];
+            }
+            frame.into_iter().collect()
+        }
+    }
+
+    fn simulate_wipe_frame(
+        &self,
+        from: &str,
+        to: &str,
+        progress: f32,
+        direction: WipeDirection,
+    ) -> String {
+        // Wipe: the new image replaces the old one line by line from the top (or bottom)
+        let from_lines: Vec<&str> = from.lines().collect();
+        let to_lines: Vec<&str> = to.lines().collect();
+        let total_lines = from_lines.len().max(to_lines.len());
+        let wiped = (total_lines as f32 * progress.clamp(0.0, 1.0)) as usize;
+
+        (0..total_lines)
+            .map(|i| {
+                let show_new = match direction {
+                    WipeDirection::Down => i < wiped,
+                    WipeDirection::Up => i >= total_lines - wiped,
+                };
+                let lines = if show_new { &to_lines } else { &from_lines };
+                lines.get(i).copied().unwrap_or("")
+            })
+            .collect::<Vec<&str>>()
+            .join("\n")
     }
 
     fn text_to_string(&self, text: &Text) -> String {
@@ -178,6 +383,7 @@ impl TransitionManager {
 mod tests {
     use super::*;
     use crate::config::SlideshowTransitionConfig;
+    use proptest::prelude::*;
     use ratatui::text::Line;
 
     #[test]
@@ -213,13 +419,16 @@ mod tests {
             enabled: false,
             effect: "scattering".to_string(),
             frame_duration_ms: 50,
+            frame_count: None,
+            direction_aware: false,
+            easing: None,
         };
         let mut manager = TransitionManager::new(config);
 
         let text1 = Text::from("Hello");
         let text2 = Text::from("World");
 
-        let result = manager.start_transition(&text1, &text2);
+        let result = manager.start_transition(&text1, &text2, Direction::Forward);
         assert!(!result);
         assert!(!manager.is_in_transition());
     }
@@ -230,17 +439,162 @@ mod tests {
             enabled: true,
             effect: "scattering".to_string(),
             frame_duration_ms: 50,
+            frame_count: None,
+            direction_aware: false,
+            easing: None,
         };
         let mut manager = TransitionManager::new(config);
 
         let text1 = Text::from("Hello");
         let text2 = Text::from("World");
 
-        let result = manager.start_transition(&text1, &text2);
+        let result = manager.start_transition(&text1, &text2, Direction::Forward);
         assert!(result);
         assert!(manager.is_in_transition());
     }
 
+    fn fade_manager(from: &str, to: &str) -> TransitionManager {
+        let config = SlideshowTransitionConfig {
+            enabled: true,
+            effect: "fade".to_string(),
+            frame_duration_ms: 50,
+            frame_count: None,
+            direction_aware: false,
+            easing: None,
+        };
+        let mut manager = TransitionManager::new(config);
+        manager.start_transition(
+            &Text::from(from.to_string()),
+            &Text::from(to.to_string()),
+            Direction::Forward,
+        );
+        manager
+    }
+
+    #[test]
+    fn test_fade_frame_endpoints() {
+        let manager = fade_manager("aaaa\naaaa", "bbbb\nbbbb");
+
+        let start = manager.simulate_fade_frame("bbbb\nbbbb", 0.0);
+        assert!(!start.contains('b'));
+        assert_eq!(start, "aaaa\naaaa");
+
+        let middle = manager.simulate_fade_frame("bbbb\nbbbb", 0.5);
+        assert_eq!(middle.trim(), "");
+
+        assert_eq!(manager.simulate_fade_frame("bbbb\nbbbb", 1.0), "bbbb\nbbbb");
+    }
+
+    #[test]
+    fn test_fade_frame_is_deterministic() {
+        let first = fade_manager("abcdefgh", "ijklmnop");
+        let second = fade_manager("abcdefgh", "ijklmnop");
+        for progress in [0.1, 0.3, 0.6, 0.9] {
+            assert_eq!(
+                first.simulate_fade_frame("ijklmnop", progress),
+                second.simulate_fade_frame("ijklmnop", progress)
+            );
+        }
+        // Partway through the dissolve some, but not all, of the old image is left
+        let frame = first.simulate_fade_frame("ijklmnop", 0.25);
+        let remaining = frame.chars().filter(|c| !c.is_whitespace()).count();
+        assert!(remaining > 0 && remaining < 8);
+    }
+
+    #[rstest::rstest]
+    #[case("wipe_down", WipeDirection::Down, [true, true, false, false])]
+    #[case("wipe_up", WipeDirection::Up, [false, false, true, true])]
+    fn test_wipe_frame_halfway(
+        #[case] effect: &str,
+        #[case] direction: WipeDirection,
+        #[case] expected_new: [bool; 4],
+    ) {
+        let config = SlideshowTransitionConfig {
+            enabled: true,
+            effect: effect.to_string(),
+            frame_duration_ms: 50,
+            frame_count: None,
+            direction_aware: false,
+            easing: None,
+        };
+        let manager = TransitionManager::new(config);
+        assert_eq!(manager.wipe_direction, Some(direction));
+
+        let from = "old1\nold2\nold3\nold4";
+        let to = "new1\nnew2\nnew3\nnew4";
+        let frame = manager.simulate_wipe_frame(from, to, 0.5, direction);
+        let target_lines: Vec<&str> = to.lines().collect();
+        let matches: Vec<bool> = frame
+            .lines()
+            .zip(&target_lines)
+            .map(|(line, target)| line == *target)
+            .collect();
+        assert_eq!(matches, expected_new);
+
+        assert_eq!(manager.simulate_wipe_frame(from, to, 0.0, direction), from);
+        assert_eq!(manager.simulate_wipe_frame(from, to, 1.0, direction), to);
+    }
+
+    #[test]
+    fn test_scrolling_frame_direction() {
+        let manager = TransitionManager::new(SlideshowTransitionConfig::default());
+        let text = "abcdefghijklmnopqrstuvwxyz";
+
+        // Forward pads on the left, backward cuts the same amount off the left
+        let forward = manager.simulate_scrolling_frame(text, 0.5, Direction::Forward);
+        let backward = manager.simulate_scrolling_frame(text, 0.5, Direction::Backward);
+        assert_eq!(forward, format!("{}{}", " ".repeat(10), text));
+        assert_eq!(backward, &text[10..]);
+
+        assert_eq!(
+            manager.simulate_scrolling_frame(text, 1.0, Direction::Forward),
+            text
+        );
+        assert_eq!(
+            manager.simulate_scrolling_frame(text, 1.0, Direction::Backward),
+            text
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(true, Direction::Backward)]
+    #[case(false, Direction::Forward)]
+    fn test_direction_hint_ignored_unless_direction_aware(
+        #[case] direction_aware: bool,
+        #[case] expected: Direction,
+    ) {
+        let config = SlideshowTransitionConfig {
+            enabled: true,
+            effect: "scrolling_left".to_string(),
+            direction_aware,
+            easing: None,
+            ..Default::default()
+        };
+        let mut manager = TransitionManager::new(config);
+        let text1 = Text::from("Hello");
+        let text2 = Text::from("World");
+
+        assert!(manager.start_transition(&text1, &text2, Direction::Backward));
+        assert_eq!(manager.direction, expected);
+    }
+
+    #[rstest::rstest]
+    #[case(false, "  ab")]
+    #[case(true, "")]
+    fn test_scrolling_right_reverses_only_when_direction_aware(
+        #[case] direction_aware: bool,
+        #[case] expected: &str,
+    ) {
+        let manager = TransitionManager::new(SlideshowTransitionConfig {
+            enabled: true,
+            effect: "scrolling_right".to_string(),
+            direction_aware,
+            ..Default::default()
+        });
+        // 20 * (1 - 0.9) = 2 columns of shift
+        assert_eq!(manager.create_transition_frame("ab", 0.9), expected);
+    }
+
     #[test]
     fn test_text_to_string_simple() {
         let config = SlideshowTransitionConfig::default();
@@ -267,6 +621,9 @@ mod tests {
             enabled: false,
             effect: "scattering".to_string(),
             frame_duration_ms: 50,
+            frame_count: None,
+            direction_aware: false,
+            easing: None,
         };
         let mut manager = TransitionManager::new(initial_config);
 
@@ -274,6 +631,9 @@ mod tests {
             enabled: true,
             effect: "typewriter".to_string(),
             frame_duration_ms: 100,
+            frame_count: None,
+            direction_aware: false,
+            easing: None,
         };
 
         manager.update_config(new_config.clone());
@@ -292,13 +652,16 @@ mod tests {
             enabled: true,
             effect: "typewriter".to_string(),
             frame_duration_ms: 50,
+            frame_count: None,
+            direction_aware: false,
+            easing: None,
         };
         let mut manager = TransitionManager::new(config);
 
         let text1 = Text::from("Hello");
         let text2 = Text::from("World");
 
-        manager.start_transition(&text1, &text2);
+        manager.start_transition(&text1, &text2, Direction::Forward);
         assert!(manager.is_in_transition());
 
         manager.reset_transition();
@@ -311,13 +674,16 @@ mod tests {
             enabled: true,
             effect: "typewriter".to_string(),
             frame_duration_ms: 10, // Very fast for testing
+            frame_count: None,
+            direction_aware: false,
+            easing: None,
         };
         let mut manager = TransitionManager::new(config);
 
         let text1 = Text::from("Hello");
         let text2 = Text::from("World");
 
-        manager.start_transition(&text1, &text2);
+        manager.start_transition(&text1, &text2, Direction::Forward);
 
         // Should have frames available initially
         let first_frame = manager.get_current_transition_frame();
@@ -329,4 +695,133 @@ mod tests {
         // Transition should be complete (None returned)
         assert!(final_frame.is_none() || !manager.is_in_transition());
     }
+
+    #[rstest::rstest]
+    fn test_apply_easing_keeps_the_ends(
+        #[values("linear", "ease-in", "ease-out", "ease-in-out", "bounce")] easing: &str,
+    ) {
+        assert_eq!(apply_easing(0.0, easing), 0.0);
+        assert_eq!(apply_easing(1.0, easing), 1.0);
+    }
+
+    #[test]
+    fn test_apply_easing_curves() {
+        for t in [0.1, 0.25, 0.5, 0.75, 0.9] {
+            assert!(apply_easing(t, "ease-out") > apply_easing(t, "linear"));
+            assert!(apply_easing(t, "ease-in") < apply_easing(t, "linear"));
+        }
+        assert_eq!(apply_easing(0.5, "ease-in-out"), 0.5);
+        assert!(apply_easing(0.25, "ease-in-out") < 0.25);
+        assert!(apply_easing(0.75, "ease-in-out") > 0.75);
+    }
+
+    #[test]
+    fn test_easing_changes_the_prerendered_frames() {
+        let halfway_frame = |easing: &str| {
+            let mut manager = TransitionManager::new(SlideshowTransitionConfig {
+                enabled: true,
+                effect: "typewriter".to_string(),
+                frame_count: Some(10),
+                easing: Some(easing.to_string()),
+                ..Default::default()
+            });
+            manager.start_transition(
+                &Text::from("old"),
+                &Text::from("abcdefghij"),
+                Direction::Forward,
+            );
+            manager.cached_frames[5].to_string()
+        };
+        assert_eq!(halfway_frame("linear"), "abcde█");
+        assert_eq!(halfway_frame("ease-in"), "ab█");
+        assert_eq!(halfway_frame("ease-out"), "abcdefg█");
+    }
+
+    fn frame_chars(frame: &Text) -> Vec<char> {
+        frame
+            .lines
+            .iter()
+            .flat_map(|line| line.spans.iter())
+            .flat_map(|span| span.content.chars())
+            .collect()
+    }
+
+    fn all_effects() -> Vec<&'static str> {
+        vec![
+            "scattering",
+            "typewriter",
+            "scrolling_left",
+            "scrolling_right",
+            "climbing",
+            "fade",
+            "wipe_down",
+            "wipe_up",
+        ]
+    }
+
+    proptest! {
+        // Frame generation is cheap, so go well past the default 256 cases to find
+        // off-by-one errors in the character counts
+        #![proptest_config(ProptestConfig::with_cases(1000))]
+
+        #[test]
+        fn test_frames_only_show_the_two_images(
+            effect in prop::sample::select(all_effects()),
+            direction_aware in any::<bool>(),
+            backward in any::<bool>(),
+            from in "[a-zA-Z\n]{1,500}",
+            to in "[a-zA-Z\n]{1,500}",
+        ) {
+            let mut manager = TransitionManager::new(SlideshowTransitionConfig {
+                enabled: true,
+                effect: effect.to_string(),
+                direction_aware,
+                ..Default::default()
+            });
+            let direction = if backward { Direction::Backward } else { Direction::Forward };
+            manager.start_transition(&Text::from(from.clone()), &Text::from(to.clone()), direction);
+
+            let frame_count = manager.config.get_frame_count() as usize;
+            prop_assert_eq!(manager.cached_frames.len(), frame_count + 1);
+            // Effects only move, blank out or add a typewriter cursor to the characters
+            for frame in &manager.cached_frames {
+                for c in frame_chars(frame) {
+                    prop_assert!(
+                        from.contains(c) || to.contains(c) || c == ' ' || c == '█',
+                        "{:?} in a {} frame",
+                        c,
+                        effect
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn test_typewriter_frames_only_grow(
+            p1 in 0.0f32..=1.0f32,
+            p2 in 0.0f32..=1.0f32,
+            text in "[a-zA-Z\n]{1,500}",
+        ) {
+            let (p1, p2) = if p1 <= p2 { (p1, p2) } else { (p2, p1) };
+            let manager = TransitionManager::new(SlideshowTransitionConfig {
+                enabled: true,
+                effect: "typewriter".to_string(),
+                ..Default::default()
+            });
+            // Counted in characters: the cursor is three bytes and goes away at 1.0
+            let first = manager.create_transition_frame(&text, p1).chars().count();
+            let second = manager.create_transition_frame(&text, p2).chars().count();
+            prop_assert!(first <= second, "{} at {} but {} at {}", first, p1, second, p2);
+        }
+
+        #[test]
+        fn test_scattering_is_fully_revealed_at_end(text in "[a-zA-Z\n]{1,500}") {
+            let manager = TransitionManager::new(SlideshowTransitionConfig {
+                enabled: true,
+                effect: "scattering".to_string(),
+                ..Default::default()
+            });
+            prop_assert_eq!(manager.simulate_scattering_frame(&text, 1.0), text);
+        }
+    }
 }
@@ -1,24 +1,94 @@
+use crate::converter::{CharsetPicker, JP2A_CHARSETS};
 use crate::file_browser::FileBrowser;
+use crate::git::GitStatus;
+use crate::history::RecentFiles;
+use crate::icons::{FileCategory, FileIcons};
 use crate::localization::Localization;
-use crate::preview::PreviewContent;
+use crate::opener::OpenWithPicker;
+use crate::preview::{ContentAlignment, PreviewContent};
+use crate::theme::ThemeConfig;
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
-    text::Text,
-    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap},
+    text::{Line, Span, Text},
+    widgets::{
+        Block, Borders, Clear, List, ListItem, Paragraph, Scrollbar, ScrollbarOrientation,
+        ScrollbarState, Wrap,
+    },
 };
 use ratatui_image::{Resize, StatefulImage};
+use std::time::Duration;
 
 const WIDE_SCREEN_WIDTH_PERCENT: u16 = 10;
 const NARROW_SCREEN_WIDTH_PERCENT: u16 = 15;
 const NARROW_SCREEN_CHAR_CUTOFF: u16 = 120;
 
+// Each grid cell is 4 rows: a thumbnail with the file name underneath
+const GRID_CELL_HEIGHT: u16 = 4;
+pub const GRID_THUMBNAIL_HEIGHT: u16 = GRID_CELL_HEIGHT - 1;
+
+const SPINNER_FRAMES: [char; 8] = ['⣾', '⣽', '⣻', '⢿', '⡿', '⣟', '⣯', '⣷'];
+const SPINNER_FRAME_MS: u128 = 100;
+
+// Fraction of the slideshow delay after which the progress bar turns yellow
+const SLIDESHOW_PROGRESS_WARNING: f64 = 0.75;
+
+// The narrowest pane that has room for the file list's icon column
+const ICON_COLUMN_MIN_PANE_WIDTH: u16 = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ViewMode {
+    List,
+    Grid,
+}
+
+/// What the debug pane at the bottom of the screen shows, cycled with D
+#[derive(Debug, Clone, PartialEq)]
+pub enum DebugPaneContent {
+    FileInfo,
+    ConverterInfo,
+    CacheStats,
+    Messages,
+    Custom(String),
+}
+
+impl DebugPaneContent {
+    /// The next mode in the cycle. The custom text, when configured, comes after Messages.
+    pub fn next(&self, custom_text: Option<&str>) -> Self {
+        match self {
+            Self::FileInfo => Self::ConverterInfo,
+            Self::ConverterInfo => Self::CacheStats,
+            Self::CacheStats => Self::Messages,
+            Self::Messages => match custom_text {
+                Some(text) => Self::Custom(text.to_string()),
+                None => Self::FileInfo,
+            },
+            Self::Custom(_) => Self::FileInfo,
+        }
+    }
+
+    pub fn title_key(&self) -> &'static str {
+        match self {
+            Self::FileInfo => "debug_pane_file_info",
+            Self::ConverterInfo => "debug_pane_converter_info",
+            Self::CacheStats => "debug_pane_cache_stats",
+            Self::Messages => "messages",
+            Self::Custom(_) => "debug_pane_custom",
+        }
+    }
+}
+
 pub struct UILayout {
     pub preview_size: u16,
     pub min_divider_percent: u16,
     pub preview_width: u16,
     pub preview_height: u16,
+    pub fullscreen_preview: bool,
+    pub view_mode: ViewMode,
+    // File browser widths from the config, used instead of the defaults when set
+    wide_width_percent: Option<u16>,
+    narrow_width_percent: Option<u16>,
 }
 
 impl Default for UILayout {
@@ -34,15 +104,46 @@ impl UILayout {
             min_divider_percent: 10,
             preview_width: 0,
             preview_height: 0,
+            fullscreen_preview: false,
+            view_mode: ViewMode::List,
+            wide_width_percent: None,
+            narrow_width_percent: None,
+        }
+    }
+
+    /// Use configured file browser widths for wide and narrow screens. A change
+    /// puts the divider back at the new width on the next draw.
+    pub fn set_file_browser_width_percents(&mut self, wide: Option<u16>, narrow: Option<u16>) {
+        if (wide, narrow) != (self.wide_width_percent, self.narrow_width_percent) {
+            self.wide_width_percent = wide;
+            self.narrow_width_percent = narrow;
+            self.preview_size = 0;
         }
     }
 
+    pub fn toggle_view_mode(&mut self) {
+        self.view_mode = match self.view_mode {
+            ViewMode::List => ViewMode::Grid,
+            ViewMode::Grid => ViewMode::List,
+        };
+    }
+
+    /// Split the file browser column into two equal panes for dual-pane mode
+    pub fn split_file_area(file_area: Rect) -> (Rect, Rect) {
+        let panes = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+            .split(file_area);
+        (panes[0], panes[1])
+    }
+
     pub fn calculate_layout(&mut self, area: Rect) -> (Rect, Rect, Rect) {
         // Determine file browser width based on screen size
         let file_browser_width = if area.width > NARROW_SCREEN_CHAR_CUTOFF {
-            WIDE_SCREEN_WIDTH_PERCENT
+            self.wide_width_percent.unwrap_or(WIDE_SCREEN_WIDTH_PERCENT)
         } else {
-            NARROW_SCREEN_WIDTH_PERCENT
+            self.narrow_width_percent
+                .unwrap_or(NARROW_SCREEN_WIDTH_PERCENT)
         };
 
         self.min_divider_percent = file_browser_width;
@@ -52,6 +153,25 @@ impl UILayout {
             self.preview_size = file_browser_width;
         }
 
+        // Full-screen preview: hide the file browser and leave one row for the status line
+        if self.fullscreen_preview {
+            let status_height = area.height.min(1);
+            let preview_area = Rect {
+                height: area.height.saturating_sub(status_height),
+                ..area
+            };
+            let status_area = Rect {
+                y: area.y + preview_area.height,
+                height: status_height,
+                ..area
+            };
+
+            self.preview_width = preview_area.width.saturating_sub(2);
+            self.preview_height = preview_area.height.saturating_sub(1);
+
+            return (Rect::new(area.x, area.y, 0, 0), preview_area, status_area);
+        }
+
         // Main vertical layout with debug pane at bottom
         // Use flexible debug pane height for small screens
         let debug_height = if area.height > 10 { 3 } else { 1 };
@@ -63,6 +183,14 @@ impl UILayout {
             ])
             .split(area);
 
+        // Grid view: thumbnails take the whole content area and replace the preview pane
+        if self.view_mode == ViewMode::Grid {
+            let preview_area = Rect::new(main_chunks[0].right(), main_chunks[0].y, 0, 0);
+            self.preview_width = 0;
+            self.preview_height = 0;
+            return (main_chunks[0], preview_area, main_chunks[1]);
+        }
+
         // Horizontal layout for file browser and preview
         let content_chunks = Layout::default()
             .direction(Direction::Horizontal)
@@ -124,34 +252,124 @@ fn centered_rect(width: u16, height: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
+/// Slideshow details shown in the status bar
+#[derive(Debug, Clone, Default)]
+pub struct SlideshowStatus {
+    pub current_image: usize,
+    pub total_images: usize,
+    pub shuffle: bool,
+    pub looping: bool,
+    pub delay: Duration,
+    /// Shown below the image when the filename overlay is on
+    pub file_name: Option<String>,
+    /// Time since the current image was shown, for the progress bar
+    pub elapsed: Duration,
+    pub show_progress_bar: bool,
+    /// A short-lived message, like a saved frame, shown instead of the status line
+    pub notice: Option<String>,
+}
+
+/// Rows the slideshow status bar takes, borders and the shortcut hints included
+pub fn slideshow_status_height(show_progress_bar: bool) -> u16 {
+    if show_progress_bar { 5 } else { 4 }
+}
+
+/// Cells of the slideshow progress bar to fill. Takes the elapsed time rather than
+/// reading a clock, so a paused slideshow can freeze the bar by passing the time it
+/// stopped at.
+pub fn slideshow_progress_fill(elapsed: Duration, delay: Duration, width: u16) -> u16 {
+    if delay.is_zero() {
+        return width;
+    }
+    let fraction = (elapsed.as_secs_f64() / delay.as_secs_f64()).clamp(0.0, 1.0);
+    (fraction * width as f64) as u16
+}
+
 pub struct UIRenderer;
 
 impl UIRenderer {
+    /// Cells taken by the widest icon the file list's icon column can show
+    fn icon_column_width(icons: &FileIcons, theme: &ThemeConfig) -> usize {
+        FileCategory::ALL
+            .iter()
+            .map(|&category| theme.category_icon(icons, category))
+            .chain(std::iter::once(icons.directory.as_str()))
+            .map(|icon| Span::raw(icon).width())
+            .max()
+            .unwrap_or(0)
+    }
+
     pub fn render_file_browser(
         f: &mut Frame,
         area: Rect,
         file_browser: &mut FileBrowser,
         is_selected_highlighted: bool,
+        icons: &FileIcons,
+        theme: &ThemeConfig,
+        localization: &Localization,
     ) {
         // Calculate visible file list dimensions and update browser
         let file_list_height = area.height.saturating_sub(2);
         file_browser.update_max_visible_files(file_list_height as usize);
+        let show_icon_column = area.width >= ICON_COLUMN_MIN_PANE_WIDTH;
+        let icon_column_width = if show_icon_column {
+            file_browser.update_visible_categories();
+            Self::icon_column_width(icons, theme)
+        } else {
+            0
+        };
 
         let file_list_items: Vec<ListItem> = file_browser
             .get_display_files()
             .map(|(i, file)| {
-                let content = if file.is_directory {
-                    format!("📁 {}", file.name)
+                let mut spans = Vec::new();
+                if show_icon_column {
+                    let icon = if file.is_directory {
+                        icons.directory.as_str()
+                    } else {
+                        let category = file_browser
+                            .get_category(file)
+                            .unwrap_or(FileCategory::Unknown);
+                        theme.category_icon(icons, category)
+                    };
+                    // Pad narrow icons so names line up whichever icon is in front
+                    let padding = icon_column_width.saturating_sub(Span::raw(icon).width());
+                    spans.push(Span::raw(format!("{}{} ", icon, " ".repeat(padding))));
                 } else {
-                    format!("🖼️ {}", file.name)
-                };
+                    // No room to line names up, so just the icon for the kind of file
+                    spans.push(Span::raw(format!("{} ", icons.icon_for(file))));
+                }
+                if let Some(status) = file_browser.get_git_status(file) {
+                    spans.push(Span::styled(
+                        format!("{} ", status.indicator()),
+                        Style::default().fg(Self::git_status_color(status)),
+                    ));
+                }
+                if file_browser.shows_permissions() {
+                    spans.push(Span::styled(
+                        format!("{} ", file.owner_permissions()),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+                spans.push(Span::raw(file.name.clone()));
+                if file.is_symlink {
+                    spans.push(Span::raw(" ↗"));
+                }
+                if let Some(count) = file_browser.get_image_count(file) {
+                    use fluent::fluent_args;
+                    let args = fluent_args!["count" => count];
+                    let images = localization.get_with_args("directory_image_count", Some(&args));
+                    spans.push(Span::raw(format!(" ({})", images)));
+                }
+                let content = Line::from(spans);
 
                 let style = if i == file_browser.selected_index && is_selected_highlighted {
                     Style::default()
-                        .fg(Color::Yellow)
+                        .fg(theme.selected_fg())
+                        .bg(theme.selected_bg())
                         .add_modifier(Modifier::BOLD)
                 } else {
-                    Style::default()
+                    Style::default().fg(theme.file_fg(file))
                 };
 
                 ListItem::new(content).style(style)
@@ -161,7 +379,12 @@ impl UIRenderer {
         let file_list = List::new(file_list_items)
             .block(
                 Block::default()
-                    .title(format!("📁 {}", file_browser.get_current_dir_display()))
+                    .title(format!(
+                        "{} {}{}",
+                        icons.directory,
+                        file_browser.get_current_dir_display(),
+                        file_browser.content_filter.title_suffix()
+                    ))
                     .borders(Borders::ALL),
             )
             .highlight_style(Style::default().bg(Color::Blue));
@@ -169,42 +392,160 @@ impl UIRenderer {
         f.render_widget(file_list, area);
     }
 
+    fn git_status_color(status: GitStatus) -> Color {
+        match status {
+            GitStatus::Modified => Color::Magenta,
+            GitStatus::Staged => Color::Green,
+            GitStatus::Untracked => Color::Red,
+            GitStatus::Ignored => Color::DarkGray,
+        }
+    }
+
+    /// Number of (columns, rows) of thumbnail cells that fit in the grid area
+    pub fn grid_dimensions(area: Rect, cell_width: u16) -> (usize, usize) {
+        let inner_width = area.width.saturating_sub(2);
+        let inner_height = area.height.saturating_sub(2);
+        let columns = (inner_width / cell_width.max(1)).max(1);
+        let rows = (inner_height / GRID_CELL_HEIGHT).max(1);
+        (columns as usize, rows as usize)
+    }
+
+    /// Render the file list as a grid of thumbnails. Files without a thumbnail
+    /// (directories, non-images, graphical converters) fall back to an icon.
+    pub fn render_file_grid(
+        f: &mut Frame,
+        area: Rect,
+        file_browser: &FileBrowser,
+        thumbnails: &[Option<Text<'static>>],
+        cell_width: u16,
+        icons: &FileIcons,
+    ) {
+        let grid_block = Block::default()
+            .title(format!(
+                "{} {}{}",
+                icons.directory,
+                file_browser.get_current_dir_display(),
+                file_browser.content_filter.title_suffix()
+            ))
+            .borders(Borders::ALL);
+        let inner_area = grid_block.inner(area);
+        f.render_widget(grid_block, area);
+
+        let (columns, _) = Self::grid_dimensions(area, cell_width);
+        let cell_width = inner_area.width / columns as u16;
+
+        for (position, (i, file)) in file_browser.get_display_files().enumerate() {
+            let column = (position % columns) as u16;
+            let row = (position / columns) as u16;
+            let cell_y = inner_area.y + row * GRID_CELL_HEIGHT;
+            if cell_y + GRID_CELL_HEIGHT > inner_area.bottom() {
+                break;
+            }
+
+            let thumbnail_area = Rect::new(
+                inner_area.x + column * cell_width,
+                cell_y,
+                cell_width.saturating_sub(1),
+                GRID_THUMBNAIL_HEIGHT,
+            );
+            let name_area = Rect::new(
+                thumbnail_area.x,
+                cell_y + GRID_THUMBNAIL_HEIGHT,
+                thumbnail_area.width,
+                1,
+            );
+
+            let thumbnail = match thumbnails.get(position) {
+                Some(Some(text)) => text.clone(),
+                _ => {
+                    let icon = icons.icon_for(file).to_string();
+                    let padding =
+                        vec![ratatui::text::Line::from(""); (GRID_THUMBNAIL_HEIGHT / 2) as usize];
+                    let mut text = Text::from(padding);
+                    text.lines.push(ratatui::text::Line::from(icon));
+                    text
+                }
+            };
+            f.render_widget(
+                Paragraph::new(thumbnail).alignment(Alignment::Center),
+                thumbnail_area,
+            );
+
+            let name_style = if i == file_browser.selected_index {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+            } else {
+                Style::default()
+            };
+            f.render_widget(
+                Paragraph::new(file.name.clone())
+                    .style(name_style)
+                    .alignment(Alignment::Center),
+                name_area,
+            );
+        }
+    }
+
+    /// `text_scroll` is the scroll offset and line count of a text file, which
+    /// gets a scrollbar down the right of the pane
+    #[allow(clippy::too_many_arguments)]
     pub fn render_preview(
         f: &mut Frame,
         area: Rect,
         preview_content: Option<&PreviewContent>,
         localization: &Localization,
         ascii_logo: Option<&Text<'static>>,
-        is_text_file: bool,
+        help_scroll_offset: usize,
+        icons: &FileIcons,
+        text_scroll: Option<(usize, usize)>,
     ) {
         // Clear the preview area first to prevent artifacts when switching between text files
         use ratatui::widgets::Clear;
         f.render_widget(Clear, area);
 
         match preview_content {
-            Some(PreviewContent::Text(text)) => {
+            Some(PreviewContent::Text(text, alignment)) => {
                 let preview_block = Block::default()
-                    .title(format!("🖼️ {}", localization.get("image_preview")))
+                    .title(format!(
+                        "{} {}",
+                        icons.image,
+                        localization.get("image_preview")
+                    ))
                     .borders(Borders::ALL);
 
-                // For text previews, left-align them to avoid centering regular text files
-                // This prevents regular text files from being centered while preserving
-                // the previous behavior for ASCII art (which may still be centered via other means)
-                let alignment = if is_text_file {
-                    Alignment::Left
+                // Text files read from the left; ASCII art stays centred both ways
+                let content = if *alignment == ContentAlignment::Image {
+                    Self::center_vertically(text, area.height.saturating_sub(2))
                 } else {
-                    Alignment::Center
+                    text.clone()
                 };
-                let preview_paragraph = Paragraph::new(text.clone())
-                    .block(preview_block)
+                let preview_paragraph = Paragraph::new(content)
                     .wrap(Wrap { trim: false })
-                    .alignment(alignment);
+                    .alignment(alignment.alignment());
 
-                f.render_widget(preview_paragraph, area);
+                let inner_area = preview_block.inner(area);
+                f.render_widget(preview_block, area);
+                match text_scroll {
+                    Some((position, total_lines)) if total_lines > 0 && !text.lines.is_empty() => {
+                        // Give the last column to the scrollbar
+                        let text_area = Rect {
+                            width: inner_area.width.saturating_sub(1),
+                            ..inner_area
+                        };
+                        f.render_widget(preview_paragraph, text_area);
+                        Self::render_text_scrollbar(f, inner_area, position, total_lines);
+                    }
+                    _ => f.render_widget(preview_paragraph, inner_area),
+                }
             }
             Some(PreviewContent::Graphical(graphical)) => {
                 let preview_block = Block::default()
-                    .title(format!("🖼️ {}", localization.get("image_preview")))
+                    .title(format!(
+                        "{} {}",
+                        icons.image,
+                        localization.get("image_preview")
+                    ))
                     .borders(Borders::ALL);
 
                 // Render block first
@@ -235,10 +576,9 @@ impl UIRenderer {
                     let font_height = (graphical_borrow.font_size.1 as u32).max(1);
 
                     // Calculate how many cells the resized image needs
-                    let needed_width_cells =
-                        ((graphical_borrow.img_width + font_width - 1) / font_width) as u16;
+                    let needed_width_cells = graphical_borrow.img_width.div_ceil(font_width) as u16;
                     let needed_height_cells =
-                        ((graphical_borrow.img_height + font_height - 1) / font_height) as u16;
+                        graphical_borrow.img_height.div_ceil(font_height) as u16;
 
                     // Clamp to available area
                     let width = needed_width_cells.min(inner_area.width);
@@ -329,14 +669,35 @@ impl UIRenderer {
                     &mut graphical_borrow.protocol,
                 );
             }
-            Some(PreviewContent::Kitty(_)) => {
+            Some(PreviewContent::Kitty(_)) | Some(PreviewContent::Inline(_)) => {
                 // Fast Kitty rendering - just draw the border block here
                 // The actual image is rendered in render_kitty_post_draw() AFTER ratatui flushes
                 let preview_block = Block::default()
-                    .title(format!("🖼️ {}", localization.get("image_preview")))
+                    .title(format!(
+                        "{} {}",
+                        icons.image,
+                        localization.get("image_preview")
+                    ))
                     .borders(Borders::ALL);
                 f.render_widget(preview_block, area);
             }
+            Some(PreviewContent::Animated { frames, .. }) => {
+                // The app normally passes the current frame as text; fall back to the first one
+                let first_frame = PreviewContent::Text(
+                    frames.first().cloned().unwrap_or_default(),
+                    ContentAlignment::Image,
+                );
+                Self::render_preview(
+                    f,
+                    area,
+                    Some(&first_frame),
+                    localization,
+                    ascii_logo,
+                    help_scroll_offset,
+                    icons,
+                    None,
+                );
+            }
             None => {
                 // Show help text with logo if available
                 let help_text = localization.get_help_text();
@@ -360,19 +721,78 @@ impl UIRenderer {
                 };
 
                 let preview_block = Block::default()
-                    .title(format!("🖼️ {}", localization.get("image_preview")))
+                    .title(format!(
+                        "{} {}",
+                        icons.image,
+                        localization.get("image_preview")
+                    ))
                     .borders(Borders::ALL);
 
                 let preview_paragraph = Paragraph::new(content)
                     .block(preview_block)
                     .wrap(Wrap { trim: false })
-                    .alignment(Alignment::Left);
+                    .alignment(ContentAlignment::Help.alignment())
+                    .scroll((help_scroll_offset.min(u16::MAX as usize) as u16, 0));
 
                 f.render_widget(preview_paragraph, area);
             }
         }
     }
 
+    /// A vertical scrollbar down the right column of `area`, placed by how far
+    /// through `total_lines` the text has been scrolled
+    fn render_text_scrollbar(f: &mut Frame, area: Rect, position: usize, total_lines: usize) {
+        let mut state =
+            ScrollbarState::new(total_lines).position(position.min(total_lines.saturating_sub(1)));
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .style(Style::default().fg(Color::DarkGray));
+        f.render_stateful_widget(scrollbar, area, &mut state);
+    }
+
+    /// Pick the spinner character for the given time spent converting
+    pub fn spinner_frame(elapsed: Duration) -> char {
+        let index =
+            (elapsed.as_millis() / SPINNER_FRAME_MS % SPINNER_FRAMES.len() as u128) as usize;
+        SPINNER_FRAMES[index]
+    }
+
+    /// Draw a spinner in the middle of the preview area while a conversion is running
+    pub fn render_loading_spinner(f: &mut Frame, area: Rect, file_name: &str, elapsed: Duration) {
+        // Stay inside the preview block's borders
+        let inner_area = Rect {
+            x: area.x + 1,
+            y: area.y + 1,
+            width: area.width.saturating_sub(2),
+            height: area.height.saturating_sub(2),
+        };
+        let spinner_area = centered_rect(inner_area.width, 2.min(inner_area.height), inner_area);
+
+        let content = Text::from(vec![
+            ratatui::text::Line::from(Self::spinner_frame(elapsed).to_string()).style(
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            ratatui::text::Line::from(file_name.to_string())
+                .style(Style::default().fg(Color::DarkGray)),
+        ]);
+
+        let spinner_paragraph = Paragraph::new(content).alignment(Alignment::Center);
+        f.render_widget(spinner_paragraph, spinner_area);
+    }
+
+    /// Pad `text` with empty lines above so it sits in the middle of `height` rows.
+    /// Text taller than that is returned as it is.
+    fn center_vertically(text: &Text<'static>, height: u16) -> Text<'static> {
+        let content_lines = text.lines.len().min(u16::MAX as usize) as u16;
+        let empty_top = height.saturating_sub(content_lines) / 2;
+        let mut centered = text.clone();
+        centered
+            .lines
+            .splice(0..0, (0..empty_top).map(|_| Line::from("")));
+        centered
+    }
+
     fn localize_logo_text(logo: &Text<'static>, localization: &Localization) -> Text<'static> {
         let mut localized_logo = Text::default();
 
@@ -408,14 +828,35 @@ impl UIRenderer {
         f: &mut Frame,
         area: Rect,
         debug_info: &str,
+        mode: &DebugPaneContent,
+        avg_color: Option<(u8, u8, u8)>,
         localization: &Localization,
     ) {
+        use fluent::fluent_args;
+
         let debug_block = Block::default()
-            .title(format!("🔍 {}", localization.get("messages")))
+            .title(format!("🔍 {}", localization.get(mode.title_key())))
             .borders(Borders::ALL)
             .style(Style::default().fg(Color::Cyan));
 
-        let debug_text = Paragraph::new(debug_info.to_string())
+        // The average colour goes on the end with a swatch painted in it
+        let mut text = Text::from(debug_info.to_string());
+        if let Some((r, g, b)) = avg_color {
+            let args = fluent_args!["hex" => format!("#{:02X}{:02X}{:02X}", r, g, b)];
+            let swatch = [
+                Span::raw(format!(
+                    " | {} ",
+                    localization.get_with_args("debug_average_color", Some(&args))
+                )),
+                Span::styled(" ", Style::default().bg(Color::Rgb(r, g, b))),
+            ];
+            match text.lines.last_mut() {
+                Some(line) => line.spans.extend(swatch),
+                None => text.lines.push(Line::from(swatch.to_vec())),
+            }
+        }
+
+        let debug_text = Paragraph::new(text)
             .block(debug_block)
             .style(Style::default().fg(Color::Gray));
 
@@ -427,25 +868,35 @@ impl UIRenderer {
         area: Rect,
         preview_content: Option<&PreviewContent>,
         localization: &Localization,
-        current_image: usize,
-        total_images: usize,
+        status: &SlideshowStatus,
     ) {
         // Create full-screen slideshow layout with status bar at bottom
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Min(1),    // Image area
-                Constraint::Length(3), // Status bar
+                Constraint::Min(1), // Image area
+                Constraint::Length(slideshow_status_height(status.show_progress_bar)),
             ])
             .split(area);
 
+        // The filename overlay takes its own row below the image so it never covers it
+        let (image_area, filename_area) = if status.file_name.is_some() {
+            let image_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(1), Constraint::Length(1)])
+                .split(chunks[0]);
+            (image_chunks[0], Some(image_chunks[1]))
+        } else {
+            (chunks[0], None)
+        };
+
         // Render the image in full screen
         match preview_content {
-            Some(PreviewContent::Text(text)) => {
+            Some(PreviewContent::Text(text, _)) => {
                 let image_paragraph = Paragraph::new(text.clone())
                     .block(Block::default().borders(Borders::NONE))
                     .alignment(Alignment::Center);
-                f.render_widget(image_paragraph, chunks[0]);
+                f.render_widget(image_paragraph, image_area);
             }
             Some(PreviewContent::Graphical(graphical)) => {
                 // Use the cached protocol - no recreation needed!
@@ -453,61 +904,63 @@ impl UIRenderer {
 
                 // Calculate centered area
                 use crate::preview::TerminalGraphicsSupport;
-                let centered_area =
-                    if graphical_borrow.protocol_type == TerminalGraphicsSupport::Iterm2 {
-                        // iTerm2: Calculate exact cell dimensions
-                        // Guard against division by zero with fallback values
-                        let font_width = (graphical_borrow.font_size.0 as u32).max(1);
-                        let font_height = (graphical_borrow.font_size.1 as u32).max(1);
-
-                        let needed_width_cells =
-                            ((graphical_borrow.img_width + font_width - 1) / font_width) as u16;
-                        let needed_height_cells =
-                            ((graphical_borrow.img_height + font_height - 1) / font_height) as u16;
-
-                        let width = needed_width_cells.min(chunks[0].width);
-                        let height = needed_height_cells.min(chunks[0].height);
-
-                        let x_offset = (chunks[0].width.saturating_sub(width)) / 2;
-                        let y_offset = (chunks[0].height.saturating_sub(height)) / 2;
-
-                        Rect {
-                            x: chunks[0].x + x_offset,
-                            y: chunks[0].y + y_offset,
-                            width,
-                            height,
-                        }
-                    } else {
-                        // Kitty/Ghostty: Fill vertical space, center horizontally
-                        // Guard against division by zero with fallback values
-                        let img_height = graphical_borrow.img_height.max(1) as f32;
-                        let img_aspect = graphical_borrow.img_width as f32 / img_height;
-                        let font_width = (graphical_borrow.font_size.0 as f32).max(1.0);
-                        let font_height = (graphical_borrow.font_size.1 as f32).max(1.0);
-                        let char_aspect = font_height / font_width;
-
-                        let display_width = (chunks[0].height as f32 * img_aspect * char_aspect) as u16;
-
-                        let (width, height) = if display_width <= chunks[0].width {
-                            (display_width, chunks[0].height)
-                        } else {
-                            // Guard against division by zero
-                            let safe_aspect = img_aspect.max(0.001);
-                            let safe_char_aspect = char_aspect.max(0.001);
-                            let display_height = (chunks[0].width as f32 / safe_aspect / safe_char_aspect) as u16;
-                            (chunks[0].width, display_height.min(chunks[0].height))
-                        };
+                let centered_area = if graphical_borrow.protocol_type
+                    == TerminalGraphicsSupport::Iterm2
+                {
+                    // iTerm2: Calculate exact cell dimensions
+                    // Guard against division by zero with fallback values
+                    let font_width = (graphical_borrow.font_size.0 as u32).max(1);
+                    let font_height = (graphical_borrow.font_size.1 as u32).max(1);
+
+                    let needed_width_cells = graphical_borrow.img_width.div_ceil(font_width) as u16;
+                    let needed_height_cells =
+                        graphical_borrow.img_height.div_ceil(font_height) as u16;
 
-                        let x_offset = (chunks[0].width.saturating_sub(width)) / 2;
+                    let width = needed_width_cells.min(image_area.width);
+                    let height = needed_height_cells.min(image_area.height);
 
-                        Rect {
-                            x: chunks[0].x + x_offset,
-                            y: chunks[0].y,
-                            width,
-                            height,
-                        }
+                    let x_offset = (image_area.width.saturating_sub(width)) / 2;
+                    let y_offset = (image_area.height.saturating_sub(height)) / 2;
+
+                    Rect {
+                        x: image_area.x + x_offset,
+                        y: image_area.y + y_offset,
+                        width,
+                        height,
+                    }
+                } else {
+                    // Kitty/Ghostty: Fill vertical space, center horizontally
+                    // Guard against division by zero with fallback values
+                    let img_height = graphical_borrow.img_height.max(1) as f32;
+                    let img_aspect = graphical_borrow.img_width as f32 / img_height;
+                    let font_width = (graphical_borrow.font_size.0 as f32).max(1.0);
+                    let font_height = (graphical_borrow.font_size.1 as f32).max(1.0);
+                    let char_aspect = font_height / font_width;
+
+                    let display_width =
+                        (image_area.height as f32 * img_aspect * char_aspect) as u16;
+
+                    let (width, height) = if display_width <= image_area.width {
+                        (display_width, image_area.height)
+                    } else {
+                        // Guard against division by zero
+                        let safe_aspect = img_aspect.max(0.001);
+                        let safe_char_aspect = char_aspect.max(0.001);
+                        let display_height =
+                            (image_area.width as f32 / safe_aspect / safe_char_aspect) as u16;
+                        (image_area.width, display_height.min(image_area.height))
                     };
 
+                    let x_offset = (image_area.width.saturating_sub(width)) / 2;
+
+                    Rect {
+                        x: image_area.x + x_offset,
+                        y: image_area.y,
+                        width,
+                        height,
+                    }
+                };
+
                 let image_widget = StatefulImage::new().resize(Resize::Scale(None));
                 f.render_stateful_widget(
                     image_widget,
@@ -515,36 +968,94 @@ impl UIRenderer {
                     &mut graphical_borrow.protocol,
                 );
             }
-            Some(PreviewContent::Kitty(_)) => {
+            Some(PreviewContent::Kitty(_)) | Some(PreviewContent::Inline(_)) => {
                 // Fast Kitty rendering - image is rendered in render_kitty_post_draw()
                 // after ratatui's frame is flushed, so nothing to do here
             }
+            Some(PreviewContent::Animated { frames, .. }) => {
+                if let Some(frame) = frames.first() {
+                    let image_paragraph = Paragraph::new(frame.clone())
+                        .block(Block::default().borders(Borders::NONE))
+                        .alignment(Alignment::Center);
+                    f.render_widget(image_paragraph, image_area);
+                }
+            }
             None => {
                 let content = Text::from(localization.get("no_file_selected"));
                 let image_paragraph = Paragraph::new(content)
                     .block(Block::default().borders(Borders::NONE))
                     .alignment(Alignment::Center);
-                f.render_widget(image_paragraph, chunks[0]);
+                f.render_widget(image_paragraph, image_area);
             }
         }
 
+        if let (Some(file_name), Some(filename_area)) = (&status.file_name, filename_area) {
+            use fluent::fluent_args;
+            let args = fluent_args!["file" => file_name.as_str()];
+            let overlay = Paragraph::new(
+                localization.get_with_args("slideshow_filename_overlay", Some(&args)),
+            )
+            .style(
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .alignment(Alignment::Left);
+            f.render_widget(overlay, filename_area);
+        }
+
         // Render status bar - clear first to avoid artifacts from Kitty graphics
         f.render_widget(Clear, chunks[1]);
 
+        let shuffle_icon = if status.shuffle { "🔀 " } else { "" };
+        let loop_status = if status.looping {
+            localization.get("slideshow_loop_on")
+        } else {
+            localization.get("slideshow_loop_off")
+        };
+        use fluent::fluent_args;
+        let delay_args = fluent_args!["seconds" => format!("{:.1}", status.delay.as_secs_f32())];
+        let image_args = fluent_args![
+            "current" => status.current_image,
+            "total" => status.total_images
+        ];
         let status_text = format!(
-            "[>] {} | {} {}/{} | {}",
+            "[>] {} | {}{} | {} | {}",
             localization.get("slideshow_mode"),
-            localization.get("slideshow_image"),
-            current_image,
-            total_images,
-            localization.get("slideshow_press_any_key")
+            shuffle_icon,
+            localization.get_with_args("slideshow_image_of", Some(&image_args)),
+            loop_status,
+            localization.get_with_args("slideshow_delay", Some(&delay_args))
         );
 
         let status_block = Block::default()
             .borders(Borders::ALL)
             .style(Style::default().fg(Color::Yellow));
 
-        let status_paragraph = Paragraph::new(status_text)
+        let status_line = status.notice.clone().unwrap_or(status_text);
+        let mut status_lines = vec![Line::from(status_line)];
+        if status.show_progress_bar {
+            let width = chunks[1].width.saturating_sub(2);
+            let filled = slideshow_progress_fill(status.elapsed, status.delay, width);
+            // Yellow for the last quarter, just before the image changes
+            let color = if status.elapsed.as_secs_f64()
+                > status.delay.as_secs_f64() * SLIDESHOW_PROGRESS_WARNING
+            {
+                Color::Yellow
+            } else {
+                Color::Green
+            };
+            status_lines.push(Line::from(Span::styled(
+                format!(
+                    "{}{}",
+                    "▰".repeat(filled as usize),
+                    "▱".repeat((width - filled) as usize)
+                ),
+                Style::default().fg(color),
+            )));
+        }
+
+        let status_paragraph = Paragraph::new(status_lines)
             .block(status_block)
             .alignment(Alignment::Center)
             .style(
@@ -554,12 +1065,94 @@ impl UIRenderer {
             );
 
         f.render_widget(status_paragraph, chunks[1]);
+
+        // The shortcut hints take the last row inside the border
+        let hints_area = Rect {
+            x: chunks[1].x + 1,
+            y: (chunks[1].y + chunks[1].height).saturating_sub(2),
+            width: chunks[1].width.saturating_sub(2),
+            height: 1,
+        };
+        Self::render_slideshow_hints(f, hints_area, localization, status.shuffle, status.looping);
+    }
+
+    /// One line listing the slideshow keys. Keys are cyan, descriptions gray, and the
+    /// shuffle and loop descriptions turn green while those modes are on.
+    pub fn render_slideshow_hints(
+        f: &mut Frame,
+        area: Rect,
+        localization: &Localization,
+        is_shuffle_active: bool,
+        is_loop_active: bool,
+    ) {
+        let key_style = Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD);
+        let description_style = Style::default().fg(Color::Gray);
+        let active_style = Style::default().fg(Color::Green);
+        let hints = [
+            ("←", " ", "slideshow_hint_prev", false),
+            ("→", " ", "slideshow_hint_next", false),
+            ("space", ": ", "slideshow_hint_exit", false),
+            ("z", ": ", "slideshow_hint_shuffle", is_shuffle_active),
+            ("l", ": ", "slideshow_hint_loop", is_loop_active),
+            ("t", ": ", "slideshow_hint_name", false),
+            ("+/-", ": ", "slideshow_hint_speed", false),
+        ];
+
+        let mut spans = Vec::new();
+        for (i, (key, separator, description, active)) in hints.into_iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::styled(" | ", Style::default().fg(Color::DarkGray)));
+            }
+            spans.push(Span::styled(key, key_style));
+            spans.push(Span::styled(
+                format!("{}{}", separator, localization.get(description)),
+                if active {
+                    active_style
+                } else {
+                    description_style
+                },
+            ));
+        }
+
+        f.render_widget(
+            Paragraph::new(Line::from(spans)).alignment(Alignment::Center),
+            area,
+        );
+    }
+
+    pub fn render_fullscreen_status(
+        f: &mut Frame,
+        area: Rect,
+        localization: &Localization,
+        file_name: &str,
+        current_file: usize,
+        total_files: usize,
+    ) {
+        let status_text = format!(
+            "[F] {} | {} {}/{}",
+            localization.get("fullscreen_mode"),
+            file_name,
+            current_file,
+            total_files
+        );
+
+        let status_paragraph = Paragraph::new(status_text).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        f.render_widget(Clear, area);
+        f.render_widget(status_paragraph, area);
     }
 
     pub fn render_delete_confirmation_dialog(
         f: &mut Frame,
         area: Rect,
         file_name: &str,
+        use_trash: bool,
         localization: &Localization,
     ) {
         use fluent::fluent_args;
@@ -578,7 +1171,12 @@ impl UIRenderer {
 
         // Create the dialog message with the file name
         let args = fluent_args!["file" => file_name];
-        let prompt = localization.get_with_args("delete_file_prompt", Some(&args));
+        let prompt_key = if use_trash {
+            "trash_file_prompt"
+        } else {
+            "delete_file_prompt"
+        };
+        let prompt = localization.get_with_args(prompt_key, Some(&args));
         let instructions = localization.get("delete_confirmation_instructions");
 
         let confirmation_text = format!("{}\n\n{}", prompt, instructions);
@@ -598,61 +1196,380 @@ impl UIRenderer {
 
         f.render_widget(dialog_paragraph, popup_area);
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::test_utils::helpers::*;
-    use ratatui::layout::Rect;
-    use ratatui::text::Text;
 
-    #[test]
-    fn test_ui_layout_creation() {
-        let layout = UILayout::new();
-        assert_eq!(layout.preview_size, 0);
-        assert_eq!(layout.min_divider_percent, 10);
-        assert_eq!(layout.preview_width, 0);
-        assert_eq!(layout.preview_height, 0);
-    }
+    /// Dialog offering to copy or move a file to the other pane's directory
+    pub fn render_transfer_dialog(
+        f: &mut Frame,
+        area: Rect,
+        file_name: &str,
+        target_dir: &str,
+        localization: &Localization,
+    ) {
+        use fluent::fluent_args;
 
-    #[test]
-    fn test_ui_layout_calculate_layout_wide_screen() {
-        let mut layout = UILayout::new();
-        let area = Rect::new(0, 0, 150, 50);
+        let dialog_width = 60.min(area.width.saturating_sub(4));
+        let dialog_height = 6.min(area.height.saturating_sub(4));
+        let popup_area = centered_rect(dialog_width, dialog_height, area);
+        f.render_widget(Clear, popup_area);
 
-        let (file_area, preview_area, debug_area) = layout.calculate_layout(area);
+        let file_args = fluent_args!["file" => file_name];
+        let dir_args = fluent_args!["dir" => target_dir];
+        let text = format!(
+            "{}\n{}\n{}",
+            localization.get_with_args("copy_to_secondary", Some(&dir_args)),
+            localization.get_with_args("move_to_secondary", Some(&dir_args)),
+            localization.get("transfer_cancel")
+        );
 
-        assert_eq!(layout.min_divider_percent, WIDE_SCREEN_WIDTH_PERCENT);
-        assert!(file_area.width > 0);
-        assert!(preview_area.width > 0);
-        assert!(debug_area.height == 3);
-        assert_eq!(file_area.height + debug_area.height, area.height);
+        let dialog = Paragraph::new(text)
+            .block(
+                Block::default()
+                    .title(localization.get_with_args("transfer_title", Some(&file_args)))
+                    .borders(Borders::ALL)
+                    .style(Style::default().fg(Color::Cyan)),
+            )
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::White));
+        f.render_widget(dialog, popup_area);
     }
 
-    #[test]
-    fn test_ui_layout_calculate_layout_narrow_screen() {
-        let mut layout = UILayout::new();
-        let area = Rect::new(0, 0, 80, 30);
-
-        let (file_area, preview_area, debug_area) = layout.calculate_layout(area);
-
-        assert_eq!(layout.min_divider_percent, NARROW_SCREEN_WIDTH_PERCENT);
-        assert!(file_area.width > 0);
-        assert!(preview_area.width > 0);
-        assert!(debug_area.height == 3);
+    /// Shows a file's octal mode and the new one being typed
+    /// The `:` command line along the bottom row, like vim's. Until something is
+    /// typed it lists the commands.
+    pub fn render_command_prompt(
+        f: &mut Frame,
+        area: Rect,
+        input: &str,
+        localization: &Localization,
+    ) {
+        if area.height == 0 {
+            return;
+        }
+        let prompt_area = Rect::new(area.x, area.bottom() - 1, area.width, 1);
+        f.render_widget(Clear, prompt_area);
+
+        let mut spans = vec![Span::styled(
+            format!(":{}_", input),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )];
+        if input.is_empty() {
+            spans.push(Span::styled(
+                format!("  {}", localization.get("command_prompt")),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        f.render_widget(Paragraph::new(Line::from(spans)), prompt_area);
     }
 
-    #[test]
-    fn test_ui_layout_preview_size_initialization() {
-        let mut layout = UILayout::new();
-        let area = Rect::new(0, 0, 100, 40);
+    pub fn render_permission_dialog(
+        f: &mut Frame,
+        area: Rect,
+        file_name: &str,
+        mode: u32,
+        input: &str,
+        localization: &Localization,
+    ) {
+        use fluent::fluent_args;
 
-        assert_eq!(layout.preview_size, 0);
+        let dialog_width = 60.min(area.width.saturating_sub(4));
+        let dialog_height = 6.min(area.height.saturating_sub(4));
+        let popup_area = centered_rect(dialog_width, dialog_height, area);
+        f.render_widget(Clear, popup_area);
 
-        layout.calculate_layout(area);
+        let file_args = fluent_args!["file" => file_name];
+        let mode_args = fluent_args!["mode" => format!("{:03o}", mode & 0o777)];
+        let text = format!(
+            "{}\n> {}_\n{}",
+            localization.get_with_args("permissions_current", Some(&mode_args)),
+            input,
+            localization.get("permissions_instructions")
+        );
 
-        assert!(layout.preview_size > 0);
+        let dialog = Paragraph::new(text)
+            .block(
+                Block::default()
+                    .title(localization.get_with_args("permissions_title", Some(&file_args)))
+                    .borders(Borders::ALL)
+                    .style(Style::default().fg(Color::Cyan)),
+            )
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::White));
+        f.render_widget(dialog, popup_area);
+    }
+
+    /// Asks whether a batch ASCII export should overwrite existing .ascii files
+    pub fn render_batch_export_dialog(
+        f: &mut Frame,
+        area: Rect,
+        image_count: usize,
+        localization: &Localization,
+    ) {
+        use fluent::fluent_args;
+
+        let dialog_width = 70.min(area.width.saturating_sub(4));
+        let dialog_height = 6.min(area.height.saturating_sub(4));
+        let popup_area = centered_rect(dialog_width, dialog_height, area);
+        f.render_widget(Clear, popup_area);
+
+        let args = fluent_args!["count" => image_count];
+        let text = format!(
+            "{}\n{}\n{}",
+            localization.get("batch_export_skip_existing"),
+            localization.get("batch_export_overwrite"),
+            localization.get("transfer_cancel")
+        );
+
+        let dialog = Paragraph::new(text)
+            .block(
+                Block::default()
+                    .title(localization.get_with_args("batch_export_title", Some(&args)))
+                    .borders(Borders::ALL)
+                    .style(Style::default().fg(Color::Cyan)),
+            )
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::White));
+        f.render_widget(dialog, popup_area);
+    }
+
+    /// One-line box with the progress of a running batch export
+    pub fn render_batch_export_progress(f: &mut Frame, area: Rect, progress: &str) {
+        let dialog_width = 60.min(area.width.saturating_sub(4));
+        let dialog_height = 3.min(area.height);
+        let popup_area = centered_rect(dialog_width, dialog_height, area);
+        f.render_widget(Clear, popup_area);
+
+        let overlay = Paragraph::new(progress.to_string())
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .style(Style::default().fg(Color::Cyan)),
+            )
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::White));
+        f.render_widget(overlay, popup_area);
+    }
+
+    /// Dialog listing recently previewed files, with files that have since gone greyed out
+    pub fn render_recent_files_dialog(
+        f: &mut Frame,
+        area: Rect,
+        recent_files: &RecentFiles,
+        selected: usize,
+        localization: &Localization,
+    ) {
+        let dialog_width = 70.min(area.width.saturating_sub(4));
+        let dialog_height = 20.min(area.height.saturating_sub(4));
+        let popup_area = centered_rect(dialog_width, dialog_height, area);
+        f.render_widget(Clear, popup_area);
+
+        // Keep the selection in view, leaving room for the instructions and borders
+        let visible_rows = dialog_height.saturating_sub(4).max(1) as usize;
+        let first_row = selected.saturating_sub(visible_rows - 1);
+
+        let mut lines: Vec<Line> = if recent_files.is_empty() {
+            vec![Line::raw(localization.get("recent_files_empty"))]
+        } else {
+            recent_files
+                .iter()
+                .enumerate()
+                .skip(first_row)
+                .take(visible_rows)
+                .map(|(index, (path, _))| {
+                    let exists = std::path::Path::new(path).exists();
+                    let marker = if exists { "  " } else { "✗ " };
+                    let style = match (index == selected, exists) {
+                        (true, _) => Style::default()
+                            .fg(Color::Black)
+                            .bg(Color::Yellow)
+                            .add_modifier(Modifier::BOLD),
+                        (false, true) => Style::default(),
+                        (false, false) => Style::default().fg(Color::DarkGray),
+                    };
+                    Line::styled(format!("{}{}", marker, path), style)
+                })
+                .collect()
+        };
+        lines.push(Line::raw(""));
+        lines.push(Line::styled(
+            localization.get("recent_files_instructions"),
+            Style::default().fg(Color::DarkGray),
+        ));
+
+        let dialog_block = Block::default()
+            .title(localization.get("recent_files_title"))
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::Cyan));
+        f.render_widget(Paragraph::new(lines).block(dialog_block), popup_area);
+    }
+
+    pub fn render_open_with_picker(
+        f: &mut Frame,
+        area: Rect,
+        picker: &OpenWithPicker,
+        localization: &Localization,
+    ) {
+        use fluent::fluent_args;
+        use ratatui::style::{Color, Modifier, Style};
+        use ratatui::text::Line;
+        use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+        // One line per opener plus a blank line, the instructions and the borders
+        let dialog_width = 50.min(area.width.saturating_sub(4));
+        let dialog_height = (picker.openers.len() as u16 + 4).min(area.height.saturating_sub(4));
+        let popup_area = centered_rect(dialog_width, dialog_height, area);
+
+        f.render_widget(Clear, popup_area);
+
+        let mut lines: Vec<Line> = picker
+            .openers
+            .iter()
+            .enumerate()
+            .map(|(index, opener)| {
+                if index == picker.selected {
+                    Line::styled(
+                        format!("> {}", opener.name),
+                        Style::default()
+                            .fg(Color::Black)
+                            .bg(Color::Yellow)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    Line::raw(format!("  {}", opener.name))
+                }
+            })
+            .collect();
+        lines.push(Line::raw(""));
+        lines.push(Line::styled(
+            localization.get("open_with_instructions"),
+            Style::default().fg(Color::DarkGray),
+        ));
+
+        let args = fluent_args!["file" => picker.file_name.as_str()];
+        let dialog_block = Block::default()
+            .title(localization.get_with_args("open_with_title", Some(&args)))
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::Cyan));
+
+        f.render_widget(Paragraph::new(lines).block(dialog_block), popup_area);
+    }
+
+    pub fn render_charset_picker(
+        f: &mut Frame,
+        area: Rect,
+        picker: &CharsetPicker,
+        localization: &Localization,
+    ) {
+        use ratatui::style::{Color, Modifier, Style};
+        use ratatui::text::Line;
+        use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+        // One line per preset, the custom entry, a blank line, the instructions and the borders
+        let entries = JP2A_CHARSETS.len() as u16 + 1;
+        let dialog_width = 50.min(area.width.saturating_sub(4));
+        let dialog_height = (entries + 4).min(area.height.saturating_sub(4));
+        let popup_area = centered_rect(dialog_width, dialog_height, area);
+
+        f.render_widget(Clear, popup_area);
+
+        let custom_label = match &picker.custom_input {
+            Some(input) => format!("{:<8} {}_", "custom", input),
+            None => "custom".to_string(),
+        };
+        let labels = JP2A_CHARSETS
+            .iter()
+            .map(|(name, chars)| format!("{:<8} {}", name, chars.unwrap_or_default()))
+            .chain(std::iter::once(custom_label));
+        let mut lines: Vec<Line> = labels
+            .enumerate()
+            .map(|(index, label)| {
+                if index == picker.selected {
+                    Line::styled(
+                        format!("> {}", label),
+                        Style::default()
+                            .fg(Color::Black)
+                            .bg(Color::Yellow)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    Line::raw(format!("  {}", label))
+                }
+            })
+            .collect();
+        lines.push(Line::raw(""));
+        let instructions = if picker.custom_input.is_some() {
+            "charset_custom_instructions"
+        } else {
+            "charset_picker_instructions"
+        };
+        lines.push(Line::styled(
+            localization.get(instructions),
+            Style::default().fg(Color::DarkGray),
+        ));
+
+        let dialog_block = Block::default()
+            .title(localization.get("charset_picker_title"))
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::Cyan));
+
+        f.render_widget(Paragraph::new(lines).block(dialog_block), popup_area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::helpers::*;
+    use ratatui::layout::Rect;
+    use ratatui::text::Text;
+
+    #[test]
+    fn test_ui_layout_creation() {
+        let layout = UILayout::new();
+        assert_eq!(layout.preview_size, 0);
+        assert_eq!(layout.min_divider_percent, 10);
+        assert_eq!(layout.preview_width, 0);
+        assert_eq!(layout.preview_height, 0);
+    }
+
+    #[test]
+    fn test_ui_layout_calculate_layout_wide_screen() {
+        let mut layout = UILayout::new();
+        let area = Rect::new(0, 0, 150, 50);
+
+        let (file_area, preview_area, debug_area) = layout.calculate_layout(area);
+
+        assert_eq!(layout.min_divider_percent, WIDE_SCREEN_WIDTH_PERCENT);
+        assert!(file_area.width > 0);
+        assert!(preview_area.width > 0);
+        assert!(debug_area.height == 3);
+        assert_eq!(file_area.height + debug_area.height, area.height);
+    }
+
+    #[test]
+    fn test_ui_layout_calculate_layout_narrow_screen() {
+        let mut layout = UILayout::new();
+        let area = Rect::new(0, 0, 80, 30);
+
+        let (file_area, preview_area, debug_area) = layout.calculate_layout(area);
+
+        assert_eq!(layout.min_divider_percent, NARROW_SCREEN_WIDTH_PERCENT);
+        assert!(file_area.width > 0);
+        assert!(preview_area.width > 0);
+        assert!(debug_area.height == 3);
+    }
+
+    #[test]
+    fn test_ui_layout_preview_size_initialization() {
+        let mut layout = UILayout::new();
+        let area = Rect::new(0, 0, 100, 40);
+
+        assert_eq!(layout.preview_size, 0);
+
+        layout.calculate_layout(area);
+
+        assert!(layout.preview_size > 0);
         assert_eq!(layout.preview_size, layout.min_divider_percent);
     }
 
@@ -719,6 +1636,29 @@ mod tests {
         assert_eq!(layout.preview_size, 15);
     }
 
+    #[rstest::rstest]
+    #[case(80)]
+    #[case(200)]
+    fn test_ui_layout_configured_width_percent(#[case] width: u16) {
+        let config = crate::config::PTuiConfig {
+            file_browser_width_percent: Some(30),
+            ..Default::default()
+        };
+        let (wide, narrow) = config.get_file_browser_width_percents();
+        let mut layout = UILayout::new();
+        layout.set_file_browser_width_percents(wide, narrow);
+
+        layout.calculate_layout(Rect::new(0, 0, width, 40));
+        assert_eq!(layout.min_divider_percent, 30);
+        assert_eq!(layout.preview_size, 30);
+
+        // [ and ] still move the divider, from the configured width
+        layout.increase_size(5);
+        assert_eq!(layout.preview_size, 35);
+        layout.decrease_size(20);
+        assert_eq!(layout.preview_size, 30);
+    }
+
     #[test]
     fn test_ui_layout_preview_dimensions_calculation() {
         let mut layout = UILayout::new();
@@ -758,88 +1698,923 @@ mod tests {
 
         terminal
             .draw(|f| {
-                UIRenderer::render_file_browser(f, area, &mut file_browser, true);
+                UIRenderer::render_file_browser(
+                    f,
+                    area,
+                    &mut file_browser,
+                    true,
+                    &FileIcons::default(),
+                    &ThemeConfig::default(),
+                    &Localization::new("en").unwrap(),
+                );
             })
             .unwrap();
     }
 
     #[test]
-    fn test_ui_renderer_preview_with_content() {
-        use crate::preview::PreviewContent;
-        let localization = crate::localization::Localization::new("en").unwrap();
-        let text = Text::from("Test preview content");
-        let preview = PreviewContent::Text(text);
-        let area = Rect::new(0, 0, 50, 20);
+    fn test_ui_renderer_file_browser_git_indicator() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_file("notes.txt", "content").unwrap();
+        temp_fs.create_file("clean.txt", "content").unwrap();
+        let mut file_browser =
+            crate::file_browser::FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        file_browser.git_status = Some(std::collections::HashMap::from([(
+            "notes.txt".to_string(),
+            GitStatus::Modified,
+        )]));
 
-        let backend = ratatui::backend::TestBackend::new(50, 20);
+        let backend = ratatui::backend::TestBackend::new(40, 6);
         let mut terminal = ratatui::Terminal::new(backend).unwrap();
-
         terminal
             .draw(|f| {
-                UIRenderer::render_preview(f, area, Some(&preview), &localization, None, false);
+                UIRenderer::render_file_browser(
+                    f,
+                    f.area(),
+                    &mut file_browser,
+                    false,
+                    &FileIcons::default(),
+                    &ThemeConfig::default(),
+                    &Localization::new("en").unwrap(),
+                )
             })
             .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| -> String { (0..40).map(|x| buffer[(x, y)].symbol()).collect() };
+        assert!(row(1).contains("clean.txt") && !row(1).contains(" M "));
+        assert!(row(2).contains("M notes.txt"));
     }
 
+    #[cfg(unix)]
     #[test]
-    fn test_ui_renderer_preview_without_content() {
-        let localization = crate::localization::Localization::new("en").unwrap();
-        let area = Rect::new(0, 0, 50, 20);
+    fn test_ui_renderer_file_browser_symlink_indicator() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let notes = temp_fs.create_file("notes.txt", "content").unwrap();
+        std::os::unix::fs::symlink(&notes, temp_fs.get_path().join("link.txt")).unwrap();
+        let mut file_browser =
+            crate::file_browser::FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
 
-        let backend = ratatui::backend::TestBackend::new(50, 20);
+        let backend = ratatui::backend::TestBackend::new(40, 6);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                UIRenderer::render_file_browser(
+                    f,
+                    f.area(),
+                    &mut file_browser,
+                    false,
+                    &FileIcons::ascii(),
+                    &ThemeConfig::default(),
+                    &Localization::new("en").unwrap(),
+                )
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| -> String { (0..40).map(|x| buffer[(x, y)].symbol()).collect() };
+        assert!(row(1).contains("link.txt ↗"));
+        assert!(row(2).contains("notes.txt") && !row(2).contains('↗'));
+    }
+
+    #[rstest::rstest]
+    #[case("en", "(1 image)", "(2 images)")]
+    #[case("de", "(1 Bild)", "(2 Bilder)")]
+    fn test_ui_renderer_file_browser_image_counts(
+        #[case] locale: &str,
+        #[case] one: &str,
+        #[case] two: &str,
+    ) {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_directory("album").unwrap();
+        temp_fs.create_test_image("album/a.jpg").unwrap();
+        temp_fs.create_directory("trip").unwrap();
+        temp_fs.create_test_image("trip/a.jpg").unwrap();
+        temp_fs.create_test_image("trip/b.jpg").unwrap();
+        let mut file_browser =
+            crate::file_browser::FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        file_browser.set_show_image_counts(true);
+        // Counted off the render path, as the app does
+        let counts: Vec<(String, usize)> = file_browser
+            .uncounted_visible_directories()
+            .iter()
+            .map(|dir| (dir.name.clone(), dir.count_images_shallow()))
+            .collect();
+        file_browser.set_image_counts(&counts);
+
+        let backend = ratatui::backend::TestBackend::new(40, 6);
         let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                UIRenderer::render_file_browser(
+                    f,
+                    f.area(),
+                    &mut file_browser,
+                    false,
+                    &FileIcons::ascii(),
+                    &ThemeConfig::default(),
+                    &Localization::new(locale).unwrap(),
+                )
+            })
+            .unwrap();
+
+        // Fluent wraps the number in bidi isolation marks
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| -> String {
+            (0..40)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+                .replace(['\u{2068}', '\u{2069}'], "")
+        };
+        assert!(row(1).contains(&format!("album {}", one)));
+        assert!(row(2).contains(&format!("trip {}", two)));
+    }
+
+    #[rstest::rstest]
+    #[case(FileIcons::default())]
+    #[case(FileIcons::ascii())]
+    fn test_ui_renderer_file_browser_icons(#[case] icons: FileIcons) {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_directory("album").unwrap();
+        temp_fs.create_file("notes.txt", "content").unwrap();
+        let mut file_browser =
+            crate::file_browser::FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
 
+        let backend = ratatui::backend::TestBackend::new(40, 6);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
         terminal
             .draw(|f| {
-                UIRenderer::render_preview(f, area, None, &localization, None, false);
+                UIRenderer::render_file_browser(
+                    f,
+                    f.area(),
+                    &mut file_browser,
+                    false,
+                    &icons,
+                    &ThemeConfig::default(),
+                    &Localization::new("en").unwrap(),
+                )
             })
             .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| -> String { (0..40).map(|x| buffer[(x, y)].symbol()).collect() };
+        assert!(row(0).contains(&icons.directory));
+        assert!(row(1).contains(&icons.directory) && row(1).contains("album"));
+        assert!(row(2).contains(&icons.text) && row(2).contains("notes.txt"));
     }
 
-    #[test]
-    fn test_ui_renderer_debug_pane() {
-        let localization = crate::localization::Localization::new("en").unwrap();
-        let debug_info = "Test debug information";
-        let area = Rect::new(0, 0, 50, 5);
+    #[rstest::rstest]
+    #[case(40, true)]
+    #[case(29, false)]
+    fn test_ui_renderer_file_browser_icon_column(#[case] width: u16, #[case] shown: bool) {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs
+            .create_binary_file("backup.zip", b"PK\x03\x04\x14\x00")
+            .unwrap();
+        temp_fs.create_file("main.rs", "fn main() {}").unwrap();
+        let mut file_browser =
+            crate::file_browser::FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        let icons = FileIcons::preset("compact").unwrap();
+        let theme = ThemeConfig {
+            icons: std::collections::HashMap::from([(FileCategory::Archive, "Z".to_string())]),
+            ..ThemeConfig::default()
+        };
 
-        let backend = ratatui::backend::TestBackend::new(50, 5);
+        let backend = ratatui::backend::TestBackend::new(width, 5);
         let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                UIRenderer::render_file_browser(
+                    f,
+                    f.area(),
+                    &mut file_browser,
+                    false,
+                    &icons,
+                    &theme,
+                    &Localization::new("en").unwrap(),
+                )
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| -> String { (1..width - 1).map(|x| buffer[(x, y)].symbol()).collect() };
+        if shown {
+            // The one-character theme icon is padded to the widest icon in the set
+            assert!(row(1).starts_with("Z   backup.zip"));
+            assert!(row(2).starts_with("[C] main.rs"));
+        } else {
+            // Narrow panes keep the single icon picked by file name
+            assert!(row(1).starts_with("[Z] backup.zip"));
+            assert!(row(2).starts_with("[T] main.rs"));
+        }
+    }
+
+    #[test]
+    fn test_ui_renderer_file_browser_theme_colors() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_directory("album").unwrap();
+        temp_fs.create_file("notes.txt", "content").unwrap();
+        let mut file_browser =
+            crate::file_browser::FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        file_browser.selected_index = 1;
+        let theme = ThemeConfig {
+            directory_fg: "#ff0000".to_string(),
+            selected_file_fg: "black".to_string(),
+            selected_file_bg: "#00ff00".to_string(),
+            ..ThemeConfig::default()
+        };
 
+        let backend = ratatui::backend::TestBackend::new(40, 6);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
         terminal
             .draw(|f| {
-                UIRenderer::render_debug_pane(f, area, debug_info, &localization);
+                UIRenderer::render_file_browser(
+                    f,
+                    f.area(),
+                    &mut file_browser,
+                    true,
+                    &FileIcons::ascii(),
+                    &theme,
+                    &Localization::new("en").unwrap(),
+                )
             })
             .unwrap();
+
+        // "[D] album" and "[T] notes.txt", the name starting in column 5
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(5, 1)].fg, Color::Rgb(255, 0, 0));
+        assert_eq!(buffer[(5, 2)].fg, Color::Black);
+        assert_eq!(buffer[(5, 2)].bg, Color::Rgb(0, 255, 0));
     }
 
     #[test]
-    fn test_ui_renderer_slideshow() {
+    fn test_ui_renderer_preview_with_content() {
         use crate::preview::PreviewContent;
         let localization = crate::localization::Localization::new("en").unwrap();
-        let text = Text::from("Slideshow content");
-        let preview = PreviewContent::Text(text);
-        let area = Rect::new(0, 0, 80, 30);
+        let text = Text::from("Test preview content");
+        let preview = PreviewContent::Text(text, ContentAlignment::Image);
+        let area = Rect::new(0, 0, 50, 20);
 
-        let backend = ratatui::backend::TestBackend::new(80, 30);
+        let backend = ratatui::backend::TestBackend::new(50, 20);
         let mut terminal = ratatui::Terminal::new(backend).unwrap();
 
         terminal
             .draw(|f| {
-                UIRenderer::render_slideshow(f, area, Some(&preview), &localization, 3, 10);
+                UIRenderer::render_preview(
+                    f,
+                    area,
+                    Some(&preview),
+                    &localization,
+                    None,
+                    0,
+                    &FileIcons::default(),
+                    None,
+                );
             })
             .unwrap();
     }
 
     #[test]
-    fn test_ui_renderer_localize_logo_text() {
+    fn test_ui_renderer_text_preview_scrollbar() {
         let localization = crate::localization::Localization::new("en").unwrap();
-        let mut logo = Text::default();
-        logo.lines
-            .push(ratatui::text::Line::from(vec![ratatui::text::Span::from(
-                "Test {app_subtitle} v{version} Logo",
-            )]));
+        let preview = PreviewContent::Text(Text::from("x".repeat(200)), ContentAlignment::Text);
+        let area = Rect::new(10, 2, 30, 8);
+        let backend = ratatui::backend::TestBackend::new(50, 12);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
 
-        let localized = UIRenderer::localize_logo_text(&logo, &localization);
+        terminal
+            .draw(|f| {
+                UIRenderer::render_preview(
+                    f,
+                    area,
+                    Some(&preview),
+                    &localization,
+                    None,
+                    0,
+                    &FileIcons::default(),
+                    Some((3, 40)),
+                );
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        // The scrollbar fills the last column inside the border, and the text stops before it
+        let scrollbar_x = area.right() - 2;
+        for y in area.top() + 1..area.bottom() - 1 {
+            let cell = &buffer[(scrollbar_x, y)];
+            assert_eq!(cell.fg, Color::DarkGray);
+            assert_ne!(cell.symbol(), "x");
+            assert_eq!(buffer[(scrollbar_x - 1, y)].symbol(), "x");
+        }
+        for y in 0..12 {
+            for x in 0..50 {
+                if !area.contains((x, y).into()) {
+                    assert_eq!(buffer[(x, y)].symbol(), " ");
+                }
+            }
+        }
+    }
+
+    #[rstest::rstest]
+    #[case(ContentAlignment::Text, 1, 1)]
+    #[case(ContentAlignment::Image, 24, 2)]
+    fn test_ui_renderer_preview_alignment(
+        #[case] alignment: ContentAlignment,
+        #[case] x: u16,
+        #[case] y: u16,
+    ) {
+        let localization = crate::localization::Localization::new("en").unwrap();
+        let preview = PreviewContent::Text(Text::from("ab"), alignment);
+        let backend = ratatui::backend::TestBackend::new(50, 5);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                UIRenderer::render_preview(
+                    f,
+                    Rect::new(0, 0, 50, 5),
+                    Some(&preview),
+                    &localization,
+                    None,
+                    0,
+                    &FileIcons::default(),
+                    None,
+                );
+            })
+            .unwrap();
+
+        assert_eq!(terminal.backend().buffer()[(x, y)].symbol(), "a");
+    }
+
+    #[rstest::rstest]
+    #[case(5, 20, 7)]
+    #[case(4, 20, 8)]
+    #[case(20, 20, 0)]
+    #[case(30, 20, 0)]
+    fn test_center_vertically(#[case] lines: usize, #[case] height: u16, #[case] empty: usize) {
+        let art = Text::from(vec![Line::from("#####"); lines]);
+        let centered = UIRenderer::center_vertically(&art, height);
+        assert_eq!(centered.lines.len(), lines + empty);
+        assert!(centered.lines[..empty].iter().all(|line| line.width() == 0));
+        assert_eq!(centered.lines[empty..], art.lines[..]);
+    }
+
+    #[test]
+    fn test_ui_renderer_preview_centers_ascii_art_vertically() {
+        let localization = crate::localization::Localization::new("en").unwrap();
+        let art = Text::from(vec![Line::from("#####"); 5]);
+        let preview = PreviewContent::Text(art, ContentAlignment::Image);
+        // 20 rows inside the border
+        let backend = ratatui::backend::TestBackend::new(30, 22);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                UIRenderer::render_preview(
+                    f,
+                    f.area(),
+                    Some(&preview),
+                    &localization,
+                    None,
+                    0,
+                    &FileIcons::default(),
+                    None,
+                );
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let art_rows: Vec<u16> = (1..21)
+            .filter(|&y| (1..29).any(|x| buffer[(x, y)].symbol() == "#"))
+            .collect();
+        assert_eq!(art_rows, (8..13).collect::<Vec<u16>>());
+    }
+
+    #[test]
+    fn test_ui_renderer_help_scroll() {
+        let localization = crate::localization::Localization::new("en").unwrap();
+        let backend = ratatui::backend::TestBackend::new(80, 5);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                UIRenderer::render_preview(
+                    f,
+                    Rect::new(0, 0, 80, 5),
+                    None,
+                    &localization,
+                    None,
+                    2,
+                    &FileIcons::default(),
+                    None,
+                );
+            })
+            .unwrap();
+
+        // The first help line after the intro and the blank line below it
+        let first_row: String = (1..79)
+            .map(|x| terminal.backend().buffer()[(x, 1)].symbol())
+            .collect();
+        assert_eq!(first_row.trim_end(), localization.get("keys_navigation"));
+    }
+
+    #[test]
+    fn test_ui_renderer_preview_without_content() {
+        let localization = crate::localization::Localization::new("en").unwrap();
+        let area = Rect::new(0, 0, 50, 20);
+
+        let backend = ratatui::backend::TestBackend::new(50, 20);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                UIRenderer::render_preview(
+                    f,
+                    area,
+                    None,
+                    &localization,
+                    None,
+                    0,
+                    &FileIcons::default(),
+                    None,
+                );
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_ui_renderer_debug_pane() {
+        let localization = crate::localization::Localization::new("en").unwrap();
+        let debug_info = "Test debug information";
+        let area = Rect::new(0, 0, 50, 5);
+
+        let backend = ratatui::backend::TestBackend::new(50, 5);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                UIRenderer::render_debug_pane(
+                    f,
+                    area,
+                    debug_info,
+                    &DebugPaneContent::Messages,
+                    None,
+                    &localization,
+                );
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_split_file_area_halves() {
+        let (left, right) = UILayout::split_file_area(Rect::new(0, 0, 40, 20));
+        assert_eq!(left, Rect::new(0, 0, 20, 20));
+        assert_eq!(right, Rect::new(20, 0, 20, 20));
+    }
+
+    #[test]
+    fn test_transfer_dialog_lists_both_actions() {
+        let localization = crate::localization::Localization::new("en").unwrap();
+        let backend = ratatui::backend::TestBackend::new(70, 12);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                UIRenderer::render_transfer_dialog(
+                    f,
+                    f.area(),
+                    "beach.jpg",
+                    "/photos",
+                    &localization,
+                );
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let screen: String = (0..12)
+            .flat_map(|y| (0..70).map(move |x| (x, y)))
+            .map(|(x, y)| buffer[(x, y)].symbol().to_string())
+            .collect();
+        assert!(screen.contains("beach.jpg"));
+        assert!(screen.contains("Copy to"));
+        assert!(screen.contains("Move to"));
+        assert!(screen.contains("/photos"));
+    }
+
+    #[test]
+    fn test_debug_pane_content_cycle() {
+        let mut mode = DebugPaneContent::Messages;
+        let mut seen = Vec::new();
+        for _ in 0..4 {
+            mode = mode.next(None);
+            seen.push(mode.clone());
+        }
+        assert_eq!(
+            seen,
+            [
+                DebugPaneContent::FileInfo,
+                DebugPaneContent::ConverterInfo,
+                DebugPaneContent::CacheStats,
+                DebugPaneContent::Messages,
+            ]
+        );
+
+        let custom = DebugPaneContent::Messages.next(Some("notes"));
+        assert_eq!(custom, DebugPaneContent::Custom("notes".to_string()));
+        assert_eq!(custom.next(Some("notes")), DebugPaneContent::FileInfo);
+    }
+
+    #[test]
+    fn test_debug_pane_title_follows_mode() {
+        let localization = crate::localization::Localization::new("en").unwrap();
+        let backend = ratatui::backend::TestBackend::new(50, 3);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                UIRenderer::render_debug_pane(
+                    f,
+                    f.area(),
+                    "Hits: 1",
+                    &DebugPaneContent::CacheStats,
+                    None,
+                    &localization,
+                );
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let top: String = (0..50).map(|x| buffer[(x, 0)].symbol()).collect();
+        assert!(top.contains(&localization.get("debug_pane_cache_stats")));
+    }
+
+    #[test]
+    fn test_debug_pane_average_color_swatch() {
+        let localization = crate::localization::Localization::new("en").unwrap();
+        let backend = ratatui::backend::TestBackend::new(50, 3);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                UIRenderer::render_debug_pane(
+                    f,
+                    f.area(),
+                    "Ready",
+                    &DebugPaneContent::Messages,
+                    Some((255, 0, 0)),
+                    &localization,
+                );
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let line: String = (1..49).map(|x| buffer[(x, 1)].symbol()).collect();
+        assert!(line.contains("#FF0000"));
+        let swatch_x = (1..49)
+            .find(|&x| buffer[(x, 1)].bg == Color::Rgb(255, 0, 0))
+            .unwrap();
+        assert_eq!(buffer[(swatch_x, 1)].symbol(), " ");
+    }
+
+    #[test]
+    fn test_ui_renderer_slideshow() {
+        use crate::preview::PreviewContent;
+        let localization = crate::localization::Localization::new("en").unwrap();
+        let text = Text::from("Slideshow content");
+        let preview = PreviewContent::Text(text, ContentAlignment::Image);
+        let area = Rect::new(0, 0, 80, 30);
+
+        let backend = ratatui::backend::TestBackend::new(80, 30);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                let status = SlideshowStatus {
+                    current_image: 3,
+                    total_images: 10,
+                    shuffle: false,
+                    looping: true,
+                    delay: Duration::from_millis(2000),
+                    file_name: None,
+                    ..Default::default()
+                };
+                UIRenderer::render_slideshow(f, area, Some(&preview), &localization, &status);
+            })
+            .unwrap();
+    }
+
+    #[rstest::rstest]
+    #[case(true, "Move")]
+    #[case(false, "Permanently delete")]
+    fn test_delete_confirmation_dialog_prompt(#[case] use_trash: bool, #[case] expected: &str) {
+        let localization = crate::localization::Localization::new("en").unwrap();
+        let backend = ratatui::backend::TestBackend::new(60, 12);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                UIRenderer::render_delete_confirmation_dialog(
+                    f,
+                    f.area(),
+                    "a.jpg",
+                    use_trash,
+                    &localization,
+                )
+            })
+            .unwrap();
+
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(rendered.contains(expected));
+        assert_eq!(rendered.contains("Trash"), use_trash);
+    }
+
+    #[rstest::rstest]
+    #[case("", true)]
+    #[case("e con", false)]
+    fn test_command_prompt_on_bottom_row(#[case] input: &str, #[case] shows_hint: bool) {
+        let localization = crate::localization::Localization::new("en").unwrap();
+        let backend = ratatui::backend::TestBackend::new(80, 5);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| UIRenderer::render_command_prompt(f, f.area(), input, &localization))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let bottom: String = (0..80).map(|x| buffer[(x, 4)].symbol()).collect();
+        assert!(bottom.starts_with(&format!(":{}_", input)));
+        assert_eq!(
+            bottom.contains(&localization.get("command_prompt")),
+            shows_hint
+        );
+    }
+
+    #[test]
+    fn test_recent_files_dialog_marks_missing_files() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        let existing = temp_fs.create_file("here.txt", "x").unwrap();
+        let dir = temp_fs.get_path().to_string_lossy().into_owned();
+        let mut recent = RecentFiles::default();
+        recent.push(&format!("{}/gone.jpg", dir), &dir);
+        recent.push(&existing, &dir);
+
+        let localization = crate::localization::Localization::new("en").unwrap();
+        let backend = ratatui::backend::TestBackend::new(120, 12);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                UIRenderer::render_recent_files_dialog(f, f.area(), &recent, 0, &localization)
+            })
+            .unwrap();
+
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(rendered.contains("Recent files"));
+        assert!(rendered.contains("✗ "));
+        assert!(rendered.contains("here.txt"));
+    }
+
+    #[test]
+    fn test_open_with_picker_dialog_lists_openers() {
+        use crate::opener::Opener;
+        let localization = crate::localization::Localization::new("en").unwrap();
+        let picker = OpenWithPicker {
+            file_path: "/photos/a.jpg".to_string(),
+            file_name: "a.jpg".to_string(),
+            openers: vec![
+                Opener::from_command("feh").unwrap(),
+                Opener::from_command("gimp").unwrap(),
+            ],
+            selected: 1,
+        };
+        let backend = ratatui::backend::TestBackend::new(60, 12);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| UIRenderer::render_open_with_picker(f, f.area(), &picker, &localization))
+            .unwrap();
+
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(rendered.contains("a.jpg"));
+        assert!(rendered.contains("  feh"));
+        assert!(rendered.contains("> gimp"));
+    }
+
+    #[test]
+    fn test_ui_renderer_slideshow_filename_overlay() {
+        use crate::preview::PreviewContent;
+        let localization = crate::localization::Localization::new("en").unwrap();
+        let preview = PreviewContent::Text(Text::from("art"), ContentAlignment::Image);
+        let area = Rect::new(0, 0, 60, 10);
+        let backend = ratatui::backend::TestBackend::new(60, 10);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        let status = SlideshowStatus {
+            current_image: 1,
+            total_images: 2,
+            file_name: Some("sunset.jpg".to_string()),
+            ..Default::default()
+        };
+        terminal
+            .draw(|f| UIRenderer::render_slideshow(f, area, Some(&preview), &localization, &status))
+            .unwrap();
+
+        // The filename sits on the row just above the 4-row status bar, starting at the left
+        let buffer = terminal.backend().buffer();
+        let filename_row: String = (0..area.width)
+            .map(|x| buffer[(x, area.height - 5)].symbol().to_string())
+            .collect();
+        assert!(filename_row.contains("sunset.jpg"));
+        assert!(!filename_row.starts_with(' '));
+        assert!(
+            buffer[(0, area.height - 5)]
+                .modifier
+                .contains(Modifier::BOLD)
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(0, 0, Color::Green)]
+    #[case(500, 19, Color::Green)]
+    #[case(1600, 62, Color::Yellow)]
+    #[case(2000, 78, Color::Yellow)]
+    #[case(5000, 78, Color::Yellow)]
+    fn test_ui_renderer_slideshow_progress_bar(
+        #[case] elapsed_ms: u64,
+        #[case] expected_fill: usize,
+        #[case] expected_color: Color,
+    ) {
+        let localization = crate::localization::Localization::new("en").unwrap();
+        let area = Rect::new(0, 0, 80, 10);
+        let backend = ratatui::backend::TestBackend::new(80, 10);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        let status = SlideshowStatus {
+            current_image: 1,
+            total_images: 4,
+            delay: Duration::from_millis(2000),
+            elapsed: Duration::from_millis(elapsed_ms),
+            show_progress_bar: true,
+            ..Default::default()
+        };
+        terminal
+            .draw(|f| UIRenderer::render_slideshow(f, area, None, &localization, &status))
+            .unwrap();
+
+        // The bar sits between the status line and the hints in the 5-row status bar
+        let buffer = terminal.backend().buffer();
+        let bar_y = area.height - 3;
+        let bar: String = (1..area.width - 1)
+            .map(|x| buffer[(x, bar_y)].symbol())
+            .collect();
+        assert_eq!(bar.matches('▰').count(), expected_fill);
+        assert_eq!(bar.matches('▱').count(), 78 - expected_fill);
+        assert_eq!(buffer[(1, bar_y)].fg, expected_color);
+    }
+
+    #[rstest::rstest]
+    #[case(false, false)]
+    #[case(true, false)]
+    #[case(false, true)]
+    fn test_render_slideshow_hints(#[case] shuffle: bool, #[case] looping: bool) {
+        let localization = crate::localization::Localization::new("en").unwrap();
+        let backend = ratatui::backend::TestBackend::new(80, 1);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                UIRenderer::render_slideshow_hints(f, f.area(), &localization, shuffle, looping)
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let row: String = (0..80).map(|x| buffer[(x, 0)].symbol()).collect();
+        assert!(row.contains("← prev | → next | space: exit | z: shuffle"));
+        assert!(row.contains("l: loop | t: name | +/-: speed"));
+
+        let cell_x = |needle: &str| {
+            let byte_offset = row.find(needle).unwrap();
+            row[..byte_offset].chars().count() as u16
+        };
+        assert_eq!(buffer[(cell_x("space"), 0)].fg, Color::Cyan);
+        assert_eq!(buffer[(cell_x("exit"), 0)].fg, Color::Gray);
+        let shuffle_color = if shuffle { Color::Green } else { Color::Gray };
+        let loop_color = if looping { Color::Green } else { Color::Gray };
+        assert_eq!(buffer[(cell_x("shuffle"), 0)].fg, shuffle_color);
+        assert_eq!(buffer[(cell_x("loop"), 0)].fg, loop_color);
+    }
+
+    #[test]
+    fn test_slideshow_progress_fill_zero_delay() {
+        assert_eq!(
+            slideshow_progress_fill(Duration::ZERO, Duration::ZERO, 40),
+            40
+        );
+    }
+
+    #[test]
+    fn test_ui_renderer_slideshow_shuffle_icon() {
+        let localization = crate::localization::Localization::new("en").unwrap();
+        let area = Rect::new(0, 0, 80, 10);
+        let backend = ratatui::backend::TestBackend::new(80, 10);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        let mut status = SlideshowStatus {
+            current_image: 1,
+            total_images: 4,
+            shuffle: true,
+            looping: false,
+            delay: Duration::from_millis(2500),
+            file_name: None,
+            ..Default::default()
+        };
+        terminal
+            .draw(|f| UIRenderer::render_slideshow(f, area, None, &localization, &status))
+            .unwrap();
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(rendered.contains('🔀'));
+        assert!(rendered.contains(&localization.get("slideshow_loop_off")));
+        assert!(rendered.contains("2.5"));
+
+        status.shuffle = false;
+        terminal
+            .draw(|f| UIRenderer::render_slideshow(f, area, None, &localization, &status))
+            .unwrap();
+        let rendered: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(!rendered.contains('🔀'));
+    }
+
+    #[test]
+    fn test_ui_renderer_spinner_frame_advances() {
+        assert_eq!(UIRenderer::spinner_frame(Duration::from_millis(0)), '⣾');
+        assert_eq!(UIRenderer::spinner_frame(Duration::from_millis(150)), '⣽');
+        assert_eq!(UIRenderer::spinner_frame(Duration::from_millis(750)), '⣷');
+        // Wraps around after all eight frames
+        assert_eq!(UIRenderer::spinner_frame(Duration::from_millis(800)), '⣾');
+    }
+
+    #[test]
+    fn test_ui_renderer_loading_spinner() {
+        let area = Rect::new(0, 0, 40, 20);
+
+        let backend = ratatui::backend::TestBackend::new(40, 20);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                UIRenderer::render_loading_spinner(
+                    f,
+                    area,
+                    "photo.jpg",
+                    Duration::from_millis(250),
+                );
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let rendered: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(rendered.contains('⣻'));
+        assert!(rendered.contains("photo.jpg"));
+    }
+
+    #[test]
+    fn test_ui_renderer_localize_logo_text() {
+        let localization = crate::localization::Localization::new("en").unwrap();
+        let mut logo = Text::default();
+        logo.lines
+            .push(ratatui::text::Line::from(vec![ratatui::text::Span::from(
+                "Test {app_subtitle} v{version} Logo",
+            )]));
+
+        let localized = UIRenderer::localize_logo_text(&logo, &localization);
 
         let content = &localized.lines[0].spans[0].content;
         assert!(content.contains(&localization.get("app_subtitle")));
@@ -848,6 +2623,111 @@ mod tests {
         assert!(!content.contains("{version}"));
     }
 
+    #[test]
+    fn test_ui_layout_fullscreen_preview() {
+        let mut layout = UILayout::new();
+        layout.fullscreen_preview = true;
+        let area = Rect::new(0, 0, 100, 40);
+
+        let (file_area, preview_area, status_area) = layout.calculate_layout(area);
+
+        assert_eq!(file_area.width, 0);
+        assert_eq!(file_area.height, 0);
+        assert_eq!(preview_area.width, area.width);
+        assert_eq!(preview_area.height, area.height - 1);
+        assert_eq!(status_area.height, 1);
+        assert_eq!(status_area.y, preview_area.height);
+        assert_eq!(layout.preview_width, area.width - 2);
+    }
+
+    #[test]
+    fn test_ui_renderer_fullscreen_status() {
+        let localization = crate::localization::Localization::new("en").unwrap();
+        let area = Rect::new(0, 0, 80, 1);
+
+        let backend = ratatui::backend::TestBackend::new(80, 1);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                UIRenderer::render_fullscreen_status(f, area, &localization, "cat.png", 2, 7);
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let rendered: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(rendered.contains(&localization.get("fullscreen_mode")));
+        assert!(rendered.contains("cat.png 2/7"));
+    }
+
+    #[test]
+    fn test_ui_layout_grid_view() {
+        let mut layout = UILayout::new();
+        layout.toggle_view_mode();
+        assert_eq!(layout.view_mode, ViewMode::Grid);
+        let area = Rect::new(0, 0, 100, 40);
+
+        let (grid_area, preview_area, debug_area) = layout.calculate_layout(area);
+
+        assert_eq!(grid_area.width, area.width);
+        assert_eq!(preview_area.width, 0);
+        assert_eq!(grid_area.height + debug_area.height, area.height);
+
+        layout.toggle_view_mode();
+        assert_eq!(layout.view_mode, ViewMode::List);
+    }
+
+    #[test]
+    fn test_ui_renderer_grid_dimensions() {
+        // 100 - 2 border = 98 columns / 18 = 5 cells, 40 - 2 border = 38 rows / 4 = 9 rows
+        let (columns, rows) = UIRenderer::grid_dimensions(Rect::new(0, 0, 100, 40), 18);
+        assert_eq!(columns, 5);
+        assert_eq!(rows, 9);
+
+        // Always at least one cell, even in tiny areas
+        let (columns, rows) = UIRenderer::grid_dimensions(Rect::new(0, 0, 5, 5), 18);
+        assert_eq!(columns, 1);
+        assert_eq!(rows, 1);
+    }
+
+    #[test]
+    fn test_ui_renderer_file_grid() {
+        let temp_fs = TestFileSystem::new().unwrap();
+        temp_fs.create_file("notes.txt", "content").unwrap();
+        temp_fs.create_test_image("photo.jpg").unwrap();
+
+        let mut file_browser =
+            crate::file_browser::FileBrowser::new_with_dir(temp_fs.get_path()).unwrap();
+        let area = Rect::new(0, 0, 60, 24);
+        let (columns, rows) = UIRenderer::grid_dimensions(area, 18);
+        file_browser.update_max_visible_files(columns * rows);
+        file_browser.set_grid_columns(columns);
+
+        let thumbnails = vec![None, Some(Text::from("THUMB"))];
+
+        let backend = ratatui::backend::TestBackend::new(60, 24);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                UIRenderer::render_file_grid(
+                    f,
+                    area,
+                    &file_browser,
+                    &thumbnails,
+                    18,
+                    &FileIcons::default(),
+                );
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let rendered: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(rendered.contains("notes.txt"));
+        assert!(rendered.contains("photo.jpg"));
+        assert!(rendered.contains("THUMB"));
+    }
+
     #[test]
     fn test_ui_layout_constraints_consistency() {
         let mut layout = UILayout::new();
@@ -0,0 +1,141 @@
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+
+/// A change to a file in the watched directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileSystemEvent {
+    Modified(PathBuf),
+    Created(PathBuf),
+    Removed(PathBuf),
+}
+
+impl FileSystemEvent {
+    /// One event per path, or none for events that don't change files (like access)
+    fn from_notify(event: Event) -> Vec<Self> {
+        let kind: fn(PathBuf) -> Self = match event.kind {
+            EventKind::Create(_) => Self::Created,
+            EventKind::Modify(_) => Self::Modified,
+            EventKind::Remove(_) => Self::Removed,
+            _ => return Vec::new(),
+        };
+        event.paths.into_iter().map(kind).collect()
+    }
+
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Modified(path) | Self::Created(path) | Self::Removed(path) => path,
+        }
+    }
+}
+
+/// Watches the directory shown in the file browser for files being added, removed or changed.
+/// Uses the same notify backend (inotify, kqueue, ...) as the config watcher.
+pub struct FileWatcher {
+    watcher: RecommendedWatcher,
+    receiver: Receiver<Vec<FileSystemEvent>>,
+    watched_dir: Option<PathBuf>,
+}
+
+impl FileWatcher {
+    pub fn new() -> Result<Self, Box<dyn Error>> {
+        let (tx, receiver) = mpsc::channel();
+        let watcher = notify::recommended_watcher(move |res: Result<Event, notify::Error>| {
+            // Watch errors just mean a missed refresh, the user can still press r
+            if let Ok(event) = res {
+                let events = FileSystemEvent::from_notify(event);
+                if !events.is_empty() {
+                    let _ = tx.send(events);
+                }
+            }
+        })?;
+
+        Ok(Self {
+            watcher,
+            receiver,
+            watched_dir: None,
+        })
+    }
+
+    /// Watch a directory instead of the one watched before. Does nothing if it's unchanged.
+    pub fn watch(&mut self, dir: &Path) -> Result<(), Box<dyn Error>> {
+        if self.watched_dir.as_deref() == Some(dir) {
+            return Ok(());
+        }
+        if let Some(previous) = self.watched_dir.take() {
+            let _ = self.watcher.unwatch(&previous);
+        }
+        self.watcher.watch(dir, RecursiveMode::NonRecursive)?;
+        self.watched_dir = Some(dir.to_path_buf());
+        Ok(())
+    }
+
+    /// Changes since the last call
+    pub fn events(&self) -> Vec<FileSystemEvent> {
+        self.receiver.try_iter().flatten().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    fn wait_for_changes(watcher: &FileWatcher) -> Vec<FileSystemEvent> {
+        let started = Instant::now();
+        let mut changed = Vec::new();
+        while changed.is_empty() && started.elapsed() < Duration::from_secs(5) {
+            std::thread::sleep(Duration::from_millis(20));
+            changed = watcher.events();
+        }
+        changed
+    }
+
+    #[test]
+    fn test_file_watcher_reports_new_files() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut watcher = FileWatcher::new().unwrap();
+        watcher.watch(temp_dir.path()).unwrap();
+
+        let path = temp_dir.path().join("new.jpg");
+        std::fs::write(&path, b"data").unwrap();
+
+        let changed = wait_for_changes(&watcher);
+        assert!(changed.iter().any(|event| matches!(
+            event, FileSystemEvent::Created(path) if path.ends_with("new.jpg")
+        )));
+    }
+
+    #[test]
+    fn test_file_watcher_reports_removed_files() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("old.jpg");
+        std::fs::write(&path, b"data").unwrap();
+        let mut watcher = FileWatcher::new().unwrap();
+        watcher.watch(temp_dir.path()).unwrap();
+
+        std::fs::remove_file(&path).unwrap();
+
+        let changed = wait_for_changes(&watcher);
+        assert!(changed.contains(&FileSystemEvent::Removed(path)));
+    }
+
+    #[test]
+    fn test_file_watcher_switches_directory() {
+        let first = tempfile::TempDir::new().unwrap();
+        let second = tempfile::TempDir::new().unwrap();
+        let mut watcher = FileWatcher::new().unwrap();
+        watcher.watch(first.path()).unwrap();
+        watcher.watch(second.path()).unwrap();
+
+        std::fs::write(second.path().join("b.jpg"), b"data").unwrap();
+        let changed = wait_for_changes(&watcher);
+        assert!(
+            changed
+                .iter()
+                .all(|event| event.path().starts_with(second.path()))
+        );
+        assert!(!changed.is_empty());
+    }
+}
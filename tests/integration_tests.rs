@@ -1,3 +1,4 @@
+use clap::Parser;
 use ptui::*;
 use tempfile::TempDir;
 
@@ -23,10 +24,13 @@ fn test_file_browser_and_preview_integration() {
     if let Some(file) = file_browser.get_selected_file() {
         let preview = preview_manager.generate_preview(file, 80, 24, 0, &localization);
         match preview {
-            preview::PreviewContent::Text(text) => {
+            preview::PreviewContent::Text(text, _) => {
                 assert!(!text.lines.is_empty());
             }
-            preview::PreviewContent::Graphical(_) | preview::PreviewContent::Kitty(_) => {
+            preview::PreviewContent::Animated { frames, .. } => assert!(!frames.is_empty()),
+            preview::PreviewContent::Graphical(_)
+            | preview::PreviewContent::Kitty(_)
+            | preview::PreviewContent::Inline(_) => {
                 // Graphical/Kitty preview is also valid
             }
         }
@@ -52,6 +56,34 @@ fn test_config_and_converter_integration() {
     assert_eq!(jp2a_converter.get_name(), "jp2a");
 }
 
+#[test]
+fn test_iterm_converter_escape_sequence() {
+    use base64::Engine;
+    use converter::AsciiConverter;
+
+    let temp_dir = TempDir::new().unwrap();
+    let image_path = create_test_image(&temp_dir, "photo.jpg").unwrap();
+    let converter = converter::ITermConverter::new(config::ITermConfig::default());
+    assert_eq!(converter.get_name(), "iterm");
+    assert!(!converter.supports_transitions());
+
+    let output = converter.convert_image(&image_path, 80, 24).unwrap();
+    let payload = output
+        .strip_prefix("\x1b]1337;File=inline=1;width=80;height=24:")
+        .and_then(|rest| rest.strip_suffix('\x07'))
+        .expect("not an iTerm2 inline image escape");
+    let decoded = base64::engine::general_purpose::STANDARD
+        .decode(payload)
+        .unwrap();
+    assert_eq!(decoded, create_minimal_jpeg());
+
+    assert!(
+        converter
+            .convert_image("/no/such/photo.jpg", 80, 24)
+            .is_err()
+    );
+}
+
 #[test]
 fn test_localization_and_ui_integration() {
     let localization = localization::Localization::new("en").unwrap();
@@ -126,6 +158,43 @@ fn test_configuration_persistence() {
         locale: Some("fr".to_string()),
         slideshow_delay_ms: Some(5000),
         slideshow_transitions: Some(config::SlideshowTransitionConfig::default()),
+        grid_cell_width: None,
+        slideshow_loop: None,
+        slideshow_delay_step_factor: None,
+        slideshow_delay_min_ms: None,
+        slideshow_delay_max_ms: None,
+        preview_loading_timeout_ms: Some(30000),
+        prefer_native_dimension_detection: Some(true),
+        use_trash: Some(true),
+        open_with: Some(std::collections::HashMap::new()),
+        git_integration: Some(false),
+        watch_current_directory: Some(false),
+        restore_session: None,
+        debug_pane_text: None,
+        use_icons: None,
+        icon_preset: None,
+        file_icons: None,
+        theme: None,
+        show_progress_bar: None,
+        batch_export_concurrent: None,
+        preview_debounce_ms: None,
+        max_preview_lines: Some(10000),
+        resize_debounce_ms: Some(100),
+        show_image_counts: Some(false),
+        poll_interval_ms: Some(50),
+        fzf_integration: None,
+        logo_file: None,
+        logo_hidden: Some(false),
+        show_permissions: Some(false),
+        notification_duration_ms: Some(0),
+        audio_waveform: Some(false),
+        file_browser_width_percent: None,
+        file_browser_width_percent_wide: None,
+        file_browser_width_percent_narrow: None,
+        char_aspect_ratio: None,
+        slideshow_order: None,
+        text_scroll_lines: None,
+        cache_size_rounding: None,
         chafa: None,
     };
 
@@ -160,14 +229,20 @@ fn test_preview_caching_behavior() {
     let preview2 = preview_manager.generate_preview(&file_item, 80, 24, 0, &localization);
 
     match preview1 {
-        preview::PreviewContent::Text(text) => assert!(!text.lines.is_empty()),
-        preview::PreviewContent::Graphical(_) | preview::PreviewContent::Kitty(_) => {
+        preview::PreviewContent::Text(text, _) => assert!(!text.lines.is_empty()),
+        preview::PreviewContent::Animated { frames, .. } => assert!(!frames.is_empty()),
+        preview::PreviewContent::Graphical(_)
+        | preview::PreviewContent::Kitty(_)
+        | preview::PreviewContent::Inline(_) => {
             // Graphical/Kitty preview is also valid
         }
     }
     match preview2 {
-        preview::PreviewContent::Text(text) => assert!(!text.lines.is_empty()),
-        preview::PreviewContent::Graphical(_) | preview::PreviewContent::Kitty(_) => {
+        preview::PreviewContent::Text(text, _) => assert!(!text.lines.is_empty()),
+        preview::PreviewContent::Animated { frames, .. } => assert!(!frames.is_empty()),
+        preview::PreviewContent::Graphical(_)
+        | preview::PreviewContent::Kitty(_)
+        | preview::PreviewContent::Inline(_) => {
             // Graphical/Kitty preview is also valid
         }
     }
@@ -175,8 +250,11 @@ fn test_preview_caching_behavior() {
     preview_manager.clear_cache();
     let preview3 = preview_manager.generate_preview(&file_item, 80, 24, 0, &localization);
     match preview3 {
-        preview::PreviewContent::Text(text) => assert!(!text.lines.is_empty()),
-        preview::PreviewContent::Graphical(_) | preview::PreviewContent::Kitty(_) => {
+        preview::PreviewContent::Text(text, _) => assert!(!text.lines.is_empty()),
+        preview::PreviewContent::Animated { frames, .. } => assert!(!frames.is_empty()),
+        preview::PreviewContent::Graphical(_)
+        | preview::PreviewContent::Kitty(_)
+        | preview::PreviewContent::Inline(_) => {
             // Graphical/Kitty preview is also valid
         }
     }
@@ -199,6 +277,35 @@ fn test_ui_layout_responsiveness() {
     assert_eq!(large_debug.height, 3);
 }
 
+#[test]
+fn test_slideshow_cli_flag_starts_slideshow() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_image(&temp_dir, "first.jpg").unwrap();
+    let second = create_test_image(&temp_dir, "second.jpg").unwrap();
+
+    let start_app = |cli: &[&str]| {
+        let args = args::Args::try_parse_from(cli).unwrap();
+        app::ChafaTui::start(
+            config::PTuiConfig::default(),
+            localization::Localization::new("en").unwrap(),
+            &args,
+            None,
+            history::RecentFiles::default(),
+        )
+        .unwrap()
+    };
+
+    let dir = temp_dir.path().to_str().unwrap();
+    assert!(start_app(&["ptui", dir, "--slideshow"]).is_slideshow_mode());
+    assert!(start_app(&["ptui", "--path", &second, "--slideshow"]).is_slideshow_mode());
+    assert!(!start_app(&["ptui", dir]).is_slideshow_mode());
+
+    // An empty directory falls back to the file browser
+    let empty_dir = TempDir::new().unwrap();
+    let empty = empty_dir.path().to_str().unwrap();
+    assert!(!start_app(&["ptui", "--path", empty, "--slideshow"]).is_slideshow_mode());
+}
+
 #[test]
 fn test_multilingual_support() {
     let locales = ["en", "de", "es", "fr", "ja", "zh"];
@@ -260,3 +367,63 @@ fn create_test_image(temp_dir: &TempDir, name: &str) -> Result<String, Box<dyn s
 fn create_minimal_jpeg() -> &'static [u8] {
     b"\xFF\xD8\xFF\xE0\x00\x10JFIF\x00\x01\x01\x01\x00H\x00H\x00\x00\xFF\xDB\x00C\x00\x08\x06\x06\x07\x06\x05\x08\x07\x07\x07\t\t\x08\n\x0C\x14\r\x0C\x0B\x0B\x0C\x19\x12\x13\x0F\x14\x1D\x1A\x1F\x1E\x1D\x1A\x1C\x1C $.\' \",#\x1C\x1C(7),01444\x1F\'9=82<.342\xFF\xC0\x00\x11\x08\x00\x01\x00\x01\x01\x01\x11\x00\x02\x11\x01\x03\x11\x01\xFF\xC4\x00\x14\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x08\xFF\xC4\x00\x14\x10\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xFF\xDA\x00\x0C\x03\x01\x00\x02\x11\x03\x11\x00\x3F\x00\xAA\xFF\xD9"
 }
+
+#[test]
+fn test_print_bash_completions() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_ptui"))
+        .args(["--print-completions", "bash"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let script = String::from_utf8(output.stdout).unwrap();
+    assert!(script.contains("_ptui()"));
+    assert!(script.contains("--print-completions"));
+}
+
+#[test]
+fn test_graphical_preview_renders_kitty_escape_sequence() {
+    use ratatui_image::picker::{Picker, ProtocolType};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let mut picker = Picker::halfblocks();
+    picker.set_protocol_type(ProtocolType::Kitty);
+    let image = image::DynamicImage::new_rgb8(40, 40);
+    let preview =
+        preview::PreviewContent::Graphical(Rc::new(RefCell::new(preview::GraphicalPreview {
+            path: "black.png".to_string(),
+            width: 20,
+            height: 10,
+            img_width: 40,
+            img_height: 40,
+            protocol: picker.new_resize_protocol(image),
+            protocol_type: preview::TerminalGraphicsSupport::Kitty,
+            font_size: (10, 20),
+        })));
+    let localization = localization::Localization::new("en").unwrap();
+    let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(40, 20)).unwrap();
+
+    terminal
+        .draw(|f| {
+            ui::UIRenderer::render_preview(
+                f,
+                f.area(),
+                Some(&preview),
+                &localization,
+                None,
+                0,
+                &icons::FileIcons::default(),
+                None,
+            );
+        })
+        .unwrap();
+
+    let symbols: String = terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect();
+    assert!(symbols.contains("\x1b_G"));
+}
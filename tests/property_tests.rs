@@ -70,11 +70,53 @@ proptest! {
                 },
                 jp2a: config::Jp2aConfig::default(),
                 graphical: config::GraphicalConfig::default(),
+                heic: config::HeicConfig::default(),
+                raw: config::RawConfig::default(),
+                sixel: config::SixelConfig::default(),
+                iterm: config::ITermConfig::default(),
                 selected: converter_selected.clone(),
+                conversion_timeout_ms: Some(10000),
             },
             locale: locale.clone(),
             slideshow_delay_ms: delay_ms,
             slideshow_transitions: Some(config::SlideshowTransitionConfig::default()),
+            grid_cell_width: None,
+            slideshow_loop: None,
+            slideshow_delay_step_factor: None,
+            slideshow_delay_min_ms: None,
+            slideshow_delay_max_ms: None,
+            preview_loading_timeout_ms: Some(30000),
+            prefer_native_dimension_detection: Some(true),
+            use_trash: Some(true),
+            open_with: Some(std::collections::HashMap::new()),
+            git_integration: Some(false),
+            watch_current_directory: Some(false),
+            restore_session: None,
+            debug_pane_text: None,
+            use_icons: None,
+            icon_preset: None,
+            file_icons: None,
+            theme: None,
+            show_progress_bar: None,
+            batch_export_concurrent: None,
+            preview_debounce_ms: None,
+            max_preview_lines: Some(10000),
+            resize_debounce_ms: Some(100),
+            show_image_counts: Some(false),
+            poll_interval_ms: Some(50),
+            fzf_integration: None,
+            logo_file: None,
+            logo_hidden: Some(false),
+            show_permissions: Some(false),
+            notification_duration_ms: Some(0),
+            audio_waveform: Some(false),
+            file_browser_width_percent: None,
+            file_browser_width_percent_wide: None,
+            file_browser_width_percent_narrow: None,
+            char_aspect_ratio: None,
+            slideshow_order: None,
+            text_scroll_lines: None,
+            cache_size_rounding: None,
             chafa: None,
         };
 
@@ -171,7 +213,12 @@ proptest! {
                     chars: None,
                 },
                 graphical: config::GraphicalConfig::default(),
+                heic: config::HeicConfig::default(),
+                raw: config::RawConfig::default(),
+                sixel: config::SixelConfig::default(),
+                iterm: config::ITermConfig::default(),
                 selected: selected.clone(),
+                conversion_timeout_ms: Some(10000),
             },
             ..Default::default()
         };
@@ -224,6 +271,36 @@ proptest! {
         }
     }
 
+    #[test]
+    fn test_avif_magic_byte_detection(
+        filename in "[a-zA-Z0-9_-]{1,20}",
+        brand in "(avif|avis)",
+        box_size in 16u32..64u32,
+        payload in prop::collection::vec(any::<u8>(), 0..64),
+    ) {
+        let temp_dir = TempDir::new().unwrap();
+        let full_filename = format!("{}.test", filename);
+        let file_path = temp_dir.path().join(&full_filename);
+
+        let mut content = box_size.to_be_bytes().to_vec();
+        content.extend_from_slice(b"ftyp");
+        content.extend_from_slice(brand.as_bytes());
+        // The NUL minor version keeps the sample binary regardless of the payload
+        content.extend_from_slice(&[0, 0, 0, 0]);
+        content.extend_from_slice(&payload);
+        std::fs::write(&file_path, &content).unwrap();
+
+        let file_item = file_browser::FileItem::new(
+            full_filename,
+            file_path.to_string_lossy().to_string(),
+            false,
+            UNIX_EPOCH,
+        );
+        prop_assert!(file_item.is_image());
+        prop_assert!(!file_item.is_video());
+        prop_assert!(file_item.can_preview());
+    }
+
     #[test]
     fn test_content_based_file_detection(
         filename in "[a-zA-Z0-9_-]{1,20}",
@@ -299,4 +376,92 @@ proptest! {
             _ => unreachable!()
         }
     }
+
+    #[test]
+    fn test_transition_prerenders_frame_count_plus_one_frames(
+        frame_count in 5u32..=120u32,
+        effect in prop::sample::select(vec!["scattering", "typewriter", "fade", "wipe_down"]),
+    ) {
+        let transitions = config::SlideshowTransitionConfig {
+            enabled: true,
+            effect: effect.to_string(),
+            frame_duration_ms: 50,
+            frame_count: Some(frame_count),
+            direction_aware: false,
+            easing: None,
+        };
+        let mut manager = transitions::TransitionManager::new(transitions);
+        let from = ratatui::text::Text::from("old\nimage");
+        let to = ratatui::text::Text::from("new\nimage");
+
+        prop_assert!(manager.start_transition(
+            &from,
+            &to,
+            transitions::Direction::Forward,
+        ));
+        prop_assert_eq!(manager.cached_frame_count(), frame_count as usize + 1);
+    }
+
+    #[test]
+    fn test_help_scroll_stays_within_help_text(
+        keys in prop::collection::vec(prop::sample::select(vec!['u', ' ', 'd']), 0..40),
+    ) {
+        let temp_dir = TempDir::new().unwrap();
+        let file_browser = file_browser::FileBrowser::new_with_dir(temp_dir.path()).unwrap();
+        let mut app = ChafaTui::with_parts(
+            config::PTuiConfig::default(),
+            file_browser,
+            localization::Localization::new("en").unwrap(),
+        );
+
+        for key in keys {
+            let event = crossterm::event::KeyEvent::new(
+                crossterm::event::KeyCode::Char(key),
+                crossterm::event::KeyModifiers::NONE,
+            );
+            prop_assert!(app.handle_key_event(event).is_ok());
+            prop_assert!(app.help_scroll_offset() < app.help_line_count());
+        }
+    }
+}
+
+// The effects that show the old image untouched before anything changes
+const EFFECTS_STARTING_FROM_THE_OLD_IMAGE: [&str; 3] = ["fade", "wipe_down", "wipe_up"];
+
+proptest! {
+    #[test]
+    fn test_transitions_start_from_the_old_image(
+        effect in prop::sample::select(EFFECTS_STARTING_FROM_THE_OLD_IMAGE.to_vec()),
+        from in "[a-zA-Z\n]{1,500}",
+        to in "[a-zA-Z\n]{1,500}",
+    ) {
+        let config = config::SlideshowTransitionConfig {
+            enabled: true,
+            effect: effect.to_string(),
+            // Long enough that the first frame is still showing when it's checked
+            frame_duration_ms: 60_000,
+            frame_count: Some(10),
+            direction_aware: false,
+            easing: None,
+        };
+        let mut manager = transitions::TransitionManager::new(config);
+        let started = manager.start_transition(
+            &ratatui::text::Text::from(from.clone()),
+            &ratatui::text::Text::from(to.clone()),
+            transitions::Direction::Forward,
+        );
+        prop_assert!(started);
+        prop_assert_eq!(manager.cached_frame_count(), 11);
+
+        // Wipes pad the old image with blank lines to the new one's height
+        let frame = manager.get_current_transition_frame().unwrap();
+        let shown: Vec<String> = frame.lines.iter().map(|line| line.to_string()).collect();
+        let shown = shown.join("\n");
+        prop_assert_eq!(
+            shown.trim_end_matches('\n'),
+            from.trim_end_matches('\n'),
+            "first {} frame",
+            effect
+        );
+    }
 }